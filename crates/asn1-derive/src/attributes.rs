@@ -0,0 +1,102 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Attribute, LitInt, LitStr, Path};
+
+const CONTEXT_SPECIFIC: u8 = 0x80;
+const APPLICATION: u8 = 0x40;
+const PRIVATE: u8 = 0xc0;
+
+/// Tag class and number from the `context`, `application`, or `private` attribute.
+#[derive(Clone, Copy)]
+pub struct TagAttribute {
+    pub class: u8,
+    pub number: u32,
+}
+
+impl TagAttribute {
+    /// Returns the tag expression. The `constructed` can be a `bool` literal or an expression.
+    pub fn to_tokens(self, constructed: impl ToTokens) -> TokenStream {
+        let TagAttribute { class, number } = self;
+
+        quote! { ::asn1_parser::derive_support::tag(#class, #constructed, #number) }
+    }
+}
+
+/// Parsed `#[asn1(...)]` attributes.
+#[derive(Default)]
+pub struct Asn1Attributes {
+    pub tag: Option<TagAttribute>,
+    pub implicit: bool,
+    pub optional: bool,
+    pub default: Option<Path>,
+    span: Option<Span>,
+}
+
+impl Asn1Attributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut attributes = Self::default();
+        let mut explicit = false;
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("asn1")) {
+            attributes.span = Some(
+                attr.path()
+                    .get_ident()
+                    .map(|ident| ident.span())
+                    .unwrap_or_else(Span::call_site),
+            );
+
+            attr.parse_nested_meta(|meta| {
+                let class = if meta.path.is_ident("context") {
+                    Some(CONTEXT_SPECIFIC)
+                } else if meta.path.is_ident("application") {
+                    Some(APPLICATION)
+                } else if meta.path.is_ident("private") {
+                    Some(PRIVATE)
+                } else {
+                    None
+                };
+
+                if let Some(class) = class {
+                    if attributes.tag.is_some() {
+                        return Err(meta.error("only one tag can be specified"));
+                    }
+
+                    let number: LitInt = meta.value()?.parse()?;
+                    attributes.tag = Some(TagAttribute {
+                        class,
+                        number: number.base10_parse()?,
+                    });
+                } else if meta.path.is_ident("explicit") {
+                    explicit = true;
+                } else if meta.path.is_ident("implicit") {
+                    attributes.implicit = true;
+                } else if meta.path.is_ident("optional") {
+                    attributes.optional = true;
+                } else if meta.path.is_ident("default") {
+                    let path: LitStr = meta.value()?.parse()?;
+                    attributes.default = Some(path.parse()?);
+                } else {
+                    return Err(meta.error("unsupported asn1 attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        if explicit && attributes.implicit {
+            return Err(attributes.error("tag can not be explicit and implicit at the same time"));
+        }
+        if (explicit || attributes.implicit) && attributes.tag.is_none() {
+            return Err(attributes.error("tagging mode requires the tag: context, application, or private"));
+        }
+        if attributes.optional && attributes.default.is_some() {
+            return Err(attributes.error("field can not be optional and have the default value at the same time"));
+        }
+
+        Ok(attributes)
+    }
+
+    pub fn error(&self, message: &str) -> syn::Error {
+        syn::Error::new(self.span.unwrap_or_else(Span::call_site), message)
+    }
+}
@@ -0,0 +1,138 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput, LitStr};
+
+use crate::{data_error, data_lifetime, parse_variants, Sequence, Tagging, Variant};
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (lifetime, declared) = data_lifetime(&input.generics)?;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let impl_generics = if declared {
+        quote! { <#lifetime> }
+    } else {
+        quote! { <'data> }
+    };
+
+    let (tag_pattern, decode, compare_tags) = match &input.data {
+        Data::Struct(data) => decode_sequence(input, &Sequence::parse(input, data)?),
+        Data::Enum(data) => decode_choice(input, &lifetime, &parse_variants(input, data)?),
+        Data::Union(_) => return Err(data_error(input)),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::asn1_parser::Asn1ValueDecoder<#lifetime> for #name #ty_generics {
+            fn decode(
+                #tag_pattern: ::asn1_parser::Tag,
+                reader: &mut ::asn1_parser::derive_support::Reader<#lifetime>,
+            ) -> ::asn1_parser::Asn1Result<Self> {
+                #decode
+            }
+
+            fn compare_tags(tag: ::asn1_parser::Tag) -> bool {
+                #compare_tags
+            }
+        }
+    })
+}
+
+/// Returns the tag parameter pattern, the `decode` body, and the `compare_tags` body.
+fn decode_sequence(input: &DeriveInput, sequence: &Sequence) -> (TokenStream, TokenStream, TokenStream) {
+    let name = input.ident.to_string();
+
+    let fields = sequence.fields.iter().map(|field| {
+        let binding = &field.binding;
+        let (ty, unwrap) = field.tagging.decoder_type(&field.ty);
+        let member = &field.member;
+        let error = LitStr::new(
+            &format!("{}.{} is missing or has unexpected tag", name, quote! { #member }),
+            Span::call_site(),
+        );
+
+        let decode_field = quote! { ::asn1_parser::derive_support::decode_field::<#ty>(reader, #error)?#unwrap };
+        let next_is = quote! { ::asn1_parser::derive_support::next_is::<#ty>(reader)? };
+
+        let value = if field.optional {
+            quote! { if #next_is { Some(#decode_field) } else { None } }
+        } else if let Some(default) = &field.default {
+            quote! { if #next_is { #decode_field } else { #default() } }
+        } else {
+            decode_field
+        };
+
+        quote! { let #binding = #value; }
+    });
+
+    let members = sequence.fields.iter().map(|field| &field.member);
+    let bindings = sequence.fields.iter().map(|field| &field.binding);
+    let value = if sequence.named {
+        quote! { Self { #(#members: #bindings),* } }
+    } else {
+        quote! { Self(#(#bindings),*) }
+    };
+
+    let trailing_data_error = LitStr::new(&format!("{} has unexpected trailing data", name), Span::call_site());
+    let decode_fields = quote! {
+        #(#fields)*
+
+        if !reader.empty() {
+            return Err(::asn1_parser::Error::from(#trailing_data_error));
+        }
+
+        Ok(#value)
+    };
+
+    let decode = match sequence.tagging {
+        Tagging::Explicit(_) => quote! {
+            let value = ::asn1_parser::derive_support::decode_sequence(reader, |reader| { #decode_fields })?;
+
+            if !reader.empty() {
+                return Err(::asn1_parser::Error::from(#trailing_data_error));
+            }
+
+            Ok(value)
+        },
+        Tagging::None | Tagging::Implicit(_) => decode_fields,
+    };
+
+    let tag = sequence.tag();
+
+    (quote! { _ }, decode, quote! { tag == #tag })
+}
+
+/// Returns the tag parameter pattern, the `decode` body, and the `compare_tags` body.
+fn decode_choice(
+    input: &DeriveInput,
+    lifetime: &syn::Lifetime,
+    variants: &[Variant],
+) -> (TokenStream, TokenStream, TokenStream) {
+    let alternatives = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let (ty, unwrap) = variant.tagging.decoder_type(&variant.ty);
+
+        quote! {
+            if <#ty as ::asn1_parser::Asn1ValueDecoder<#lifetime>>::compare_tags(tag) {
+                return Ok(Self::#ident(
+                    <#ty as ::asn1_parser::Asn1ValueDecoder<#lifetime>>::decode(tag, reader)?#unwrap,
+                ));
+            }
+        }
+    });
+    let error = LitStr::new(
+        &format!("{} has no alternative for the tag", input.ident),
+        Span::call_site(),
+    );
+    let decode = quote! {
+        #(#alternatives)*
+
+        Err(::asn1_parser::Error::from(#error))
+    };
+
+    let compare_tags = variants.iter().map(|variant| {
+        let (ty, _) = variant.tagging.decoder_type(&variant.ty);
+
+        quote! { <#ty as ::asn1_parser::Asn1ValueDecoder<#lifetime>>::compare_tags(tag) }
+    });
+
+    (quote! { tag }, decode, quote! { false #(|| #compare_tags)* })
+}
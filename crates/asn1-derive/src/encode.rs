@@ -0,0 +1,170 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput};
+
+use crate::{data_error, data_lifetime, parse_variants, Sequence, Tagging, Variant};
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    data_lifetime(&input.generics)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let (tag, needed_buf_size, encode) = match &input.data {
+        Data::Struct(data) => encode_sequence(&Sequence::parse(input, data)?),
+        Data::Enum(data) => encode_choice(&parse_variants(input, data)?),
+        Data::Union(_) => return Err(data_error(input)),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::asn1_parser::Taggable for #name #ty_generics #where_clause {
+            fn tag(&self) -> ::asn1_parser::Tag {
+                #tag
+            }
+        }
+
+        impl #impl_generics ::asn1_parser::Asn1Encoder for #name #ty_generics #where_clause {
+            fn needed_buf_size(&self) -> usize {
+                #needed_buf_size
+            }
+
+            fn encode(&self, writer: &mut ::asn1_parser::derive_support::Writer) -> ::asn1_parser::Asn1Result<()> {
+                #encode
+            }
+        }
+    })
+}
+
+/// Returns the `tag`, `needed_buf_size`, and `encode` bodies.
+fn encode_sequence(sequence: &Sequence) -> (TokenStream, TokenStream, TokenStream) {
+    let fields_len = sequence.fields.iter().map(|field| {
+        let member = &field.member;
+
+        if field.optional {
+            let len = field.tagging.encoded_len(&quote! { value });
+            quote! { self.#member.as_ref().map(|value| #len).unwrap_or(0) }
+        } else if let Some(default) = &field.default {
+            let len = field.tagging.encoded_len(&quote! { &self.#member });
+            quote! { if self.#member == #default() { 0 } else { #len } }
+        } else {
+            field.tagging.encoded_len(&quote! { &self.#member })
+        }
+    });
+    let data_len = quote! { 0 #(+ #fields_len)* };
+
+    let fields = sequence.fields.iter().map(|field| {
+        let member = &field.member;
+
+        if field.optional {
+            let encode = field.tagging.encode(&quote! { value });
+            quote! {
+                if let Some(value) = self.#member.as_ref() {
+                    #encode?;
+                }
+            }
+        } else if let Some(default) = &field.default {
+            // DER requires the value equal to the default one to be omitted.
+            let encode = field.tagging.encode(&quote! { &self.#member });
+            quote! {
+                if self.#member != #default() {
+                    #encode?;
+                }
+            }
+        } else {
+            let encode = field.tagging.encode(&quote! { &self.#member });
+            quote! { #encode?; }
+        }
+    });
+
+    let tag = sequence.tag();
+    let (needed_buf_size, header) = match sequence.tagging {
+        Tagging::Explicit(_) => (
+            quote! {
+                ::asn1_parser::derive_support::tlv_len(
+                    #tag,
+                    ::asn1_parser::derive_support::tlv_len(::asn1_parser::Sequence::TAG, data_len),
+                )
+            },
+            quote! {
+                ::asn1_parser::derive_support::write_header(
+                    #tag,
+                    ::asn1_parser::derive_support::tlv_len(::asn1_parser::Sequence::TAG, data_len),
+                    writer,
+                )?;
+                ::asn1_parser::derive_support::write_header(::asn1_parser::Sequence::TAG, data_len, writer)?;
+            },
+        ),
+        Tagging::None | Tagging::Implicit(_) => (
+            quote! { ::asn1_parser::derive_support::tlv_len(#tag, data_len) },
+            quote! { ::asn1_parser::derive_support::write_header(#tag, data_len, writer)?; },
+        ),
+    };
+
+    (
+        tag,
+        quote! {
+            let data_len = #data_len;
+
+            #needed_buf_size
+        },
+        quote! {
+            let data_len = #data_len;
+
+            #header
+            #(#fields)*
+
+            Ok(())
+        },
+    )
+}
+
+/// Returns the `tag`, `needed_buf_size`, and `encode` bodies.
+fn encode_choice(variants: &[Variant]) -> (TokenStream, TokenStream, TokenStream) {
+    let value = quote! { value };
+
+    let tags = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        match variant.tagging {
+            Tagging::None => quote! { Self::#ident(value) => ::asn1_parser::Taggable::tag(value), },
+            Tagging::Explicit(tag) => {
+                let tag = tag.to_tokens(true);
+                quote! { Self::#ident(_) => #tag, }
+            }
+            Tagging::Implicit(tag) => {
+                let tag = tag.to_tokens(quote! { ::asn1_parser::Taggable::tag(value).is_constructed() });
+                quote! { Self::#ident(value) => #tag, }
+            }
+        }
+    });
+
+    let lens = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let len = variant.tagging.encoded_len(&value);
+
+        quote! { Self::#ident(value) => #len, }
+    });
+
+    let encodes = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let encode = variant.tagging.encode(&value);
+
+        quote! { Self::#ident(value) => #encode, }
+    });
+
+    (
+        quote! {
+            match self {
+                #(#tags)*
+            }
+        },
+        quote! {
+            match self {
+                #(#lens)*
+            }
+        },
+        quote! {
+            match self {
+                #(#encodes)*
+            }
+        },
+    )
+}
@@ -0,0 +1,250 @@
+//! Derive macros for the `asn1-parser` crate.
+//!
+//! Structs are encoded as `SEQUENCE` and enums are encoded as `CHOICE`. See the README for supported attributes.
+
+mod attributes;
+mod decode;
+mod encode;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, DataEnum, DataStruct, DeriveInput, Fields, GenericArgument, Generics, Ident, Lifetime, Member,
+    PathArguments, Type,
+};
+
+use crate::attributes::{Asn1Attributes, TagAttribute};
+
+#[proc_macro_derive(Asn1Decode, attributes(asn1))]
+pub fn derive_asn1_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    decode::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(Asn1Encode, attributes(asn1))]
+pub fn derive_asn1_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    encode::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Tagging of the field, variant, or container.
+#[derive(Clone, Copy)]
+enum Tagging {
+    None,
+    Explicit(TagAttribute),
+    Implicit(TagAttribute),
+}
+
+impl Tagging {
+    fn from_attributes(attributes: &Asn1Attributes) -> Self {
+        match attributes.tag {
+            None => Tagging::None,
+            Some(tag) if attributes.implicit => Tagging::Implicit(tag),
+            Some(tag) => Tagging::Explicit(tag),
+        }
+    }
+
+    /// Returns the type used for decoding and the suffix to get the decoded value from it.
+    fn decoder_type(self, ty: &Type) -> (TokenStream2, TokenStream2) {
+        match self {
+            Tagging::None => (quote! { #ty }, quote! {}),
+            Tagging::Explicit(TagAttribute { class, number }) => (
+                quote! { ::asn1_parser::derive_support::Explicit<#ty, #class, #number> },
+                quote! { .0 },
+            ),
+            Tagging::Implicit(TagAttribute { class, number }) => (
+                quote! { ::asn1_parser::derive_support::Implicit<#ty, #class, #number> },
+                quote! { .0 },
+            ),
+        }
+    }
+
+    /// Returns the expression that calculates the encoded length of the `value` reference.
+    fn encoded_len(self, value: &TokenStream2) -> TokenStream2 {
+        match self {
+            Tagging::None => quote! { ::asn1_parser::Asn1Encoder::needed_buf_size(#value) },
+            Tagging::Explicit(tag) => {
+                let tag = tag.to_tokens(true);
+                quote! { ::asn1_parser::derive_support::explicit_len(#tag, #value) }
+            }
+            Tagging::Implicit(tag) => {
+                let tag = tag.to_tokens(false);
+                quote! { ::asn1_parser::derive_support::implicit_len(#tag, #value) }
+            }
+        }
+    }
+
+    /// Returns the expression that encodes the `value` reference into the `writer`.
+    fn encode(self, value: &TokenStream2) -> TokenStream2 {
+        match self {
+            Tagging::None => quote! { ::asn1_parser::Asn1Encoder::encode(#value, writer) },
+            Tagging::Explicit(tag) => {
+                let tag = tag.to_tokens(true);
+                quote! { ::asn1_parser::derive_support::encode_explicit(#tag, #value, writer) }
+            }
+            Tagging::Implicit(tag) => {
+                let tag = tag.to_tokens(false);
+                quote! { ::asn1_parser::derive_support::encode_implicit(#tag, #value, writer) }
+            }
+        }
+    }
+}
+
+/// Field of the struct (SEQUENCE component).
+struct Field {
+    member: Member,
+    binding: Ident,
+    /// Field type. For the optional fields, it is the type inside the `Option`.
+    ty: Type,
+    tagging: Tagging,
+    optional: bool,
+    default: Option<syn::Path>,
+}
+
+/// Variant of the enum (CHOICE alternative).
+struct Variant {
+    ident: Ident,
+    ty: Type,
+    tagging: Tagging,
+}
+
+/// Struct container: its outer tagging and fields.
+struct Sequence {
+    tagging: Tagging,
+    fields: Vec<Field>,
+    named: bool,
+}
+
+impl Sequence {
+    fn parse(input: &DeriveInput, data: &DataStruct) -> syn::Result<Self> {
+        let attributes = Asn1Attributes::parse(&input.attrs)?;
+        if attributes.optional || attributes.default.is_some() {
+            return Err(attributes.error("struct can not be optional or have the default value"));
+        }
+
+        let fields = data
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let attributes = Asn1Attributes::parse(&field.attrs)?;
+
+                let ty = if attributes.optional {
+                    option_inner_type(&field.ty)
+                        .ok_or_else(|| attributes.error("optional field should have the Option<T> type"))?
+                        .clone()
+                } else {
+                    field.ty.clone()
+                };
+
+                Ok(Field {
+                    member: field
+                        .ident
+                        .clone()
+                        .map(Member::Named)
+                        .unwrap_or_else(|| Member::Unnamed(index.into())),
+                    binding: format_ident!("__field{}", index),
+                    ty,
+                    tagging: Tagging::from_attributes(&attributes),
+                    optional: attributes.optional,
+                    default: attributes.default,
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            tagging: Tagging::from_attributes(&attributes),
+            fields,
+            named: matches!(data.fields, Fields::Named(_)),
+        })
+    }
+
+    /// Returns the outer tag of the encoded struct.
+    fn tag(&self) -> TokenStream2 {
+        match self.tagging {
+            Tagging::None => quote! { ::asn1_parser::Sequence::TAG },
+            Tagging::Explicit(tag) | Tagging::Implicit(tag) => tag.to_tokens(true),
+        }
+    }
+}
+
+fn parse_variants(input: &DeriveInput, data: &DataEnum) -> syn::Result<Vec<Variant>> {
+    let attributes = Asn1Attributes::parse(&input.attrs)?;
+    if attributes.tag.is_some() || attributes.optional || attributes.default.is_some() {
+        return Err(
+            attributes.error("CHOICE can not be tagged, optional, or have the default value. Tag the field instead")
+        );
+    }
+
+    data.variants
+        .iter()
+        .map(|variant| {
+            let attributes = Asn1Attributes::parse(&variant.attrs)?;
+            if attributes.optional || attributes.default.is_some() {
+                return Err(attributes.error("CHOICE alternative can not be optional or have the default value"));
+            }
+
+            let ty = match &variant.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed[0].ty.clone(),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "CHOICE alternative should have exactly one unnamed field",
+                    ))
+                }
+            };
+
+            Ok(Variant {
+                ident: variant.ident.clone(),
+                ty,
+                tagging: Tagging::from_attributes(&attributes),
+            })
+        })
+        .collect()
+}
+
+/// Returns the lifetime of the decoded data: the type's lifetime parameter or a new one.
+fn data_lifetime(generics: &Generics) -> syn::Result<(Lifetime, bool)> {
+    if generics.type_params().next().is_some() || generics.const_params().next().is_some() {
+        return Err(syn::Error::new_spanned(generics, "generic types are not supported"));
+    }
+
+    let mut lifetimes = generics.lifetimes();
+    match (lifetimes.next(), lifetimes.next()) {
+        (None, _) => Ok((Lifetime::new("'data", proc_macro2::Span::call_site()), false)),
+        (Some(lifetime), None) => Ok((lifetime.lifetime.clone(), true)),
+        (Some(_), Some(_)) => Err(syn::Error::new_spanned(
+            generics,
+            "only one lifetime parameter is supported",
+        )),
+    }
+}
+
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+fn data_error(input: &DeriveInput) -> syn::Error {
+    syn::Error::new_spanned(&input.ident, "only structs and enums are supported")
+}
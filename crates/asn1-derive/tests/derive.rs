@@ -0,0 +1,84 @@
+use asn1_derive::{Asn1Decode, Asn1Encode};
+use asn1_parser::{
+    Asn1Decoder, Asn1Encoder, Bool, Integer, OctetString, OwnedInteger, OwnedOctetString, Taggable, Tlv, Utf8String,
+};
+
+fn default_flag() -> Bool {
+    Bool::from(false)
+}
+
+#[derive(Debug, PartialEq, Asn1Encode, Asn1Decode)]
+#[asn1(application = 1)]
+struct Message<'data> {
+    #[asn1(context = 0)]
+    version: Integer<'data>,
+    #[asn1(context = 1, implicit, optional)]
+    name: Option<Utf8String<'data>>,
+    #[asn1(default = "default_flag")]
+    flag: Bool,
+    value: Value<'data>,
+}
+
+#[derive(Debug, PartialEq, Asn1Encode, Asn1Decode)]
+enum Value<'data> {
+    Integer(Integer<'data>),
+    #[asn1(context = 2, implicit)]
+    Octets(OctetString<'data>),
+    #[asn1(private = 40)]
+    Flag(Bool),
+}
+
+#[derive(Debug, PartialEq, Asn1Encode, Asn1Decode)]
+#[asn1(context = 3, implicit)]
+struct Pair<'data>(Integer<'data>, Bool);
+
+fn round_trip<'data, T>(value: &T, buff: &'data mut Vec<u8>) -> T
+where
+    T: Asn1Encoder + asn1_parser::Asn1ValueDecoder<'data>,
+{
+    *buff = vec![0; value.needed_buf_size()];
+    value.encode_buff(buff).unwrap();
+
+    Tlv::<T>::decode_buff(buff).unwrap().into_inner()
+}
+
+#[test]
+fn sequence() {
+    let raw = [
+        0x61, 0x0f, 0x30, 0x0d, 0xa0, 0x03, 0x02, 0x01, 0x05, 0x81, 0x02, 0x61, 0x62, 0x82, 0x02, 0xaa, 0xbb,
+    ];
+    let message = Tlv::<Message>::decode_buff(&raw).unwrap().into_inner();
+    assert_eq!(message.version, OwnedInteger::from(5));
+    assert_eq!(message.name.as_ref().map(|name| name.string()), Some("ab"));
+    assert_eq!(message.flag, Bool::from(false));
+    assert_eq!(message.value, Value::Octets(OwnedOctetString::from(vec![0xaa, 0xbb])));
+    assert_eq!(message.tag().number(), 1);
+
+    let mut buff = vec![0; message.needed_buf_size()];
+    message.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // optional and default fields are omitted
+    let message = Message {
+        version: OwnedInteger::from(1),
+        name: None,
+        flag: Bool::from(true),
+        value: Value::Flag(Bool::from(true)),
+    };
+    let mut buff = Vec::new();
+    assert_eq!(round_trip(&message, &mut buff), message);
+    assert_eq!(
+        buff,
+        [0x61, 0x10, 0x30, 0x0e, 0xa0, 0x03, 0x02, 0x01, 0x01, 0x01, 0x01, 0xff, 0xff, 0x28, 0x03, 0x01, 0x01, 0xff]
+    );
+
+    let pair = Pair(OwnedInteger::from(7), Bool::from(true));
+    let mut buff = Vec::new();
+    assert_eq!(round_trip(&pair, &mut buff), pair);
+    assert_eq!(buff, [0xa3, 0x06, 0x02, 0x01, 0x07, 0x01, 0x01, 0xff]);
+
+    // the required field is missing
+    assert!(Tlv::<Message>::decode_buff(&[0x61, 0x07, 0x30, 0x05, 0xa0, 0x03, 0x02, 0x01, 0x05]).is_err());
+    // trailing data
+    assert!(Tlv::<Pair>::decode_buff(&[0xa3, 0x08, 0x02, 0x01, 0x07, 0x01, 0x01, 0xff, 0x05, 0x00]).is_err());
+}
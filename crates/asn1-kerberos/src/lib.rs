@@ -0,0 +1,12 @@
+//! Typed [RFC 4120](https://www.rfc-editor.org/rfc/rfc4120) Kerberos messages built on top of the `asn1-parser` crate.
+//!
+//! Every message is decoded using the `Tlv`: `Tlv::<KrbMessage>::decode_buff(raw)?.into_inner()`.
+
+mod messages;
+mod types;
+
+pub use messages::{ApReq, AsRep, AsReq, KrbError, KrbMessage, TgsRep, TgsReq};
+pub use types::{
+    EncryptedData, HostAddress, Int32, KdcReqBody, KerberosFlags, KerberosString, KerberosTime, PaData, PrincipalName,
+    Realm, Ticket,
+};
@@ -0,0 +1,144 @@
+use asn1_derive::{Asn1Decode, Asn1Encode};
+use asn1_parser::{OctetString, SequenceOf};
+
+use crate::types::{
+    EncryptedData, Int32, KdcReqBody, KerberosFlags, KerberosString, KerberosTime, PaData, PrincipalName, Realm, Ticket,
+};
+
+/// Defines the [KDC-REQ](https://www.rfc-editor.org/rfc/rfc4120#section-5.4.1) message with the provided application tag.
+macro_rules! kdc_req {
+    ($(#[$meta:meta])* $name:ident, $number:tt) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Asn1Encode, Asn1Decode)]
+        #[asn1(application = $number)]
+        pub struct $name<'data> {
+            #[asn1(context = 1)]
+            pub pvno: Int32<'data>,
+            #[asn1(context = 2)]
+            pub msg_type: Int32<'data>,
+            #[asn1(context = 3, optional)]
+            pub padata: Option<SequenceOf<PaData<'data>>>,
+            #[asn1(context = 4)]
+            pub req_body: KdcReqBody<'data>,
+        }
+    };
+}
+
+/// Defines the [KDC-REP](https://www.rfc-editor.org/rfc/rfc4120#section-5.4.2) message with the provided application tag.
+macro_rules! kdc_rep {
+    ($(#[$meta:meta])* $name:ident, $number:tt) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Asn1Encode, Asn1Decode)]
+        #[asn1(application = $number)]
+        pub struct $name<'data> {
+            #[asn1(context = 0)]
+            pub pvno: Int32<'data>,
+            #[asn1(context = 1)]
+            pub msg_type: Int32<'data>,
+            #[asn1(context = 2, optional)]
+            pub padata: Option<SequenceOf<PaData<'data>>>,
+            #[asn1(context = 3)]
+            pub crealm: Realm<'data>,
+            #[asn1(context = 4)]
+            pub cname: PrincipalName<'data>,
+            #[asn1(context = 5)]
+            pub ticket: Ticket<'data>,
+            #[asn1(context = 6)]
+            pub enc_part: EncryptedData<'data>,
+        }
+    };
+}
+
+kdc_req!(
+    /// AS-REQ: `[APPLICATION 10] KDC-REQ`
+    AsReq,
+    10
+);
+kdc_rep!(
+    /// AS-REP: `[APPLICATION 11] KDC-REP`
+    AsRep,
+    11
+);
+kdc_req!(
+    /// TGS-REQ: `[APPLICATION 12] KDC-REQ`
+    TgsReq,
+    12
+);
+kdc_rep!(
+    /// TGS-REP: `[APPLICATION 13] KDC-REP`
+    TgsRep,
+    13
+);
+
+/// [AP-REQ](https://www.rfc-editor.org/rfc/rfc4120#section-5.5.1)
+#[derive(Debug, Clone, PartialEq, Asn1Encode, Asn1Decode)]
+#[asn1(application = 14)]
+pub struct ApReq<'data> {
+    #[asn1(context = 0)]
+    pub pvno: Int32<'data>,
+    #[asn1(context = 1)]
+    pub msg_type: Int32<'data>,
+    #[asn1(context = 2)]
+    pub ap_options: KerberosFlags<'data>,
+    #[asn1(context = 3)]
+    pub ticket: Ticket<'data>,
+    #[asn1(context = 4)]
+    pub authenticator: EncryptedData<'data>,
+}
+
+/// [KRB-ERROR](https://www.rfc-editor.org/rfc/rfc4120#section-5.9.1)
+#[derive(Debug, Clone, PartialEq, Asn1Encode, Asn1Decode)]
+#[asn1(application = 30)]
+pub struct KrbError<'data> {
+    #[asn1(context = 0)]
+    pub pvno: Int32<'data>,
+    #[asn1(context = 1)]
+    pub msg_type: Int32<'data>,
+    #[asn1(context = 2, optional)]
+    pub ctime: Option<KerberosTime>,
+    #[asn1(context = 3, optional)]
+    pub cusec: Option<Int32<'data>>,
+    #[asn1(context = 4)]
+    pub stime: KerberosTime,
+    #[asn1(context = 5)]
+    pub susec: Int32<'data>,
+    #[asn1(context = 6)]
+    pub error_code: Int32<'data>,
+    #[asn1(context = 7, optional)]
+    pub crealm: Option<Realm<'data>>,
+    #[asn1(context = 8, optional)]
+    pub cname: Option<PrincipalName<'data>>,
+    #[asn1(context = 9)]
+    pub realm: Realm<'data>,
+    #[asn1(context = 10)]
+    pub sname: PrincipalName<'data>,
+    #[asn1(context = 11, optional)]
+    pub e_text: Option<KerberosString<'data>>,
+    #[asn1(context = 12, optional)]
+    pub e_data: Option<OctetString<'data>>,
+}
+
+/// Any of the supported Kerberos messages. The message type is selected by the application tag.
+#[derive(Debug, Clone, PartialEq, Asn1Encode, Asn1Decode)]
+pub enum KrbMessage<'data> {
+    AsReq(AsReq<'data>),
+    AsRep(AsRep<'data>),
+    TgsReq(TgsReq<'data>),
+    TgsRep(TgsRep<'data>),
+    ApReq(ApReq<'data>),
+    KrbError(KrbError<'data>),
+}
+
+impl KrbMessage<'_> {
+    /// Returns the message name as in RFC 4120, e.g. `AS-REQ`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            KrbMessage::AsReq(_) => "AS-REQ",
+            KrbMessage::AsRep(_) => "AS-REP",
+            KrbMessage::TgsReq(_) => "TGS-REQ",
+            KrbMessage::TgsRep(_) => "TGS-REP",
+            KrbMessage::ApReq(_) => "AP-REQ",
+            KrbMessage::KrbError(_) => "KRB-ERROR",
+        }
+    }
+}
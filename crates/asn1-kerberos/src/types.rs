@@ -0,0 +1,110 @@
+use std::fmt;
+
+use asn1_derive::{Asn1Decode, Asn1Encode};
+use asn1_parser::{BitString, GeneralString, GeneralizedTime, Integer, OctetString, SequenceOf};
+
+/// `Int32`, `UInt32`, and `Microseconds` are encoded as INTEGER.
+pub type Int32<'data> = Integer<'data>;
+
+pub type KerberosString<'data> = GeneralString<'data>;
+
+pub type Realm<'data> = KerberosString<'data>;
+
+pub type KerberosTime = GeneralizedTime;
+
+/// `KDCOptions`, `APOptions`, and `TicketFlags` are encoded as BIT STRING.
+pub type KerberosFlags<'data> = BitString<'data>;
+
+/// [PrincipalName](https://www.rfc-editor.org/rfc/rfc4120#section-5.2.2)
+#[derive(Debug, Clone, PartialEq, Asn1Encode, Asn1Decode)]
+pub struct PrincipalName<'data> {
+    #[asn1(context = 0)]
+    pub name_type: Int32<'data>,
+    #[asn1(context = 1)]
+    pub name_string: SequenceOf<KerberosString<'data>>,
+}
+
+impl fmt::Display for PrincipalName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let components = self
+            .name_string
+            .elements()
+            .iter()
+            .map(|component| component.string())
+            .collect::<Vec<_>>();
+
+        f.write_str(&components.join("/"))
+    }
+}
+
+/// [EncryptedData](https://www.rfc-editor.org/rfc/rfc4120#section-5.2.9)
+#[derive(Debug, Clone, PartialEq, Asn1Encode, Asn1Decode)]
+pub struct EncryptedData<'data> {
+    #[asn1(context = 0)]
+    pub etype: Int32<'data>,
+    #[asn1(context = 1, optional)]
+    pub kvno: Option<Int32<'data>>,
+    #[asn1(context = 2)]
+    pub cipher: OctetString<'data>,
+}
+
+/// [HostAddress](https://www.rfc-editor.org/rfc/rfc4120#section-5.2.5)
+#[derive(Debug, Clone, PartialEq, Asn1Encode, Asn1Decode)]
+pub struct HostAddress<'data> {
+    #[asn1(context = 0)]
+    pub addr_type: Int32<'data>,
+    #[asn1(context = 1)]
+    pub address: OctetString<'data>,
+}
+
+/// [PA-DATA](https://www.rfc-editor.org/rfc/rfc4120#section-5.2.7)
+#[derive(Debug, Clone, PartialEq, Asn1Encode, Asn1Decode)]
+pub struct PaData<'data> {
+    #[asn1(context = 1)]
+    pub padata_type: Int32<'data>,
+    #[asn1(context = 2)]
+    pub padata_value: OctetString<'data>,
+}
+
+/// [Ticket](https://www.rfc-editor.org/rfc/rfc4120#section-5.3)
+#[derive(Debug, Clone, PartialEq, Asn1Encode, Asn1Decode)]
+#[asn1(application = 1)]
+pub struct Ticket<'data> {
+    #[asn1(context = 0)]
+    pub tkt_vno: Int32<'data>,
+    #[asn1(context = 1)]
+    pub realm: Realm<'data>,
+    #[asn1(context = 2)]
+    pub sname: PrincipalName<'data>,
+    #[asn1(context = 3)]
+    pub enc_part: EncryptedData<'data>,
+}
+
+/// [KDC-REQ-BODY](https://www.rfc-editor.org/rfc/rfc4120#section-5.4.1)
+#[derive(Debug, Clone, PartialEq, Asn1Encode, Asn1Decode)]
+pub struct KdcReqBody<'data> {
+    #[asn1(context = 0)]
+    pub kdc_options: KerberosFlags<'data>,
+    #[asn1(context = 1, optional)]
+    pub cname: Option<PrincipalName<'data>>,
+    #[asn1(context = 2)]
+    pub realm: Realm<'data>,
+    #[asn1(context = 3, optional)]
+    pub sname: Option<PrincipalName<'data>>,
+    #[asn1(context = 4, optional)]
+    pub from: Option<KerberosTime>,
+    #[asn1(context = 5)]
+    pub till: KerberosTime,
+    #[asn1(context = 6, optional)]
+    pub rtime: Option<KerberosTime>,
+    #[asn1(context = 7)]
+    pub nonce: Int32<'data>,
+    #[asn1(context = 8)]
+    pub etype: SequenceOf<Int32<'data>>,
+    #[asn1(context = 9, optional)]
+    pub addresses: Option<SequenceOf<HostAddress<'data>>>,
+    #[asn1(context = 10, optional)]
+    pub enc_authorization_data: Option<EncryptedData<'data>>,
+    #[asn1(context = 11, optional)]
+    pub additional_tickets: Option<SequenceOf<Ticket<'data>>>,
+}
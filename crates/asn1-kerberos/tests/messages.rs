@@ -0,0 +1,130 @@
+use asn1_kerberos::{
+    AsReq, EncryptedData, KdcReqBody, KerberosTime, KrbError, KrbMessage, PaData, PrincipalName, Ticket,
+};
+use asn1_parser::{
+    Asn1Decoder, Asn1Encoder, BitString, GeneralString, OctetString, OwnedInteger, SequenceOf, Taggable, Tlv,
+};
+
+const TIME: &[u8] = b"\x18\x0f20240102030405Z";
+
+fn time() -> KerberosTime {
+    Tlv::<KerberosTime>::decode_buff(TIME).unwrap().into_inner()
+}
+
+fn principal(components: &[&'static str]) -> PrincipalName<'static> {
+    PrincipalName {
+        name_type: OwnedInteger::from(1),
+        name_string: SequenceOf::new(
+            components
+                .iter()
+                .map(|component| GeneralString::from(*component))
+                .collect(),
+        ),
+    }
+}
+
+fn encode(message: &impl Asn1Encoder) -> Vec<u8> {
+    let mut buff = vec![0; message.needed_buf_size()];
+    message.encode_buff(&mut buff).unwrap();
+
+    buff
+}
+
+#[test]
+fn as_req() {
+    let as_req = AsReq {
+        pvno: OwnedInteger::from(5),
+        msg_type: OwnedInteger::from(10),
+        padata: Some(SequenceOf::new(vec![PaData {
+            padata_type: OwnedInteger::from(128),
+            padata_value: OctetString::from(vec![0x30, 0x05, 0xa0, 0x03, 0x01, 0x01, 0xff]),
+        }])),
+        req_body: KdcReqBody {
+            kdc_options: BitString::from(vec![0x00, 0x40, 0x81, 0x00, 0x10]),
+            cname: Some(principal(&["alice"])),
+            realm: GeneralString::from("EXAMPLE.COM"),
+            sname: Some(principal(&["krbtgt", "EXAMPLE.COM"])),
+            from: None,
+            till: time(),
+            rtime: None,
+            nonce: OwnedInteger::from(0x1234),
+            etype: SequenceOf::new(vec![OwnedInteger::from(18), OwnedInteger::from(17)]),
+            addresses: None,
+            enc_authorization_data: None,
+            additional_tickets: None,
+        },
+    };
+    assert_eq!(as_req.tag().number(), 10);
+
+    let raw = encode(&as_req);
+    assert_eq!(raw[0], 0x6a);
+
+    let message = Tlv::<KrbMessage>::decode_buff(&raw).unwrap().into_inner();
+    assert_eq!(message.name(), "AS-REQ");
+
+    let KrbMessage::AsReq(decoded) = message else {
+        panic!("expected AS-REQ");
+    };
+    // encapsulated values have different node ids, so the encodings are compared
+    assert_eq!(encode(&decoded), raw);
+    assert_eq!(decoded.req_body.realm.string(), "EXAMPLE.COM");
+    assert_eq!(decoded.req_body.sname.unwrap().to_string(), "krbtgt/EXAMPLE.COM");
+
+    // AP-REQ tag with the AS-REQ content
+    let mut invalid = raw.clone();
+    invalid[0] = 0x6e;
+    assert!(Tlv::<KrbMessage>::decode_buff(&invalid).is_err());
+}
+
+#[test]
+fn krb_error() {
+    let krb_error = KrbError {
+        pvno: OwnedInteger::from(5),
+        msg_type: OwnedInteger::from(30),
+        ctime: None,
+        cusec: None,
+        stime: time(),
+        susec: OwnedInteger::from(123),
+        error_code: OwnedInteger::from(25),
+        crealm: None,
+        cname: None,
+        realm: GeneralString::from("EXAMPLE.COM"),
+        sname: principal(&["krbtgt", "EXAMPLE.COM"]),
+        e_text: None,
+        e_data: Some(OctetString::from(vec![0x30, 0x00])),
+    };
+
+    let raw = encode(&krb_error);
+    assert_eq!(raw[0], 0x7e);
+
+    let message = Tlv::<KrbMessage>::decode_buff(&raw).unwrap().into_inner();
+    assert_eq!(message.name(), "KRB-ERROR");
+
+    let KrbMessage::KrbError(decoded) = message else {
+        panic!("expected KRB-ERROR");
+    };
+    assert_eq!(decoded.error_code.as_i64(), Some(25));
+    assert_eq!(decoded.stime, time());
+    assert_eq!(encode(&decoded), raw);
+}
+
+#[test]
+fn ticket() {
+    let ticket = Ticket {
+        tkt_vno: OwnedInteger::from(5),
+        realm: GeneralString::from("EXAMPLE.COM"),
+        sname: principal(&["HTTP", "www.example.com"]),
+        enc_part: EncryptedData {
+            etype: OwnedInteger::from(18),
+            kvno: Some(OwnedInteger::from(2)),
+            cipher: OctetString::from(vec![0xde, 0xad, 0xbe, 0xef]),
+        },
+    };
+
+    let raw = encode(&ticket);
+    assert_eq!(&raw[..1], [0x61]);
+
+    let decoded = Tlv::<Ticket>::decode_buff(&raw).unwrap().into_inner();
+    assert_eq!(decoded, ticket);
+    assert_eq!(decoded.sname.to_string(), "HTTP/www.example.com");
+}
@@ -0,0 +1,57 @@
+//! Compares the full decoding with the decoding that skips the encapsulated values.
+//!
+//! Run with `cargo bench --bench decode`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use asn1_parser::{Asn1, Asn1Decoder, Asn1Encoder, DecodeProfile, Integer, ObjectIdentifier, OctetString, Sequence};
+
+const CERTIFICATES: usize = 1000;
+const ITERATIONS: u32 = 50;
+
+/// Builds a SEQUENCE of certificate-like values. Every value contains extensions with DER-encoded OCTET STRINGs.
+fn certificate_bundle() -> Vec<u8> {
+    let certificates = (0..CERTIFICATES).map(|index| {
+        let extensions = (0..8u8).map(|extension| {
+            let extension_value = Sequence::of([
+                Asn1::from(Integer::from(i64::from(extension))),
+                Asn1::from(OctetString::from(vec![index as u8; 64])),
+            ]);
+
+            Sequence::of([
+                Asn1::from("2.5.29.15".parse::<ObjectIdentifier>().unwrap()),
+                Asn1::from(OctetString::from(extension_value.encode_to_vec().unwrap())),
+            ])
+        });
+
+        Sequence::of([
+            Asn1::from(Integer::from(index as i64)),
+            Asn1::from(Sequence::of(extensions)),
+        ])
+    });
+
+    Sequence::of(certificates).encode_to_vec().unwrap()
+}
+
+fn bench(name: &str, decode: impl Fn() -> Asn1<'static>) {
+    let mut total = Duration::ZERO;
+
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        black_box(decode());
+        total += start.elapsed();
+    }
+
+    println!("{name}: {:?} per iteration", total / ITERATIONS);
+}
+
+fn main() {
+    let bundle: &'static [u8] = Box::leak(certificate_bundle().into_boxed_slice());
+    println!("bundle size: {} bytes", bundle.len());
+
+    bench("full", || Asn1::decode_buff(bundle).unwrap());
+    bench("shallow", || {
+        Asn1::decode_buff_shallow(bundle, DecodeProfile::default()).unwrap()
+    });
+}
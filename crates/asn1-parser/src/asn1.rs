@@ -1,15 +1,23 @@
 use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
 use core::ops::Range;
+use core::slice;
 
-use crate::reader::Reader;
+use crate::reader::{DecodeProfile, Reader};
 use crate::writer::Writer;
 use crate::{
-    ApplicationTag, Asn1Encoder, Asn1Result, Asn1ValueDecoder, BitString, BmpString, Bool, Error, ExplicitTag,
-    GeneralString, GeneralizedTime, IA5String, ImplicitTag, Integer, MetaInfo, Null, NumericString, ObjectIdentifier,
-    OctetString, PrintableString, Sequence, Set, Tag, Taggable, Tlv, UtcTime, Utf8String, VisibleString,
+    ApplicationTag, Asn1Decoder, Asn1Encoder, Asn1Entity, Asn1Result, Asn1ValueDecoder, BitString, BmpString, Bool,
+    Date, DateTime, DecodeError, Duration, EmbeddedPdv, Enumerated, Error, ExplicitTag, External, ExternalEncoding,
+    GeneralString, GeneralizedTime, GraphicString, IA5String, ImplicitTag, Integer, MetaInfo, Null, NumericString,
+    ObjectIdentifier, OctetString, PrintableString, PrivateTag, Real, RelativeOid, Sequence, Set, Tag, Taggable,
+    TimeOfDay, Tlv, UniversalString, UtcTime, Utf8String, VideotexString, VisibleString,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Asn1Type<'data> {
     Sequence(Sequence<'data>),
     Set(Set<'data>),
@@ -18,23 +26,39 @@ pub enum Asn1Type<'data> {
     Utf8String(Utf8String<'data>),
     BitString(BitString<'data>),
     BmpString(BmpString<'data>),
+    UniversalString(UniversalString<'data>),
     IA5String(IA5String<'data>),
     PrintableString(PrintableString<'data>),
     GeneralString(GeneralString<'data>),
+    GraphicString(GraphicString<'data>),
+    VideotexString(VideotexString<'data>),
     NumericString(NumericString<'data>),
     VisibleString(VisibleString<'data>),
 
     UtcTime(UtcTime),
     GeneralizedTime(GeneralizedTime),
+    Date(Date),
+    TimeOfDay(TimeOfDay),
+    DateTime(DateTime),
+    Duration(Duration),
+    External(External<'data>),
+    EmbeddedPdv(EmbeddedPdv<'data>),
 
     Bool(Bool),
     Null(Null),
     Integer(Integer<'data>),
+    Enumerated(Enumerated<'data>),
+    Real(Real<'data>),
     ObjectIdentifier(ObjectIdentifier),
+    RelativeOid(RelativeOid),
 
     ExplicitTag(ExplicitTag<'data>),
     ImplicitTag(ImplicitTag<'data>),
     ApplicationTag(ApplicationTag<'data>),
+    PrivateTag(PrivateTag<'data>),
+
+    /// Undecodable data. It is produced only by the lenient decoding.
+    DecodeError(DecodeError<'data>),
 }
 
 pub type Asn1<'data> = Tlv<'data, Asn1Type<'data>>;
@@ -42,6 +66,278 @@ pub type OwnedAsn1 = Tlv<'static, Asn1Type<'static>>;
 
 pub type OwnedAsn1Type = Asn1Type<'static>;
 
+macro_rules! impl_from_value {
+    ($($variant:ident($ty:ty),)*) => {
+        $(
+            impl<'data> From<$ty> for Asn1Type<'data> {
+                fn from(value: $ty) -> Self {
+                    Asn1Type::$variant(value)
+                }
+            }
+
+            impl<'data> From<$ty> for Asn1<'data> {
+                fn from(value: $ty) -> Self {
+                    Asn1::from(Asn1Type::$variant(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_value! {
+    Sequence(Sequence<'data>),
+    Set(Set<'data>),
+    OctetString(OctetString<'data>),
+    Utf8String(Utf8String<'data>),
+    BitString(BitString<'data>),
+    BmpString(BmpString<'data>),
+    UniversalString(UniversalString<'data>),
+    IA5String(IA5String<'data>),
+    PrintableString(PrintableString<'data>),
+    GeneralString(GeneralString<'data>),
+    GraphicString(GraphicString<'data>),
+    VideotexString(VideotexString<'data>),
+    NumericString(NumericString<'data>),
+    VisibleString(VisibleString<'data>),
+    UtcTime(UtcTime),
+    GeneralizedTime(GeneralizedTime),
+    Date(Date),
+    TimeOfDay(TimeOfDay),
+    DateTime(DateTime),
+    Duration(Duration),
+    External(External<'data>),
+    EmbeddedPdv(EmbeddedPdv<'data>),
+    Bool(Bool),
+    Null(Null),
+    Integer(Integer<'data>),
+    Enumerated(Enumerated<'data>),
+    Real(Real<'data>),
+    ObjectIdentifier(ObjectIdentifier),
+    RelativeOid(RelativeOid),
+    ExplicitTag(ExplicitTag<'data>),
+    ImplicitTag(ImplicitTag<'data>),
+    ApplicationTag(ApplicationTag<'data>),
+    PrivateTag(PrivateTag<'data>),
+    DecodeError(DecodeError<'data>),
+}
+
+impl Asn1<'_> {
+    /// Re-encodes the tree and decodes it again.
+    ///
+    /// Use it after editing the tree: the raw data, node ids, and encapsulated values of the returned tree
+    /// match the new structure.
+    pub fn recompute(&self) -> Asn1Result<OwnedAsn1> {
+        let encoded = self.encode_to_vec()?;
+        // The edited tree may not follow the DER rules (e.g. unsorted SET fields).
+        let asn1 = Asn1::decode_buff_with_profile(&encoded, DecodeProfile::Ber)?;
+
+        Ok(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned()))
+    }
+
+    /// Returns child nodes of the constructed or encapsulating node and `None` for other nodes.
+    pub(crate) fn child_nodes(&self) -> Option<&[Asn1<'_>]> {
+        match self.inner_asn1() {
+            Asn1Type::Sequence(sequence) => Some(sequence.fields()),
+            Asn1Type::Set(set) => Some(set.fields()),
+            Asn1Type::ExplicitTag(explicit_tag) => Some(explicit_tag.inner()),
+            Asn1Type::ApplicationTag(application_tag) => Some(application_tag.inner()),
+            Asn1Type::PrivateTag(private_tag) if private_tag.is_constructed() => Some(private_tag.inner()),
+            Asn1Type::OctetString(octet_string) => octet_string.inner().map(slice::from_ref),
+            Asn1Type::BitString(bit_string) => bit_string.inner().map(slice::from_ref),
+            Asn1Type::ImplicitTag(implicit_tag) => implicit_tag.inner_asn1().map(slice::from_ref),
+            Asn1Type::External(external) => external.inner().map(slice::from_ref),
+            _ => None,
+        }
+    }
+
+    /// Finds the node with the provided id in this subtree.
+    ///
+    /// The decoder assigns ids after decoding the child nodes, so every subtree occupies a continuous id range
+    /// ending with the id of its root. It allows the binary search over child nodes on every level.
+    /// Edited trees should be [recomputed](Asn1::recompute) before the lookup.
+    pub fn find_by_id(&self, id: u64) -> Option<&Asn1<'_>> {
+        let mut node = self;
+
+        while node.id() != id {
+            if id > node.id() {
+                return None;
+            }
+
+            let children = node.child_nodes()?;
+            node = children.get(children.partition_point(|child| child.id() < id))?;
+        }
+
+        Some(node)
+    }
+
+    /// Finds the node by the child indices from this node, e.g. the [DiffEntry](crate::DiffEntry) path.
+    /// Encapsulated value (e.g. inside OCTET STRING) has index 0.
+    pub fn find_by_path(&self, path: &[usize]) -> Option<&Asn1<'_>> {
+        path.iter()
+            .try_fold(self, |node, index| node.child_nodes()?.get(*index))
+    }
+
+    /// Finds the deepest node that contains the byte at the provided offset of the input data.
+    pub fn node_at_offset(&self, offset: usize) -> Option<&Asn1<'_>> {
+        let contains = |node: &Asn1<'_>| {
+            let meta = node.meta();
+
+            (meta.tag_position()..meta.tag_position() + meta.raw_bytes().len()).contains(&offset)
+        };
+
+        if !contains(self) {
+            return None;
+        }
+
+        let mut node = self;
+        while let Some(children) = node.child_nodes() {
+            let index = children.partition_point(|child| child.meta().tag_position() <= offset);
+
+            match index.checked_sub(1).map(|index| &children[index]) {
+                Some(child) if contains(child) => node = child,
+                _ => break,
+            }
+        }
+
+        Some(node)
+    }
+
+    /// Returns fields of the constructed node. Encapsulated values are not fields.
+    fn constructed_fields(&self) -> Option<&[Asn1<'_>]> {
+        match self.inner_asn1() {
+            Asn1Type::Sequence(sequence) => Some(sequence.fields()),
+            Asn1Type::Set(set) => Some(set.fields()),
+            Asn1Type::ExplicitTag(explicit_tag) => Some(explicit_tag.inner()),
+            Asn1Type::ApplicationTag(application_tag) => Some(application_tag.inner()),
+            Asn1Type::PrivateTag(private_tag) if private_tag.is_constructed() => Some(private_tag.inner()),
+            _ => None,
+        }
+    }
+
+    /// Compares the trees by their structure and values.
+    ///
+    /// Node ids, raw data, and annotations are ignored, so the trees decoded from different buffers
+    /// (or using different length encodings) are equal if they have the same tags and values.
+    pub fn structural_eq(&self, other: &Asn1<'_>) -> bool {
+        if self.tag() != other.tag() {
+            return false;
+        }
+
+        match (self.constructed_fields(), other.constructed_fields()) {
+            (Some(fields), Some(other_fields)) => {
+                fields.len() == other_fields.len()
+                    && fields
+                        .iter()
+                        .zip(other_fields)
+                        .all(|(field, other_field)| field.structural_eq(other_field))
+            }
+            (None, None) => value_eq(self.inner_asn1(), other.inner_asn1()),
+            _ => false,
+        }
+    }
+
+    /// Feeds the tree structure and values into the hasher. Consistent with [Asn1::structural_eq].
+    pub fn structural_hash<H: Hasher>(&self, state: &mut H) {
+        self.tag().hash(state);
+
+        match self.constructed_fields() {
+            Some(fields) => {
+                fields.len().hash(state);
+                fields.iter().for_each(|field| field.structural_hash(state));
+            }
+            // Equal values have the same encoding. Encapsulated values are not encoded.
+            None => self.inner_asn1().encode_to_vec().ok().hash(state),
+        }
+    }
+}
+
+/// Compares values of the nodes without fields.
+///
+/// Encapsulated values are ignored: they are decoded from the compared octets and may be absent
+/// in the constructed (BER) string encoding.
+fn value_eq(value: &Asn1Type<'_>, other: &Asn1Type<'_>) -> bool {
+    match (value, other) {
+        (Asn1Type::OctetString(octet_string), Asn1Type::OctetString(other)) => octet_string.octets() == other.octets(),
+        (Asn1Type::BitString(bit_string), Asn1Type::BitString(other)) => bit_string.raw_bits() == other.raw_bits(),
+        (Asn1Type::ImplicitTag(implicit_tag), Asn1Type::ImplicitTag(other)) => implicit_tag.octets() == other.octets(),
+        (Asn1Type::External(external), Asn1Type::External(other)) => {
+            external.direct_reference() == other.direct_reference()
+                && external.indirect_reference() == other.indirect_reference()
+                && external.data_value_descriptor() == other.data_value_descriptor()
+                && match (external.encoding(), other.encoding()) {
+                    (ExternalEncoding::SingleAsn1Type(asn1), ExternalEncoding::SingleAsn1Type(other)) => {
+                        asn1.structural_eq(other)
+                    }
+                    (ExternalEncoding::OctetAligned(octets), ExternalEncoding::OctetAligned(other)) => {
+                        octets.octets() == other.octets()
+                    }
+                    (ExternalEncoding::Arbitrary(bits), ExternalEncoding::Arbitrary(other)) => {
+                        bits.raw_bits() == other.raw_bits()
+                    }
+                    _ => false,
+                }
+        }
+        (Asn1Type::EmbeddedPdv(embedded_pdv), Asn1Type::EmbeddedPdv(other)) => {
+            embedded_pdv.identification() == other.identification()
+                && embedded_pdv.data_value().octets() == other.data_value().octets()
+        }
+        // The error range depends on the node offset in the input.
+        (Asn1Type::DecodeError(error), Asn1Type::DecodeError(other)) => error.raw_data() == other.raw_data(),
+        _ => value == other,
+    }
+}
+
+impl<'data> Asn1<'data> {
+    /// Returns fields of the constructed node that are encoded from the tree (not from the raw data).
+    fn editable_fields_mut(&mut self) -> Option<&mut Vec<Asn1<'data>>> {
+        match self.inner_asn1_mut() {
+            Asn1Type::Sequence(sequence) => Some(sequence.fields_mut()),
+            Asn1Type::Set(set) => Some(set.fields_mut()),
+            Asn1Type::ExplicitTag(explicit_tag) => Some(explicit_tag.fields_mut()),
+            Asn1Type::ApplicationTag(application_tag) => Some(application_tag.fields_mut()),
+            Asn1Type::PrivateTag(private_tag) => Some(private_tag.fields_mut()),
+            _ => None,
+        }
+    }
+
+    /// Finds the node with the provided id in this subtree for editing.
+    ///
+    /// Unlike [Asn1::find_by_id], it does not descend into encapsulated values: they are encoded from the raw
+    /// data of the encapsulating node, so their edits would be lost.
+    pub fn find_by_id_mut(&mut self, id: u64) -> Option<&mut Asn1<'data>> {
+        if self.id() == id {
+            return Some(self);
+        }
+
+        if id > self.id() {
+            return None;
+        }
+
+        let fields = self.editable_fields_mut()?;
+        let index = fields.partition_point(|field| field.id() < id);
+
+        fields.get_mut(index)?.find_by_id_mut(id)
+    }
+
+    /// Removes the node with the provided id from this subtree and returns it.
+    ///
+    /// The root node and nodes inside encapsulated values can not be removed.
+    pub fn remove_by_id(&mut self, id: u64) -> Option<Asn1<'data>> {
+        if id >= self.id() {
+            return None;
+        }
+
+        let fields = self.editable_fields_mut()?;
+        let index = fields.partition_point(|field| field.id() < id);
+
+        if fields.get(index)?.id() == id {
+            Some(fields.remove(index))
+        } else {
+            fields[index].remove_by_id(id)
+        }
+    }
+}
+
 impl Asn1Type<'_> {
     pub fn to_owned(&self) -> OwnedAsn1Type {
         match self {
@@ -53,18 +349,32 @@ impl Asn1Type<'_> {
             Asn1Type::IA5String(i) => Asn1Type::IA5String(i.to_owned()),
             Asn1Type::PrintableString(p) => Asn1Type::PrintableString(p.to_owned()),
             Asn1Type::GeneralString(g) => Asn1Type::GeneralString(g.to_owned()),
+            Asn1Type::GraphicString(graphic) => Asn1Type::GraphicString(graphic.to_owned()),
+            Asn1Type::VideotexString(videotex) => Asn1Type::VideotexString(videotex.to_owned()),
             Asn1Type::NumericString(n) => Asn1Type::NumericString(n.to_owned()),
             Asn1Type::VisibleString(n) => Asn1Type::VisibleString(n.to_owned()),
             Asn1Type::Bool(b) => Asn1Type::Bool(b.clone()),
             Asn1Type::Null(n) => Asn1Type::Null(n.clone()),
             Asn1Type::Integer(i) => Asn1Type::Integer(i.to_owned()),
+            Asn1Type::Enumerated(e) => Asn1Type::Enumerated(e.to_owned()),
+            Asn1Type::Real(real) => Asn1Type::Real(real.to_owned()),
             Asn1Type::ObjectIdentifier(o) => Asn1Type::ObjectIdentifier(o.clone()),
+            Asn1Type::RelativeOid(r) => Asn1Type::RelativeOid(r.clone()),
             Asn1Type::ExplicitTag(e) => Asn1Type::ExplicitTag(e.to_owned()),
             Asn1Type::ImplicitTag(i) => Asn1Type::ImplicitTag(i.to_owned()),
             Asn1Type::ApplicationTag(a) => Asn1Type::ApplicationTag(a.to_owned()),
+            Asn1Type::PrivateTag(private) => Asn1Type::PrivateTag(private.to_owned()),
             Asn1Type::BmpString(b) => Asn1Type::BmpString(b.to_owned()),
+            Asn1Type::UniversalString(universal) => Asn1Type::UniversalString(universal.to_owned()),
             Asn1Type::UtcTime(u) => Asn1Type::UtcTime(u.clone()),
             Asn1Type::GeneralizedTime(u) => Asn1Type::GeneralizedTime(u.clone()),
+            Asn1Type::Date(date) => Asn1Type::Date(date.clone()),
+            Asn1Type::TimeOfDay(time_of_day) => Asn1Type::TimeOfDay(time_of_day.clone()),
+            Asn1Type::DateTime(date_time) => Asn1Type::DateTime(date_time.clone()),
+            Asn1Type::Duration(duration) => Asn1Type::Duration(duration.clone()),
+            Asn1Type::External(external) => Asn1Type::External(external.to_owned()),
+            Asn1Type::EmbeddedPdv(embedded_pdv) => Asn1Type::EmbeddedPdv(embedded_pdv.to_owned()),
+            Asn1Type::DecodeError(error) => Asn1Type::DecodeError(error.to_owned()),
         }
     }
 }
@@ -78,20 +388,34 @@ impl Taggable for Asn1Type<'_> {
             Asn1Type::Utf8String(u) => u.tag(),
             Asn1Type::BitString(b) => b.tag(),
             Asn1Type::BmpString(b) => b.tag(),
+            Asn1Type::UniversalString(universal) => universal.tag(),
             Asn1Type::IA5String(i) => i.tag(),
             Asn1Type::PrintableString(p) => p.tag(),
             Asn1Type::GeneralString(g) => g.tag(),
+            Asn1Type::GraphicString(graphic) => graphic.tag(),
+            Asn1Type::VideotexString(videotex) => videotex.tag(),
             Asn1Type::NumericString(g) => g.tag(),
             Asn1Type::VisibleString(g) => g.tag(),
             Asn1Type::Bool(b) => b.tag(),
             Asn1Type::Null(n) => n.tag(),
             Asn1Type::Integer(i) => i.tag(),
+            Asn1Type::Enumerated(e) => e.tag(),
+            Asn1Type::Real(real) => real.tag(),
             Asn1Type::ObjectIdentifier(o) => o.tag(),
+            Asn1Type::RelativeOid(r) => r.tag(),
             Asn1Type::ExplicitTag(e) => e.tag(),
             Asn1Type::ImplicitTag(i) => i.tag(),
             Asn1Type::ApplicationTag(a) => a.tag(),
+            Asn1Type::PrivateTag(private) => private.tag(),
             Asn1Type::UtcTime(u) => u.tag(),
             Asn1Type::GeneralizedTime(u) => u.tag(),
+            Asn1Type::Date(date) => date.tag(),
+            Asn1Type::TimeOfDay(time_of_day) => time_of_day.tag(),
+            Asn1Type::DateTime(date_time) => date_time.tag(),
+            Asn1Type::Duration(duration) => duration.tag(),
+            Asn1Type::External(external) => external.tag(),
+            Asn1Type::EmbeddedPdv(embedded_pdv) => embedded_pdv.tag(),
+            Asn1Type::DecodeError(error) => error.tag(),
         }
     }
 }
@@ -106,25 +430,42 @@ impl<'data> Asn1ValueDecoder<'data> for Asn1Type<'data> {
             BitString,
             BmpString,
             IA5String,
+            UniversalString,
             PrintableString,
             GeneralString,
             NumericString,
+            GraphicString,
             VisibleString,
+            VideotexString,
             Bool,
             Integer,
+            Enumerated,
             ObjectIdentifier,
+            Real,
+            RelativeOid,
             ExplicitTag,
             ImplicitTag,
             ApplicationTag,
             Null,
+            PrivateTag,
             UtcTime,
-            GeneralizedTime;
+            GeneralizedTime,
+            Date,
+            TimeOfDay,
+            DateTime,
+            Duration,
+            External,
+            EmbeddedPdv;
             in tag, reader
         );
 
         Err(Error::from("Invalid asn1 data"))
     }
 
+    fn from_decode_error(error: DecodeError<'data>) -> Option<Self> {
+        Some(Asn1Type::DecodeError(error))
+    }
+
     fn compare_tags(_tag: Tag) -> bool {
         true
     }
@@ -139,20 +480,34 @@ impl Asn1Encoder for Asn1Type<'_> {
             Asn1Type::Set(set) => set.needed_buf_size(),
             Asn1Type::BitString(bit) => bit.needed_buf_size(),
             Asn1Type::BmpString(bmp) => bmp.needed_buf_size(),
+            Asn1Type::UniversalString(universal) => universal.needed_buf_size(),
             Asn1Type::IA5String(i) => i.needed_buf_size(),
             Asn1Type::PrintableString(p) => p.needed_buf_size(),
             Asn1Type::GeneralString(g) => g.needed_buf_size(),
+            Asn1Type::GraphicString(graphic) => graphic.needed_buf_size(),
+            Asn1Type::VideotexString(videotex) => videotex.needed_buf_size(),
             Asn1Type::NumericString(g) => g.needed_buf_size(),
             Asn1Type::VisibleString(g) => g.needed_buf_size(),
             Asn1Type::Bool(boolean) => boolean.needed_buf_size(),
             Asn1Type::Integer(integer) => integer.needed_buf_size(),
+            Asn1Type::Enumerated(enumerated) => enumerated.needed_buf_size(),
+            Asn1Type::Real(real) => real.needed_buf_size(),
             Asn1Type::ObjectIdentifier(object_identifier) => object_identifier.needed_buf_size(),
+            Asn1Type::RelativeOid(relative_oid) => relative_oid.needed_buf_size(),
             Asn1Type::ExplicitTag(e) => e.needed_buf_size(),
             Asn1Type::ImplicitTag(i) => i.needed_buf_size(),
             Asn1Type::ApplicationTag(a) => a.needed_buf_size(),
+            Asn1Type::PrivateTag(private) => private.needed_buf_size(),
             Asn1Type::Null(n) => n.needed_buf_size(),
             Asn1Type::UtcTime(u) => u.needed_buf_size(),
             Asn1Type::GeneralizedTime(u) => u.needed_buf_size(),
+            Asn1Type::Date(date) => date.needed_buf_size(),
+            Asn1Type::TimeOfDay(time_of_day) => time_of_day.needed_buf_size(),
+            Asn1Type::DateTime(date_time) => date_time.needed_buf_size(),
+            Asn1Type::Duration(duration) => duration.needed_buf_size(),
+            Asn1Type::External(external) => external.needed_buf_size(),
+            Asn1Type::EmbeddedPdv(embedded_pdv) => embedded_pdv.needed_buf_size(),
+            Asn1Type::DecodeError(error) => error.needed_buf_size(),
         }
     }
 
@@ -164,20 +519,34 @@ impl Asn1Encoder for Asn1Type<'_> {
             Asn1Type::Set(set) => set.encode(writer),
             Asn1Type::BitString(bit) => bit.encode(writer),
             Asn1Type::BmpString(bmp) => bmp.encode(writer),
+            Asn1Type::UniversalString(universal) => universal.encode(writer),
             Asn1Type::IA5String(ia5) => ia5.encode(writer),
             Asn1Type::PrintableString(printable) => printable.encode(writer),
             Asn1Type::GeneralString(general) => general.encode(writer),
+            Asn1Type::GraphicString(graphic) => graphic.encode(writer),
+            Asn1Type::VideotexString(videotex) => videotex.encode(writer),
             Asn1Type::NumericString(numeric) => numeric.encode(writer),
             Asn1Type::VisibleString(numeric) => numeric.encode(writer),
             Asn1Type::Bool(boolean) => boolean.encode(writer),
             Asn1Type::Integer(integer) => integer.encode(writer),
+            Asn1Type::Enumerated(enumerated) => enumerated.encode(writer),
+            Asn1Type::Real(real) => real.encode(writer),
             Asn1Type::ObjectIdentifier(object_identifier) => object_identifier.encode(writer),
+            Asn1Type::RelativeOid(relative_oid) => relative_oid.encode(writer),
             Asn1Type::ExplicitTag(e) => e.encode(writer),
             Asn1Type::ImplicitTag(i) => i.encode(writer),
             Asn1Type::ApplicationTag(a) => a.encode(writer),
+            Asn1Type::PrivateTag(private) => private.encode(writer),
             Asn1Type::Null(n) => n.encode(writer),
             Asn1Type::UtcTime(utc_time) => utc_time.encode(writer),
             Asn1Type::GeneralizedTime(generalized_time) => generalized_time.encode(writer),
+            Asn1Type::Date(date) => date.encode(writer),
+            Asn1Type::TimeOfDay(time_of_day) => time_of_day.encode(writer),
+            Asn1Type::DateTime(date_time) => date_time.encode(writer),
+            Asn1Type::Duration(duration) => duration.encode(writer),
+            Asn1Type::External(external) => external.encode(writer),
+            Asn1Type::EmbeddedPdv(embedded_pdv) => embedded_pdv.encode(writer),
+            Asn1Type::DecodeError(error) => error.encode(writer),
         }
     }
 }
@@ -189,28 +558,43 @@ impl MetaInfo for Asn1Type<'_> {
             Asn1Type::Utf8String(_) => {}
             Asn1Type::Sequence(sequence) => sequence.clear_meta(),
             Asn1Type::Set(set) => set.clear_meta(),
-            Asn1Type::BitString(_) => {}
+            Asn1Type::BitString(bit_string) => bit_string.clear_meta(),
             Asn1Type::BmpString(_) => {}
+            Asn1Type::UniversalString(_) => {}
             Asn1Type::IA5String(_) => {}
             Asn1Type::PrintableString(_) => {}
             Asn1Type::GeneralString(_) => {}
+            Asn1Type::GraphicString(_) => {}
+            Asn1Type::VideotexString(_) => {}
             Asn1Type::NumericString(_) => {}
             Asn1Type::VisibleString(_) => {}
             Asn1Type::Bool(_) => {}
             Asn1Type::Integer(_) => {}
+            Asn1Type::Enumerated(_) => {}
+            Asn1Type::Real(_) => {}
             Asn1Type::ObjectIdentifier(_) => {}
+            Asn1Type::RelativeOid(_) => {}
             Asn1Type::ExplicitTag(explicit_tag) => explicit_tag.clear_meta(),
             Asn1Type::ImplicitTag(implicit_tag) => implicit_tag.clear_meta(),
             Asn1Type::ApplicationTag(application_tag) => application_tag.clear_meta(),
+            Asn1Type::PrivateTag(private_tag) => private_tag.clear_meta(),
             Asn1Type::Null(_) => {}
             Asn1Type::UtcTime(_) => {}
             Asn1Type::GeneralizedTime(_) => {}
+            Asn1Type::Date(_) => {}
+            Asn1Type::TimeOfDay(_) => {}
+            Asn1Type::DateTime(_) => {}
+            Asn1Type::Duration(_) => {}
+            Asn1Type::External(external) => external.clear_meta(),
+            Asn1Type::EmbeddedPdv(embedded_pdv) => embedded_pdv.clear_meta(),
+            Asn1Type::DecodeError(_) => {}
         }
     }
 }
 
 /// Information about raw data of the asn1 entity
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RawAsn1EntityData<'data> {
     /// Raw input bytes for the *current* asn1 node
     pub raw_data: Cow<'data, [u8]>,
@@ -223,6 +607,12 @@ pub struct RawAsn1EntityData<'data> {
 
     /// Range that corresponds to the inner data in the raw_data
     pub data: Range<usize>,
+
+    /// Ranges that correspond to the segments data of the constructed (BER or CER) string in the raw_data
+    pub segments: Vec<Range<usize>>,
+
+    /// User-defined key/value annotations, e.g. the schema field name or a comment. The decoder leaves it empty.
+    pub annotations: BTreeMap<String, String>,
 }
 
 pub type OwnedRawAsn1EntityData = RawAsn1EntityData<'static>;
@@ -240,10 +630,19 @@ impl RawAsn1EntityData<'_> {
         self.data.clone()
     }
 
+    pub fn segment_ranges(&self) -> &[Range<usize>] {
+        &self.segments
+    }
+
     pub fn raw_bytes(&self) -> &[u8] {
         self.raw_data.as_ref()
     }
 
+    /// Identifier octets. There is more than one octet for the high tag numbers.
+    pub fn tag_bytes(&self) -> &[u8] {
+        &self.raw_data[..self.length.start]
+    }
+
     pub fn length_bytes(&self) -> &[u8] {
         &self.raw_data[self.length.clone()]
     }
@@ -252,12 +651,19 @@ impl RawAsn1EntityData<'_> {
         &self.raw_data[self.data.clone()]
     }
 
+    /// End-of-contents octets of the indefinite length encoding. Empty for the definite length encoding.
+    pub fn end_of_contents_bytes(&self) -> &[u8] {
+        &self.raw_data[self.data.end..]
+    }
+
     pub fn to_owned(&self) -> OwnedRawAsn1EntityData {
         RawAsn1EntityData {
             raw_data: self.raw_data.to_vec().into(),
             tag: self.tag,
             length: self.length.clone(),
             data: self.data.clone(),
+            segments: self.segments.clone(),
+            annotations: self.annotations.clone(),
         }
     }
 }
@@ -0,0 +1,190 @@
+use alloc::borrow::Cow;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::asn1::{Asn1, Asn1Type};
+use crate::constructors::der_order;
+use crate::length::{len_size, read_len, write_len, END_OF_CONTENTS};
+use crate::reader::{read_value_data, DecodeProfile, Reader};
+use crate::string::CER_SEGMENT_LEN;
+use crate::writer::Writer;
+use crate::{Asn1Decoder, Asn1Encoder, Asn1Entity, Asn1Result, BitString, OctetString, Tag};
+
+/// Indefinite length octet.
+pub(crate) const INDEFINITE_LENGTH: u8 = 0x80;
+
+pub(crate) fn write_tag(tag: Tag, encoded: &mut Vec<u8>) -> Asn1Result<()> {
+    let mut buff = vec![0; tag.encoded_len()];
+    tag.to_writer(&mut Writer::new(&mut buff))?;
+    encoded.extend_from_slice(&buff);
+
+    Ok(())
+}
+
+/// Writes the primitive encoding with the definite length.
+fn write_primitive(tag: Tag, data: &[u8], encoded: &mut Vec<u8>) -> Asn1Result<()> {
+    write_tag(tag, encoded)?;
+
+    let mut len = vec![0; len_size(data.len())];
+    write_len(data.len(), &mut Writer::new(&mut len))?;
+    encoded.extend_from_slice(&len);
+
+    encoded.extend_from_slice(data);
+
+    Ok(())
+}
+
+/// Writes the constructed encoding with the indefinite length.
+fn write_constructed(tag: Tag, fields: impl Iterator<Item = Vec<u8>>, encoded: &mut Vec<u8>) -> Asn1Result<()> {
+    write_tag(tag.constructed(), encoded)?;
+    encoded.push(INDEFINITE_LENGTH);
+
+    fields.for_each(|field| encoded.extend_from_slice(&field));
+
+    encoded.extend_from_slice(&END_OF_CONTENTS);
+
+    Ok(())
+}
+
+/// Writes the constructed encoding with the 1000-octet OCTET STRING segments.
+fn write_segmented(tag: Tag, data: &[u8], encoded: &mut Vec<u8>) -> Asn1Result<()> {
+    let segments = data
+        .chunks(CER_SEGMENT_LEN)
+        .map(|segment| {
+            let mut encoded = Vec::new();
+            write_primitive(OctetString::TAG, segment, &mut encoded)?;
+
+            Ok(encoded)
+        })
+        .collect::<Asn1Result<Vec<_>>>()?;
+
+    write_constructed(tag, segments.into_iter(), encoded)
+}
+
+/// Returns the contents octets of the restricted character string.
+fn restricted_string_data<'a>(asn1_type: &'a Asn1Type<'_>) -> Option<Cow<'a, [u8]>> {
+    Some(match asn1_type {
+        Asn1Type::Utf8String(string) => Cow::Borrowed(string.raw_data()),
+        Asn1Type::NumericString(string) => Cow::Borrowed(string.raw_data()),
+        Asn1Type::PrintableString(string) => Cow::Borrowed(string.raw_data()),
+        Asn1Type::IA5String(string) => Cow::Borrowed(string.raw_data()),
+        Asn1Type::VisibleString(string) => Cow::Borrowed(string.raw_data()),
+        Asn1Type::VideotexString(string) => Cow::Borrowed(string.raw_data()),
+        Asn1Type::GraphicString(string) => Cow::Borrowed(string.raw_data()),
+        Asn1Type::GeneralString(string) => Cow::Borrowed(string.raw_data()),
+        Asn1Type::BmpString(string) => Cow::Owned(string.to_utf16_be()),
+        Asn1Type::UniversalString(string) => Cow::Owned(string.to_utf32_be()),
+        _ => return None,
+    })
+}
+
+/// Re-encodes the fields of the constructed value that are not the tree nodes, e.g. the EXTERNAL fields.
+///
+/// Fields that can be decoded as a tree are encoded as nodes, so SET fields are sorted and strings are segmented.
+/// Other constructed fields are re-encoded field by field and primitive fields are kept as is.
+fn encode_raw_fields(data: &[u8]) -> Asn1Result<Vec<Vec<u8>>> {
+    let mut reader = Reader::new(data);
+    let mut fields = Vec::new();
+
+    while !reader.empty() {
+        let start = reader.position();
+        let tag = Tag::from_reader(&mut reader)?;
+        let (len, _) = read_len(&mut reader)?;
+        let (value, _) = read_value_data(tag, len, &mut reader)?;
+        let raw = &data[start..reader.position()];
+
+        let mut encoded = Vec::new();
+        match Asn1::decode_buff_with_profile(raw, DecodeProfile::Ber) {
+            Ok(asn1) => encoded = encode_node(&asn1)?,
+            Err(_) if tag.is_constructed() => {
+                write_constructed(tag, encode_raw_fields(value)?.into_iter(), &mut encoded)?
+            }
+            Err(_) => encoded.extend_from_slice(raw),
+        }
+        fields.push(encoded);
+    }
+
+    Ok(fields)
+}
+
+fn encode_node(asn1: &Asn1<'_>) -> Asn1Result<Vec<u8>> {
+    let mut encoded = Vec::new();
+
+    let fields = match asn1.inner_asn1() {
+        Asn1Type::OctetString(octet_string) if octet_string.octets().len() > CER_SEGMENT_LEN => {
+            write_segmented(OctetString::TAG, octet_string.octets(), &mut encoded)?;
+
+            return Ok(encoded);
+        }
+        Asn1Type::BitString(bit_string) if bit_string.raw_bits().len() > CER_SEGMENT_LEN => {
+            // Every segment starts with its own unused bits octet. Only the last segment can have unused bits.
+            let (unused_bits, bits) = bit_string.raw_bits().split_at(1);
+            let mut segments = bits
+                .chunks(CER_SEGMENT_LEN - 1)
+                .map(|bits| {
+                    let mut data = Vec::with_capacity(bits.len() + 1);
+                    data.push(0);
+                    data.extend_from_slice(bits);
+
+                    data
+                })
+                .collect::<Vec<_>>();
+            if let Some(last) = segments.last_mut() {
+                last[0] = unused_bits[0];
+            }
+
+            let segments = segments
+                .iter()
+                .map(|data| {
+                    let mut encoded = Vec::new();
+                    write_primitive(BitString::TAG, data, &mut encoded)?;
+
+                    Ok(encoded)
+                })
+                .collect::<Asn1Result<Vec<_>>>()?;
+            write_constructed(BitString::TAG, segments.into_iter(), &mut encoded)?;
+
+            return Ok(encoded);
+        }
+        Asn1Type::External(_) | Asn1Type::EmbeddedPdv(_) => {
+            let fields = encode_raw_fields(asn1.meta().data_bytes())?;
+            write_constructed(asn1.tag(), fields.into_iter(), &mut encoded)?;
+
+            return Ok(encoded);
+        }
+        Asn1Type::Sequence(sequence) => sequence.fields(),
+        Asn1Type::Set(set) => set.fields(),
+        Asn1Type::ExplicitTag(explicit_tag) => explicit_tag.inner(),
+        Asn1Type::ApplicationTag(application_tag) => application_tag.inner(),
+        Asn1Type::PrivateTag(private_tag) if private_tag.is_constructed() => private_tag.inner(),
+        asn1_type => {
+            match restricted_string_data(asn1_type) {
+                Some(data) if data.len() > CER_SEGMENT_LEN => write_segmented(asn1.tag(), &data, &mut encoded)?,
+                _ => encoded.extend_from_slice(asn1.meta().raw_bytes()),
+            }
+
+            return Ok(encoded);
+        }
+    };
+
+    let mut fields = fields.iter().map(encode_node).collect::<Asn1Result<Vec<_>>>()?;
+    if matches!(asn1.inner_asn1(), Asn1Type::Set(_)) {
+        fields.sort_by(|a, b| der_order(a, b));
+    }
+
+    write_constructed(asn1.tag(), fields.into_iter(), &mut encoded)?;
+
+    Ok(encoded)
+}
+
+/// Encodes the value using CER.
+///
+/// The value is encoded using the definite length first and then every node is re-encoded using CER rules.
+pub(crate) fn encode_cer<E: Asn1Encoder + ?Sized>(value: &E) -> Asn1Result<Vec<u8>> {
+    let mut buff = vec![0; value.needed_buf_size()];
+    value.encode_buff(&mut buff)?;
+
+    let asn1 = Asn1::decode_buff_with_profile(&buff, DecodeProfile::Ber)?;
+
+    encode_node(&asn1)
+}
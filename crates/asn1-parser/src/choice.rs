@@ -0,0 +1,59 @@
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
+
+macro_rules! impl_choice {
+    ($name:ident, $($alternative:ident),+) => {
+        /// ASN.1 CHOICE of the provided alternatives.
+        ///
+        /// Alternatives are tried in the declaration order and the first one that accepts the tag is decoded.
+        /// The `Asn1Decode` derive on enums is preferable when the alternatives are tagged or need names.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum $name<$($alternative),+> {
+            $($alternative($alternative)),+
+        }
+
+        impl<'data, $($alternative: Asn1ValueDecoder<'data>),+> Asn1ValueDecoder<'data> for $name<$($alternative),+> {
+            fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+                $(
+                    if $alternative::compare_tags(tag) {
+                        return Ok(Self::$alternative($alternative::decode(tag, reader)?));
+                    }
+                )+
+
+                Err(Error::from("CHOICE has no alternative for the tag"))
+            }
+
+            fn compare_tags(tag: Tag) -> bool {
+                $($alternative::compare_tags(tag))||+
+            }
+        }
+
+        impl<$($alternative: Taggable),+> Taggable for $name<$($alternative),+> {
+            fn tag(&self) -> Tag {
+                match self {
+                    $(Self::$alternative(value) => value.tag()),+
+                }
+            }
+        }
+
+        impl<$($alternative: Asn1Encoder),+> Asn1Encoder for $name<$($alternative),+> {
+            fn needed_buf_size(&self) -> usize {
+                match self {
+                    $(Self::$alternative(value) => value.needed_buf_size()),+
+                }
+            }
+
+            fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+                match self {
+                    $(Self::$alternative(value) => value.encode(writer)),+
+                }
+            }
+        }
+    };
+}
+
+impl_choice!(Choice2, A, B);
+impl_choice!(Choice3, A, B, C);
+impl_choice!(Choice4, A, B, C, D);
+impl_choice!(Choice5, A, B, C, D, E);
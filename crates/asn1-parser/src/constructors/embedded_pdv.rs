@@ -0,0 +1,298 @@
+use crate::derive_support::{
+    decode_constructed, decode_field, encode_implicit, implicit_len, next_is, tlv_len, write_header, Implicit,
+};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{
+    Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Integer, MetaInfo, ObjectIdentifier, OctetString, Tag, Taggable,
+};
+
+const IDENTIFICATION_TAG: Tag = Tag::context_specific(0, true);
+const DATA_VALUE_TAG: Tag = Tag::context_specific(2, false);
+
+const SYNTAXES_TAG: Tag = Tag::context_specific(0, true);
+const SYNTAX_TAG: Tag = Tag::context_specific(1, false);
+const PRESENTATION_CONTEXT_ID_TAG: Tag = Tag::context_specific(2, false);
+const CONTEXT_NEGOTIATION_TAG: Tag = Tag::context_specific(3, true);
+const TRANSFER_SYNTAX_TAG: Tag = Tag::context_specific(4, false);
+const FIXED_TAG: Tag = Tag::context_specific(5, false);
+
+const FIRST_FIELD_TAG: Tag = Tag::context_specific(0, false);
+const SECOND_FIELD_TAG: Tag = Tag::context_specific(1, false);
+
+/// Identification of the [EmbeddedPdv] abstract and transfer syntaxes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PdvIdentification<'data> {
+    /// `[0] SEQUENCE { abstract [0] OBJECT IDENTIFIER, transfer [1] OBJECT IDENTIFIER }`
+    Syntaxes {
+        abstract_syntax: ObjectIdentifier,
+        transfer_syntax: ObjectIdentifier,
+    },
+    /// `[1] OBJECT IDENTIFIER`
+    Syntax(ObjectIdentifier),
+    /// `[2] INTEGER`
+    PresentationContextId(Integer<'data>),
+    /// `[3] SEQUENCE { presentation-context-id [0] INTEGER, transfer-syntax [1] OBJECT IDENTIFIER }`
+    ContextNegotiation {
+        presentation_context_id: Integer<'data>,
+        transfer_syntax: ObjectIdentifier,
+    },
+    /// `[4] OBJECT IDENTIFIER`
+    TransferSyntax(ObjectIdentifier),
+    /// `[5] NULL`
+    Fixed,
+}
+
+impl PdvIdentification<'_> {
+    fn to_owned(&self) -> PdvIdentification<'static> {
+        match self {
+            PdvIdentification::Syntaxes {
+                abstract_syntax,
+                transfer_syntax,
+            } => PdvIdentification::Syntaxes {
+                abstract_syntax: abstract_syntax.clone(),
+                transfer_syntax: transfer_syntax.clone(),
+            },
+            PdvIdentification::Syntax(syntax) => PdvIdentification::Syntax(syntax.clone()),
+            PdvIdentification::PresentationContextId(id) => PdvIdentification::PresentationContextId(id.to_owned()),
+            PdvIdentification::ContextNegotiation {
+                presentation_context_id,
+                transfer_syntax,
+            } => PdvIdentification::ContextNegotiation {
+                presentation_context_id: presentation_context_id.to_owned(),
+                transfer_syntax: transfer_syntax.clone(),
+            },
+            PdvIdentification::TransferSyntax(syntax) => PdvIdentification::TransferSyntax(syntax.clone()),
+            PdvIdentification::Fixed => PdvIdentification::Fixed,
+        }
+    }
+
+    fn data_len(&self) -> usize {
+        match self {
+            PdvIdentification::Syntaxes {
+                abstract_syntax,
+                transfer_syntax,
+            } => tlv_len(
+                SYNTAXES_TAG,
+                implicit_len(FIRST_FIELD_TAG, abstract_syntax) + implicit_len(SECOND_FIELD_TAG, transfer_syntax),
+            ),
+            PdvIdentification::Syntax(syntax) => implicit_len(SYNTAX_TAG, syntax),
+            PdvIdentification::PresentationContextId(id) => implicit_len(PRESENTATION_CONTEXT_ID_TAG, id),
+            PdvIdentification::ContextNegotiation {
+                presentation_context_id,
+                transfer_syntax,
+            } => tlv_len(
+                CONTEXT_NEGOTIATION_TAG,
+                implicit_len(FIRST_FIELD_TAG, presentation_context_id)
+                    + implicit_len(SECOND_FIELD_TAG, transfer_syntax),
+            ),
+            PdvIdentification::TransferSyntax(syntax) => implicit_len(TRANSFER_SYNTAX_TAG, syntax),
+            PdvIdentification::Fixed => tlv_len(FIXED_TAG, 0),
+        }
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        write_header(IDENTIFICATION_TAG, self.data_len(), writer)?;
+
+        match self {
+            PdvIdentification::Syntaxes {
+                abstract_syntax,
+                transfer_syntax,
+            } => {
+                write_header(
+                    SYNTAXES_TAG,
+                    implicit_len(FIRST_FIELD_TAG, abstract_syntax) + implicit_len(SECOND_FIELD_TAG, transfer_syntax),
+                    writer,
+                )?;
+                encode_implicit(FIRST_FIELD_TAG, abstract_syntax, writer)?;
+                encode_implicit(SECOND_FIELD_TAG, transfer_syntax, writer)
+            }
+            PdvIdentification::Syntax(syntax) => encode_implicit(SYNTAX_TAG, syntax, writer),
+            PdvIdentification::PresentationContextId(id) => encode_implicit(PRESENTATION_CONTEXT_ID_TAG, id, writer),
+            PdvIdentification::ContextNegotiation {
+                presentation_context_id,
+                transfer_syntax,
+            } => {
+                write_header(
+                    CONTEXT_NEGOTIATION_TAG,
+                    implicit_len(FIRST_FIELD_TAG, presentation_context_id)
+                        + implicit_len(SECOND_FIELD_TAG, transfer_syntax),
+                    writer,
+                )?;
+                encode_implicit(FIRST_FIELD_TAG, presentation_context_id, writer)?;
+                encode_implicit(SECOND_FIELD_TAG, transfer_syntax, writer)
+            }
+            PdvIdentification::TransferSyntax(syntax) => encode_implicit(TRANSFER_SYNTAX_TAG, syntax, writer),
+            PdvIdentification::Fixed => write_header(FIXED_TAG, 0, writer),
+        }
+    }
+
+    fn decode<'data>(reader: &mut Reader<'data>) -> Asn1Result<PdvIdentification<'data>> {
+        const ERROR: &str = "Invalid EMBEDDED PDV identification";
+
+        if next_is::<Implicit<ObjectIdentifier, 0x80, 1>>(reader)? {
+            let Implicit(syntax) = decode_field::<Implicit<ObjectIdentifier, 0x80, 1>>(reader, ERROR)?;
+
+            return Ok(PdvIdentification::Syntax(syntax));
+        }
+
+        if next_is::<Implicit<Integer, 0x80, 2>>(reader)? {
+            let Implicit(id) = decode_field::<Implicit<Integer, 0x80, 2>>(reader, ERROR)?;
+
+            return Ok(PdvIdentification::PresentationContextId(id));
+        }
+
+        if next_is::<Implicit<ObjectIdentifier, 0x80, 4>>(reader)? {
+            let Implicit(syntax) = decode_field::<Implicit<ObjectIdentifier, 0x80, 4>>(reader, ERROR)?;
+
+            return Ok(PdvIdentification::TransferSyntax(syntax));
+        }
+
+        if !reader.empty() && Tag::peek(reader)? == FIXED_TAG {
+            decode_constructed(reader, FIXED_TAG, ERROR, |inner_reader| {
+                if !inner_reader.empty() {
+                    return Err(Error::from("EMBEDDED PDV fixed identification should be empty"));
+                }
+
+                Ok(())
+            })?;
+
+            return Ok(PdvIdentification::Fixed);
+        }
+
+        if !reader.empty() && Tag::peek(reader)? == CONTEXT_NEGOTIATION_TAG {
+            return decode_constructed(reader, CONTEXT_NEGOTIATION_TAG, ERROR, |inner_reader| {
+                let Implicit(presentation_context_id) =
+                    decode_field::<Implicit<Integer, 0x80, 0>>(inner_reader, ERROR)?;
+                let Implicit(transfer_syntax) =
+                    decode_field::<Implicit<ObjectIdentifier, 0x80, 1>>(inner_reader, ERROR)?;
+
+                Ok(PdvIdentification::ContextNegotiation {
+                    presentation_context_id,
+                    transfer_syntax,
+                })
+            });
+        }
+
+        decode_constructed(reader, SYNTAXES_TAG, ERROR, |inner_reader| {
+            let Implicit(abstract_syntax) = decode_field::<Implicit<ObjectIdentifier, 0x80, 0>>(inner_reader, ERROR)?;
+            let Implicit(transfer_syntax) = decode_field::<Implicit<ObjectIdentifier, 0x80, 1>>(inner_reader, ERROR)?;
+
+            Ok(PdvIdentification::Syntaxes {
+                abstract_syntax,
+                transfer_syntax,
+            })
+        })
+    }
+}
+
+/// [EMBEDDED PDV](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/embedded-pdv.html)
+///
+/// Value of the type defined outside the current specification. Encoded as (X.690 8.19):
+///
+/// ```not_rust
+/// [UNIVERSAL 11] IMPLICIT SEQUENCE {
+///     identification  [0] CHOICE { ... },
+///     data-value      [2] IMPLICIT OCTET STRING }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmbeddedPdv<'data> {
+    identification: PdvIdentification<'data>,
+    data_value: OctetString<'data>,
+}
+
+pub type OwnedEmbeddedPdv = EmbeddedPdv<'static>;
+
+impl<'data> EmbeddedPdv<'data> {
+    pub const TAG: Tag = Tag::universal(11, true);
+
+    pub fn new(identification: PdvIdentification<'data>, data_value: OctetString<'data>) -> Self {
+        Self {
+            identification,
+            data_value,
+        }
+    }
+
+    pub fn identification(&self) -> &PdvIdentification<'data> {
+        &self.identification
+    }
+
+    pub fn data_value(&self) -> &OctetString<'data> {
+        &self.data_value
+    }
+
+    pub fn to_owned(&self) -> OwnedEmbeddedPdv {
+        EmbeddedPdv {
+            identification: self.identification.to_owned(),
+            data_value: self.data_value.to_owned(),
+        }
+    }
+
+    fn data_len(&self) -> usize {
+        tlv_len(IDENTIFICATION_TAG, self.identification.data_len()) + implicit_len(DATA_VALUE_TAG, &self.data_value)
+    }
+}
+
+impl<'data> Asn1ValueDecoder<'data> for EmbeddedPdv<'data> {
+    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        let identification = decode_constructed(
+            reader,
+            IDENTIFICATION_TAG,
+            "EMBEDDED PDV should start with the identification",
+            |inner_reader| {
+                let identification = PdvIdentification::decode(inner_reader)?;
+
+                if !inner_reader.empty() {
+                    return Err(Error::from("EMBEDDED PDV identification should contain only one value"));
+                }
+
+                Ok(identification)
+            },
+        )?;
+
+        let Implicit(data_value) = decode_field::<Implicit<OctetString, 0x80, 2>>(
+            reader,
+            "EMBEDDED PDV should contain the data value after the identification",
+        )?;
+
+        if !reader.empty() {
+            return Err(Error::from("EMBEDDED PDV value contains trailing data"));
+        }
+
+        Ok(Self {
+            identification,
+            data_value,
+        })
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        Self::TAG == tag
+    }
+}
+
+impl Taggable for EmbeddedPdv<'_> {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl Asn1Encoder for EmbeddedPdv<'_> {
+    fn needed_buf_size(&self) -> usize {
+        tlv_len(Self::TAG, self.data_len())
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        write_header(Self::TAG, self.data_len(), writer)?;
+
+        self.identification.encode(writer)?;
+        encode_implicit(DATA_VALUE_TAG, &self.data_value, writer)
+    }
+}
+
+impl MetaInfo for EmbeddedPdv<'_> {
+    fn clear_meta(&mut self) {
+        self.data_value.clear_meta()
+    }
+}
@@ -0,0 +1,260 @@
+use alloc::boxed::Box;
+
+use crate::asn1::Asn1;
+use crate::derive_support::{
+    decode_constructed, decode_field, encode_explicit, encode_implicit, explicit_len, implicit_len, next_is, tlv_len,
+    write_header, Implicit,
+};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{
+    Asn1Decoder, Asn1Encoder, Asn1Result, Asn1ValueDecoder, BitString, Error, GraphicString, Integer, MetaInfo,
+    ObjectIdentifier, OctetString, Tag, Taggable,
+};
+
+const DESCRIPTOR_TAG: Tag = Tag::universal(7, false);
+const SINGLE_ASN1_TYPE_TAG: Tag = Tag::context_specific(0, true);
+const OCTET_ALIGNED_TAG: Tag = Tag::context_specific(1, false);
+const ARBITRARY_TAG: Tag = Tag::context_specific(2, false);
+
+/// Encoding of the [External] data value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExternalEncoding<'data> {
+    /// `[0] EXPLICIT` asn1 value
+    SingleAsn1Type(Box<Asn1<'data>>),
+    /// `[1] IMPLICIT OCTET STRING`
+    OctetAligned(OctetString<'data>),
+    /// `[2] IMPLICIT BIT STRING`
+    Arbitrary(BitString<'data>),
+}
+
+impl ExternalEncoding<'_> {
+    fn to_owned(&self) -> ExternalEncoding<'static> {
+        match self {
+            ExternalEncoding::SingleAsn1Type(asn1) => {
+                ExternalEncoding::SingleAsn1Type(Box::new(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned())))
+            }
+            ExternalEncoding::OctetAligned(octets) => ExternalEncoding::OctetAligned(octets.to_owned()),
+            ExternalEncoding::Arbitrary(bits) => ExternalEncoding::Arbitrary(bits.to_owned()),
+        }
+    }
+
+    fn needed_buf_size(&self) -> usize {
+        match self {
+            ExternalEncoding::SingleAsn1Type(asn1) => explicit_len(SINGLE_ASN1_TYPE_TAG, asn1.as_ref()),
+            ExternalEncoding::OctetAligned(octets) => implicit_len(OCTET_ALIGNED_TAG, octets),
+            ExternalEncoding::Arbitrary(bits) => implicit_len(ARBITRARY_TAG, bits),
+        }
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        match self {
+            ExternalEncoding::SingleAsn1Type(asn1) => encode_explicit(SINGLE_ASN1_TYPE_TAG, asn1.as_ref(), writer),
+            ExternalEncoding::OctetAligned(octets) => encode_implicit(OCTET_ALIGNED_TAG, octets, writer),
+            ExternalEncoding::Arbitrary(bits) => encode_implicit(ARBITRARY_TAG, bits, writer),
+        }
+    }
+}
+
+/// [EXTERNAL](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/external.html)
+///
+/// Value of the type defined outside the current specification. Encoded as (X.690 8.18):
+///
+/// ```not_rust
+/// [UNIVERSAL 8] IMPLICIT SEQUENCE {
+///     direct-reference        OBJECT IDENTIFIER OPTIONAL,
+///     indirect-reference      INTEGER OPTIONAL,
+///     data-value-descriptor   ObjectDescriptor OPTIONAL,
+///     encoding                CHOICE { ... } }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct External<'data> {
+    direct_reference: Option<ObjectIdentifier>,
+    indirect_reference: Option<Integer<'data>>,
+    data_value_descriptor: Option<GraphicString<'data>>,
+    encoding: ExternalEncoding<'data>,
+}
+
+pub type OwnedExternal = External<'static>;
+
+impl<'data> External<'data> {
+    pub const TAG: Tag = Tag::universal(8, true);
+
+    pub fn new(
+        direct_reference: Option<ObjectIdentifier>,
+        indirect_reference: Option<Integer<'data>>,
+        data_value_descriptor: Option<GraphicString<'data>>,
+        encoding: ExternalEncoding<'data>,
+    ) -> Self {
+        Self {
+            direct_reference,
+            indirect_reference,
+            data_value_descriptor,
+            encoding,
+        }
+    }
+
+    pub fn direct_reference(&self) -> Option<&ObjectIdentifier> {
+        self.direct_reference.as_ref()
+    }
+
+    pub fn indirect_reference(&self) -> Option<&Integer<'data>> {
+        self.indirect_reference.as_ref()
+    }
+
+    pub fn data_value_descriptor(&self) -> Option<&GraphicString<'data>> {
+        self.data_value_descriptor.as_ref()
+    }
+
+    pub fn encoding(&self) -> &ExternalEncoding<'data> {
+        &self.encoding
+    }
+
+    /// Returns the data value if it is encoded as a single asn1 value.
+    pub fn inner(&self) -> Option<&Asn1<'data>> {
+        match &self.encoding {
+            ExternalEncoding::SingleAsn1Type(asn1) => Some(asn1),
+            _ => None,
+        }
+    }
+
+    pub fn to_owned(&self) -> OwnedExternal {
+        External {
+            direct_reference: self.direct_reference.clone(),
+            indirect_reference: self.indirect_reference.as_ref().map(Integer::to_owned),
+            data_value_descriptor: self.data_value_descriptor.as_ref().map(GraphicString::to_owned),
+            encoding: self.encoding.to_owned(),
+        }
+    }
+
+    fn data_len(&self) -> usize {
+        self.direct_reference
+            .as_ref()
+            .map(Asn1Encoder::needed_buf_size)
+            .unwrap_or_default()
+            + self
+                .indirect_reference
+                .as_ref()
+                .map(Asn1Encoder::needed_buf_size)
+                .unwrap_or_default()
+            + self
+                .data_value_descriptor
+                .as_ref()
+                .map(|descriptor| implicit_len(DESCRIPTOR_TAG, descriptor))
+                .unwrap_or_default()
+            + self.encoding.needed_buf_size()
+    }
+}
+
+fn decode_encoding<'data>(reader: &mut Reader<'data>) -> Asn1Result<ExternalEncoding<'data>> {
+    if next_is::<Implicit<OctetString, 0x80, 1>>(reader)? {
+        let Implicit(octets) = decode_field::<Implicit<OctetString, 0x80, 1>>(reader, "Invalid EXTERNAL encoding")?;
+
+        return Ok(ExternalEncoding::OctetAligned(octets));
+    }
+
+    if next_is::<Implicit<BitString, 0x80, 2>>(reader)? {
+        let Implicit(bits) = decode_field::<Implicit<BitString, 0x80, 2>>(reader, "Invalid EXTERNAL encoding")?;
+
+        return Ok(ExternalEncoding::Arbitrary(bits));
+    }
+
+    decode_constructed(
+        reader,
+        SINGLE_ASN1_TYPE_TAG,
+        "EXTERNAL encoding should be one of [0], [1], or [2]",
+        |inner_reader| {
+            let asn1 = Asn1::decode(inner_reader)?;
+
+            if !inner_reader.empty() {
+                return Err(Error::from("EXTERNAL single-ASN1-type should contain only one value"));
+            }
+
+            Ok(ExternalEncoding::SingleAsn1Type(Box::new(asn1)))
+        },
+    )
+}
+
+impl<'data> Asn1ValueDecoder<'data> for External<'data> {
+    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        let direct_reference = if next_is::<ObjectIdentifier>(reader)? {
+            Some(decode_field(reader, "Invalid EXTERNAL direct-reference")?)
+        } else {
+            None
+        };
+
+        let indirect_reference = if next_is::<Integer>(reader)? {
+            Some(decode_field(reader, "Invalid EXTERNAL indirect-reference")?)
+        } else {
+            None
+        };
+
+        let data_value_descriptor = if next_is::<Implicit<GraphicString, 0, 7>>(reader)? {
+            let Implicit(descriptor) =
+                decode_field::<Implicit<GraphicString, 0, 7>>(reader, "Invalid EXTERNAL data-value-descriptor")?;
+
+            Some(descriptor)
+        } else {
+            None
+        };
+
+        let encoding = decode_encoding(reader)?;
+
+        if !reader.empty() {
+            return Err(Error::from("EXTERNAL value contains trailing data"));
+        }
+
+        Ok(Self {
+            direct_reference,
+            indirect_reference,
+            data_value_descriptor,
+            encoding,
+        })
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        Self::TAG == tag
+    }
+}
+
+impl Taggable for External<'_> {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl Asn1Encoder for External<'_> {
+    fn needed_buf_size(&self) -> usize {
+        tlv_len(Self::TAG, self.data_len())
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        write_header(Self::TAG, self.data_len(), writer)?;
+
+        if let Some(direct_reference) = self.direct_reference.as_ref() {
+            direct_reference.encode(writer)?;
+        }
+
+        if let Some(indirect_reference) = self.indirect_reference.as_ref() {
+            indirect_reference.encode(writer)?;
+        }
+
+        if let Some(descriptor) = self.data_value_descriptor.as_ref() {
+            encode_implicit(DESCRIPTOR_TAG, descriptor, writer)?;
+        }
+
+        self.encoding.encode(writer)
+    }
+}
+
+impl MetaInfo for External<'_> {
+    fn clear_meta(&mut self) {
+        match &mut self.encoding {
+            ExternalEncoding::SingleAsn1Type(asn1) => asn1.clear_meta(),
+            ExternalEncoding::OctetAligned(octets) => octets.clear_meta(),
+            ExternalEncoding::Arbitrary(bits) => bits.clear_meta(),
+        }
+    }
+}
@@ -1,5 +1,14 @@
+mod embedded_pdv;
+mod external;
 mod sequence;
+mod sequence_of;
 mod set;
+mod set_of;
 
+pub use embedded_pdv::{EmbeddedPdv, OwnedEmbeddedPdv, PdvIdentification};
+pub use external::{External, ExternalEncoding, OwnedExternal};
 pub use sequence::{OwnedSequence, Sequence};
+pub use sequence_of::SequenceOf;
+pub(crate) use set::{der_order, validate_der_order};
 pub use set::{OwnedSet, Set};
+pub use set_of::SetOf;
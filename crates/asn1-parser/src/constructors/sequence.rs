@@ -10,19 +10,25 @@ use crate::{Asn1Decoder, Asn1Encoder, Asn1Result, Asn1ValueDecoder, MetaInfo, Ta
 ///
 /// In ASN.1, an ordered list of elements (or components) comprises a SEQUENCE.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Sequence<'data>(Vec<Asn1<'data>>);
 
 pub type OwnedSequence = Sequence<'static>;
 
-impl Sequence<'_> {
+impl<'data> Sequence<'data> {
     /// Tag value of the [SEQUENCE] type
-    pub const TAG: Tag = Tag(0x30);
+    pub const TAG: Tag = Tag::from_octet(0x30);
 
     /// Creates a new [Sequence] from passed fields
     pub fn new(fields: Vec<Asn1>) -> Sequence {
         Sequence(fields)
     }
 
+    /// Creates a new [Sequence] from values convertible into [Asn1] nodes
+    pub fn of<T: Into<Asn1<'data>>>(fields: impl IntoIterator<Item = T>) -> Self {
+        Sequence(fields.into_iter().map(Into::into).collect())
+    }
+
     /// Returns [Sequence] fields
     pub fn fields(&self) -> &[Asn1<'_>] {
         &self.0
@@ -39,6 +45,8 @@ impl Sequence<'_> {
     }
 }
 
+impl_fields_editing!(Sequence, 0);
+
 impl<'data> From<Vec<Asn1<'data>>> for Sequence<'data> {
     fn from(fields: Vec<Asn1<'data>>) -> Self {
         Self(fields)
@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+
+use crate::length::{len_size, write_len};
+use crate::reader::Reader;
+use crate::tlv::Tlv;
+use crate::writer::Writer;
+use crate::{Asn1Decoder, Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, MetaInfo, Sequence, Tag, Taggable};
+
+/// [ASN.1 SEQUENCE OF](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/sequence-of.html)
+///
+/// Typed SEQUENCE where all elements have the same type. Elements are decoded directly into `T` values.
+/// It is not a part of the [crate::Asn1Type] and is intended for the typed protocol parsers: `Tlv::<SequenceOf<Integer>>::decode_buff(raw)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SequenceOf<T>(Vec<T>);
+
+impl<T> SequenceOf<T> {
+    /// Tag value of the [SEQUENCE OF] type
+    pub const TAG: Tag = Sequence::TAG;
+
+    /// Creates a new [SequenceOf] from passed elements
+    pub fn new(elements: Vec<T>) -> Self {
+        Self(elements)
+    }
+
+    /// Returns [SequenceOf] elements
+    pub fn elements(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns [SequenceOf] elements as a vector
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Default for SequenceOf<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> From<Vec<T>> for SequenceOf<T> {
+    fn from(elements: Vec<T>) -> Self {
+        Self(elements)
+    }
+}
+
+impl<T> From<SequenceOf<T>> for Vec<T> {
+    fn from(sequence_of: SequenceOf<T>) -> Self {
+        sequence_of.0
+    }
+}
+
+impl<T> Taggable for SequenceOf<T> {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl<T: Asn1Encoder> Asn1Encoder for SequenceOf<T> {
+    fn needed_buf_size(&self) -> usize {
+        let data_len = self.0.iter().map(|e| e.needed_buf_size()).sum();
+
+        1 /* tag */ + len_size(data_len) + data_len
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        writer.write_byte(Self::TAG.into())?;
+
+        let data_len = self.0.iter().map(|e| e.needed_buf_size()).sum();
+        write_len(data_len, writer)?;
+
+        self.0.iter().try_for_each(|e| e.encode(writer))
+    }
+}
+
+impl<'data, T: Asn1ValueDecoder<'data>> Asn1ValueDecoder<'data> for SequenceOf<T> {
+    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        let mut elements = Vec::new();
+
+        while !reader.empty() {
+            if !T::compare_tags(Tag::peek(reader)?) {
+                return Err(Error::from("SequenceOf element has unexpected tag"));
+            }
+
+            elements.push(Tlv::<T>::decode(reader)?.into_inner());
+        }
+
+        Ok(Self(elements))
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        Self::TAG == tag
+    }
+}
+
+impl<T: MetaInfo> MetaInfo for SequenceOf<T> {
+    fn clear_meta(&mut self) {
+        self.0.iter_mut().for_each(|e| e.clear_meta())
+    }
+}
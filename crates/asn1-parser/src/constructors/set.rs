@@ -1,35 +1,124 @@
+use alloc::vec;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 
 use crate::asn1::Asn1;
 use crate::length::write_len;
-use crate::reader::Reader;
+use crate::reader::{DecodeProfile, Reader};
 use crate::writer::Writer;
-use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, MetaInfo, Sequence, Tag, Taggable};
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, MetaInfo, Sequence, Tag, Taggable};
+
+/// Compares encoded SET elements according to the DER canonical order (X.690, 10.3 and 11.6):
+/// elements are ordered by their tags (class, then number) and elements with the same tag are ordered by their encodings.
+pub(crate) fn der_order(a: &[u8], b: &[u8]) -> Ordering {
+    let tag_key = |encoded: &[u8]| {
+        let mut reader = Reader::new(encoded);
+        reader.set_profile(DecodeProfile::Ber);
+
+        Tag::from_reader(&mut reader)
+            .ok()
+            .map(|tag| (tag.class(), tag.number()))
+    };
+
+    tag_key(a).cmp(&tag_key(b)).then_with(|| a.cmp(b))
+}
+
+/// Checks that encoded SET elements are in the DER canonical order.
+pub(crate) fn validate_der_order<'a>(encoded: impl Iterator<Item = &'a [u8]>) -> Asn1Result<()> {
+    let mut previous: Option<&[u8]> = None;
+
+    for element in encoded {
+        if previous
+            .map(|previous| der_order(previous, element) == Ordering::Greater)
+            .unwrap_or(false)
+        {
+            return Err(Error::from("SET elements are not in the DER canonical order"));
+        }
+
+        previous = Some(element);
+    }
+
+    Ok(())
+}
+
+/// Sorts SET elements according to the DER canonical order.
+pub(crate) fn sort_der<T: Asn1Encoder>(elements: &mut Vec<T>) -> Asn1Result<()> {
+    let mut encoded = core::mem::take(elements)
+        .into_iter()
+        .map(|element| {
+            let mut buff = vec![0; element.needed_buf_size()];
+            element.encode_buff(&mut buff)?;
+
+            Ok((buff, element))
+        })
+        .collect::<Asn1Result<Vec<_>>>()?;
+
+    encoded.sort_by(|(a, _), (b, _)| der_order(a, b));
+    elements.extend(encoded.into_iter().map(|(_, element)| element));
+
+    Ok(())
+}
 
 /// [ASN.1 SET](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/set.html)
 ///
 /// The ASN.1 SET type is similar to the SEQUENCE type. The key difference is that the elements
 /// in each value of a SEQUENCE type must appear in the order shown in the definition.
-/// The elements of a SET type value may appear in any order, regardless of how they are listed in the SET's definition
+/// The elements of a SET type value may appear in any order, regardless of how they are listed in the SET's definition.
+/// DER requires the elements to be in the canonical order. Use [Set::sort_der] to sort them before encoding.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Set<'data>(Sequence<'data>);
 
 pub type OwnedSet = Set<'static>;
 
-impl Set<'_> {
+impl<'data> Set<'data> {
     /// Tag value of the [SET] type
-    pub const TAG: Tag = Tag(0x31);
+    pub const TAG: Tag = Tag::from_octet(0x31);
 
     /// Creates a new [Set] from passed fields
     pub fn new(fields: Vec<Asn1>) -> Set {
         Set(Sequence::new(fields))
     }
 
+    /// Creates a new [Set] from values convertible into [Asn1] nodes. DER requires sorted fields: use [Set::sort_der].
+    pub fn of<T: Into<Asn1<'data>>>(fields: impl IntoIterator<Item = T>) -> Self {
+        Set(Sequence::of(fields))
+    }
+
     /// Returns [Set] fields
     pub fn fields(&self) -> &[Asn1<'_>] {
         self.0.fields()
     }
 
+    pub(crate) fn fields_mut(&mut self) -> &mut Vec<Asn1<'data>> {
+        self.0.fields_mut()
+    }
+
+    /// Returns a mutable reference to the field. DER requires sorted fields: use [Set::sort_der] after editing.
+    pub fn field_mut(&mut self, index: usize) -> Option<&mut Asn1<'data>> {
+        self.0.field_mut(index)
+    }
+
+    /// Inserts the field at the `index`. Pass the number of fields as the `index` to append the field.
+    pub fn insert_field(&mut self, index: usize, field: Asn1<'data>) -> Asn1Result<()> {
+        self.0.insert_field(index, field)
+    }
+
+    /// Removes the field and returns it.
+    pub fn remove_field(&mut self, index: usize) -> Asn1Result<Asn1<'data>> {
+        self.0.remove_field(index)
+    }
+
+    /// Replaces the field and returns the old one.
+    pub fn replace_field(&mut self, index: usize, field: Asn1<'data>) -> Asn1Result<Asn1<'data>> {
+        self.0.replace_field(index, field)
+    }
+
+    /// Sorts [Set] fields according to the DER canonical order
+    pub fn sort_der(&mut self) -> Asn1Result<()> {
+        sort_der(self.0.fields_mut())
+    }
+
     /// Returns owned version of the [Set]
     pub fn to_owned(&self) -> OwnedSet {
         Set(Sequence::from(
@@ -71,7 +160,13 @@ impl Asn1Encoder for Set<'_> {
 
 impl<'data> Asn1ValueDecoder<'data> for Set<'data> {
     fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
-        Ok(Self(Sequence::decode(tag, reader)?))
+        let fields = Sequence::decode(tag, reader)?;
+
+        if reader.profile().is_canonical() {
+            validate_der_order(fields.fields().iter().map(|f| f.meta().raw_bytes()))?;
+        }
+
+        Ok(Self(fields))
     }
 
     fn compare_tags(tag: Tag) -> bool {
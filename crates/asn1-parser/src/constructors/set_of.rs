@@ -0,0 +1,117 @@
+use alloc::vec::Vec;
+
+use super::set::{sort_der, validate_der_order};
+use crate::length::{len_size, write_len};
+use crate::reader::Reader;
+use crate::tlv::Tlv;
+use crate::writer::Writer;
+use crate::{Asn1Decoder, Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, MetaInfo, Set, Tag, Taggable};
+
+/// [ASN.1 SET OF](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/set-of.html)
+///
+/// Typed SET where all elements have the same type. Elements are decoded directly into `T` values.
+/// DER requires the elements to be sorted by their encodings. Use [SetOf::sort_der] to sort them before encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetOf<T>(Vec<T>);
+
+impl<T> SetOf<T> {
+    /// Tag value of the [SET OF] type
+    pub const TAG: Tag = Set::TAG;
+
+    /// Creates a new [SetOf] from passed elements
+    pub fn new(elements: Vec<T>) -> Self {
+        Self(elements)
+    }
+
+    /// Returns [SetOf] elements
+    pub fn elements(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns [SetOf] elements as a vector
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T: Asn1Encoder> SetOf<T> {
+    /// Sorts [SetOf] elements according to the DER canonical order
+    pub fn sort_der(&mut self) -> Asn1Result<()> {
+        sort_der(&mut self.0)
+    }
+}
+
+impl<T> Default for SetOf<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> From<Vec<T>> for SetOf<T> {
+    fn from(elements: Vec<T>) -> Self {
+        Self(elements)
+    }
+}
+
+impl<T> From<SetOf<T>> for Vec<T> {
+    fn from(set_of: SetOf<T>) -> Self {
+        set_of.0
+    }
+}
+
+impl<T> Taggable for SetOf<T> {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl<T: Asn1Encoder> Asn1Encoder for SetOf<T> {
+    fn needed_buf_size(&self) -> usize {
+        let data_len = self.0.iter().map(|e| e.needed_buf_size()).sum();
+
+        1 /* tag */ + len_size(data_len) + data_len
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        writer.write_byte(Self::TAG.into())?;
+
+        let data_len = self.0.iter().map(|e| e.needed_buf_size()).sum();
+        write_len(data_len, writer)?;
+
+        self.0.iter().try_for_each(|e| e.encode(writer))
+    }
+}
+
+impl<'data, T: Asn1ValueDecoder<'data>> Asn1ValueDecoder<'data> for SetOf<T> {
+    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        let mut elements = Vec::new();
+        let mut encoded = Vec::new();
+
+        while !reader.empty() {
+            if !T::compare_tags(Tag::peek(reader)?) {
+                return Err(Error::from("SetOf element has unexpected tag"));
+            }
+
+            let start = reader.position();
+            elements.push(Tlv::<T>::decode(reader)?.into_inner());
+            encoded.push(reader.data_in_range(start..reader.position())?);
+        }
+
+        if reader.profile().is_canonical() {
+            validate_der_order(encoded.into_iter())?;
+        }
+
+        Ok(Self(elements))
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        Self::TAG == tag
+    }
+}
+
+impl<T: MetaInfo> MetaInfo for SetOf<T> {
+    fn clear_meta(&mut self) {
+        self.0.iter_mut().for_each(|e| e.clear_meta())
+    }
+}
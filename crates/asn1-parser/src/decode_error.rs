@@ -0,0 +1,76 @@
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::ops::Range;
+
+use crate::writer::Writer;
+use crate::{Asn1Encoder, Asn1Result, Error, Tag, Taggable};
+
+/// Undecodable region of the input data. It is produced only by the lenient decoding.
+///
+/// If the tag and length are valid, the region is one value. Otherwise, it is the rest of the enclosing value data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecodeError<'data> {
+    tag: Tag,
+    raw_data: Cow<'data, [u8]>,
+    range: Range<usize>,
+    reason: String,
+}
+
+pub type OwnedDecodeError = DecodeError<'static>;
+
+impl<'data> DecodeError<'data> {
+    /// Creates a new [DecodeError] from the raw data that starts at the `offset` of the input.
+    pub fn new(tag: Tag, raw_data: &'data [u8], offset: usize, error: &Error) -> Self {
+        Self {
+            tag,
+            raw_data: Cow::Borrowed(raw_data),
+            range: offset..offset + raw_data.len(),
+            reason: error.message().to_string(),
+        }
+    }
+}
+
+impl DecodeError<'_> {
+    /// Returns raw bytes of the undecodable region
+    pub fn raw_data(&self) -> &[u8] {
+        &self.raw_data
+    }
+
+    /// Returns range of the undecodable region in the input data
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the decoding error message
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub fn to_owned(&self) -> OwnedDecodeError {
+        DecodeError {
+            tag: self.tag,
+            raw_data: self.raw_data.to_vec().into(),
+            range: self.range.clone(),
+            reason: self.reason.clone(),
+        }
+    }
+}
+
+impl Taggable for DecodeError<'_> {
+    /// Returns the tag of the undecodable value. It is made up from the first octet if the tag itself is invalid.
+    fn tag(&self) -> Tag {
+        self.tag
+    }
+}
+
+impl Asn1Encoder for DecodeError<'_> {
+    fn needed_buf_size(&self) -> usize {
+        self.raw_data.len()
+    }
+
+    /// Writes the raw bytes as is
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        writer.write_slice(&self.raw_data)
+    }
+}
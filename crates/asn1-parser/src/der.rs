@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::asn1::{Asn1, Asn1Type};
+use crate::constructors::validate_der_order;
+use crate::primitives::has_redundant_leading_byte;
+use crate::Asn1Entity;
+
+/// Kind of the DER rule violation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DerViolationKind {
+    /// The length is not encoded in the minimal number of bytes.
+    NonMinimalLength,
+    /// The indefinite length form is used.
+    IndefiniteLength,
+    /// The BOOLEAN value is not `0x00` or `0xff`.
+    NonCanonicalBool,
+    /// SET elements are not in the canonical order.
+    UnsortedSet,
+    /// INTEGER or ENUMERATED value has a redundant leading byte.
+    IntegerLeadingZero,
+}
+
+impl fmt::Display for DerViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DerViolationKind::NonMinimalLength => "non-minimal length encoding",
+            DerViolationKind::IndefiniteLength => "indefinite length",
+            DerViolationKind::NonCanonicalBool => "BOOLEAN value other than 0x00 or 0xff",
+            DerViolationKind::UnsortedSet => "SET elements are not sorted",
+            DerViolationKind::IntegerLeadingZero => "INTEGER with unnecessary leading zero",
+        })
+    }
+}
+
+/// DER rule violation found by [Asn1::validate_der].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DerViolation {
+    /// Id of the node that violates the rule
+    pub node_id: u64,
+    /// Offset of the violating bytes in the input data
+    pub offset: usize,
+    pub kind: DerViolationKind,
+}
+
+impl Asn1<'_> {
+    /// Checks the decoded tree against DER rules and reports all violations instead of failing on the first one.
+    ///
+    /// The data should be decoded using the BER profile because the DER decoding rejects most of these violations.
+    /// Encapsulated values (e.g. inside OCTET STRING) are not checked.
+    pub fn validate_der(&self) -> Vec<DerViolation> {
+        let mut violations = Vec::new();
+        validate_node(self, &mut violations);
+
+        violations
+    }
+}
+
+fn validate_node(asn1: &Asn1<'_>, violations: &mut Vec<DerViolation>) {
+    let meta = asn1.meta();
+    let mut report = |offset: usize, kind| {
+        violations.push(DerViolation {
+            node_id: asn1.id(),
+            offset: meta.tag_position() + offset,
+            kind,
+        })
+    };
+
+    match meta.length_bytes() {
+        [0x80] => report(meta.length_range().start, DerViolationKind::IndefiniteLength),
+        [first, rest @ ..] if first & 0x80 != 0 && (rest.first() == Some(&0) || meta.data_range().len() < 128) => {
+            report(meta.length_range().start, DerViolationKind::NonMinimalLength)
+        }
+        _ => {}
+    }
+
+    let fields = match asn1.inner_asn1() {
+        Asn1Type::Bool(_) => {
+            if !matches!(meta.data_bytes(), [0x00] | [0xff]) {
+                report(meta.data_range().start, DerViolationKind::NonCanonicalBool);
+            }
+
+            return;
+        }
+        Asn1Type::Integer(_) | Asn1Type::Enumerated(_) => {
+            if has_redundant_leading_byte(meta.data_bytes()) {
+                report(meta.data_range().start, DerViolationKind::IntegerLeadingZero);
+            }
+
+            return;
+        }
+        Asn1Type::Set(set) => {
+            if validate_der_order(set.fields().iter().map(|f| f.meta().raw_bytes())).is_err() {
+                report(0, DerViolationKind::UnsortedSet);
+            }
+
+            set.fields()
+        }
+        Asn1Type::Sequence(sequence) => sequence.fields(),
+        Asn1Type::ExplicitTag(explicit_tag) => explicit_tag.inner(),
+        Asn1Type::ApplicationTag(application_tag) => application_tag.inner(),
+        Asn1Type::PrivateTag(private_tag) => private_tag.inner(),
+        _ => return,
+    };
+
+    fields.iter().for_each(|field| validate_node(field, violations));
+}
@@ -0,0 +1,149 @@
+//! Helpers for the code generated by the `asn1-derive` crate. It is not a part of the public API.
+
+use alloc::vec;
+
+use crate::length::{len_size, read_len, write_len};
+use crate::reader::read_value_data;
+pub use crate::reader::Reader;
+pub use crate::writer::Writer;
+use crate::{Asn1Decoder, Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Sequence, Tag, Taggable, Tlv};
+
+fn same_class_and_number(tag: Tag, class: u8, number: u32) -> bool {
+    tag.class().bits() == class && tag.number() == number
+}
+
+pub const fn tag(class: u8, constructed: bool, number: u32) -> Tag {
+    Tag::new(class, constructed, number)
+}
+
+/// Returns `true` if the next value can be decoded as `T`.
+pub fn next_is<'data, T: Asn1ValueDecoder<'data>>(reader: &Reader<'data>) -> Asn1Result<bool> {
+    if reader.empty() {
+        return Ok(false);
+    }
+
+    Ok(T::compare_tags(Tag::peek(reader)?))
+}
+
+/// Decodes the next value as `T`. The `error` is returned if the value is missing or has an unexpected tag.
+pub fn decode_field<'data, T: Asn1ValueDecoder<'data>>(
+    reader: &mut Reader<'data>,
+    error: &'static str,
+) -> Asn1Result<T> {
+    if !next_is::<T>(reader)? {
+        return Err(Error::from(error));
+    }
+
+    Ok(Tlv::<T>::decode(reader)?.into_inner())
+}
+
+/// Reads the next SEQUENCE and decodes its fields using the provided function.
+pub fn decode_sequence<'data, R>(
+    reader: &mut Reader<'data>,
+    decode_fields: impl FnOnce(&mut Reader<'data>) -> Asn1Result<R>,
+) -> Asn1Result<R> {
+    decode_constructed(
+        reader,
+        Sequence::TAG,
+        "Explicitly tagged value should contain SEQUENCE",
+        decode_fields,
+    )
+}
+
+/// Reads the next value with the provided tag and decodes its data using the provided function.
+/// The `error` is returned if the value has an unexpected tag.
+pub fn decode_constructed<'data, R>(
+    reader: &mut Reader<'data>,
+    expected_tag: Tag,
+    error: &'static str,
+    decode_fields: impl FnOnce(&mut Reader<'data>) -> Asn1Result<R>,
+) -> Asn1Result<R> {
+    let tag = Tag::from_reader(reader)?;
+    if tag != expected_tag {
+        return Err(Error::from(error));
+    }
+
+    let (len, _) = read_len(reader)?;
+    let (data, data_range) = read_value_data(tag, len, reader)?;
+
+    let mut inner_reader = reader.nested(data)?;
+    inner_reader.set_next_id(reader.next_id());
+    inner_reader.set_offset(reader.offset_of(data_range.start)?);
+    let value = decode_fields(&mut inner_reader)?;
+    reader.set_next_id(inner_reader.next_id());
+
+    Ok(value)
+}
+
+/// Returns the number of bytes needed to encode the value with the provided tag and data length.
+pub fn tlv_len(tag: Tag, data_len: usize) -> usize {
+    tag.encoded_len() + len_size(data_len) + data_len
+}
+
+pub fn write_header(tag: Tag, data_len: usize, writer: &mut Writer) -> Asn1Result<()> {
+    tag.to_writer(writer)?;
+    write_len(data_len, writer)
+}
+
+pub fn explicit_len<T: Asn1Encoder>(tag: Tag, value: &T) -> usize {
+    tlv_len(tag, value.needed_buf_size())
+}
+
+pub fn encode_explicit<T: Asn1Encoder>(tag: Tag, value: &T, writer: &mut Writer) -> Asn1Result<()> {
+    write_header(tag, value.needed_buf_size(), writer)?;
+    value.encode(writer)
+}
+
+pub fn implicit_len<T: Asn1Encoder + Taggable>(tag: Tag, value: &T) -> usize {
+    value.needed_buf_size() - value.tag().encoded_len() + tag.encoded_len()
+}
+
+/// Encodes the value replacing its tag. The constructed bit of the original tag is preserved.
+pub fn encode_implicit<T: Asn1Encoder + Taggable>(tag: Tag, value: &T, writer: &mut Writer) -> Asn1Result<()> {
+    let mut buff = vec![0; value.needed_buf_size()];
+    value.encode_buff(&mut buff)?;
+
+    let tag = if value.tag().is_constructed() {
+        tag.constructed()
+    } else {
+        tag
+    };
+    tag.to_writer(writer)?;
+    writer.write_slice(&buff[value.tag().encoded_len()..])
+}
+
+/// `[CLASS NUMBER] EXPLICIT T`. Used for decoding the tagged fields.
+pub struct Explicit<T, const CLASS: u8, const NUMBER: u32>(pub T);
+
+impl<'data, T: Asn1ValueDecoder<'data>, const CLASS: u8, const NUMBER: u32> Asn1ValueDecoder<'data>
+    for Explicit<T, CLASS, NUMBER>
+{
+    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        let value = decode_field(reader, "Explicitly tagged value has unexpected tag")?;
+
+        if !reader.empty() {
+            return Err(Error::from("Explicitly tagged value should contain only one value"));
+        }
+
+        Ok(Self(value))
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        tag.is_constructed() && same_class_and_number(tag, CLASS, NUMBER)
+    }
+}
+
+/// `[CLASS NUMBER] IMPLICIT T`. Used for decoding the tagged fields.
+pub struct Implicit<T, const CLASS: u8, const NUMBER: u32>(pub T);
+
+impl<'data, T: Asn1ValueDecoder<'data>, const CLASS: u8, const NUMBER: u32> Asn1ValueDecoder<'data>
+    for Implicit<T, CLASS, NUMBER>
+{
+    fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        Ok(Self(T::decode(tag, reader)?))
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        same_class_and_number(tag, CLASS, NUMBER)
+    }
+}
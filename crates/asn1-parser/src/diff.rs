@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::asn1::Asn1;
+use crate::Asn1Entity;
+
+/// Kind of the difference between two asn1 trees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiffKind {
+    /// The node is present only in the second tree.
+    Added,
+    /// The node is present only in the first tree.
+    Removed,
+    /// The node has a different tag or value.
+    Changed,
+}
+
+/// Difference between two asn1 trees found by [asn1_diff].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiffEntry {
+    pub kind: DiffKind,
+    /// Child indices from the root to the node. Encapsulated value (e.g. inside OCTET STRING) has index 0.
+    pub path: Vec<usize>,
+    /// Range of the node bytes in the first tree input data. `None` for the added nodes.
+    pub left: Option<Range<usize>>,
+    /// Range of the node bytes in the second tree input data. `None` for the removed nodes.
+    pub right: Option<Range<usize>>,
+}
+
+/// Compares two asn1 trees node by node.
+///
+/// Child nodes are matched by their position. Constructed nodes are not reported as changed
+/// when only their child nodes differ: the differing child nodes are reported instead.
+pub fn asn1_diff(a: &Asn1<'_>, b: &Asn1<'_>) -> Vec<DiffEntry> {
+    let mut entries = Vec::new();
+    diff_node(a, b, &mut Vec::new(), &mut entries);
+
+    entries
+}
+
+fn node_range(asn1: &Asn1<'_>) -> Range<usize> {
+    let meta = asn1.meta();
+
+    meta.tag_position()..meta.tag_position() + meta.raw_bytes().len()
+}
+
+fn diff_node(a: &Asn1<'_>, b: &Asn1<'_>, path: &mut Vec<usize>, entries: &mut Vec<DiffEntry>) {
+    let changed = || DiffEntry {
+        kind: DiffKind::Changed,
+        path: path.clone(),
+        left: Some(node_range(a)),
+        right: Some(node_range(b)),
+    };
+
+    if a.tag() != b.tag() {
+        entries.push(changed());
+
+        return;
+    }
+
+    let (a_children, b_children) = match (a.child_nodes(), b.child_nodes()) {
+        (Some(a_children), Some(b_children)) => (a_children, b_children),
+        (None, None) => {
+            if !a.structural_eq(b) {
+                entries.push(changed());
+            }
+
+            return;
+        }
+        _ => {
+            entries.push(changed());
+
+            return;
+        }
+    };
+
+    for index in 0..a_children.len().max(b_children.len()) {
+        path.push(index);
+
+        match (a_children.get(index), b_children.get(index)) {
+            (Some(a_child), Some(b_child)) => diff_node(a_child, b_child, path, entries),
+            (Some(a_child), None) => entries.push(DiffEntry {
+                kind: DiffKind::Removed,
+                path: path.clone(),
+                left: Some(node_range(a_child)),
+                right: None,
+            }),
+            (None, Some(b_child)) => entries.push(DiffEntry {
+                kind: DiffKind::Added,
+                path: path.clone(),
+                left: None,
+                right: Some(node_range(b_child)),
+            }),
+            (None, None) => {}
+        }
+
+        path.pop();
+    }
+}
@@ -0,0 +1,147 @@
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+use core::str::from_utf8;
+
+use crate::asn1::{Asn1, Asn1Type};
+use crate::Asn1Entity;
+
+/// Width of the type name column. Values of primitive nodes are aligned after it.
+const NAME_WIDTH: usize = 18;
+
+impl Asn1<'_> {
+    /// Formats the tree as the indented text dump similar to the `openssl asn1parse -i` output.
+    ///
+    /// Every node is a line with the node offset, depth, header length, value length (`inf` for the indefinite length),
+    /// primitive/constructed flag, and the indented type name followed by the value of the primitive node:
+    ///
+    /// ```text
+    ///     0:d=0  hl=2 l=   6 cons: SEQUENCE
+    ///     2:d=1  hl=2 l=   1 prim:  INTEGER           :05
+    /// ```
+    pub fn to_text_dump(&self) -> String {
+        let mut dump = String::new();
+
+        for (depth, node) in self.iter() {
+            let meta = node.meta();
+            let length = if meta.length_bytes() == [0x80] {
+                "inf".to_string()
+            } else {
+                meta.data_range().len().to_string()
+            };
+            let form = if node.tag().is_constructed() { "cons" } else { "prim" };
+            let name = type_name(node.inner_asn1());
+
+            let _ = write!(
+                dump,
+                "{:5}:d={:<2} hl={} l={:>4} {}: {:depth$}{}",
+                meta.tag_position(),
+                depth,
+                meta.data_range().start,
+                length,
+                form,
+                "",
+                name,
+            );
+            if let Some(value) = value_text(node) {
+                let _ = write!(
+                    dump,
+                    "{:width$}:{}",
+                    "",
+                    value,
+                    width = NAME_WIDTH.saturating_sub(name.len())
+                );
+            }
+            dump.push('\n');
+        }
+
+        dump
+    }
+}
+
+fn type_name(asn1: &Asn1Type<'_>) -> Cow<'static, str> {
+    Cow::Borrowed(match asn1 {
+        Asn1Type::Sequence(_) => "SEQUENCE",
+        Asn1Type::Set(_) => "SET",
+        Asn1Type::OctetString(_) => "OCTET STRING",
+        Asn1Type::Utf8String(_) => "UTF8STRING",
+        Asn1Type::BitString(_) => "BIT STRING",
+        Asn1Type::BmpString(_) => "BMPSTRING",
+        Asn1Type::UniversalString(_) => "UNIVERSALSTRING",
+        Asn1Type::IA5String(_) => "IA5STRING",
+        Asn1Type::PrintableString(_) => "PRINTABLESTRING",
+        Asn1Type::GeneralString(_) => "GENERALSTRING",
+        Asn1Type::GraphicString(_) => "GRAPHICSTRING",
+        Asn1Type::VideotexString(_) => "VIDEOTEXSTRING",
+        Asn1Type::NumericString(_) => "NUMERICSTRING",
+        Asn1Type::VisibleString(_) => "VISIBLESTRING",
+        Asn1Type::UtcTime(_) => "UTCTIME",
+        Asn1Type::GeneralizedTime(_) => "GENERALIZEDTIME",
+        Asn1Type::Date(_) => "DATE",
+        Asn1Type::TimeOfDay(_) => "TIME-OF-DAY",
+        Asn1Type::DateTime(_) => "DATE-TIME",
+        Asn1Type::Duration(_) => "DURATION",
+        Asn1Type::External(_) => "EXTERNAL",
+        Asn1Type::EmbeddedPdv(_) => "EMBEDDED PDV",
+        Asn1Type::Bool(_) => "BOOLEAN",
+        Asn1Type::Null(_) => "NULL",
+        Asn1Type::Integer(_) => "INTEGER",
+        Asn1Type::Enumerated(_) => "ENUMERATED",
+        Asn1Type::Real(_) => "REAL",
+        Asn1Type::ObjectIdentifier(_) => "OBJECT",
+        Asn1Type::RelativeOid(_) => "RELATIVE-OID",
+        Asn1Type::ExplicitTag(explicit_tag) => return format!("cont [ {} ]", explicit_tag.tag_number()).into(),
+        Asn1Type::ImplicitTag(implicit_tag) => return format!("cont [ {} ]", implicit_tag.tag_number()).into(),
+        Asn1Type::ApplicationTag(application_tag) => {
+            return format!("appl [ {} ]", application_tag.tag_number()).into()
+        }
+        Asn1Type::PrivateTag(private_tag) => return format!("priv [ {} ]", private_tag.tag_number()).into(),
+        Asn1Type::DecodeError(_) => "DECODE ERROR",
+    })
+}
+
+fn upper_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut hex, byte| {
+            let _ = write!(hex, "{:02X}", byte);
+            hex
+        })
+}
+
+/// Returns the value of the primitive node. Nodes with child nodes have no value: their children are dumped instead.
+fn value_text(asn1: &Asn1<'_>) -> Option<String> {
+    if asn1.iter().nth(1).is_some() {
+        return None;
+    }
+
+    let data = asn1.meta().data_bytes();
+
+    Some(match asn1.inner_asn1() {
+        Asn1Type::Utf8String(_)
+        | Asn1Type::IA5String(_)
+        | Asn1Type::PrintableString(_)
+        | Asn1Type::NumericString(_)
+        | Asn1Type::VisibleString(_)
+        | Asn1Type::UtcTime(_)
+        | Asn1Type::GeneralizedTime(_) => from_utf8(data).ok()?.to_string(),
+        Asn1Type::GeneralString(string) => string.string(),
+        Asn1Type::GraphicString(string) => string.string(),
+        Asn1Type::VideotexString(string) => string.string(),
+        Asn1Type::BmpString(string) => string.string().to_string(),
+        Asn1Type::UniversalString(string) => string.string().to_string(),
+        Asn1Type::Date(date) => date.to_string(),
+        Asn1Type::TimeOfDay(time_of_day) => time_of_day.to_string(),
+        Asn1Type::DateTime(date_time) => date_time.to_string(),
+        Asn1Type::Duration(duration) => duration.to_string(),
+        Asn1Type::Bool(_) => data.first()?.to_string(),
+        Asn1Type::Integer(_) | Asn1Type::Enumerated(_) => upper_hex(data),
+        Asn1Type::ObjectIdentifier(object_identifier) => object_identifier.format(),
+        Asn1Type::RelativeOid(relative_oid) => relative_oid.format(),
+        Asn1Type::DecodeError(decode_error) => format!("error: {}", decode_error.reason()),
+        Asn1Type::Null(_) | Asn1Type::Sequence(_) | Asn1Type::Set(_) => return None,
+        _ if data.is_empty() => return None,
+        _ => format!("[HEX DUMP]:{}", upper_hex(data)),
+    })
+}
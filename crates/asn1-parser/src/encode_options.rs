@@ -0,0 +1,74 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::asn1::{Asn1, Asn1Type};
+use crate::cer::{write_tag, INDEFINITE_LENGTH};
+use crate::length::{len_size, write_len, END_OF_CONTENTS};
+use crate::writer::Writer;
+use crate::{Asn1Encoder, Asn1Entity, Asn1Result};
+
+/// Configuration of the [Asn1::encode_with_options].
+///
+/// All nodes use the definite length by default, so the output is the same as [Asn1Encoder::encode_to_vec].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EncodeOptions {
+    /// Ids of the constructed nodes that should use the indefinite length encoding.
+    pub indefinite_length: BTreeSet<u64>,
+}
+
+impl EncodeOptions {
+    /// Uses the indefinite length encoding for the node with the provided id.
+    pub fn with_indefinite_length(mut self, id: u64) -> Self {
+        self.indefinite_length.insert(id);
+        self
+    }
+}
+
+impl Asn1<'_> {
+    /// Encodes the tree using the provided options, e.g. to reproduce the BER encoding of another implementation.
+    ///
+    /// Only SEQUENCE, SET, and constructed tagged values can use the indefinite length. The options are ignored
+    /// for other nodes and for the values encapsulated into OCTET STRING, BIT STRING, and implicit tags.
+    pub fn encode_with_options(&self, options: &EncodeOptions) -> Asn1Result<Vec<u8>> {
+        let mut encoded = Vec::new();
+        encode_node(self, options, &mut encoded)?;
+
+        Ok(encoded)
+    }
+}
+
+fn encode_node(asn1: &Asn1<'_>, options: &EncodeOptions, encoded: &mut Vec<u8>) -> Asn1Result<()> {
+    let fields = match asn1.inner_asn1() {
+        Asn1Type::Sequence(sequence) => sequence.fields(),
+        Asn1Type::Set(set) => set.fields(),
+        Asn1Type::ExplicitTag(explicit_tag) => explicit_tag.inner(),
+        Asn1Type::ApplicationTag(application_tag) => application_tag.inner(),
+        Asn1Type::PrivateTag(private_tag) if private_tag.is_constructed() => private_tag.inner(),
+        _ => {
+            encoded.extend_from_slice(&asn1.encode_to_vec()?);
+
+            return Ok(());
+        }
+    };
+
+    write_tag(asn1.tag(), encoded)?;
+
+    let mut data = Vec::new();
+    for field in fields {
+        encode_node(field, options, &mut data)?;
+    }
+
+    if options.indefinite_length.contains(&asn1.id()) {
+        encoded.push(INDEFINITE_LENGTH);
+        encoded.extend_from_slice(&data);
+        encoded.extend_from_slice(&END_OF_CONTENTS);
+    } else {
+        let mut len = vec![0; len_size(data.len())];
+        write_len(data.len(), &mut Writer::new(&mut len))?;
+        encoded.extend_from_slice(&len);
+        encoded.extend_from_slice(&data);
+    }
+
+    Ok(())
+}
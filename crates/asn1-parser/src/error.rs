@@ -1,68 +1,107 @@
+#[cfg(feature = "alloc")]
 use alloc::string::FromUtf16Error;
+use core::fmt;
 use core::num::{ParseFloatError, ParseIntError, TryFromIntError};
 use core::str::Utf8Error;
 
 use oid::ObjectIdentifierError;
 
+/// Kind of the [Error].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The data is invalid or can not be processed.
+    InvalidData,
+    /// One of the [DecodeLimits](crate::DecodeLimits) is exceeded.
+    LimitExceeded,
+    /// The encoded length or offset does not fit into `usize`.
+    Overflow,
+}
+
 #[derive(Debug)]
 pub struct Error {
     message: &'static str,
+    kind: ErrorKind,
 }
 
 impl Error {
+    pub(crate) fn limit_exceeded(message: &'static str) -> Self {
+        Self {
+            message,
+            kind: ErrorKind::LimitExceeded,
+        }
+    }
+
+    pub(crate) fn overflow(message: &'static str) -> Self {
+        Self {
+            message,
+            kind: ErrorKind::Overflow,
+        }
+    }
+
     pub fn message(&self) -> &str {
         self.message
     }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message)
+    }
 }
 
 impl From<&'static str> for Error {
     fn from(message: &'static str) -> Self {
-        Self { message }
+        Self {
+            message,
+            kind: ErrorKind::InvalidData,
+        }
     }
 }
 
 impl From<Utf8Error> for Error {
     fn from(_value: Utf8Error) -> Self {
-        Self { message: "Utf8error" }
+        Self::from("Utf8error")
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<FromUtf16Error> for Error {
     fn from(_value: FromUtf16Error) -> Self {
-        Self {
-            message: "FromUtf16Error",
-        }
+        Self::from("FromUtf16Error")
     }
 }
 
 impl From<TryFromIntError> for Error {
     fn from(_value: TryFromIntError) -> Self {
-        Self {
-            message: "Numbers conversion error",
-        }
+        Self::from("Numbers conversion error")
     }
 }
 
 impl From<ObjectIdentifierError> for Error {
     fn from(_value: ObjectIdentifierError) -> Self {
-        Self {
-            message: "ObjectIdentifierError",
-        }
+        Self::from("ObjectIdentifierError")
     }
 }
 
 impl From<ParseFloatError> for Error {
     fn from(_value: ParseFloatError) -> Self {
-        Self {
-            message: "Float parse error",
-        }
+        Self::from("Float parse error")
     }
 }
 
 impl From<ParseIntError> for Error {
     fn from(_value: ParseIntError) -> Self {
-        Self {
-            message: "Int parse error",
-        }
+        Self::from("Int parse error")
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(value: Error) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, value.message)
     }
 }
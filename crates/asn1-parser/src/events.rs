@@ -0,0 +1,139 @@
+use core::array;
+
+use crate::length::read_len;
+use crate::reader::{read_value_data, DecodeProfile, Reader};
+use crate::{Asn1Result, Error, Tag};
+
+/// Flat decoding event produced by [Asn1Events].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Asn1Event<'data> {
+    /// Primitive value.
+    Primitive {
+        tag: Tag,
+        /// Position of the tag in the input data.
+        offset: usize,
+        depth: usize,
+        data: &'data [u8],
+    },
+    /// Start of the constructed value. Its child values follow up to the matching [Asn1Event::End].
+    Start {
+        tag: Tag,
+        /// Position of the tag in the input data.
+        offset: usize,
+        depth: usize,
+        /// Encoded child values. The end-of-contents octets of the indefinite length encoding are not included.
+        data: &'data [u8],
+    },
+    /// End of the constructed value.
+    End { tag: Tag, depth: usize },
+}
+
+struct Frame<'data> {
+    tag: Tag,
+    reader: Reader<'data>,
+}
+
+/// Decoder that yields [Asn1Event]s in the document order without building the tree.
+///
+/// It does not allocate: open constructed values are kept in a fixed-size stack of `DEPTH` frames.
+/// The iteration stops after the first error.
+pub struct Asn1Events<'data, const DEPTH: usize = 32> {
+    root: Reader<'data>,
+    frames: [Option<Frame<'data>>; DEPTH],
+    depth: usize,
+    failed: bool,
+}
+
+impl<'data, const DEPTH: usize> Asn1Events<'data, DEPTH> {
+    pub fn new(data: &'data [u8], profile: DecodeProfile) -> Self {
+        let mut root = Reader::new(data);
+        root.set_profile(profile);
+
+        Self {
+            root,
+            frames: array::from_fn(|_| None),
+            depth: 0,
+            failed: false,
+        }
+    }
+
+    fn current_reader(&mut self) -> &mut Reader<'data> {
+        match self.depth.checked_sub(1) {
+            Some(index) => {
+                &mut self.frames[index]
+                    .as_mut()
+                    .expect("Open frame should be present")
+                    .reader
+            }
+            None => &mut self.root,
+        }
+    }
+
+    fn next_event(&mut self) -> Asn1Result<Option<Asn1Event<'data>>> {
+        let depth = self.depth;
+        let reader = self.current_reader();
+
+        if reader.empty() {
+            let Some(index) = depth.checked_sub(1) else {
+                return Ok(None);
+            };
+
+            self.depth = index;
+            let frame = self.frames[index].take().expect("Open frame should be present");
+
+            return Ok(Some(Asn1Event::End {
+                tag: frame.tag,
+                depth: index,
+            }));
+        }
+
+        let offset = reader.full_offset();
+        let tag = Tag::from_reader(reader)?;
+        let (len, _) = read_len(reader)?;
+        let (data, data_range) = read_value_data(tag, len, reader)?;
+
+        if !tag.is_constructed() {
+            return Ok(Some(Asn1Event::Primitive {
+                tag,
+                offset,
+                depth,
+                data,
+            }));
+        }
+
+        if depth == DEPTH {
+            return Err(Error::limit_exceeded("Maximum nesting depth is exceeded"));
+        }
+
+        let mut inner_reader = reader.nested(data)?;
+        inner_reader.set_offset(reader.offset_of(data_range.start)?);
+
+        self.frames[depth] = Some(Frame {
+            tag,
+            reader: inner_reader,
+        });
+        self.depth += 1;
+
+        Ok(Some(Asn1Event::Start {
+            tag,
+            offset,
+            depth,
+            data,
+        }))
+    }
+}
+
+impl<'data, const DEPTH: usize> Iterator for Asn1Events<'data, DEPTH> {
+    type Item = Asn1Result<Asn1Event<'data>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let event = self.next_event().transpose();
+        self.failed = matches!(event, Some(Err(_)));
+
+        event
+    }
+}
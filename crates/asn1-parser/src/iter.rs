@@ -0,0 +1,82 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::asn1::Asn1;
+
+/// Depth-first iterator over the tree nodes in the document order. Created by [Asn1::iter].
+///
+/// Yields `(depth, node)` pairs. The depth of the starting node is `0`.
+pub struct Asn1Iter<'a> {
+    stack: Vec<(usize, &'a Asn1<'a>)>,
+}
+
+impl<'a> Iterator for Asn1Iter<'a> {
+    type Item = (usize, &'a Asn1<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, node) = self.stack.pop()?;
+
+        if let Some(children) = node.child_nodes() {
+            self.stack.extend(children.iter().rev().map(|child| (depth + 1, child)));
+        }
+
+        Some((depth, node))
+    }
+}
+
+/// Breadth-first iterator over the tree nodes. Created by [Asn1::bfs_iter].
+///
+/// Yields `(depth, node)` pairs. The depth of the starting node is `0`.
+pub struct Asn1BfsIter<'a> {
+    queue: VecDeque<(usize, &'a Asn1<'a>)>,
+}
+
+impl<'a> Iterator for Asn1BfsIter<'a> {
+    type Item = (usize, &'a Asn1<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, node) = self.queue.pop_front()?;
+
+        if let Some(children) = node.child_nodes() {
+            self.queue.extend(children.iter().map(|child| (depth + 1, child)));
+        }
+
+        Some((depth, node))
+    }
+}
+
+/// Iterator over the primitive nodes (nodes without child nodes) in the document order. Created by [Asn1::leaves].
+pub struct Leaves<'a> {
+    inner: Asn1Iter<'a>,
+}
+
+impl<'a> Iterator for Leaves<'a> {
+    type Item = &'a Asn1<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .by_ref()
+            .map(|(_, node)| node)
+            .find(|node| node.child_nodes().is_none())
+    }
+}
+
+impl Asn1<'_> {
+    /// Returns a depth-first iterator over this node and all its descendants, including encapsulated values.
+    pub fn iter(&self) -> Asn1Iter<'_> {
+        Asn1Iter { stack: vec![(0, self)] }
+    }
+
+    /// Returns a breadth-first iterator over this node and all its descendants, including encapsulated values.
+    pub fn bfs_iter(&self) -> Asn1BfsIter<'_> {
+        Asn1BfsIter {
+            queue: VecDeque::from([(0, self)]),
+        }
+    }
+
+    /// Returns an iterator over the primitive nodes of the tree.
+    pub fn leaves(&self) -> Leaves<'_> {
+        Leaves { inner: self.iter() }
+    }
+}
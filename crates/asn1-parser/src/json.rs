@@ -0,0 +1,145 @@
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+use core::str::from_utf8;
+
+use serde_json::{json, Map, Value};
+
+use crate::asn1::{Asn1, Asn1Type};
+use crate::{Asn1Entity, Tag, TagClass};
+
+impl Asn1<'_> {
+    /// Exports the tree as JSON.
+    ///
+    /// Every node is an object with the following fields:
+    /// * `type`: asn1 type name, e.g. `"Sequence"`.
+    /// * `tag`: `{ "class": "universal" | "application" | "context" | "private", "constructed": bool, "number": u32 }`.
+    /// * `offset` and `length`: position and length of the whole node in the input data.
+    /// * `data_offset` and `data_length`: position and length of the node data in the input data.
+    /// * `hex`: hex-encoded node data.
+    /// * `value`: decoded value of the primitive node (omitted for the strings of octets or bits).
+    /// * `children`: child nodes of the constructed or encapsulating node.
+    pub fn to_json(&self) -> Value {
+        let meta = self.meta();
+
+        let mut node = Map::new();
+        node.insert("type".into(), type_name(self.inner_asn1()).into());
+        node.insert("tag".into(), tag_to_json(self.tag()));
+        node.insert("offset".into(), meta.tag_position().into());
+        node.insert("length".into(), meta.raw_bytes().len().into());
+        node.insert(
+            "data_offset".into(),
+            (meta.tag_position() + meta.data_range().start).into(),
+        );
+        node.insert("data_length".into(), meta.data_range().len().into());
+        node.insert("hex".into(), hex(meta.data_bytes()).into());
+
+        if let Some(value) = value_to_json(self.inner_asn1()) {
+            node.insert("value".into(), value);
+        }
+
+        if let Some(children) = self.child_nodes() {
+            node.insert("children".into(), children.iter().map(Asn1::to_json).collect());
+        }
+
+        Value::Object(node)
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+            hex
+        })
+}
+
+fn tag_to_json(tag: Tag) -> Value {
+    let class = match tag.class() {
+        TagClass::Universal => "universal",
+        TagClass::Application => "application",
+        TagClass::ContextSpecific => "context",
+        TagClass::Private => "private",
+    };
+
+    json!({
+        "class": class,
+        "constructed": tag.is_constructed(),
+        "number": tag.number(),
+    })
+}
+
+fn type_name(asn1: &Asn1Type<'_>) -> &'static str {
+    match asn1 {
+        Asn1Type::Sequence(_) => "Sequence",
+        Asn1Type::Set(_) => "Set",
+        Asn1Type::OctetString(_) => "OctetString",
+        Asn1Type::Utf8String(_) => "Utf8String",
+        Asn1Type::BitString(_) => "BitString",
+        Asn1Type::BmpString(_) => "BmpString",
+        Asn1Type::UniversalString(_) => "UniversalString",
+        Asn1Type::IA5String(_) => "IA5String",
+        Asn1Type::PrintableString(_) => "PrintableString",
+        Asn1Type::GeneralString(_) => "GeneralString",
+        Asn1Type::GraphicString(_) => "GraphicString",
+        Asn1Type::VideotexString(_) => "VideotexString",
+        Asn1Type::NumericString(_) => "NumericString",
+        Asn1Type::VisibleString(_) => "VisibleString",
+        Asn1Type::UtcTime(_) => "UtcTime",
+        Asn1Type::GeneralizedTime(_) => "GeneralizedTime",
+        Asn1Type::Date(_) => "Date",
+        Asn1Type::TimeOfDay(_) => "TimeOfDay",
+        Asn1Type::DateTime(_) => "DateTime",
+        Asn1Type::Duration(_) => "Duration",
+        Asn1Type::External(_) => "External",
+        Asn1Type::EmbeddedPdv(_) => "EmbeddedPdv",
+        Asn1Type::Bool(_) => "Bool",
+        Asn1Type::Null(_) => "Null",
+        Asn1Type::Integer(_) => "Integer",
+        Asn1Type::Enumerated(_) => "Enumerated",
+        Asn1Type::Real(_) => "Real",
+        Asn1Type::ObjectIdentifier(_) => "ObjectIdentifier",
+        Asn1Type::RelativeOid(_) => "RelativeOid",
+        Asn1Type::ExplicitTag(_) => "ExplicitTag",
+        Asn1Type::ImplicitTag(_) => "ImplicitTag",
+        Asn1Type::ApplicationTag(_) => "ApplicationTag",
+        Asn1Type::PrivateTag(_) => "PrivateTag",
+        Asn1Type::DecodeError(_) => "DecodeError",
+    }
+}
+
+/// Returns the decoded value of the primitive node. Big numbers are represented as decimal strings.
+fn value_to_json(asn1: &Asn1Type<'_>) -> Option<Value> {
+    Some(match asn1 {
+        Asn1Type::Utf8String(string) => string.string().into(),
+        Asn1Type::BmpString(string) => string.string().into(),
+        Asn1Type::UniversalString(string) => string.string().into(),
+        Asn1Type::IA5String(string) => string.string().into(),
+        Asn1Type::PrintableString(string) => string.string().into(),
+        Asn1Type::GeneralString(string) => string.string().into(),
+        Asn1Type::GraphicString(string) => string.string().into(),
+        Asn1Type::VideotexString(string) => string.string().into(),
+        Asn1Type::NumericString(string) => string.string().into(),
+        Asn1Type::VisibleString(string) => string.string().into(),
+        Asn1Type::UtcTime(utc_time) => utc_time.to_unix_timestamp().into(),
+        Asn1Type::GeneralizedTime(generalized_time) => generalized_time.to_unix_timestamp().into(),
+        Asn1Type::Date(date) => date.to_string().into(),
+        Asn1Type::TimeOfDay(time_of_day) => time_of_day.to_string().into(),
+        Asn1Type::DateTime(date_time) => date_time.to_string().into(),
+        Asn1Type::Duration(duration) => duration.to_string().into(),
+        Asn1Type::EmbeddedPdv(embedded_pdv) => hex(embedded_pdv.data_value().octets()).into(),
+        Asn1Type::Bool(bool) => bool.value().into(),
+        Asn1Type::Null(_) => Value::Null,
+        Asn1Type::Integer(integer) => integer.as_big_int().to_string().into(),
+        Asn1Type::Enumerated(enumerated) => enumerated.as_big_int().to_string().into(),
+        Asn1Type::Real(real) => real.to_f64().ok()?.into(),
+        Asn1Type::ObjectIdentifier(object_identifier) => object_identifier.format().into(),
+        Asn1Type::RelativeOid(relative_oid) => relative_oid.format().into(),
+        Asn1Type::PrivateTag(private_tag) if !private_tag.is_constructed() => hex(private_tag.octets()).into(),
+        Asn1Type::DecodeError(decode_error) => decode_error.reason().into(),
+        Asn1Type::ImplicitTag(implicit_tag) if implicit_tag.inner_asn1().is_none() => {
+            from_utf8(implicit_tag.octets()).ok()?.into()
+        }
+        _ => return None,
+    })
+}
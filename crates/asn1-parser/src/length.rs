@@ -1,29 +1,51 @@
 use core::mem::size_of;
 use core::ops::Range;
 
-use crate::reader::Reader;
+use crate::reader::{DecodeProfile, Reader};
+#[cfg(feature = "alloc")]
 use crate::writer::Writer;
 use crate::{Asn1Result, Error};
 
 const USIZE_LEN: usize = size_of::<usize>();
 
+/// Octets that terminate the indefinite length encoding.
+pub const END_OF_CONTENTS: [u8; 2] = [0x00, 0x00];
+
 /// Reads length from the reader
-pub fn read_len(reader: &mut Reader) -> Asn1Result<(usize, Range<usize>)> {
+///
+/// Returns `None` for the indefinite length form.
+pub fn read_len(reader: &mut Reader) -> Asn1Result<(Option<usize>, Range<usize>)> {
     let before = reader.position();
 
     let length = match reader.read_byte()? {
-        n @ 128..=255 => {
+        0x80 => {
+            if reader.profile() == DecodeProfile::Der {
+                return Err(Error::from("Indefinite length is not allowed in DER"));
+            }
+
+            None
+        }
+        0xff => return Err(Error::from("Reserved length byte")),
+        n @ 129..=254 => {
             let len = n as usize & 127;
             if len > USIZE_LEN {
-                return Err(Error::from("Invalid length bytes"));
+                return Err(Error::overflow("Long-form length has more octets than usize can hold"));
             }
 
             let mut num = [0; USIZE_LEN];
-            reader.read_exact(&mut num[USIZE_LEN - len..])?;
+            reader
+                .read_exact(&mut num[USIZE_LEN - len..])
+                .map_err(|_| Error::from("Long-form length octets are truncated"))?;
+
+            let length = usize::from_be_bytes(num);
+
+            if reader.profile().is_canonical() && (num[USIZE_LEN - len] == 0 || length < 128) {
+                return Err(Error::from("Length should be encoded in the minimal number of bytes"));
+            }
 
-            usize::from_be_bytes(num)
+            Some(length)
         }
-        n => n as usize,
+        n => Some(n as usize),
     };
 
     let after = reader.position();
@@ -32,6 +54,7 @@ pub fn read_len(reader: &mut Reader) -> Asn1Result<(usize, Range<usize>)> {
 }
 
 /// Writes asn1 length into provided writer
+#[cfg(feature = "alloc")]
 pub fn write_len(length: usize, writer: &mut Writer) -> Asn1Result<()> {
     if length < 128 {
         writer.write_byte(length.try_into()?)
@@ -46,6 +69,7 @@ pub fn write_len(length: usize, writer: &mut Writer) -> Asn1Result<()> {
 }
 
 /// Returns how many bytes encoded length will take
+#[cfg(feature = "alloc")]
 pub fn len_size(data_len: usize) -> usize {
     if data_len < 128 {
         1
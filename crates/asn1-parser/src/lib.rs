@@ -1,7 +1,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+// The decoding internals are used only by the tree API (`alloc`) and the flat events (`no-alloc`).
+#![cfg_attr(not(any(feature = "alloc", feature = "no-alloc")), allow(dead_code))]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
 #[macro_use]
 mod macros;
 
@@ -9,33 +13,118 @@ mod macros;
 #[macro_use]
 extern crate log;
 
+#[cfg(feature = "alloc")]
 mod asn1;
+#[cfg(feature = "alloc")]
+mod cer;
+#[cfg(feature = "alloc")]
+mod choice;
+#[cfg(feature = "alloc")]
 mod constructors;
+#[cfg(feature = "alloc")]
+mod decode_error;
+#[cfg(feature = "alloc")]
+mod der;
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod derive_support;
+#[cfg(feature = "alloc")]
+mod diff;
+#[cfg(feature = "alloc")]
+mod dump;
+#[cfg(feature = "alloc")]
+mod encode_options;
 mod error;
+#[cfg(feature = "no-alloc")]
+mod events;
+#[cfg(feature = "alloc")]
+mod iter;
+#[cfg(feature = "json")]
+mod json;
 mod length;
+#[cfg(feature = "alloc")]
+mod lint;
+#[cfg(feature = "oid-registry")]
+mod oid_registry;
+#[cfg(feature = "alloc")]
+mod path;
+#[cfg(feature = "pem")]
+mod pem;
+#[cfg(feature = "alloc")]
 mod primitives;
 mod reader;
+#[cfg(feature = "alloc")]
 mod string;
 mod tag;
+#[cfg(feature = "alloc")]
 mod tags;
+#[cfg(feature = "alloc")]
 mod time;
+#[cfg(feature = "alloc")]
 mod tlv;
+#[cfg(feature = "alloc")]
+mod visitor;
+#[cfg(feature = "alloc")]
 mod writer;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
 pub use asn1::{Asn1, Asn1Type, OwnedAsn1, OwnedAsn1Type, OwnedRawAsn1EntityData, RawAsn1EntityData};
+#[cfg(feature = "alloc")]
+pub use choice::{Choice2, Choice3, Choice4, Choice5};
+#[cfg(feature = "alloc")]
 pub use constructors::*;
-pub use error::Error;
+#[cfg(feature = "alloc")]
+pub use decode_error::{DecodeError, OwnedDecodeError};
+#[cfg(feature = "alloc")]
+pub use der::{DerViolation, DerViolationKind};
+#[cfg(feature = "alloc")]
+pub use diff::{asn1_diff, DiffEntry, DiffKind};
+#[cfg(feature = "alloc")]
+pub use encode_options::EncodeOptions;
+pub use error::{Error, ErrorKind};
+#[cfg(feature = "no-alloc")]
+pub use events::{Asn1Event, Asn1Events};
+#[cfg(feature = "alloc")]
+pub use iter::{Asn1BfsIter, Asn1Iter, Leaves};
+#[cfg(feature = "alloc")]
+pub use lint::{Lint, LintKind};
+#[cfg(feature = "oid-registry")]
+pub use oid_registry::{oid_name, OidRegistry};
+#[cfg(feature = "alloc")]
+pub use path::{NodePath, PathSegment};
+#[cfg(feature = "pem")]
+pub use pem::Pem;
+#[cfg(feature = "alloc")]
 pub use primitives::*;
 use reader::Reader;
+pub use reader::{DecodeLimits, DecodeProfile};
+#[cfg(feature = "alloc")]
 pub use string::*;
-pub use tag::Tag;
+pub use tag::{Tag, TagClass};
+#[cfg(feature = "alloc")]
 pub use tags::*;
+#[cfg(feature = "alloc")]
 pub use time::*;
+#[cfg(feature = "alloc")]
 pub use tlv::Tlv;
+#[cfg(feature = "alloc")]
+pub use visitor::{walk_asn1, Asn1Visitor};
+#[cfg(feature = "alloc")]
 use writer::Writer;
 
 pub type Asn1Result<T> = Result<T, Error>;
 
+/// Decodes one asn1 value of any type using the default encoding rules and limits.
+///
+/// It never panics on malformed data, so it can be called directly from a fuzz target.
+#[cfg(feature = "alloc")]
+pub fn decode_any(data: &[u8]) -> Asn1Result<Asn1<'_>> {
+    Asn1::decode_buff(data)
+}
+
 /// General trait for decoding asn1 entities.
 pub trait Asn1Decoder<'data>: Sized {
     /// Check if the provided tag belongs to decoding implementation.
@@ -44,19 +133,71 @@ pub trait Asn1Decoder<'data>: Sized {
     /// Decodes the asn1 entity using provided Reader.
     fn decode(reader: &mut Reader<'data>) -> Asn1Result<Self>;
 
-    /// Decodes the asn1 entity using provided buffer.
+    /// Decodes the asn1 entity using provided buffer and the default [DecodeProfile::Der] encoding rules.
+    ///
+    /// Non-canonical BER encodings are rejected. Use [Asn1Decoder::decode_buff_with_profile] with
+    /// [DecodeProfile::Ber] to accept them.
     fn decode_buff(buff: &'data [u8]) -> Asn1Result<Self> {
         Self::decode(&mut Reader::new(buff))
     }
+
+    /// Decodes the asn1 entity using provided buffer and encoding rules.
+    fn decode_buff_with_profile(buff: &'data [u8], profile: DecodeProfile) -> Asn1Result<Self> {
+        let mut reader = Reader::new(buff);
+        reader.set_profile(profile);
+
+        Self::decode(&mut reader)
+    }
+
+    /// Decodes the asn1 entity using provided buffer, encoding rules, and decoding limits.
+    fn decode_buff_with_limits(buff: &'data [u8], profile: DecodeProfile, limits: DecodeLimits) -> Asn1Result<Self> {
+        let mut reader = Reader::new(buff);
+        reader.set_profile(profile);
+        reader.set_limits(limits);
+
+        Self::decode(&mut reader)
+    }
+
+    /// Decodes the asn1 entity using provided buffer and encoding rules without the encapsulated values.
+    ///
+    /// The contents of OCTET STRING, BIT STRING, and implicitly tagged values are not decoded as asn1,
+    /// so their `inner` is `None`. It saves allocations when the encapsulated values are not needed.
+    fn decode_buff_shallow(buff: &'data [u8], profile: DecodeProfile) -> Asn1Result<Self> {
+        let mut reader = Reader::new(buff);
+        reader.set_profile(profile);
+        reader.set_encapsulated(false);
+
+        Self::decode(&mut reader)
+    }
+
+    /// Decodes the asn1 entity using provided buffer and encoding rules.
+    ///
+    /// Undecodable values are decoded as [DecodeError] nodes instead of failing the decoding.
+    /// Exceeded [DecodeLimits] still fail the decoding.
+    fn decode_buff_lenient(buff: &'data [u8], profile: DecodeProfile) -> Asn1Result<Self> {
+        let mut reader = Reader::new(buff);
+        reader.set_profile(profile);
+        reader.set_lenient(true);
+
+        Self::decode(&mut reader)
+    }
 }
 
+#[cfg(feature = "alloc")]
 pub trait Asn1ValueDecoder<'data>: Sized {
     fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self>;
 
     fn compare_tags(tag: Tag) -> bool;
+
+    /// Creates the value that represents the undecodable data in the lenient decoding.
+    /// Returns `None` if the type can not represent it.
+    fn from_decode_error(_error: DecodeError<'data>) -> Option<Self> {
+        None
+    }
 }
 
 /// General trait for encoding asn1 entities
+#[cfg(feature = "alloc")]
 pub trait Asn1Encoder {
     /// Returns needed buffer size for asn1 entity encoding
     fn needed_buf_size(&self) -> usize;
@@ -68,6 +209,29 @@ pub trait Asn1Encoder {
 
     /// Encodes asn1 entity into provided writer
     fn encode(&self, writer: &mut Writer) -> Asn1Result<()>;
+
+    /// Encodes asn1 entity into a new buffer.
+    ///
+    /// All tag and length bytes are generated from the typed values and the cached raw data is ignored,
+    /// so the output always matches the (possibly edited) structure.
+    fn encode_to_vec(&self) -> Asn1Result<Vec<u8>> {
+        let mut buff = Vec::with_capacity(self.needed_buf_size());
+        self.encode(&mut Writer::from_vec(&mut buff))?;
+
+        Ok(buff)
+    }
+
+    /// Encodes asn1 entity into provided [std::io::Write].
+    #[cfg(feature = "std")]
+    fn encode_to_io(&self, writer: &mut dyn std::io::Write) -> std::io::Result<()> {
+        writer.write_all(&self.encode_to_vec()?)
+    }
+
+    /// Encodes asn1 entity using CER: constructed values use the indefinite length,
+    /// and BIT STRING and OCTET STRING values longer than 1000 octets are split into 1000-octet segments.
+    fn encode_cer(&self) -> Asn1Result<Vec<u8>> {
+        cer::encode_cer(self)
+    }
 }
 
 /// Every asn1 entity should implement this trait.
@@ -0,0 +1,86 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::asn1::{Asn1, Asn1Type};
+use crate::primitives::has_redundant_leading_byte;
+use crate::{Asn1Decoder, Asn1Entity};
+
+/// Kind of the suspicious encoding found by [Asn1::lints].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LintKind {
+    /// INTEGER or ENUMERATED value has a redundant leading byte.
+    IntegerLeadingZero,
+    /// UTCTime value omits seconds.
+    UtcTimeWithoutSeconds,
+    /// SEQUENCE has no fields.
+    EmptySequence,
+    /// OCTET STRING content starts like a SEQUENCE or SET but can not be decoded.
+    MalformedEncapsulatedValue,
+}
+
+impl fmt::Display for LintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LintKind::IntegerLeadingZero => "INTEGER with unnecessary leading zero",
+            LintKind::UtcTimeWithoutSeconds => "UTCTime without seconds",
+            LintKind::EmptySequence => "empty SEQUENCE",
+            LintKind::MalformedEncapsulatedValue => "OCTET STRING that looks like nested DER but failed to parse",
+        })
+    }
+}
+
+/// Suspicious but valid encoding found by [Asn1::lints].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lint {
+    /// Id of the suspicious node
+    pub node_id: u64,
+    pub kind: LintKind,
+}
+
+impl Asn1<'_> {
+    /// Reports encodings that are valid but usually point to a bug in the producer.
+    ///
+    /// Unlike [Asn1::validate_der], it also checks encapsulated values.
+    pub fn lints(&self) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        lint_node(self, &mut lints);
+
+        lints
+    }
+}
+
+fn looks_like_der(data: &[u8]) -> bool {
+    // Constructed SEQUENCE or SET with some content after the tag.
+    matches!(data, [0x30 | 0x31, _, ..])
+}
+
+fn lint_node(asn1: &Asn1<'_>, lints: &mut Vec<Lint>) {
+    let mut report = |kind| {
+        lints.push(Lint {
+            node_id: asn1.id(),
+            kind,
+        })
+    };
+
+    match asn1.inner_asn1() {
+        Asn1Type::Integer(_) | Asn1Type::Enumerated(_) if has_redundant_leading_byte(asn1.meta().data_bytes()) => {
+            report(LintKind::IntegerLeadingZero)
+        }
+        Asn1Type::UtcTime(utc_time) if utc_time.second.is_none() => report(LintKind::UtcTimeWithoutSeconds),
+        Asn1Type::Sequence(sequence) if sequence.fields().is_empty() => report(LintKind::EmptySequence),
+        Asn1Type::OctetString(octet_string)
+            if octet_string.inner().is_none()
+                && looks_like_der(octet_string.octets())
+                && Asn1::decode_buff(octet_string.octets()).is_err() =>
+        {
+            report(LintKind::MalformedEncapsulatedValue)
+        }
+        _ => {}
+    }
+
+    if let Some(children) = asn1.child_nodes() {
+        children.iter().for_each(|child| lint_node(child, lints));
+    }
+}
@@ -1,7 +1,8 @@
 macro_rules! impl_utf8_asn1 {
     ($name:ident, $tag:expr, $validator_fn:ident) => {
         #[derive(Debug, Clone, PartialEq, Eq)]
-        pub struct $name<'data>(Utf8Value<'data, 19>);
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name<'data>(Utf8Value<'data, $tag>);
 
         paste::paste! {
             pub type [<Owned $name>] = $name<'static>;
@@ -34,19 +35,29 @@ macro_rules! impl_utf8_asn1 {
                 let utf8_value = Utf8Value::decode(tag, reader)?;
 
                 if !$validator_fn(utf8_value.as_str()) {
-                    return Err("invalid string data".into());
+                    // Legacy encoders produce strings with characters outside of the allowed character set.
+                    // BER decoding accepts such strings, so they can still be inspected.
+                    if reader.profile().is_canonical() {
+                        return Err("invalid string data".into());
+                    }
+
+                    warn!(
+                        "{} contains characters outside of its character set: {:?}",
+                        stringify!($name),
+                        utf8_value.as_str()
+                    );
                 }
 
                 Ok(Self(utf8_value))
             }
 
             fn compare_tags(tag: Tag) -> bool {
-                Self::TAG == tag
+                Self::TAG == tag || Self::TAG.constructed() == tag
             }
         }
 
         impl $name<'_> {
-            pub const TAG: Tag = Tag($tag);
+            pub const TAG: Tag = Tag::from_octet($tag);
 
             pub fn raw_data(&self) -> &[u8] {
                 self.0.as_bytes()
@@ -70,6 +81,89 @@ macro_rules! impl_utf8_asn1 {
     };
 }
 
+macro_rules! impl_bytes_string_asn1 {
+    ($name:ident, $tag:expr) => {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name<'data>(Cow<'data, [u8]>);
+
+        paste::paste! {
+            pub type [<Owned $name>] = $name<'static>;
+        }
+
+        impl Asn1Encoder for $name<'_> {
+            fn needed_buf_size(&self) -> usize {
+                let data_len = self.0.len();
+                1 /* tag */ + len_size(data_len) + data_len
+            }
+
+            fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+                writer.write_byte(Self::TAG.into())?;
+                write_len(self.0.len(), writer)?;
+                writer.write_slice(&self.0)
+            }
+        }
+
+        impl crate::Taggable for $name<'_> {
+            fn tag(&self) -> Tag {
+                Self::TAG
+            }
+        }
+
+        impl<'data> Asn1ValueDecoder<'data> for $name<'data> {
+            fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+                Ok(Self(read_restricted_string_data(tag, reader)?))
+            }
+
+            fn compare_tags(tag: Tag) -> bool {
+                Self::TAG == tag || Self::TAG.constructed() == tag
+            }
+        }
+
+        impl $name<'_> {
+            pub const TAG: Tag = Tag::from_octet($tag);
+
+            pub fn new_owned(data: Vec<u8>) -> $name<'static> {
+                $name(Cow::Owned(data))
+            }
+
+            pub fn raw_data(&self) -> &[u8] {
+                &self.0
+            }
+
+            /// Returns the string data if it contains only ASCII characters.
+            pub fn as_ascii(&self) -> Option<&str> {
+                if self.0.is_ascii() {
+                    core::str::from_utf8(&self.0).ok()
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the string data interpreted as UTF-8. Invalid sequences are replaced with `U+FFFD`.
+            pub fn string(&self) -> String {
+                String::from_utf8_lossy(&self.0).into_owned()
+            }
+
+            pub fn to_owned(&self) -> $name<'static> {
+                $name(Cow::Owned(self.0.to_vec()))
+            }
+        }
+
+        impl<'data> From<&'data [u8]> for $name<'data> {
+            fn from(data: &'data [u8]) -> Self {
+                Self(Cow::Borrowed(data))
+            }
+        }
+
+        impl<'data> From<&'data str> for $name<'data> {
+            fn from(data: &'data str) -> Self {
+                Self(Cow::Borrowed(data.as_bytes()))
+            }
+        }
+    };
+}
+
 macro_rules! decode_asn1 {
     ($($name:ident),*; in $tag:expr, $reader:expr)  => {
         {
@@ -81,3 +175,59 @@ macro_rules! decode_asn1 {
         }
     };
 }
+
+/// Implements editing methods for the constructed types that contain a list of fields.
+///
+/// The optional `$guard` method is called before inserting the field.
+macro_rules! impl_fields_editing {
+    ($name:ident, $fields:tt $(, $guard:ident)?) => {
+        impl<'data> $name<'data> {
+            /// Returns a mutable reference to the field.
+            ///
+            /// The raw data of the edited nodes is not updated. Encode the tree to get the bytes of the edited structure.
+            pub fn field_mut(&mut self, index: usize) -> Option<&mut crate::asn1::Asn1<'data>> {
+                self.$fields.get_mut(index)
+            }
+
+            pub(crate) fn fields_mut(&mut self) -> &mut alloc::vec::Vec<crate::asn1::Asn1<'data>> {
+                &mut self.$fields
+            }
+
+            /// Inserts the field at the `index`. Pass the number of fields as the `index` to append the field.
+            pub fn insert_field(&mut self, index: usize, field: crate::asn1::Asn1<'data>) -> crate::Asn1Result<()> {
+                $(self.$guard()?;)?
+
+                if index > self.$fields.len() {
+                    return Err(crate::Error::from("Field index is out of bounds"));
+                }
+
+                self.$fields.insert(index, field);
+
+                Ok(())
+            }
+
+            /// Removes the field and returns it.
+            pub fn remove_field(&mut self, index: usize) -> crate::Asn1Result<crate::asn1::Asn1<'data>> {
+                if index >= self.$fields.len() {
+                    return Err(crate::Error::from("Field index is out of bounds"));
+                }
+
+                Ok(self.$fields.remove(index))
+            }
+
+            /// Replaces the field and returns the old one.
+            pub fn replace_field(
+                &mut self,
+                index: usize,
+                field: crate::asn1::Asn1<'data>,
+            ) -> crate::Asn1Result<crate::asn1::Asn1<'data>> {
+                let old_field = self
+                    .$fields
+                    .get_mut(index)
+                    .ok_or(crate::Error::from("Field index is out of bounds"))?;
+
+                Ok(core::mem::replace(old_field, field))
+            }
+        }
+    };
+}
@@ -0,0 +1,290 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ObjectIdentifier;
+
+/// Built-in object identifiers sorted by arcs: algorithms, elliptic curves, X.509 attributes and extensions,
+/// PKCS attributes, Kerberos and GSS-API mechanisms.
+const OIDS: &[(&[u64], &str)] = &[
+    (&[0, 9, 2342, 19200300, 100, 1, 25], "domainComponent"),
+    (&[1, 2, 840, 10040, 4, 3], "id-dsa-with-sha1"),
+    (&[1, 2, 840, 10045, 2, 1], "id-ecPublicKey"),
+    (&[1, 2, 840, 10045, 3, 1, 1], "prime192v1 (secp192r1)"),
+    (&[1, 2, 840, 10045, 3, 1, 7], "prime256v1 (secp256r1)"),
+    (&[1, 2, 840, 10045, 4, 3, 2], "ecdsa-with-SHA256"),
+    (&[1, 2, 840, 10045, 4, 3, 3], "ecdsa-with-SHA384"),
+    (&[1, 2, 840, 10045, 4, 3, 4], "ecdsa-with-SHA512"),
+    (&[1, 2, 840, 10046, 2, 1], "dh-public-number"),
+    (&[1, 2, 840, 48018, 1, 2, 2], "MS-KILE"),
+    (&[1, 2, 840, 113549, 1, 1, 1], "rsaEncryption"),
+    (&[1, 2, 840, 113549, 1, 1, 2], "md2WithRSAEncryption"),
+    (&[1, 2, 840, 113549, 1, 1, 4], "md5WithRSAEncryption"),
+    (&[1, 2, 840, 113549, 1, 1, 5], "sha1-with-rsa-signature"),
+    (&[1, 2, 840, 113549, 1, 1, 7], "id-RSAES-OAEP"),
+    (&[1, 2, 840, 113549, 1, 1, 8], "id-mgf1"),
+    (&[1, 2, 840, 113549, 1, 1, 10], "rsassa-pss"),
+    (&[1, 2, 840, 113549, 1, 1, 11], "sha256-with-rsa-signature"),
+    (&[1, 2, 840, 113549, 1, 1, 12], "sha384-with-rsa-signature"),
+    (&[1, 2, 840, 113549, 1, 1, 13], "sha512-with-rsa-signature"),
+    (&[1, 2, 840, 113549, 1, 1, 14], "sha224-with-rsa-signature"),
+    (&[1, 2, 840, 113549, 1, 5, 12], "id-PBKDF2"),
+    (&[1, 2, 840, 113549, 1, 5, 13], "pbes2"),
+    (&[1, 2, 840, 113549, 1, 7, 1], "id-data"),
+    (&[1, 2, 840, 113549, 1, 7, 2], "id-signedData"),
+    (&[1, 2, 840, 113549, 1, 7, 6], "id-encryptedData"),
+    (&[1, 2, 840, 113549, 1, 9, 1], "pkcs-9-at-emailAddress"),
+    (&[1, 2, 840, 113549, 1, 9, 2], "pkcs-9-at-unstructuredName"),
+    (&[1, 2, 840, 113549, 1, 9, 3], "pkcs-9-at-contentType"),
+    (&[1, 2, 840, 113549, 1, 9, 4], "id-messageDigest"),
+    (&[1, 2, 840, 113549, 1, 9, 5], "pkcs-9-at-signingTime"),
+    (&[1, 2, 840, 113549, 1, 9, 6], "pkcs-9-at-counterSignature"),
+    (&[1, 2, 840, 113549, 1, 9, 7], "pkcs-9-at-challengePassword"),
+    (&[1, 2, 840, 113549, 1, 9, 14], "pkcs-9-at-extensionRequest"),
+    (&[1, 2, 840, 113549, 1, 9, 15], "pkcs-9-at-smimeCapabilities"),
+    (&[1, 2, 840, 113549, 1, 9, 16, 2, 12], "id-aa-signingCertificate"),
+    (&[1, 2, 840, 113549, 1, 9, 16, 2, 47], "Signing certificate V2"),
+    (&[1, 2, 840, 113549, 1, 9, 20], "pkcs-9-at-friendlyName"),
+    (&[1, 2, 840, 113549, 1, 9, 21], "pkcs-9-at-localKeyId"),
+    (&[1, 2, 840, 113549, 1, 9, 22, 1], "x509Certificate"),
+    (&[1, 2, 840, 113549, 1, 9, 23, 1], "x509Crl"),
+    (&[1, 2, 840, 113549, 1, 9, 52], "id-aa-CMSAlgorithmProtection"),
+    (&[1, 2, 840, 113549, 1, 12, 1, 3], "pbeWithSHAAnd3-KeyTripleDES-CBC"),
+    (&[1, 2, 840, 113549, 1, 12, 1, 6], "pbeWithSHAAnd40BitRC2-CBC"),
+    (&[1, 2, 840, 113549, 1, 12, 10, 1, 1], "keyBag"),
+    (&[1, 2, 840, 113549, 1, 12, 10, 1, 2], "pkcs-8ShroudedKeyBag"),
+    (&[1, 2, 840, 113549, 1, 12, 10, 1, 3], "certBag"),
+    (&[1, 2, 840, 113549, 1, 12, 10, 1, 4], "crlBag"),
+    (&[1, 2, 840, 113549, 1, 12, 10, 1, 5], "secretBag"),
+    (&[1, 2, 840, 113549, 1, 12, 10, 1, 6], "safeContentsBag"),
+    (&[1, 2, 840, 113549, 2], "digestAlgorithm"),
+    (&[1, 2, 840, 113549, 2, 5], "md5"),
+    (&[1, 2, 840, 113549, 2, 7], "hmacWithSHA1"),
+    (&[1, 2, 840, 113549, 2, 8], "hmacWithSHA224"),
+    (&[1, 2, 840, 113549, 2, 9], "hmacWithSHA256"),
+    (&[1, 2, 840, 113549, 2, 10], "hmacWithSHA384"),
+    (&[1, 2, 840, 113549, 2, 11], "hmacWithSHA512"),
+    (&[1, 2, 840, 113554, 1, 2, 2], "krb5"),
+    (&[1, 2, 840, 113554, 1, 2, 2, 3], "user-to-user-mechanism"),
+    (&[1, 3, 6, 1, 4, 1, 311, 2, 1, 4], "SPC_INDIRECT_DATA_OBJID"),
+    (&[1, 3, 6, 1, 4, 1, 311, 2, 1, 11], "SPC_STATEMENT_TYPE_OBJID"),
+    (&[1, 3, 6, 1, 4, 1, 311, 2, 1, 12], "SPC_SP_OPUS_INFO_OBJID"),
+    (&[1, 3, 6, 1, 4, 1, 311, 2, 1, 15], "SPC_PE_IMAGE_DATA_OBJID"),
+    (&[1, 3, 6, 1, 4, 1, 311, 2, 1, 30], "SPC_SIPINFO_OBJID"),
+    (&[1, 3, 6, 1, 4, 1, 311, 2, 2, 10], "NTLM"),
+    (&[1, 3, 6, 1, 4, 1, 311, 2, 2, 30], "NEGOEX"),
+    (&[1, 3, 6, 1, 4, 1, 311, 3, 2, 1], "SPC_TIME_STAMP_REQUEST_OBJID"),
+    (
+        &[1, 3, 6, 1, 4, 1, 311, 3, 3, 1],
+        "Timestamping signature (Ms-CounterSign)",
+    ),
+    (&[1, 3, 6, 1, 4, 1, 311, 10, 1], "szOID_CTL"),
+    (&[1, 3, 6, 1, 4, 1, 311, 10, 3, 9], "szOID_ROOT_LIST_SIGNER"),
+    (&[1, 3, 6, 1, 4, 1, 311, 10, 3, 13], "szOID_KP_LIFETIME_SIGNING"),
+    (&[1, 3, 6, 1, 4, 1, 311, 10, 11, 9], "CERT_ENHKEY_USAGE_PROP_ID"),
+    (&[1, 3, 6, 1, 4, 1, 311, 10, 11, 11], "CERT_FRIENDLY_NAME_PROP_ID"),
+    (&[1, 3, 6, 1, 4, 1, 311, 10, 11, 20], "certKeyIdentifierPropId"),
+    (&[1, 3, 6, 1, 4, 1, 311, 10, 11, 29], "certSubjectNameMd5HashPropId"),
+    (
+        &[1, 3, 6, 1, 4, 1, 311, 10, 11, 83],
+        "CERT_ROOT_PROGRAM_CERT_POLICIES_PROP_ID",
+    ),
+    (
+        &[1, 3, 6, 1, 4, 1, 311, 10, 11, 98],
+        "CERT_AUTH_ROOT_SHA256_HASH_PROP_ID",
+    ),
+    (
+        &[1, 3, 6, 1, 4, 1, 311, 10, 11, 104],
+        "CERT_DISALLOWED_FILETIME_PROP_ID",
+    ),
+    (
+        &[1, 3, 6, 1, 4, 1, 311, 10, 11, 105],
+        "CERT_ROOT_PROGRAM_CHAIN_POLICIES_PROP_ID",
+    ),
+    (&[1, 3, 6, 1, 4, 1, 311, 10, 11, 122], "DISALLOWED_ENHKEY_USAGE"),
+    (
+        &[1, 3, 6, 1, 4, 1, 311, 10, 11, 126],
+        "CERT_NOT_BEFORE_FILETIME_PROP_ID",
+    ),
+    (
+        &[1, 3, 6, 1, 4, 1, 311, 10, 11, 127],
+        "CERT_NOT_BEFORE_ENHKEY_USAGE_PROP_ID",
+    ),
+    (
+        &[1, 3, 6, 1, 4, 1, 311, 20, 2, 2],
+        "Smartcard logon (Microsoft enhanced key usage)",
+    ),
+    (&[1, 3, 6, 1, 4, 1, 311, 20, 2, 3], "User Principal Name"),
+    (&[1, 3, 6, 1, 4, 1, 311, 21, 7], "szOID_CERTIFICATE_TEMPLATE"),
+    (&[1, 3, 6, 1, 4, 1, 311, 21, 10], "szOID_APPLICATION_CERT_POLICIES"),
+    (&[1, 3, 6, 1, 4, 1, 311, 60, 3, 2], "Auto Update End Revocation"),
+    (
+        &[1, 3, 6, 1, 4, 1, 11129, 2, 4, 2],
+        "Rec. ITU-T X.509v3 certificate extension",
+    ),
+    (
+        &[1, 3, 6, 1, 4, 1, 44947, 1, 1, 1],
+        "ISRG Domain Validated (by Let's Encrypt)",
+    ),
+    (&[1, 3, 6, 1, 5, 2, 3, 1], "id-pkinit-authData"),
+    (&[1, 3, 6, 1, 5, 2, 3, 2], "id-pkinit-DHKeyData"),
+    (&[1, 3, 6, 1, 5, 2, 3, 3], "id-pkinit-rkeyData"),
+    (&[1, 3, 6, 1, 5, 2, 3, 4], "id-pkinit-san"),
+    (&[1, 3, 6, 1, 5, 2, 3, 5], "id-pkinit-KPClientAuth"),
+    (&[1, 3, 6, 1, 5, 2, 3, 6], "id-pkinit-KPKdc"),
+    (&[1, 3, 6, 1, 5, 2, 7], "GSS PKU2U"),
+    (&[1, 3, 6, 1, 5, 5, 2], "snego"),
+    (&[1, 3, 6, 1, 5, 5, 7, 1, 1], "id-pe-authorityInfoAccess"),
+    (&[1, 3, 6, 1, 5, 5, 7, 2, 1], "PKIX CPS pointer qualifier"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 1], "id-kp-serverAuth"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 2], "id-kp-clientAuth"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 3], "id-kp-codeSigning"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 4], "id-kp-emailProtection"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 5], "id-kp-ipsecEndSystem"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 6], "id-kp-ipsecTunnel"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 7], "id-kp-ipsecUser"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 8], "id-kp-timeStamping"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 9], "id-kp-OCSPSigning"),
+    (&[1, 3, 6, 1, 5, 5, 7, 48, 1], "id-pkix-ocsp"),
+    (&[1, 3, 6, 1, 5, 5, 7, 48, 1, 2], "id-pkix-ocsp-nonce"),
+    (&[1, 3, 6, 1, 5, 5, 7, 48, 2], "id-ad-caIssuers"),
+    (&[1, 3, 14, 3, 2, 26], "hashAlgorithmIdentifier"),
+    (&[1, 3, 101, 110], "id-X25519"),
+    (&[1, 3, 101, 111], "id-X448"),
+    (&[1, 3, 101, 112], "id-Ed25519"),
+    (&[1, 3, 101, 113], "id-Ed448"),
+    (&[1, 3, 132, 0, 1], "ansit163k1 (sect163k1)"),
+    (&[1, 3, 132, 0, 10], "ansip256k1 (secp256k1)"),
+    (&[1, 3, 132, 0, 15], "ansit163r2 (sect163r2)"),
+    (&[1, 3, 132, 0, 16], "ansit283k1 (sect283k1)"),
+    (&[1, 3, 132, 0, 17], "ansit283r1 (sect283r1)"),
+    (&[1, 3, 132, 0, 26], "ansit233k1 (sect233k1)"),
+    (&[1, 3, 132, 0, 27], "ansit233r1 (sect233r1)"),
+    (&[1, 3, 132, 0, 33], "ansip224r1 (secp224r1)"),
+    (&[1, 3, 132, 0, 34], "ansip384r1 (secp384r1)"),
+    (&[1, 3, 132, 0, 35], "ansip521r1 (secp521r1)"),
+    (&[1, 3, 132, 0, 36], "ansit409k1 (sect409k1)"),
+    (&[1, 3, 132, 0, 37], "ansit409r1 (sect409r1)"),
+    (&[1, 3, 132, 0, 38], "ansit571k1 (sect571k1)"),
+    (&[1, 3, 132, 0, 39], "ansit571r1 (sect571r1)"),
+    (&[2, 5, 4, 3], "commonName"),
+    (&[2, 5, 4, 4], "surname"),
+    (&[2, 5, 4, 5], "serialNumber"),
+    (&[2, 5, 4, 6], "countryName"),
+    (&[2, 5, 4, 7], "localityName"),
+    (&[2, 5, 4, 8], "stateOrProvinceName"),
+    (&[2, 5, 4, 9], "streetAddress"),
+    (&[2, 5, 4, 10], "organizationName"),
+    (&[2, 5, 4, 11], "organizationalUnitName"),
+    (&[2, 5, 4, 12], "title"),
+    (&[2, 5, 4, 20], "telephoneNumber"),
+    (&[2, 5, 4, 42], "givenName"),
+    (&[2, 5, 4, 46], "dnQualifier"),
+    (&[2, 5, 29, 9], "subjectDirectoryAttributes"),
+    (&[2, 5, 29, 14], "subjectKeyIdentifier"),
+    (&[2, 5, 29, 15], "keyUsage"),
+    (&[2, 5, 29, 16], "privateKeyUsagePeriod"),
+    (&[2, 5, 29, 17], "subjectAltName"),
+    (&[2, 5, 29, 18], "issuerAltName"),
+    (&[2, 5, 29, 19], "basicConstraints"),
+    (&[2, 5, 29, 20], "cRLNumber"),
+    (&[2, 5, 29, 21], "cRLReason"),
+    (&[2, 5, 29, 30], "nameConstraints"),
+    (&[2, 5, 29, 31], "cRLDistributionPoints (X509 extension)"),
+    (&[2, 5, 29, 32], "id-ce-certificatePolicies"),
+    (&[2, 5, 29, 33], "policyMappings"),
+    (&[2, 5, 29, 35], "authorityKeyIdentifier"),
+    (&[2, 5, 29, 36], "policyConstraints"),
+    (&[2, 5, 29, 37], "extKeyUsage"),
+    (&[2, 5, 29, 37, 0], "anyExtendedKeyUsage"),
+    (&[2, 5, 29, 46], "freshestCRL"),
+    (&[2, 5, 29, 54], "inhibitAnyPolicy"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 1], "aes128-ECB"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 2], "aes128-CBC-PAD"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 3], "aes128-OFB"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 4], "aes128-CFB"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 5], "aes128-wrap"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 6], "aes128-GCM"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 7], "aes128-CCM"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 8], "aes128-wrap-pad"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 21], "aes192-ECB"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 22], "aes192-CBC-PAD"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 23], "aes192-OFB"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 24], "aes192-CFB"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 25], "aes192-wrap"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 26], "aes192-GCM"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 27], "aes192-CCM"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 28], "aes192-wrap-pad"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 41], "aes256-ECB"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 42], "aes256-CBC-PAD"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 43], "aes256-OFB"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 44], "aes256-CFB"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 45], "id-aes256-wrap"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 46], "aes256-GCM"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 47], "aes256-CCM"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 48], "aes256-wrap-pad"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 1], "sha256"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 2], "sha384"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 3], "sha512"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 4], "sha224"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 5], "sha512-224"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 6], "sha512-256"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 7], "sha3-224"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 8], "sha3-256"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 9], "sha3-384"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 10], "sha3-512"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 11], "id-shake128"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 12], "id-shake256"),
+    (&[2, 16, 840, 1, 101, 3, 4, 3, 1], "dsa-with-sha224"),
+    (&[2, 16, 840, 1, 101, 3, 4, 3, 2], "dsa-with-sha256"),
+    (&[2, 16, 840, 1, 101, 3, 4, 3, 3], "dsa-with-sha384"),
+    (&[2, 16, 840, 1, 101, 3, 4, 3, 4], "dsa-with-sha512"),
+    (&[2, 16, 840, 1, 101, 3, 4, 3, 10], "id-ecdsa-with-sha3-256"),
+    (&[2, 16, 840, 1, 101, 3, 4, 3, 13], "id-rsassa-pkcs1-v1-5-with-sha3-224"),
+    (&[2, 16, 840, 1, 101, 3, 4, 3, 14], "id-rsassa-pkcs1-v1-5-with-sha3-256"),
+    (&[2, 16, 840, 1, 101, 3, 4, 3, 15], "id-rsassa-pkcs1-v1-5-with-sha3-384"),
+    (&[2, 16, 840, 1, 101, 3, 4, 3, 16], "id-rsassa-pkcs1-v1-5-with-sha3-512"),
+    (&[2, 23, 140, 1, 1], "ev-guidelines"),
+    (&[2, 23, 140, 1, 2, 1], "domain-validated"),
+    (&[2, 23, 140, 1, 2, 2], "organization-validated"),
+    (&[2, 23, 140, 1, 2, 3], "individual-validated"),
+];
+
+/// Returns the friendly name of the object identifier from the built-in table.
+pub fn oid_name(oid: &ObjectIdentifier) -> Option<&'static str> {
+    OIDS.binary_search_by(|(arcs, _)| (*arcs).cmp(oid.arcs()))
+        .ok()
+        .map(|index| OIDS[index].1)
+}
+
+/// Object identifier names registry.
+///
+/// User-registered names take precedence over the built-in ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OidRegistry {
+    names: Vec<(ObjectIdentifier, String)>,
+}
+
+impl OidRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the name of the object identifier. The previously registered name is replaced.
+    pub fn register(&mut self, oid: ObjectIdentifier, name: impl Into<String>) {
+        let name = name.into();
+
+        match self.names.iter_mut().find(|(registered, _)| *registered == oid) {
+            Some((_, registered_name)) => *registered_name = name,
+            None => self.names.push((oid, name)),
+        }
+    }
+
+    /// Returns the name of the object identifier: the user-registered one or the built-in one.
+    pub fn name(&self, oid: &ObjectIdentifier) -> Option<&str> {
+        self.names
+            .iter()
+            .find(|(registered, _)| registered == oid)
+            .map(|(_, name)| name.as_str())
+            .or_else(|| oid_name(oid))
+    }
+}
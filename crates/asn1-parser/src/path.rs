@@ -0,0 +1,123 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::asn1::Asn1;
+use crate::{Asn1Entity, Error};
+
+/// Step of the [NodePath]: the child index and the expected first identifier octet of the child.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathSegment {
+    pub index: usize,
+    pub tag: Option<u8>,
+}
+
+/// Path of the node from the root, e.g. `/0/2/1[tag=0xA3]/0`. The root itself has the `/` path.
+///
+/// Segments are child indices like in [Asn1::find_by_path]. The tag of the segment is checked while resolving
+/// the path, so the path of the optional field does not point to another field when the optional one is absent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NodePath(Vec<PathSegment>);
+
+impl NodePath {
+    pub fn new(segments: Vec<PathSegment>) -> Self {
+        Self(segments)
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+}
+
+impl fmt::Display for NodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str("/");
+        }
+
+        for segment in &self.0 {
+            write!(f, "/{}", segment.index)?;
+            if let Some(tag) = segment.tag {
+                write!(f, "[tag=0x{:02X}]", tag)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_segment(segment: &str) -> Result<PathSegment, Error> {
+    let Some((index, tag)) = segment.split_once('[') else {
+        return Ok(PathSegment {
+            index: segment.parse()?,
+            tag: None,
+        });
+    };
+
+    let tag = tag
+        .strip_suffix(']')
+        .and_then(|tag| tag.strip_prefix("tag=0x").or_else(|| tag.strip_prefix("tag=0X")))
+        .ok_or(Error::from("Path segment tag should be in the [tag=0xNN] form"))?;
+
+    Ok(PathSegment {
+        index: index.parse()?,
+        tag: Some(u8::from_str_radix(tag, 16)?),
+    })
+}
+
+impl FromStr for NodePath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let path = s
+            .trim()
+            .strip_prefix('/')
+            .ok_or(Error::from("Node path should start with '/'"))?;
+
+        if path.is_empty() {
+            return Ok(Self::default());
+        }
+
+        path.split('/').map(parse_segment).collect::<Result<_, _>>().map(Self)
+    }
+}
+
+impl Asn1<'_> {
+    /// Returns the path of the node with the provided id in this subtree.
+    ///
+    /// Segments of the non-universal nodes contain the tag because their position depends on the present optional fields.
+    pub fn path_of(&self, id: u64) -> Option<NodePath> {
+        let mut segments = Vec::new();
+        let mut node = self;
+
+        while node.id() != id {
+            if id > node.id() {
+                return None;
+            }
+
+            let children = node.child_nodes()?;
+            let index = children.partition_point(|child| child.id() < id);
+            node = children.get(index)?;
+
+            let tag = node.tag();
+            segments.push(PathSegment {
+                index,
+                tag: (!tag.is_universal()).then(|| tag.first_octet()),
+            });
+        }
+
+        Some(NodePath(segments))
+    }
+
+    /// Finds the node by the [NodePath] from this node.
+    pub fn find_by_node_path(&self, path: &NodePath) -> Option<&Asn1<'_>> {
+        path.segments().iter().try_fold(self, |node, segment| {
+            let child = node.child_nodes()?.get(segment.index)?;
+
+            match segment.tag {
+                Some(tag) if child.tag().first_octet() != tag => None,
+                _ => Some(child),
+            }
+        })
+    }
+}
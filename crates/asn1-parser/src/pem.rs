@@ -0,0 +1,114 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::{Asn1Result, Error};
+
+const BEGIN: &str = "-----BEGIN ";
+const END: &str = "-----END ";
+const BOUNDARY_END: &str = "-----";
+
+/// Max number of base64 characters in one line of the encoded PEM.
+const LINE_LEN: usize = 64;
+
+/// [PEM](https://www.rfc-editor.org/rfc/rfc7468) armored data: label and DER bytes.
+///
+/// Any text before the encapsulation boundary and after the end one is ignored.
+/// PEM headers (RFC 1421 `Proc-Type`, etc.) are not supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pem {
+    label: String,
+    data: Vec<u8>,
+}
+
+impl Pem {
+    pub fn new(label: impl Into<String>, data: Vec<u8>) -> Self {
+        Self {
+            label: label.into(),
+            data,
+        }
+    }
+
+    /// Returns the label from the encapsulation boundaries: `CERTIFICATE`, `PRIVATE KEY`, etc.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns decoded DER bytes.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Returns `true` if the input contains the PEM pre-encapsulation boundary.
+    pub fn is_pem(input: &str) -> bool {
+        input.contains(BEGIN)
+    }
+
+    /// Strips PEM armor and decodes base64 data.
+    pub fn decode(input: &str) -> Asn1Result<Self> {
+        let begin = input.find(BEGIN).ok_or(Error::from("PEM begin boundary is missing"))?;
+        let input = &input[begin + BEGIN.len()..];
+
+        let label_end = input
+            .find(BOUNDARY_END)
+            .ok_or(Error::from("PEM begin boundary is incomplete"))?;
+        let label = &input[..label_end];
+        if label.contains(['\r', '\n']) {
+            return Err(Error::from("PEM begin boundary is incomplete"));
+        }
+        let input = &input[label_end + BOUNDARY_END.len()..];
+
+        let end = input.find(END).ok_or(Error::from("PEM end boundary is missing"))?;
+        let end_label = input[end + END.len()..]
+            .strip_prefix(label)
+            .ok_or(Error::from("PEM end boundary label does not match the begin one"))?;
+        if !end_label.starts_with(BOUNDARY_END) {
+            return Err(Error::from("PEM end boundary label does not match the begin one"));
+        }
+
+        let body = input[..end]
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect::<String>();
+        if body.contains(':') {
+            return Err(Error::from("PEM headers are not supported"));
+        }
+
+        Ok(Self {
+            label: label.to_string(),
+            data: STANDARD
+                .decode(body)
+                .map_err(|_| Error::from("Invalid PEM base64 data"))?,
+        })
+    }
+
+    /// Encodes data in PEM format. Lines are wrapped at 64 characters.
+    pub fn encode(&self) -> String {
+        let encoded = STANDARD.encode(&self.data);
+
+        let mut pem = String::with_capacity(encoded.len() + encoded.len() / LINE_LEN + 2 * self.label.len() + 32);
+        pem.push_str(BEGIN);
+        pem.push_str(&self.label);
+        pem.push_str(BOUNDARY_END);
+        pem.push('\n');
+
+        // Base64 output is always ASCII, so splitting by bytes does not break characters.
+        for line in encoded.as_bytes().chunks(LINE_LEN) {
+            pem.extend(line.iter().map(|c| char::from(*c)));
+            pem.push('\n');
+        }
+
+        pem.push_str(END);
+        pem.push_str(&self.label);
+        pem.push_str(BOUNDARY_END);
+        pem.push('\n');
+
+        pem
+    }
+}
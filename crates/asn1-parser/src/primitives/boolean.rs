@@ -6,19 +6,31 @@ use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
 /// [Boolen](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/boolean.html)
 ///
 /// The ASN.1 BOOLEAN type has two possible values: TRUE and FALSE.
+/// DER and CER allow only `0x00` and `0xff` values. BER treats any non-zero value as TRUE.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bool(bool);
 
 impl Bool {
-    pub const TAG: Tag = Tag(1);
+    pub const TAG: Tag = Tag::from_octet(1);
 
     pub fn value(&self) -> bool {
         self.0
     }
 
+    /// Creates a [Bool] using BER rules: any non-zero byte is TRUE.
     pub fn from_byte(byte: u8) -> Self {
         Bool(byte != 0)
     }
+
+    /// Creates a [Bool] using DER rules: only `0x00` and `0xff` are allowed.
+    pub fn from_der_byte(byte: u8) -> Asn1Result<Self> {
+        match byte {
+            0x00 => Ok(Bool(false)),
+            0xff => Ok(Bool(true)),
+            _ => Err(Error::from("Bool value should be 0x00 or 0xff in DER")),
+        }
+    }
 }
 
 impl From<bool> for Bool {
@@ -41,7 +53,11 @@ impl<'data> Asn1ValueDecoder<'data> for Bool {
             return Err(Error::from("Bool data len should be equal to 1"));
         }
 
-        Ok(Bool::from_byte(data[0]))
+        if reader.profile().is_canonical() {
+            Bool::from_der_byte(data[0])
+        } else {
+            Ok(Bool::from_byte(data[0]))
+        }
     }
 
     fn compare_tags(tag: Tag) -> bool {
@@ -0,0 +1,82 @@
+use num_bigint_dig::BigInt;
+
+use crate::length::{len_size, write_len};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Integer, OwnedInteger, Tag, Taggable};
+
+/// [Enumerated](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/enumerated.html)
+///
+/// The ASN.1 ENUMERATED type is encoded as INTEGER but uses its own tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Enumerated<'data>(Integer<'data>);
+
+pub type OwnedEnumerated = Enumerated<'static>;
+
+impl<'data> Enumerated<'data> {
+    pub const TAG: Tag = Tag::from_octet(10);
+
+    pub fn raw_data(&self) -> &[u8] {
+        self.0.raw_data()
+    }
+
+    /// Returns the enumerated value as an [Integer].
+    pub fn integer(&self) -> &Integer<'data> {
+        &self.0
+    }
+
+    pub fn as_big_int(&self) -> BigInt {
+        self.0.as_big_int()
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        self.0.as_i64()
+    }
+
+    pub fn to_owned(&self) -> OwnedEnumerated {
+        Enumerated(self.0.to_owned())
+    }
+}
+
+impl<'data> From<Integer<'data>> for Enumerated<'data> {
+    fn from(integer: Integer<'data>) -> Self {
+        Self(integer)
+    }
+}
+
+impl From<i64> for OwnedEnumerated {
+    fn from(value: i64) -> Self {
+        Self(OwnedInteger::from(value))
+    }
+}
+
+impl Taggable for Enumerated<'_> {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl<'data> Asn1ValueDecoder<'data> for Enumerated<'data> {
+    fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        Ok(Self(Integer::decode(tag, reader)?))
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        Self::TAG == tag
+    }
+}
+
+impl Asn1Encoder for Enumerated<'_> {
+    fn needed_buf_size(&self) -> usize {
+        let data_len = self.0.raw_data().len();
+
+        1 /* tag */ + len_size(data_len) + data_len
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        writer.write_byte(Self::TAG.into())?;
+        write_len(self.0.raw_data().len(), writer)?;
+        writer.write_slice(self.0.raw_data())
+    }
+}
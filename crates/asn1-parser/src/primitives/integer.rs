@@ -1,20 +1,30 @@
 use alloc::borrow::Cow;
 use alloc::vec::Vec;
 
-use num_bigint_dig::BigUint;
+use num_bigint_dig::{BigInt, BigUint};
 
 use crate::length::{len_size, write_len};
 use crate::reader::Reader;
 use crate::writer::Writer;
 use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Tag, Taggable};
 
+/// Checks if the first byte of the two's complement encoding can be removed without changing the value.
+pub(crate) fn has_redundant_leading_byte(bytes: &[u8]) -> bool {
+    match bytes {
+        [0x00, second, ..] => second & 0x80 == 0,
+        [0xff, second, ..] => second & 0x80 != 0,
+        _ => false,
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Integer<'data>(Cow<'data, [u8]>);
 
 pub type OwnedInteger = Integer<'static>;
 
 impl Integer<'_> {
-    pub const TAG: Tag = Tag(2);
+    pub const TAG: Tag = Tag::from_octet(2);
 
     pub fn raw_data(&self) -> &[u8] {
         self.0.as_ref()
@@ -34,6 +44,36 @@ impl Integer<'_> {
         })
     }
 
+    /// Returns `true` if the two's complement value is negative.
+    pub fn is_negative(&self) -> bool {
+        self.0.first().map(|byte| byte & 0x80 != 0).unwrap_or(false)
+    }
+
+    pub fn as_big_int(&self) -> BigInt {
+        BigInt::from_signed_bytes_be(&self.0)
+    }
+
+    /// Returns the integer value if it fits into `i64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        let sign_byte = if self.is_negative() { 0xff } else { 0x00 };
+
+        // BER allows redundant leading bytes, so they are skipped before the length check.
+        let significant = self
+            .0
+            .iter()
+            .position(|byte| *byte != sign_byte)
+            .map(|position| &self.0[position..])
+            .unwrap_or(&[]);
+        if significant.len() > 8 || (significant.len() == 8 && (significant[0] ^ sign_byte) & 0x80 != 0) {
+            return None;
+        }
+
+        let mut bytes = [sign_byte; 8];
+        bytes[8 - significant.len()..].copy_from_slice(significant);
+
+        Some(i64::from_be_bytes(bytes))
+    }
+
     pub fn to_owned(&self) -> OwnedInteger {
         Integer(Cow::Owned(self.0.as_ref().to_vec()))
     }
@@ -45,6 +85,20 @@ impl From<Vec<u8>> for OwnedInteger {
     }
 }
 
+impl From<i64> for OwnedInteger {
+    fn from(value: i64) -> Self {
+        let bytes = value.to_be_bytes();
+
+        // Minimal two's complement encoding: redundant leading bytes are removed while keeping the sign bit.
+        let mut start = 0;
+        while has_redundant_leading_byte(&bytes[start..]) {
+            start += 1;
+        }
+
+        Self(Cow::Owned(bytes[start..].to_vec()))
+    }
+}
+
 impl Taggable for Integer<'_> {
     fn tag(&self) -> Tag {
         Self::TAG
@@ -8,11 +8,13 @@ use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
 /// The ASN.1 NULL type is used when you need a placeholder for which there is no value.
 /// For example, it can be used to mark a currently empty space.
 /// The NULL type has only one possible value, also called NULL.
+/// Non-empty content is rejected in DER and CER and ignored in BER.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Null;
 
 impl Null {
-    pub const TAG: Tag = Tag(5);
+    pub const TAG: Tag = Tag::from_octet(5);
 }
 
 impl Taggable for Null {
@@ -24,7 +26,14 @@ impl Taggable for Null {
 impl<'data> Asn1ValueDecoder<'data> for Null {
     fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
         if !reader.remaining().is_empty() {
-            return Err(Error::from("Null data should be empty"));
+            if reader.profile().is_canonical() {
+                return Err(Error::from("Null data should be empty"));
+            }
+
+            warn!(
+                "Null contains {} bytes of data. Ignoring it.",
+                reader.read_remaining().len()
+            );
         }
 
         Ok(Self)
@@ -1,29 +1,186 @@
-use alloc::string::String;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
 
 use crate::length::{len_size, write_len};
 use crate::reader::Reader;
 use crate::writer::Writer;
-use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Tag, Taggable};
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ObjectIdentifier(oid::ObjectIdentifier);
+/// Decodes base-128 encoded subidentifiers.
+pub(crate) fn decode_subidentifiers(data: &[u8]) -> Asn1Result<Vec<u64>> {
+    let mut subidentifiers = Vec::new();
+
+    let mut value: u64 = 0;
+    let mut first_byte = true;
+    for byte in data {
+        if first_byte && *byte == 0x80 {
+            return Err(Error::from(
+                "Subidentifier should be encoded in the minimal number of bytes",
+            ));
+        }
+
+        value = value.checked_mul(0x80).ok_or(Error::from("Subidentifier is too big"))? | u64::from(byte & 0x7f);
+
+        first_byte = byte & 0x80 == 0;
+        if first_byte {
+            subidentifiers.push(value);
+            value = 0;
+        }
+    }
+
+    if !first_byte {
+        return Err(Error::from("The last subidentifier is incomplete"));
+    }
+
+    Ok(subidentifiers)
+}
+
+fn subidentifier_len(mut value: u64) -> usize {
+    let mut len = 1;
+    while value >= 0x80 {
+        value >>= 7;
+        len += 1;
+    }
+
+    len
+}
+
+/// Returns the number of bytes needed to encode subidentifiers.
+pub(crate) fn subidentifiers_len(subidentifiers: impl Iterator<Item = u64>) -> usize {
+    subidentifiers.map(subidentifier_len).sum()
+}
+
+/// Encodes subidentifiers using base-128 encoding.
+pub(crate) fn write_subidentifiers(subidentifiers: impl Iterator<Item = u64>, writer: &mut Writer) -> Asn1Result<()> {
+    for value in subidentifiers {
+        let len = subidentifier_len(value);
+        for i in (0..len).rev() {
+            let byte = ((value >> (i * 7)) & 0x7f) as u8;
+            writer.write_byte(if i == 0 { byte } else { byte | 0x80 })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses dotted arcs representation: "1.2.840.113549".
+pub(crate) fn parse_arcs(s: &str) -> Asn1Result<Vec<u64>> {
+    s.split('.')
+        .map(|arc| {
+            if arc.is_empty() || !arc.bytes().all(|c| c.is_ascii_digit()) {
+                return Err(Error::from("Arc should be a decimal number"));
+            }
+
+            Ok(arc.parse::<u64>()?)
+        })
+        .collect()
+}
+
+pub(crate) fn format_arcs(arcs: &[u64], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, arc) in arcs.iter().enumerate() {
+        if i != 0 {
+            f.write_str(".")?;
+        }
+        write!(f, "{}", arc)?;
+    }
+
+    Ok(())
+}
+
+/// [Object Identifier](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/object-identifier.html)
+///
+/// The ASN.1 OBJECT IDENTIFIER type is used to identify objects: algorithms, attributes, extensions, etc.
+/// The first two arcs are encoded in one subidentifier: `first * 40 + second`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Vec<u64>"))]
+pub struct ObjectIdentifier(Vec<u64>);
 
 impl ObjectIdentifier {
-    pub const TAG: Tag = Tag(0x06);
+    pub const TAG: Tag = Tag::from_octet(0x06);
 
-    pub fn oid(&self) -> &oid::ObjectIdentifier {
+    /// Creates a new [ObjectIdentifier] from its arcs.
+    ///
+    /// The first arc should be 0, 1, or 2. The second arc should be less than 40 if the first arc is 0 or 1.
+    pub fn new(arcs: Vec<u64>) -> Asn1Result<Self> {
+        match arcs.as_slice() {
+            [0 | 1, second, ..] if *second >= 40 => Err(Error::from("The second arc should be less than 40")),
+            [0 | 1, _, ..] => Ok(Self(arcs)),
+            [2, second, ..] if second.checked_add(80).is_none() => Err(Error::from("The second arc is too big")),
+            [2, _, ..] => Ok(Self(arcs)),
+            [_, _, ..] => Err(Error::from("The first arc should be 0, 1, or 2")),
+            _ => Err(Error::from("Object identifier should have at least two arcs")),
+        }
+    }
+
+    pub fn arcs(&self) -> &[u64] {
         &self.0
     }
 
+    /// Returns dotted representation of the object identifier: "1.2.840.113549".
     pub fn format(&self) -> String {
-        { &self.0 }.into()
+        self.to_string()
+    }
+
+    fn subidentifiers(&self) -> impl Iterator<Item = u64> + '_ {
+        // The first two arcs are validated in `ObjectIdentifier::new`, so the addition can not overflow.
+        core::iter::once(self.0[0] * 40 + self.0[1]).chain(self.0[2..].iter().copied())
+    }
+}
+
+impl fmt::Display for ObjectIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_arcs(&self.0, f)
+    }
+}
+
+impl FromStr for ObjectIdentifier {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(parse_arcs(s)?)
+    }
+}
+
+impl TryFrom<Vec<u64>> for ObjectIdentifier {
+    type Error = Error;
+
+    fn try_from(arcs: Vec<u64>) -> Result<Self, Self::Error> {
+        Self::new(arcs)
+    }
+}
+
+impl TryFrom<&[u8]> for ObjectIdentifier {
+    type Error = Error;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let subidentifiers = decode_subidentifiers(data)?;
+
+        let (first, rest) = subidentifiers
+            .split_first()
+            .ok_or(Error::from("Object identifier data should not be empty"))?;
+
+        let mut arcs = Vec::with_capacity(subidentifiers.len() + 1);
+        match *first {
+            first @ 0..=39 => arcs.extend([0, first]),
+            first @ 40..=79 => arcs.extend([1, first - 40]),
+            first => arcs.extend([2, first - 80]),
+        }
+        arcs.extend_from_slice(rest);
+
+        Ok(Self(arcs))
     }
 }
 
 impl From<oid::ObjectIdentifier> for ObjectIdentifier {
     fn from(value: oid::ObjectIdentifier) -> Self {
-        Self(value)
+        let formatted: String = value.into();
+
+        formatted
+            .parse()
+            .expect("oid::ObjectIdentifier should always have valid arcs")
     }
 }
 
@@ -35,7 +192,7 @@ impl Taggable for ObjectIdentifier {
 
 impl Asn1ValueDecoder<'_> for ObjectIdentifier {
     fn decode(_tag: Tag, reader: &mut Reader<'_>) -> Asn1Result<Self> {
-        Ok(Self(oid::ObjectIdentifier::try_from(reader.remaining())?))
+        Self::try_from(reader.read_remaining())
     }
 
     fn compare_tags(tag: Tag) -> bool {
@@ -45,16 +202,15 @@ impl Asn1ValueDecoder<'_> for ObjectIdentifier {
 
 impl Asn1Encoder for ObjectIdentifier {
     fn needed_buf_size(&self) -> usize {
-        let encoded: Vec<u8> = self.0.clone().into();
-        encoded.len() + len_size(encoded.len()) + 1 /* tag */
+        let data_len = subidentifiers_len(self.subidentifiers());
+
+        1 /* tag */ + len_size(data_len) + data_len
     }
 
     fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
-        let encoded: Vec<u8> = self.0.clone().into();
-
         writer.write_byte(Self::TAG.into())?;
-        write_len(encoded.len(), writer)?;
+        write_len(subidentifiers_len(self.subidentifiers()), writer)?;
 
-        writer.write_slice(&encoded)
+        write_subidentifiers(self.subidentifiers(), writer)
     }
 }
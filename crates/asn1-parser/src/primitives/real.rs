@@ -0,0 +1,238 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use crate::length::{len_size, write_len};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
+
+const PLUS_INFINITY: u8 = 0x40;
+const MINUS_INFINITY: u8 = 0x41;
+const NOT_A_NUMBER: u8 = 0x42;
+const MINUS_ZERO: u8 = 0x43;
+
+/// Exponents outside of this range always give zero or infinity for the `f64`.
+const MAX_BINARY_EXPONENT: i64 = 2200;
+
+/// Multiplies the value by `2^exp`.
+fn mul_pow2(mut value: f64, exp: i64) -> f64 {
+    let mut exp = exp.clamp(-MAX_BINARY_EXPONENT, MAX_BINARY_EXPONENT);
+
+    while exp != 0 {
+        let step = exp.clamp(-1000, 1000);
+        // 2^step
+        let factor = f64::from_bits(((1023 + step) as u64) << 52);
+        value *= factor;
+        exp -= step;
+    }
+
+    value
+}
+
+fn decode_binary(data: &[u8]) -> Asn1Result<f64> {
+    let first = data[0];
+
+    let base_bits = match (first >> 4) & 0x03 {
+        0 => 1,
+        1 => 3,
+        2 => 4,
+        _ => return Err(Error::from("Reserved Real base")),
+    };
+    let scaling_factor = i64::from((first >> 2) & 0x03);
+
+    let (exponent_len, exponent_start) = match first & 0x03 {
+        3 => (
+            usize::from(*data.get(1).ok_or(Error::from("Real exponent length is missing"))?),
+            2,
+        ),
+        len => (usize::from(len) + 1, 1),
+    };
+    if exponent_len == 0 {
+        return Err(Error::from("Real exponent can not be empty"));
+    }
+    if exponent_len > 8 {
+        return Err(Error::from("Real exponent is too big"));
+    }
+
+    let exponent_end = exponent_start + exponent_len;
+    let exponent_bytes = data
+        .get(exponent_start..exponent_end)
+        .ok_or(Error::from("Real exponent is incomplete"))?;
+    let mut exponent = [if exponent_bytes[0] & 0x80 != 0 { 0xff } else { 0 }; 8];
+    exponent[8 - exponent_len..].copy_from_slice(exponent_bytes);
+    let exponent = i64::from_be_bytes(exponent);
+
+    let mantissa = data[exponent_end..]
+        .iter()
+        .fold(0.0, |mantissa, byte| mantissa * 256.0 + f64::from(*byte));
+
+    let value = mul_pow2(
+        mantissa,
+        exponent.saturating_mul(base_bits).saturating_add(scaling_factor),
+    );
+
+    Ok(if first & 0x40 != 0 { -value } else { value })
+}
+
+fn decode_decimal(data: &[u8]) -> Asn1Result<f64> {
+    if !matches!(data[0] & 0x3f, 1..=3) {
+        return Err(Error::from("Invalid Real decimal encoding form"));
+    }
+
+    let number = core::str::from_utf8(&data[1..])?.trim_start_matches(' ');
+
+    // ISO 6093 allows a comma as the decimal mark.
+    Ok(number.replace(',', ".").parse::<f64>()?)
+}
+
+/// [Real](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/real.html)
+///
+/// The ASN.1 REAL type is used to represent real (floating-point) values.
+/// Both binary (base 2, 8, and 16) and decimal (ISO 6093 NR1, NR2, and NR3) encodings are supported.
+/// The raw value is preserved, so the encoded data is always the same as the decoded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Real<'data>(Cow<'data, [u8]>);
+
+pub type OwnedReal = Real<'static>;
+
+impl Real<'_> {
+    pub const TAG: Tag = Tag::from_octet(9);
+
+    pub fn raw_data(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+
+    pub fn is_binary(&self) -> bool {
+        self.0.first().map(|byte| byte & 0x80 != 0).unwrap_or(false)
+    }
+
+    pub fn is_decimal(&self) -> bool {
+        self.0.first().map(|byte| byte & 0xc0 == 0).unwrap_or(false)
+    }
+
+    /// Converts the Real value to `f64`. The conversion may lose precision.
+    pub fn to_f64(&self) -> Asn1Result<f64> {
+        match self.0.as_ref() {
+            [] => Ok(0.0),
+            [PLUS_INFINITY] => Ok(f64::INFINITY),
+            [MINUS_INFINITY] => Ok(f64::NEG_INFINITY),
+            [NOT_A_NUMBER] => Ok(f64::NAN),
+            [MINUS_ZERO] => Ok(-0.0),
+            data if data[0] & 0x80 != 0 => decode_binary(data),
+            data if data[0] & 0x40 == 0 => decode_decimal(data),
+            _ => Err(Error::from("Invalid Real special value")),
+        }
+    }
+
+    /// Checks the X.690 11.3.1 restrictions: the binary encoding should use base 2
+    /// and the decimal encoding should use the NR3 form.
+    fn validate_der(&self) -> Asn1Result<()> {
+        match self.0.first() {
+            Some(first) if first & 0x80 != 0 && first & 0x30 != 0 => {
+                Err(Error::from("DER binary Real encoding should use base 2"))
+            }
+            Some(first) if first & 0xc0 == 0 && first & 0x3f != 3 => {
+                Err(Error::from("DER decimal Real encoding should use the NR3 form"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn to_owned(&self) -> OwnedReal {
+        Real(Cow::Owned(self.0.as_ref().to_vec()))
+    }
+}
+
+impl From<f64> for OwnedReal {
+    /// Encodes the value using the binary form with base 2 (the DER form).
+    fn from(value: f64) -> Self {
+        if value.is_nan() {
+            return Self(Cow::Owned([NOT_A_NUMBER].to_vec()));
+        }
+        if value.is_infinite() {
+            return Self(Cow::Owned(
+                [if value > 0.0 { PLUS_INFINITY } else { MINUS_INFINITY }].to_vec(),
+            ));
+        }
+        if value == 0.0 {
+            return Self(Cow::Owned(if value.is_sign_negative() {
+                [MINUS_ZERO].to_vec()
+            } else {
+                Vec::new()
+            }));
+        }
+
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i16;
+        let fraction = bits & 0x000f_ffff_ffff_ffff;
+
+        let (mut mantissa, mut exponent) = if biased_exponent == 0 {
+            // subnormal number
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+
+        // DER requires the mantissa to be odd.
+        let trailing_zeros = mantissa.trailing_zeros();
+        mantissa >>= trailing_zeros;
+        exponent += trailing_zeros as i16;
+
+        let exponent_bytes = exponent.to_be_bytes();
+        let exponent_bytes = if i8::try_from(exponent).is_ok() {
+            &exponent_bytes[1..]
+        } else {
+            &exponent_bytes[..]
+        };
+
+        let mantissa_bytes = mantissa.to_be_bytes();
+        let mantissa_bytes = &mantissa_bytes[(mantissa.leading_zeros() / 8) as usize..];
+
+        let mut data = Vec::with_capacity(1 + exponent_bytes.len() + mantissa_bytes.len());
+        data.push(0x80 | if value < 0.0 { 0x40 } else { 0 } | (exponent_bytes.len() as u8 - 1));
+        data.extend_from_slice(exponent_bytes);
+        data.extend_from_slice(mantissa_bytes);
+
+        Self(Cow::Owned(data))
+    }
+}
+
+impl Taggable for Real<'_> {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl<'data> Asn1ValueDecoder<'data> for Real<'data> {
+    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        let real = Self(Cow::Borrowed(reader.read_remaining()));
+
+        // Validates the encoded value.
+        real.to_f64()?;
+
+        if reader.profile().is_canonical() {
+            real.validate_der()?;
+        }
+
+        Ok(real)
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        Self::TAG == tag
+    }
+}
+
+impl Asn1Encoder for Real<'_> {
+    fn needed_buf_size(&self) -> usize {
+        let data_len = self.0.len();
+
+        1 /* tag */ + len_size(data_len) + data_len
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        writer.write_byte(Self::TAG.into())?;
+        write_len(self.0.len(), writer)?;
+        writer.write_slice(self.0.as_ref())
+    }
+}
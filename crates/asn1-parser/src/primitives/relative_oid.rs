@@ -0,0 +1,84 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::length::{len_size, write_len};
+use crate::primitives::object_identifier::{
+    decode_subidentifiers, format_arcs, parse_arcs, subidentifiers_len, write_subidentifiers,
+};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
+
+/// [Relative Object Identifier](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/relative-oid.html)
+///
+/// The ASN.1 RELATIVE-OID type identifies an object relative to some known object identifier.
+/// Unlike OBJECT IDENTIFIER, every arc is encoded in its own subidentifier.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelativeOid(Vec<u64>);
+
+impl RelativeOid {
+    pub const TAG: Tag = Tag::from_octet(0x0d);
+
+    pub fn arcs(&self) -> &[u64] {
+        &self.0
+    }
+
+    /// Returns dotted representation of the relative object identifier: "8571.3.2".
+    pub fn format(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl From<Vec<u64>> for RelativeOid {
+    fn from(arcs: Vec<u64>) -> Self {
+        Self(arcs)
+    }
+}
+
+impl fmt::Display for RelativeOid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_arcs(&self.0, f)
+    }
+}
+
+impl FromStr for RelativeOid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(parse_arcs(s)?))
+    }
+}
+
+impl Taggable for RelativeOid {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl Asn1ValueDecoder<'_> for RelativeOid {
+    fn decode(_tag: Tag, reader: &mut Reader<'_>) -> Asn1Result<Self> {
+        Ok(Self(decode_subidentifiers(reader.read_remaining())?))
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        Self::TAG == tag
+    }
+}
+
+impl Asn1Encoder for RelativeOid {
+    fn needed_buf_size(&self) -> usize {
+        let data_len = subidentifiers_len(self.0.iter().copied());
+
+        1 /* tag */ + len_size(data_len) + data_len
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        writer.write_byte(Self::TAG.into())?;
+        write_len(subidentifiers_len(self.0.iter().copied()), writer)?;
+
+        write_subidentifiers(self.0.iter().copied(), writer)
+    }
+}
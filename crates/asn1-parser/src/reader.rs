@@ -1,10 +1,72 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::ops::Range;
 
-use crate::{Asn1Result, Error};
+use crate::length::{read_len, END_OF_CONTENTS};
+use crate::{Asn1Result, Error, Tag};
+
+/// Encoding rules the decoder enforces.
+///
+/// The default profile is [DecodeProfile::Der], so [Asn1Decoder::decode_buff](crate::Asn1Decoder::decode_buff)
+/// rejects non-canonical encodings that BER allows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DecodeProfile {
+    /// Distinguished Encoding Rules: only canonical encodings with definite lengths are accepted.
+    #[default]
+    Der,
+    /// Canonical Encoding Rules: constructed values should use the indefinite length
+    /// and strings longer than 1000 octets should be split into 1000-octet segments.
+    Cer,
+    /// Basic Encoding Rules: non-canonical encodings are accepted.
+    Ber,
+}
+
+impl DecodeProfile {
+    /// Returns `true` if the encoding rules allow only one encoding of the value (DER and CER).
+    pub fn is_canonical(self) -> bool {
+        self != DecodeProfile::Ber
+    }
+}
+
+/// Limits that protect the decoder from deeply nested or oversized inputs.
+///
+/// [Error::kind] is [ErrorKind::LimitExceeded](crate::ErrorKind::LimitExceeded) when any of the limits is exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecodeLimits {
+    /// Maximum nesting depth of the values, including the encapsulated ones (e.g. inside OCTET STRING).
+    pub max_depth: usize,
+    /// Maximum number of the decoded nodes, including the speculatively decoded encapsulated values.
+    pub max_nodes: u64,
+    /// Maximum length of the value data in bytes.
+    pub max_len: usize,
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 128,
+            max_nodes: 1_000_000,
+            max_len: usize::MAX,
+        }
+    }
+}
 
 #[derive(Debug)]
 pub struct Reader<'data> {
     next_node_id: u64,
+    profile: DecodeProfile,
+    limits: DecodeLimits,
+    /// Nesting depth of the data this reader reads.
+    depth: usize,
+    /// Undecodable values are decoded as [DecodeError](crate::DecodeError) nodes instead of failing the decoding.
+    lenient: bool,
+    /// Whether the encapsulated values (e.g. inside OCTET STRING) are decoded.
+    encapsulated: bool,
+    /// Segments of the constructed string (in full offsets) found during the current node decoding.
+    #[cfg(feature = "alloc")]
+    segments: Vec<Range<usize>>,
     offset: usize,
     position: usize,
     inner: &'data [u8],
@@ -15,6 +77,13 @@ impl<'data> Reader<'data> {
         Self {
             offset: 0,
             next_node_id: 0,
+            profile: DecodeProfile::default(),
+            limits: DecodeLimits::default(),
+            depth: 0,
+            lenient: false,
+            encapsulated: true,
+            #[cfg(feature = "alloc")]
+            segments: Vec::new(),
             position: 0,
             inner: data,
         }
@@ -24,11 +93,79 @@ impl<'data> Reader<'data> {
         self.offset = offset;
     }
 
+    pub fn profile(&self) -> DecodeProfile {
+        self.profile
+    }
+
+    pub fn set_profile(&mut self, profile: DecodeProfile) {
+        self.profile = profile;
+    }
+
+    pub fn limits(&self) -> DecodeLimits {
+        self.limits
+    }
+
+    pub fn set_limits(&mut self, limits: DecodeLimits) {
+        self.limits = limits;
+    }
+
+    pub fn is_lenient(&self) -> bool {
+        self.lenient
+    }
+
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
+    }
+
+    pub fn decodes_encapsulated(&self) -> bool {
+        self.encapsulated
+    }
+
+    pub fn set_encapsulated(&mut self, encapsulated: bool) {
+        self.encapsulated = encapsulated;
+    }
+
+    /// Creates a reader for the nested data with the same profile, limits, and options.
+    pub fn nested(&self, data: &'data [u8]) -> Asn1Result<Self> {
+        if self.depth >= self.limits.max_depth {
+            return Err(Error::limit_exceeded("Maximum nesting depth is exceeded"));
+        }
+
+        let mut reader = Self::new(data);
+        reader.profile = self.profile;
+        reader.limits = self.limits;
+        reader.depth = self.depth + 1;
+        reader.lenient = self.lenient;
+        reader.encapsulated = self.encapsulated;
+
+        Ok(reader)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn add_segment(&mut self, segment: Range<usize>) {
+        self.segments.push(segment);
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn take_segments(&mut self) -> Vec<Range<usize>> {
+        core::mem::take(&mut self.segments)
+    }
+
     pub fn next_id(&mut self) -> u64 {
         self.next_node_id += 1;
         self.next_node_id
     }
 
+    /// Returns the id for the new node or an error if [DecodeLimits::max_nodes] is exceeded.
+    pub fn next_node_id(&mut self) -> Asn1Result<u64> {
+        let id = self.next_id();
+        if id > self.limits.max_nodes {
+            return Err(Error::limit_exceeded("Maximum number of nodes is exceeded"));
+        }
+
+        Ok(id)
+    }
+
     pub fn set_next_id(&mut self, next_node_id: u64) {
         self.next_node_id = next_node_id;
     }
@@ -41,6 +178,13 @@ impl<'data> Reader<'data> {
         self.offset + self.position
     }
 
+    /// Returns the full offset of the provided position of this reader.
+    pub fn offset_of(&self, position: usize) -> Asn1Result<usize> {
+        self.offset
+            .checked_add(position)
+            .ok_or(Error::overflow("Value offset overflows usize"))
+    }
+
     pub fn empty(&self) -> bool {
         self.position == self.inner.len()
     }
@@ -64,13 +208,17 @@ impl<'data> Reader<'data> {
     }
 
     pub fn read(&mut self, len: usize) -> Asn1Result<&'data [u8]> {
-        if self.position + len > self.inner.len() {
-            return Err(Error::from("Outside"));
+        let end = self
+            .position
+            .checked_add(len)
+            .ok_or(Error::overflow("Value end overflows usize"))?;
+        if end > self.inner.len() {
+            return Err(Error::from("Unexpected end of the input"));
         }
 
-        let data = &self.inner[self.position..(self.position + len)];
+        let data = &self.inner[self.position..end];
 
-        self.position += len;
+        self.position = end;
 
         Ok(data)
     }
@@ -95,6 +243,10 @@ impl<'data> Reader<'data> {
 }
 
 pub fn read_data<'data>(reader: &mut Reader<'data>, len: usize) -> Asn1Result<(&'data [u8], Range<usize>)> {
+    if len > reader.remaining().len() {
+        return Err(Error::from("Value length exceeds the remaining input"));
+    }
+
     let before = reader.position();
 
     let data = reader.read(len)?;
@@ -103,3 +255,71 @@ pub fn read_data<'data>(reader: &mut Reader<'data>, len: usize) -> Asn1Result<(&
 
     Ok((data, before..after))
 }
+
+/// Skips encoded values up to and including the end-of-contents octets.
+fn skip_to_end_of_contents(reader: &mut Reader) -> Asn1Result<()> {
+    // Number of the nested indefinite length values that are not closed yet.
+    let mut open_values = 0;
+
+    loop {
+        if reader.remaining().starts_with(&END_OF_CONTENTS) {
+            reader.read(END_OF_CONTENTS.len())?;
+
+            if open_values == 0 {
+                return Ok(());
+            }
+            open_values -= 1;
+
+            continue;
+        }
+
+        Tag::from_reader(reader)?;
+
+        match read_len(reader)?.0 {
+            Some(len) => {
+                reader.read(len)?;
+            }
+            None => {
+                open_values += 1;
+
+                if reader.depth + open_values > reader.limits.max_depth {
+                    return Err(Error::limit_exceeded("Maximum nesting depth is exceeded"));
+                }
+            }
+        }
+    }
+}
+
+/// Reads data of the indefinite length encoding.
+///
+/// The end-of-contents octets are consumed but not included in the returned data.
+fn read_indefinite_data<'data>(reader: &mut Reader<'data>) -> Asn1Result<(&'data [u8], Range<usize>)> {
+    let mut inner_reader = reader.nested(reader.remaining())?;
+    skip_to_end_of_contents(&mut inner_reader)?;
+
+    if inner_reader.position() - END_OF_CONTENTS.len() > reader.limits().max_len {
+        return Err(Error::limit_exceeded("Maximum value length is exceeded"));
+    }
+
+    let data = read_data(reader, inner_reader.position() - END_OF_CONTENTS.len())?;
+    reader.read(END_OF_CONTENTS.len())?;
+
+    Ok(data)
+}
+
+/// Reads data of the value with the provided tag and length (`None` for the indefinite length).
+pub fn read_value_data<'data>(
+    tag: Tag,
+    len: Option<usize>,
+    reader: &mut Reader<'data>,
+) -> Asn1Result<(&'data [u8], Range<usize>)> {
+    match len {
+        Some(len) if len > reader.limits().max_len => Err(Error::limit_exceeded("Maximum value length is exceeded")),
+        Some(_) if reader.profile() == DecodeProfile::Cer && tag.is_constructed() => Err(Error::from(
+            "Constructed values should use the indefinite length in CER",
+        )),
+        Some(len) => read_data(reader, len),
+        None if tag.is_constructed() => read_indefinite_data(reader),
+        None => Err(Error::from("Primitive values can not use the indefinite length")),
+    }
+}
@@ -1,17 +1,22 @@
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::vec;
 use alloc::vec::Vec;
 
 use crate::length::{len_size, write_len};
 use crate::reader::Reader;
+use crate::string::{decode_encapsulated, decode_owned_inner, read_segments, validate_primitive_string};
 use crate::writer::Writer;
-use crate::{Asn1, Asn1Decoder, Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, MetaInfo, Tag, Taggable};
+use crate::{Asn1, Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, MetaInfo, Tag, Taggable};
 
 /// [BitString](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/bitstring.html)
 ///
 /// ASN.1 BIT STRING type values are arbitrary length strings of bits.
 /// A BIT STRING value doesn't need to be an even multiple of eight bits.
+///
+/// BER constructed encoding is supported: segments are reassembled into one bits buffer.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BitString<'data> {
     octets: Cow<'data, [u8]>,
     inner: Option<Box<Asn1<'data>>>,
@@ -20,7 +25,7 @@ pub struct BitString<'data> {
 pub type OwnedBitString = BitString<'static>;
 
 impl BitString<'_> {
-    pub const TAG: Tag = Tag(3);
+    pub const TAG: Tag = Tag::from_octet(3);
 
     /// Returns inner bits
     pub fn raw_bits(&self) -> &[u8] {
@@ -51,9 +56,9 @@ impl BitString<'_> {
         bits.insert(0, unused_bits);
 
         let inner = if !bits.is_empty() {
-            Asn1::decode_buff(&bits[1..]).ok().map(|mut asn1| {
+            decode_owned_inner(&bits[1..]).map(|mut asn1| {
                 asn1.clear_meta();
-                Box::new(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned()))
+                asn1
             })
         } else {
             None
@@ -80,9 +85,7 @@ impl BitString<'_> {
 // we assume here that firs vector byte contains amount of unused bytes
 impl From<Vec<u8>> for BitString<'_> {
     fn from(data: Vec<u8>) -> Self {
-        let inner = Asn1::decode_buff(&data)
-            .ok()
-            .map(|asn1| Box::new(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned())));
+        let inner = decode_owned_inner(&data);
         Self {
             octets: Cow::Owned(data),
             inner,
@@ -97,22 +100,35 @@ impl Taggable for BitString<'_> {
 }
 
 impl<'data> Asn1ValueDecoder<'data> for BitString<'data> {
-    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
-        let data = reader.read_remaining();
-
-        let inner = if !data.is_empty() {
-            let mut inner_reader = Reader::new(&data[1..]);
-            inner_reader.set_next_id(reader.next_id());
-            inner_reader.set_offset(reader.full_offset() - data.len());
-            let mut inner = Asn1::decode(&mut inner_reader).ok().map(Box::new);
-
-            if !inner_reader.empty() && inner.is_some() {
-                inner = None;
+    fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        if tag.is_constructed() {
+            let segments = read_segments(Self::TAG, reader)?;
+
+            let mut octets = vec![0];
+            for (i, segment) in segments.iter().enumerate() {
+                let (unused_bits, bits) = segment
+                    .split_first()
+                    .ok_or(Error::from("BitString segment can not be empty"))?;
+
+                if *unused_bits != 0 && i + 1 != segments.len() {
+                    return Err(Error::from("Only the last BitString segment can have unused bits"));
+                }
+
+                octets[0] = *unused_bits;
+                octets.extend_from_slice(bits);
             }
 
-            reader.set_next_id(inner_reader.next_id());
+            return Ok(Self {
+                octets: Cow::Owned(octets),
+                inner: None,
+            });
+        }
+
+        let data = reader.read_remaining();
+        validate_primitive_string(data, reader)?;
 
-            inner
+        let inner = if !data.is_empty() {
+            decode_encapsulated(&data[1..], reader.full_offset() - data.len(), reader)
         } else {
             None
         };
@@ -124,7 +140,7 @@ impl<'data> Asn1ValueDecoder<'data> for BitString<'data> {
     }
 
     fn compare_tags(tag: Tag) -> bool {
-        Self::TAG == tag
+        Self::TAG == tag || Self::TAG.constructed() == tag
     }
 }
 
@@ -140,3 +156,9 @@ impl Asn1Encoder for BitString<'_> {
         writer.write_slice(&self.octets)
     }
 }
+
+impl MetaInfo for BitString<'_> {
+    fn clear_meta(&mut self) {
+        self.inner = None;
+    }
+}
@@ -1,74 +1,108 @@
 use alloc::borrow::Cow;
+use alloc::string::String;
 use alloc::vec::Vec;
 
 use crate::length::{len_size, write_len};
 use crate::reader::Reader;
+use crate::string::read_restricted_string_data;
 use crate::writer::Writer;
-use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Tag, Taggable};
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
 
 /// [BmpString](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/bmpstring.html)
 ///
 /// The ASN.1 BMPString type contains UNICODE characters. They are two-byte characters, and are not recommended for use unless properly subtyped.
+/// The data is encoded using UTF-16BE. Surrogate pairs are accepted because many encoders (e.g. Windows) use full UTF-16.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct BmpString<'data>(Cow<'data, [u8]>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BmpString<'data>(Cow<'data, str>);
 
 pub type OwnedBmpString = BmpString<'static>;
 
 impl BmpString<'_> {
-    pub const TAG: Tag = Tag(30);
+    pub const TAG: Tag = Tag::from_octet(30);
 
-    /// Returns inner raw [BmpString] data
-    pub fn raw_data(&self) -> &[u8] {
+    /// Returns decoded [BmpString] data
+    pub fn string(&self) -> &str {
         &self.0
     }
 
+    /// Returns [BmpString] data encoded using UTF-16BE
+    pub fn to_utf16_be(&self) -> Vec<u8> {
+        self.0.encode_utf16().flat_map(|c| c.to_be_bytes()).collect()
+    }
+
     /// Returns owned version of the [BmpString]
     pub fn to_owned(&self) -> OwnedBmpString {
-        BmpString(self.0.to_vec().into())
+        BmpString(Cow::Owned(self.0.as_ref().into()))
     }
 
-    pub fn new_owned(data: Vec<u8>) -> OwnedBmpString {
-        BmpString(Cow::Owned(data))
+    fn data_len(&self) -> usize {
+        self.0.encode_utf16().count() * 2
     }
 }
 
-impl From<&str> for OwnedBmpString {
-    fn from(value: &str) -> Self {
-        Self(Cow::Owned(value.encode_utf16().flat_map(|c| c.to_le_bytes()).collect()))
+impl<'data> From<&'data str> for BmpString<'data> {
+    fn from(value: &'data str) -> Self {
+        Self(Cow::Borrowed(value))
     }
 }
 
-impl Taggable for BmpString<'_> {
-    fn tag(&self) -> Tag {
-        Self::TAG
+impl From<String> for OwnedBmpString {
+    fn from(value: String) -> Self {
+        Self(Cow::Owned(value))
     }
 }
 
-impl<'data> Asn1ValueDecoder<'data> for BmpString<'data> {
-    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
-        let data = reader.remaining();
+impl TryFrom<&[u8]> for OwnedBmpString {
+    type Error = Error;
 
+    /// Decodes UTF-16BE data.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
         if data.len() % 2 == 1 {
-            return Err("Invalid BmpString".into());
+            return Err("Invalid BmpString: data length should be even".into());
         }
 
-        Ok(Self(Cow::Borrowed(data)))
+        let string = char::decode_utf16(
+            data.chunks_exact(2)
+                .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]])),
+        )
+        .collect::<Result<String, _>>()
+        .map_err(|_| Error::from("Invalid BmpString: unpaired surrogate"))?;
+
+        Ok(Self(Cow::Owned(string)))
+    }
+}
+
+impl Taggable for BmpString<'_> {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl<'data> Asn1ValueDecoder<'data> for BmpString<'data> {
+    fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        OwnedBmpString::try_from(read_restricted_string_data(tag, reader)?.as_ref())
     }
 
     fn compare_tags(tag: Tag) -> bool {
-        Self::TAG == tag
+        Self::TAG == tag || Self::TAG.constructed() == tag
     }
 }
 
 impl Asn1Encoder for BmpString<'_> {
     fn needed_buf_size(&self) -> usize {
-        let data_len = self.0.len();
+        let data_len = self.data_len();
         1 /* tag */ + len_size(data_len) + data_len
     }
 
     fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
         writer.write_byte(Self::TAG.into())?;
-        write_len(self.0.len(), writer)?;
-        writer.write_slice(&self.0)
+        write_len(self.data_len(), writer)?;
+
+        for c in self.0.encode_utf16() {
+            writer.write_slice(&c.to_be_bytes())?;
+        }
+
+        Ok(())
     }
 }
@@ -1,24 +1,161 @@
 mod bit_string;
 mod bmp_string;
 mod octet_string;
+mod universal_string;
 mod validators;
 
 use alloc::borrow::Cow;
+use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::str::from_utf8;
 
 pub use bit_string::{BitString, OwnedBitString};
 pub use bmp_string::{BmpString, OwnedBmpString};
 pub use octet_string::{OctetString, OwnedOctetString};
-use validators::{validate_general, validate_ia5, validate_printable, validate_utf8};
+pub use universal_string::{OwnedUniversalString, UniversalString};
+use validators::{validate_ia5, validate_printable, validate_utf8};
 
-use crate::length::{len_size, write_len};
-use crate::reader::Reader;
+use crate::asn1::{Asn1, OwnedAsn1};
+use crate::length::{len_size, read_len, write_len};
+use crate::reader::{read_value_data, DecodeProfile, Reader};
 use crate::string::validators::{validate_numeric, validate_visible};
 use crate::writer::Writer;
-use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Tag};
+use crate::{Asn1Decoder, Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag};
+
+/// Tries to decode the string data as an asn1 entity. The data should contain exactly one entity.
+fn decode_owned_inner(data: &[u8]) -> Option<Box<OwnedAsn1>> {
+    let mut reader = Reader::new(data);
+    let asn1 = Asn1::decode(&mut reader).ok()?;
+
+    if !reader.empty() {
+        return None;
+    }
+
+    Some(Box::new(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned())))
+}
+
+/// Tries to decode the data that starts at the `offset` of the input as an encapsulated asn1 entity.
+/// The data should contain exactly one entity.
+///
+/// Errors are ignored, including exceeded [DecodeLimits](crate::DecodeLimits): the data is left undecoded.
+/// Nothing is decoded if the reader does not decode the encapsulated values.
+pub(crate) fn decode_encapsulated<'data>(
+    data: &'data [u8],
+    offset: usize,
+    reader: &mut Reader<'data>,
+) -> Option<Box<Asn1<'data>>> {
+    if !reader.decodes_encapsulated() {
+        return None;
+    }
+
+    let mut inner_reader = reader.nested(data).ok()?;
+    // The data may not contain asn1 at all, so it should not be decoded as error nodes.
+    inner_reader.set_lenient(false);
+    inner_reader.set_next_id(reader.next_id());
+    inner_reader.set_offset(offset);
+    let inner = Asn1::decode(&mut inner_reader).ok();
+
+    reader.set_next_id(inner_reader.next_id());
+
+    inner.filter(|_| inner_reader.empty()).map(Box::new)
+}
+
+/// Max number of the string contents octets in the primitive CER encoding and in every CER string segment.
+pub(crate) const CER_SEGMENT_LEN: usize = 1000;
+
+/// Checks that the primitive string encoding is allowed in the current profile.
+fn validate_primitive_string(data: &[u8], reader: &Reader) -> Asn1Result<()> {
+    if reader.profile() == DecodeProfile::Cer && data.len() > CER_SEGMENT_LEN {
+        return Err(Error::from(
+            "Strings longer than 1000 octets should use the constructed encoding in CER",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Reads segments of the constructed string encoding (BER and CER only).
+///
+/// Every segment is the primitive or (recursively) constructed encoding of the same string type.
+/// In CER, segments are primitive and all of them except the last one contain exactly 1000 octets.
+/// Segment ranges are recorded in the reader, so they are available in the [crate::RawAsn1EntityData].
+fn read_segments<'data>(primitive_tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Vec<&'data [u8]>> {
+    if reader.profile() == DecodeProfile::Der {
+        return Err(Error::from("Constructed string encoding is not allowed in DER"));
+    }
+
+    let mut segments = Vec::new();
+
+    while !reader.empty() {
+        let tag = Tag::from_reader(reader)?;
+        let (len, _) = read_len(reader)?;
+        let (data, data_range) = read_value_data(tag, len, reader)?;
+        let data_offset = reader.full_offset() - reader.position() + data_range.start;
+
+        if tag == primitive_tag {
+            reader.add_segment(data_offset..(data_offset + data.len()));
+            segments.push(data);
+        } else if tag == primitive_tag.constructed() {
+            if reader.profile() == DecodeProfile::Cer {
+                return Err(Error::from("String segments should be primitive in CER"));
+            }
+
+            let mut inner_reader = reader.nested(data)?;
+            inner_reader.set_offset(data_offset);
+
+            segments.extend(read_segments(primitive_tag, &mut inner_reader)?);
+
+            for segment in inner_reader.take_segments() {
+                reader.add_segment(segment);
+            }
+        } else {
+            return Err(Error::from("Invalid constructed string segment tag"));
+        }
+    }
+
+    if reader.profile() == DecodeProfile::Cer {
+        validate_cer_segments(&segments)?;
+    }
+
+    Ok(segments)
+}
+
+/// Reads the restricted character string contents.
+///
+/// Segments of the constructed encoding are OCTET STRINGs because the restricted string types are encoded
+/// as if they were implicitly tagged OCTET STRINGs (X.690 8.23.5).
+fn read_restricted_string_data<'data>(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Cow<'data, [u8]>> {
+    if tag.is_constructed() {
+        return Ok(Cow::Owned(read_segments(OctetString::TAG, reader)?.concat()));
+    }
+
+    let data = reader.read_remaining();
+    validate_primitive_string(data, reader)?;
+
+    Ok(Cow::Borrowed(data))
+}
+
+fn validate_cer_segments(segments: &[&[u8]]) -> Asn1Result<()> {
+    let (last, rest) = segments
+        .split_last()
+        .ok_or(Error::from("Constructed string should have segments in CER"))?;
+
+    if rest.is_empty() {
+        return Err(Error::from(
+            "Strings up to 1000 octets should use the primitive encoding in CER",
+        ));
+    }
+
+    if rest.iter().any(|segment| segment.len() != CER_SEGMENT_LEN) || last.len() > CER_SEGMENT_LEN {
+        return Err(Error::from("String segments should contain 1000 octets in CER"));
+    }
+
+    Ok(())
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Utf8Value<'data, const TAG: u8>(Cow<'data, str>);
 
 type OwnedUtf8Value<const TAG: u8> = Utf8Value<'static, TAG>;
@@ -46,12 +183,15 @@ impl<'data, const TAG: u8> From<&'data str> for Utf8Value<'data, TAG> {
 }
 
 impl<'data, const TAG: u8> Asn1ValueDecoder<'data> for Utf8Value<'data, TAG> {
-    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
-        Ok(Self(Cow::Borrowed(from_utf8(reader.remaining())?)))
+    fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        Ok(Self(match read_restricted_string_data(tag, reader)? {
+            Cow::Borrowed(data) => Cow::Borrowed(from_utf8(data)?),
+            Cow::Owned(data) => Cow::Owned(String::from(from_utf8(&data)?)),
+        }))
     }
 
     fn compare_tags(tag: Tag) -> bool {
-        tag.0 == TAG
+        tag == Tag::from_octet(TAG) || tag == Tag::from_octet(TAG).constructed()
     }
 }
 
@@ -73,4 +213,7 @@ impl_utf8_asn1!(NumericString, 18, validate_numeric);
 impl_utf8_asn1!(PrintableString, 19, validate_printable);
 impl_utf8_asn1!(IA5String, 22, validate_ia5);
 impl_utf8_asn1!(VisibleString, 26, validate_visible);
-impl_utf8_asn1!(GeneralString, 27, validate_general);
+
+impl_bytes_string_asn1!(VideotexString, 21);
+impl_bytes_string_asn1!(GraphicString, 25);
+impl_bytes_string_asn1!(GeneralString, 27);
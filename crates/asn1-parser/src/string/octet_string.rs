@@ -5,14 +5,18 @@ use alloc::vec::Vec;
 use crate::asn1::Asn1;
 use crate::length::{len_size, write_len};
 use crate::reader::Reader;
+use crate::string::{decode_encapsulated, decode_owned_inner, read_segments, validate_primitive_string};
 use crate::writer::Writer;
-use crate::{Asn1Decoder, Asn1Encoder, Asn1Result, Asn1ValueDecoder, MetaInfo, Tag, Taggable};
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, MetaInfo, Tag, Taggable};
 
 /// [OctetString](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/octetstring.html)
 ///
 /// The ASN.1 OCTET STRING type contains arbitrary strings of octets. This type is very similar to BIT STRING,
 /// except that all values must be an integral number of eight bits.
+///
+/// BER constructed encoding is supported: segments are reassembled into one octets buffer.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OctetString<'data> {
     octets: Cow<'data, [u8]>,
     inner: Option<Box<Asn1<'data>>>,
@@ -21,7 +25,7 @@ pub struct OctetString<'data> {
 pub type OwnedOctetString = OctetString<'static>;
 
 impl OctetString<'_> {
-    pub const TAG: Tag = Tag(4);
+    pub const TAG: Tag = Tag::from_octet(4);
 
     /// Returns inner octets
     pub fn octets(&self) -> &[u8] {
@@ -44,9 +48,9 @@ impl OctetString<'_> {
     }
 
     pub fn new_owned(octets: Vec<u8>) -> OwnedOctetString {
-        let inner = Asn1::decode_buff(&octets).ok().map(|mut asn1| {
+        let inner = decode_owned_inner(&octets).map(|mut asn1| {
             asn1.clear_meta();
-            Box::new(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned()))
+            asn1
         });
 
         OwnedOctetString {
@@ -56,11 +60,28 @@ impl OctetString<'_> {
     }
 }
 
+impl OwnedOctetString {
+    /// Creates a new [OctetString] from the hex-encoded octets. Whitespaces are ignored.
+    pub fn from_hex(hex: &str) -> Asn1Result<Self> {
+        let digits = hex
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .map(|c| c.to_digit(16).ok_or_else(|| Error::from("Invalid hex digit")))
+            .collect::<Asn1Result<Vec<_>>>()?;
+
+        if digits.len() % 2 != 0 {
+            return Err(Error::from("Hex string should contain an even number of digits"));
+        }
+
+        Ok(Self::new_owned(
+            digits.chunks(2).map(|pair| (pair[0] << 4 | pair[1]) as u8).collect(),
+        ))
+    }
+}
+
 impl From<Vec<u8>> for OwnedOctetString {
     fn from(data: Vec<u8>) -> Self {
-        let inner = Asn1::decode_buff(&data)
-            .ok()
-            .map(|asn1| Box::new(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned())));
+        let inner = decode_owned_inner(&data);
         Self {
             octets: Cow::Owned(data),
             inner,
@@ -69,19 +90,18 @@ impl From<Vec<u8>> for OwnedOctetString {
 }
 
 impl<'data> Asn1ValueDecoder<'data> for OctetString<'data> {
-    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
-        let data = reader.read_remaining();
-
-        let mut inner_reader = Reader::new(data);
-        inner_reader.set_next_id(reader.next_id());
-        inner_reader.set_offset(reader.full_offset() - data.len());
-        let mut inner = Asn1::decode(&mut inner_reader).ok().map(Box::new);
-
-        if !inner_reader.empty() && inner.is_some() {
-            inner = None;
+    fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        if tag.is_constructed() {
+            return Ok(Self {
+                octets: Cow::Owned(read_segments(Self::TAG, reader)?.concat()),
+                inner: None,
+            });
         }
 
-        reader.set_next_id(inner_reader.next_id());
+        let data = reader.read_remaining();
+        validate_primitive_string(data, reader)?;
+
+        let inner = decode_encapsulated(data, reader.full_offset() - data.len(), reader);
 
         Ok(Self {
             octets: Cow::Borrowed(data),
@@ -90,7 +110,7 @@ impl<'data> Asn1ValueDecoder<'data> for OctetString<'data> {
     }
 
     fn compare_tags(tag: Tag) -> bool {
-        Self::TAG == tag
+        Self::TAG == tag || Self::TAG.constructed() == tag
     }
 }
 
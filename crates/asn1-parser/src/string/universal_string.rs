@@ -0,0 +1,109 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::length::{len_size, write_len};
+use crate::reader::Reader;
+use crate::string::read_restricted_string_data;
+use crate::writer::Writer;
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
+
+/// [UniversalString](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/universalstring.html)
+///
+/// The ASN.1 UniversalString type contains UNICODE characters. They are four-byte characters encoded using UTF-32BE.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UniversalString<'data>(Cow<'data, str>);
+
+pub type OwnedUniversalString = UniversalString<'static>;
+
+impl UniversalString<'_> {
+    pub const TAG: Tag = Tag::from_octet(28);
+
+    /// Returns decoded [UniversalString] data
+    pub fn string(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns [UniversalString] data encoded using UTF-32BE
+    pub fn to_utf32_be(&self) -> Vec<u8> {
+        self.0.chars().flat_map(|c| u32::from(c).to_be_bytes()).collect()
+    }
+
+    /// Returns owned version of the [UniversalString]
+    pub fn to_owned(&self) -> OwnedUniversalString {
+        UniversalString(Cow::Owned(self.0.as_ref().into()))
+    }
+
+    fn data_len(&self) -> usize {
+        self.0.chars().count() * 4
+    }
+}
+
+impl<'data> From<&'data str> for UniversalString<'data> {
+    fn from(value: &'data str) -> Self {
+        Self(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for OwnedUniversalString {
+    fn from(value: String) -> Self {
+        Self(Cow::Owned(value))
+    }
+}
+
+impl TryFrom<&[u8]> for OwnedUniversalString {
+    type Error = Error;
+
+    /// Decodes UTF-32BE data.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() % 4 != 0 {
+            return Err("Invalid UniversalString: data length should be a multiple of 4".into());
+        }
+
+        let string = data
+            .chunks_exact(4)
+            .map(|bytes| {
+                let code_point = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+
+                char::from_u32(code_point).ok_or(Error::from("Invalid UniversalString: invalid code point"))
+            })
+            .collect::<Asn1Result<String>>()?;
+
+        Ok(Self(Cow::Owned(string)))
+    }
+}
+
+impl Taggable for UniversalString<'_> {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl<'data> Asn1ValueDecoder<'data> for UniversalString<'data> {
+    fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        OwnedUniversalString::try_from(read_restricted_string_data(tag, reader)?.as_ref())
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        Self::TAG == tag || Self::TAG.constructed() == tag
+    }
+}
+
+impl Asn1Encoder for UniversalString<'_> {
+    fn needed_buf_size(&self) -> usize {
+        let data_len = self.data_len();
+        1 /* tag */ + len_size(data_len) + data_len
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        writer.write_byte(Self::TAG.into())?;
+        write_len(self.data_len(), writer)?;
+
+        for c in self.0.chars() {
+            writer.write_slice(&u32::from(c).to_be_bytes())?;
+        }
+
+        Ok(())
+    }
+}
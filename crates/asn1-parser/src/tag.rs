@@ -1,32 +1,254 @@
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub struct Tag(pub(crate) u8);
+use core::fmt;
+
+use crate::reader::Reader;
+#[cfg(feature = "alloc")]
+use crate::writer::Writer;
+use crate::{Asn1Result, Error};
+
+/// Low five bits of the first identifier octet that indicate the high tag number form.
+const HIGH_TAG_NUMBER_FORM: u8 = 0x1f;
+
+const CLASS_MASK: u8 = 0xc0;
+const CONSTRUCTED_BIT: u8 = 0x20;
+
+/// Class of the [Tag].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TagClass {
+    Universal,
+    Application,
+    ContextSpecific,
+    Private,
+}
+
+impl TagClass {
+    /// Returns the class bits as they are placed in the first identifier octet.
+    pub const fn bits(self) -> u8 {
+        (self as u8) << 6
+    }
+
+    const fn from_bits(bits: u8) -> Self {
+        match bits & CLASS_MASK {
+            0x00 => TagClass::Universal,
+            0x40 => TagClass::Application,
+            0x80 => TagClass::ContextSpecific,
+            _ => TagClass::Private,
+        }
+    }
+}
+
+/// Identifier of the ASN.1 value: class, primitive/constructed flag, and tag number.
+///
+/// Tag numbers greater than 30 are encoded in the high tag number form: `0x1f` octet followed by
+/// the tag number in base 128 (bit 8 of every octet except the last one is set to 1).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tag {
+    /// Class bits as they are placed in the first identifier octet.
+    class: u8,
+    constructed: bool,
+    number: u32,
+}
 
 impl Tag {
+    /// Creates a new [Tag] from the single identifier octet.
+    ///
+    /// The `0x1f` tag number bits are treated as the tag number 31.
+    pub const fn from_octet(octet: u8) -> Self {
+        Self {
+            class: octet & CLASS_MASK,
+            constructed: octet & CONSTRUCTED_BIT == CONSTRUCTED_BIT,
+            number: (octet & HIGH_TAG_NUMBER_FORM) as u32,
+        }
+    }
+
+    pub(crate) const fn new(class: u8, constructed: bool, number: u32) -> Self {
+        Self {
+            class: class & CLASS_MASK,
+            constructed,
+            number,
+        }
+    }
+
+    /// Creates a new [Tag] with the provided class, constructed flag, and tag number.
+    pub const fn from_parts(class: TagClass, constructed: bool, number: u32) -> Self {
+        Self::new(class.bits(), constructed, number)
+    }
+
+    pub const fn universal(number: u32, constructed: bool) -> Self {
+        Self::from_parts(TagClass::Universal, constructed, number)
+    }
+
+    pub const fn application(number: u32, constructed: bool) -> Self {
+        Self::from_parts(TagClass::Application, constructed, number)
+    }
+
+    pub const fn context_specific(number: u32, constructed: bool) -> Self {
+        Self::from_parts(TagClass::ContextSpecific, constructed, number)
+    }
+
+    pub const fn private(number: u32, constructed: bool) -> Self {
+        Self::from_parts(TagClass::Private, constructed, number)
+    }
+
+    pub fn class(self) -> TagClass {
+        TagClass::from_bits(self.class)
+    }
+
+    pub fn number(self) -> u32 {
+        self.number
+    }
+
+    pub fn is_universal(self) -> bool {
+        self.class() == TagClass::Universal
+    }
+
     pub fn is_context_specific(self) -> bool {
-        self.0 & 0xc0 == 0x80
+        self.class() == TagClass::ContextSpecific
     }
 
     pub fn is_application(self) -> bool {
-        self.0 & 0xc0 == 0x40
+        self.class() == TagClass::Application
+    }
+
+    pub fn is_private(self) -> bool {
+        self.class() == TagClass::Private
     }
 
     pub fn is_constructed(self) -> bool {
-        self.0 & 0x20 == 0x20
+        self.constructed
     }
 
     pub fn is_primitive(self) -> bool {
         !self.is_constructed()
     }
+
+    /// Returns `true` if the tag number does not fit in the single identifier octet.
+    pub fn is_high_tag_number(self) -> bool {
+        self.number >= u32::from(HIGH_TAG_NUMBER_FORM)
+    }
+
+    /// Returns the same tag with the constructed bit set.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn constructed(self) -> Self {
+        Self {
+            constructed: true,
+            ..self
+        }
+    }
+
+    /// Returns the first identifier octet.
+    pub fn first_octet(self) -> u8 {
+        let number = if self.is_high_tag_number() {
+            HIGH_TAG_NUMBER_FORM
+        } else {
+            self.number as u8
+        };
+
+        self.class | if self.constructed { CONSTRUCTED_BIT } else { 0 } | number
+    }
+
+    /// Returns the number of the identifier octets.
+    pub fn encoded_len(self) -> usize {
+        if !self.is_high_tag_number() {
+            return 1;
+        }
+
+        let mut len = 2;
+        let mut number = self.number >> 7;
+        while number != 0 {
+            number >>= 7;
+            len += 1;
+        }
+
+        len
+    }
+
+    pub(crate) fn from_reader(reader: &mut Reader) -> Asn1Result<Self> {
+        let first_octet = reader.read_byte()?;
+        let mut tag = Self::from_octet(first_octet);
+
+        if first_octet & HIGH_TAG_NUMBER_FORM != HIGH_TAG_NUMBER_FORM {
+            return Ok(tag);
+        }
+
+        let mut number: u32 = 0;
+        loop {
+            let octet = reader.read_byte()?;
+
+            if number == 0 && octet == 0x80 {
+                return Err(Error::from(
+                    "Tag number should be encoded in the minimal number of octets",
+                ));
+            }
+
+            number = number.checked_mul(0x80).ok_or(Error::from("Tag number is too big"))? | u32::from(octet & 0x7f);
+
+            if octet & 0x80 == 0 {
+                break;
+            }
+        }
+
+        if number < u32::from(HIGH_TAG_NUMBER_FORM) {
+            if reader.profile().is_canonical() {
+                return Err(Error::from(
+                    "Tag numbers below 31 should use the single identifier octet",
+                ));
+            }
+
+            warn!("Tag number {} is encoded in the high tag number form.", number);
+        }
+
+        tag.number = number;
+
+        Ok(tag)
+    }
+
+    /// Reads the tag without advancing the reader.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn peek(reader: &Reader) -> Asn1Result<Self> {
+        let mut peek_reader = Reader::new(reader.remaining());
+        peek_reader.set_profile(reader.profile());
+
+        Self::from_reader(&mut peek_reader)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn to_writer(self, writer: &mut Writer) -> Asn1Result<()> {
+        writer.write_byte(self.first_octet())?;
+
+        if self.is_high_tag_number() {
+            for i in (0..self.encoded_len() - 1).rev() {
+                let octet = ((self.number >> (i * 7)) & 0x7f) as u8;
+                writer.write_byte(if i == 0 { octet } else { octet | 0x80 })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the tag in the ASN.1 notation, e.g. `[APPLICATION 10]` or `[0]` for the context-specific tags.
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.class() {
+            TagClass::Universal => write!(f, "[UNIVERSAL {}]", self.number),
+            TagClass::Application => write!(f, "[APPLICATION {}]", self.number),
+            TagClass::ContextSpecific => write!(f, "[{}]", self.number),
+            TagClass::Private => write!(f, "[PRIVATE {}]", self.number),
+        }
+    }
 }
 
 impl From<u8> for Tag {
     fn from(tag: u8) -> Self {
-        Self(tag)
+        Self::from_octet(tag)
     }
 }
 
 impl From<Tag> for u8 {
+    /// Returns the first identifier octet. Use [Tag::number] for the high tag numbers.
     fn from(tag: Tag) -> Self {
-        tag.0
+        tag.first_octet()
     }
 }
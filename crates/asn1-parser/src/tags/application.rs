@@ -1,3 +1,4 @@
+use alloc::vec;
 use alloc::vec::Vec;
 
 use crate::asn1::Asn1;
@@ -7,23 +8,29 @@ use crate::writer::Writer;
 use crate::{Asn1Decoder, Asn1Encoder, Asn1Result, Asn1ValueDecoder, MetaInfo, Tag, Taggable};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ApplicationTag<'data> {
-    tag: u8,
+    tag: Tag,
     inner: Vec<Asn1<'data>>,
 }
 
 pub type OwnedApplicationTag = ApplicationTag<'static>;
 
 impl<'data> ApplicationTag<'data> {
-    pub fn new(tag: u8, inner: Vec<Asn1<'data>>) -> Self {
+    pub fn new(tag_number: u32, inner: Vec<Asn1<'data>>) -> Self {
         Self {
-            tag: tag & 0x1f | 0x60,
+            tag: Tag::application(tag_number, true),
             inner,
         }
     }
 
-    pub fn tag_number(&self) -> u8 {
-        self.tag & 0x1f
+    /// Creates a new [ApplicationTag] that contains one value
+    pub fn wrap(tag_number: u32, value: impl Into<Asn1<'data>>) -> Self {
+        Self::new(tag_number, vec![value.into()])
+    }
+
+    pub fn tag_number(&self) -> u32 {
+        self.tag.number()
     }
 
     pub fn inner(&self) -> &[Asn1<'data>] {
@@ -42,9 +49,11 @@ impl<'data> ApplicationTag<'data> {
     }
 }
 
+impl_fields_editing!(ApplicationTag, inner);
+
 impl Taggable for ApplicationTag<'_> {
     fn tag(&self) -> Tag {
-        Tag(self.tag)
+        self.tag
     }
 }
 
@@ -56,7 +65,7 @@ impl<'data> Asn1ValueDecoder<'data> for ApplicationTag<'data> {
             inner.push(Asn1::decode(reader)?);
         }
 
-        Ok(Self { tag: tag.0, inner })
+        Ok(Self { tag, inner })
     }
 
     fn compare_tags(tag: Tag) -> bool {
@@ -68,11 +77,11 @@ impl Asn1Encoder for ApplicationTag<'_> {
     fn needed_buf_size(&self) -> usize {
         let data_len = self.inner.iter().map(|f| f.needed_buf_size()).sum();
 
-        1 /* tag */ + len_size(data_len) + data_len
+        self.tag.encoded_len() + len_size(data_len) + data_len
     }
 
     fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
-        writer.write_byte(self.tag)?;
+        self.tag.to_writer(writer)?;
 
         let data_len = self.inner.iter().map(|f| f.needed_buf_size()).sum();
         write_len(data_len, writer)?;
@@ -1,16 +1,33 @@
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::vec;
 use alloc::vec::Vec;
 
 use crate::asn1::Asn1;
 use crate::length::{len_size, write_len};
 use crate::reader::Reader;
+use crate::string::decode_encapsulated;
 use crate::writer::Writer;
-use crate::{Asn1Decoder, Asn1Encoder, Asn1Result, Asn1ValueDecoder, MetaInfo, Tag, Taggable};
+use crate::{Asn1Decoder, Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, MetaInfo, Tag, Taggable};
+
+/// Decodes the implicitly tagged value as the `T` type.
+///
+/// The original tag is passed to the `T` decoder, so the constructed bit (e.g. for BER constructed strings) is preserved.
+pub(crate) fn decode_implicit<'data, T: Asn1ValueDecoder<'data>>(
+    tag: Tag,
+    reader: &mut Reader<'data>,
+) -> Asn1Result<T> {
+    if !tag.is_context_specific() {
+        return Err(Error::from("Implicit tag should be context-specific"));
+    }
+
+    T::decode(tag, reader)
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImplicitTag<'data> {
-    tag: u8,
+    tag: Tag,
     octets: Cow<'data, [u8]>,
     inner: Option<Box<Asn1<'data>>>,
 }
@@ -18,21 +35,21 @@ pub struct ImplicitTag<'data> {
 pub type OwnedImplicitTag = ImplicitTag<'static>;
 
 impl<'data> ImplicitTag<'data> {
-    pub fn new_owned(tag: u8, octets: Vec<u8>) -> Self {
+    pub fn new_owned(tag_number: u32, octets: Vec<u8>) -> Self {
         let inner = Asn1::decode_buff(&octets).ok().map(|mut asn1| {
             asn1.clear_meta();
             Box::new(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned()))
         });
 
         Self {
-            tag,
+            tag: Tag::context_specific(tag_number, false),
             octets: Cow::Owned(octets),
             inner,
         }
     }
 
-    pub fn tag_number(&self) -> u8 {
-        self.tag & 0x1f
+    pub fn tag_number(&self) -> u32 {
+        self.tag.number()
     }
 
     pub fn inner_asn1(&self) -> Option<&Asn1<'data>> {
@@ -43,6 +60,13 @@ impl<'data> ImplicitTag<'data> {
         self.octets.as_ref()
     }
 
+    /// Reinterprets the implicitly tagged value as the `T` type.
+    ///
+    /// The underlying type of the IMPLICIT tag is not encoded, so it should be known from the ASN.1 definition.
+    pub fn decode_as<'a, T: Asn1ValueDecoder<'a>>(&'a self) -> Asn1Result<T> {
+        decode_implicit(self.tag, &mut Reader::new(self.octets.as_ref()))
+    }
+
     pub fn to_owned(&self) -> OwnedImplicitTag {
         OwnedImplicitTag {
             tag: self.tag,
@@ -57,7 +81,7 @@ impl<'data> ImplicitTag<'data> {
 
 impl Taggable for ImplicitTag<'_> {
     fn tag(&self) -> Tag {
-        Tag(self.tag)
+        self.tag
     }
 }
 
@@ -65,19 +89,10 @@ impl<'data> Asn1ValueDecoder<'data> for ImplicitTag<'data> {
     fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
         let data = reader.read_remaining();
 
-        let mut inner_reader = Reader::new(data);
-        inner_reader.set_next_id(reader.next_id());
-        inner_reader.set_offset(reader.full_offset() - data.len());
-        let mut inner = Asn1::decode(&mut inner_reader).ok().map(Box::new);
-
-        if !inner_reader.empty() && inner.is_some() {
-            inner = None;
-        }
-
-        reader.set_next_id(inner_reader.next_id());
+        let inner = decode_encapsulated(data, reader.full_offset() - data.len(), reader);
 
         Ok(Self {
-            tag: tag.0,
+            tag,
             octets: Cow::Borrowed(data),
             inner,
         })
@@ -92,11 +107,11 @@ impl Asn1Encoder for ImplicitTag<'_> {
     fn needed_buf_size(&self) -> usize {
         let data_len = self.octets.len();
 
-        1 /* tag */ + len_size(data_len) + data_len
+        self.tag.encoded_len() + len_size(data_len) + data_len
     }
 
     fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
-        writer.write_byte(self.tag)?;
+        self.tag.to_writer(writer)?;
         write_len(self.octets.len(), writer)?;
         writer.write_slice(&self.octets)
     }
@@ -107,3 +122,65 @@ impl MetaInfo for ImplicitTag<'_> {
         self.inner = None;
     }
 }
+
+/// Typed context-specific IMPLICIT tag: `[TAG_NUMBER] IMPLICIT T`.
+///
+/// It is intended for the typed protocol parsers: `Tlv::<Implicit<Integer, 0>>::decode_buff(raw)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Implicit<T, const TAG_NUMBER: u32>(T);
+
+impl<T, const TAG_NUMBER: u32> Implicit<T, TAG_NUMBER> {
+    pub fn new(inner: T) -> Self {
+        Self(inner)
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Taggable, const TAG_NUMBER: u32> Taggable for Implicit<T, TAG_NUMBER> {
+    fn tag(&self) -> Tag {
+        Tag::context_specific(TAG_NUMBER, self.0.tag().is_constructed())
+    }
+}
+
+impl<'data, T: Asn1ValueDecoder<'data>, const TAG_NUMBER: u32> Asn1ValueDecoder<'data> for Implicit<T, TAG_NUMBER> {
+    fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        if !Self::compare_tags(tag) {
+            return Err(Error::from("Invalid implicit tag number"));
+        }
+
+        Ok(Self(decode_implicit(tag, reader)?))
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        tag.is_context_specific() && tag.number() == TAG_NUMBER
+    }
+}
+
+impl<T: Asn1Encoder + Taggable, const TAG_NUMBER: u32> Asn1Encoder for Implicit<T, TAG_NUMBER> {
+    fn needed_buf_size(&self) -> usize {
+        self.0.needed_buf_size() - self.0.tag().encoded_len() + self.tag().encoded_len()
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        // The underlying type encoding is the same except the tag.
+        let mut buff = vec![0; self.0.needed_buf_size()];
+        self.0.encode_buff(&mut buff)?;
+
+        self.tag().to_writer(writer)?;
+        writer.write_slice(&buff[self.0.tag().encoded_len()..])
+    }
+}
+
+impl<T: MetaInfo, const TAG_NUMBER: u32> MetaInfo for Implicit<T, TAG_NUMBER> {
+    fn clear_meta(&mut self) {
+        self.0.clear_meta()
+    }
+}
@@ -1,7 +1,9 @@
 mod application;
 mod explicit;
 mod implicit;
+mod private;
 
 pub use application::{ApplicationTag, OwnedApplicationTag};
 pub use explicit::{ExplicitTag, OwnedExplicitTag};
-pub use implicit::{ImplicitTag, OwnedImplicitTag};
+pub use implicit::{Implicit, ImplicitTag, OwnedImplicitTag};
+pub use private::{OwnedPrivateTag, PrivateTag};
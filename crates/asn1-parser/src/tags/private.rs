@@ -0,0 +1,148 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use crate::asn1::Asn1;
+use crate::length::{len_size, write_len};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{Asn1Decoder, Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, MetaInfo, Tag, Taggable};
+
+/// Private-class tag (class bits `0b11`). It is used by vendor-specific extensions.
+///
+/// The constructed form contains decoded inner fields. The primitive form contains raw octets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrivateTag<'data> {
+    tag: Tag,
+    octets: Cow<'data, [u8]>,
+    inner: Vec<Asn1<'data>>,
+}
+
+pub type OwnedPrivateTag = PrivateTag<'static>;
+
+impl<'data> PrivateTag<'data> {
+    /// Creates a new constructed [PrivateTag] from passed fields
+    pub fn new(tag_number: u32, inner: Vec<Asn1<'data>>) -> Self {
+        Self {
+            tag: Tag::private(tag_number, true),
+            octets: Cow::Borrowed(&[]),
+            inner,
+        }
+    }
+
+    /// Creates a new primitive [PrivateTag] from passed octets
+    pub fn new_primitive(tag_number: u32, octets: Vec<u8>) -> OwnedPrivateTag {
+        PrivateTag {
+            tag: Tag::private(tag_number, false),
+            octets: Cow::Owned(octets),
+            inner: Vec::new(),
+        }
+    }
+
+    pub fn tag_number(&self) -> u32 {
+        self.tag.number()
+    }
+
+    pub fn is_constructed(&self) -> bool {
+        self.tag.is_constructed()
+    }
+
+    /// Returns inner fields of the constructed [PrivateTag]
+    pub fn inner(&self) -> &[Asn1<'data>] {
+        &self.inner
+    }
+
+    /// Returns octets of the primitive [PrivateTag]
+    pub fn octets(&self) -> &[u8] {
+        self.octets.as_ref()
+    }
+
+    pub fn to_owned(&self) -> OwnedPrivateTag {
+        OwnedPrivateTag {
+            tag: self.tag,
+            octets: self.octets.to_vec().into(),
+            inner: self
+                .inner
+                .iter()
+                .map(|f| f.to_owned_with_asn1(f.inner_asn1().to_owned()))
+                .collect(),
+        }
+    }
+
+    fn ensure_constructed(&self) -> Asn1Result<()> {
+        if !self.is_constructed() {
+            return Err(Error::from("Primitive PrivateTag can not contain fields"));
+        }
+
+        Ok(())
+    }
+
+    fn data_len(&self) -> usize {
+        if self.is_constructed() {
+            self.inner.iter().map(|f| f.needed_buf_size()).sum()
+        } else {
+            self.octets.len()
+        }
+    }
+}
+
+impl_fields_editing!(PrivateTag, inner, ensure_constructed);
+
+impl Taggable for PrivateTag<'_> {
+    fn tag(&self) -> Tag {
+        self.tag
+    }
+}
+
+impl<'data> Asn1ValueDecoder<'data> for PrivateTag<'data> {
+    fn decode(tag: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        if tag.is_primitive() {
+            return Ok(Self {
+                tag,
+                octets: Cow::Borrowed(reader.read_remaining()),
+                inner: Vec::new(),
+            });
+        }
+
+        let mut inner = Vec::new();
+
+        while !reader.empty() {
+            inner.push(Asn1::decode(reader)?);
+        }
+
+        Ok(Self {
+            tag,
+            octets: Cow::Borrowed(&[]),
+            inner,
+        })
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        tag.is_private()
+    }
+}
+
+impl Asn1Encoder for PrivateTag<'_> {
+    fn needed_buf_size(&self) -> usize {
+        let data_len = self.data_len();
+
+        self.tag.encoded_len() + len_size(data_len) + data_len
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        self.tag.to_writer(writer)?;
+        write_len(self.data_len(), writer)?;
+
+        if self.is_constructed() {
+            self.inner.iter().try_for_each(|f| f.encode(writer))
+        } else {
+            writer.write_slice(&self.octets)
+        }
+    }
+}
+
+impl MetaInfo for PrivateTag<'_> {
+    fn clear_meta(&mut self) {
+        self.inner.iter_mut().for_each(|f| f.clear_meta())
+    }
+}
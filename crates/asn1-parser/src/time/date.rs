@@ -0,0 +1,97 @@
+use alloc::format;
+use core::fmt;
+
+use super::{days_from_civil, read_number, read_separator, validate_date_time, Day, FullYear, Month};
+use crate::length::{len_size, write_len};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
+
+/// DATE (X.680 2008)
+///
+/// Supported format: `YYYY-MM-DD`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Date {
+    pub year: FullYear,
+    pub month: Month,
+    pub day: Day,
+}
+
+impl Date {
+    pub const TAG: Tag = Tag::universal(31, false);
+
+    pub(super) const ENCODED_LEN: usize = 4 /* year */ + 1 + 2 /* month */ + 1 + 2 /* day */;
+
+    pub fn new(year: FullYear, month: Month, day: Day) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Returns the number of seconds since 1970-01-01 00:00:00 UTC to the start of the day.
+    pub fn to_unix_timestamp(&self) -> i64 {
+        days_from_civil(*self.year.as_ref(), *self.month.as_ref(), *self.day.as_ref()) * 86400
+    }
+
+    pub(super) fn from_reader(reader: &mut Reader) -> Asn1Result<Self> {
+        let year = FullYear::from_reader(reader)?;
+        read_separator(reader, b'-')?;
+        let month = Month::try_from(read_number(reader)?)?;
+        read_separator(reader, b'-')?;
+        let day = Day::try_from(read_number(reader)?)?;
+
+        validate_date_time(*year.as_ref(), *month.as_ref(), *day.as_ref(), 0, 0, 0)?;
+
+        Ok(Self { year, month, day })
+    }
+
+    pub(super) fn to_writer(&self, writer: &mut Writer) -> Asn1Result<()> {
+        writer.write_slice(format!("{}", self).as_bytes())
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}",
+            self.year.as_ref(),
+            self.month.as_ref(),
+            self.day.as_ref()
+        )
+    }
+}
+
+impl Taggable for Date {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl<'data> Asn1ValueDecoder<'data> for Date {
+    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        let date = Self::from_reader(reader)?;
+
+        if !reader.empty() {
+            return Err(Error::from("DATE value contains trailing data"));
+        }
+
+        Ok(date)
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        Self::TAG == tag
+    }
+}
+
+impl Asn1Encoder for Date {
+    fn needed_buf_size(&self) -> usize {
+        Self::TAG.encoded_len() + len_size(Self::ENCODED_LEN) + Self::ENCODED_LEN
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        Self::TAG.to_writer(writer)?;
+        write_len(Self::ENCODED_LEN, writer)?;
+
+        self.to_writer(writer)
+    }
+}
@@ -0,0 +1,77 @@
+use core::fmt;
+
+use super::{read_separator, Date, TimeOfDay};
+use crate::length::{len_size, write_len};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
+
+/// DATE-TIME (X.680 2008)
+///
+/// Supported format: `YYYY-MM-DDThh:mm:ss`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateTime {
+    pub date: Date,
+    pub time: TimeOfDay,
+}
+
+impl DateTime {
+    pub const TAG: Tag = Tag::universal(33, false);
+
+    const ENCODED_LEN: usize = Date::ENCODED_LEN + 1 /* 'T' */ + TimeOfDay::ENCODED_LEN;
+
+    pub fn new(date: Date, time: TimeOfDay) -> Self {
+        Self { date, time }
+    }
+
+    /// Returns the number of seconds since 1970-01-01 00:00:00. The value has no time zone, so it is treated as UTC.
+    pub fn to_unix_timestamp(&self) -> i64 {
+        self.date.to_unix_timestamp() + i64::from(self.time.seconds_from_midnight())
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+
+impl Taggable for DateTime {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl<'data> Asn1ValueDecoder<'data> for DateTime {
+    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        let date = Date::from_reader(reader)?;
+        read_separator(reader, b'T')?;
+        let time = TimeOfDay::from_reader(reader)?;
+
+        if !reader.empty() {
+            return Err(Error::from("DATE-TIME value contains trailing data"));
+        }
+
+        Ok(Self { date, time })
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        Self::TAG == tag
+    }
+}
+
+impl Asn1Encoder for DateTime {
+    fn needed_buf_size(&self) -> usize {
+        Self::TAG.encoded_len() + len_size(Self::ENCODED_LEN) + Self::ENCODED_LEN
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        Self::TAG.to_writer(writer)?;
+        write_len(Self::ENCODED_LEN, writer)?;
+
+        self.date.to_writer(writer)?;
+        writer.write_byte(b'T')?;
+        self.time.to_writer(writer)
+    }
+}
@@ -0,0 +1,187 @@
+use alloc::string::ToString;
+use core::fmt;
+
+use crate::length::{len_size, write_len};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
+
+const DATE_DESIGNATORS: [u8; 4] = *b"YMWD";
+const TIME_DESIGNATORS: [u8; 3] = *b"HMS";
+
+/// DURATION (X.680 2008)
+///
+/// Supported format: `PnYnMnDTnHnMnS` and `PnW` (ISO 8601). Omitted components are `None`.
+/// The fractional components are not supported.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Duration {
+    pub years: Option<u32>,
+    pub months: Option<u32>,
+    pub weeks: Option<u32>,
+    pub days: Option<u32>,
+    pub hours: Option<u32>,
+    pub minutes: Option<u32>,
+    pub seconds: Option<u32>,
+}
+
+impl Duration {
+    pub const TAG: Tag = Tag::universal(34, false);
+
+    fn date_components(&self) -> [Option<u32>; 4] {
+        [self.years, self.months, self.weeks, self.days]
+    }
+
+    fn time_components(&self) -> [Option<u32>; 3] {
+        [self.hours, self.minutes, self.seconds]
+    }
+}
+
+/// Reads the decimal number of the duration component.
+fn read_component(reader: &mut Reader) -> Asn1Result<u32> {
+    let mut value: u32 = 0;
+    let mut digits = 0;
+
+    while !reader.empty() && reader.peek_byte()?.is_ascii_digit() {
+        let digit = u32::from(reader.read_byte()? - b'0');
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(digit))
+            .ok_or(Error::from("DURATION component is too big"))?;
+        digits += 1;
+    }
+
+    if digits == 0 {
+        return Err(Error::from("DURATION component should have at least one digit"));
+    }
+
+    Ok(value)
+}
+
+/// Reads the components up to the end of data or the 'T' designator. Components should follow the designators order.
+fn read_components<const N: usize>(reader: &mut Reader, designators: [u8; N]) -> Asn1Result<[Option<u32>; N]> {
+    let mut components = [None; N];
+    let mut next = 0;
+
+    while !reader.empty() && reader.peek_byte()? != b'T' {
+        let value = read_component(reader)?;
+
+        let designator = reader.read_byte()?;
+        if designator == b'.' || designator == b',' {
+            return Err(Error::from("Fractional DURATION components are not supported"));
+        }
+
+        let index = designators[next..]
+            .iter()
+            .position(|d| *d == designator)
+            .ok_or(Error::from("Invalid or misplaced DURATION designator"))?
+            + next;
+        components[index] = Some(value);
+        next = index + 1;
+    }
+
+    Ok(components)
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "P")?;
+
+        for (value, designator) in self.date_components().into_iter().zip(DATE_DESIGNATORS) {
+            if let Some(value) = value {
+                write!(f, "{}{}", value, char::from(designator))?;
+            }
+        }
+
+        if self.time_components().iter().any(Option::is_some) {
+            write!(f, "T")?;
+
+            for (value, designator) in self.time_components().into_iter().zip(TIME_DESIGNATORS) {
+                if let Some(value) = value {
+                    write!(f, "{}{}", value, char::from(designator))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Taggable for Duration {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl<'data> Asn1ValueDecoder<'data> for Duration {
+    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        if reader.read_byte()? != b'P' {
+            return Err(Error::from("DURATION value should start with 'P'"));
+        }
+
+        let [years, months, weeks, days] = read_components(reader, DATE_DESIGNATORS)?;
+
+        let mut time_components = [None; 3];
+        if !reader.empty() {
+            // The only reason to stop reading the date components is the 'T' designator.
+            reader.read_byte()?;
+            time_components = read_components(reader, TIME_DESIGNATORS)?;
+
+            if time_components.iter().all(Option::is_none) {
+                return Err(Error::from("DURATION time part should have at least one component"));
+            }
+
+            if !reader.empty() {
+                return Err(Error::from("DURATION value contains trailing data"));
+            }
+        }
+        let [hours, minutes, seconds] = time_components;
+
+        let duration = Self {
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+        };
+
+        let components = duration
+            .date_components()
+            .into_iter()
+            .chain(duration.time_components())
+            .flatten()
+            .count();
+        if components == 0 {
+            return Err(Error::from("DURATION value should have at least one component"));
+        }
+
+        if duration.weeks.is_some() && components > 1 {
+            return Err(Error::from("Weeks can not be combined with other DURATION components"));
+        }
+
+        Ok(duration)
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        Self::TAG == tag
+    }
+}
+
+impl Asn1Encoder for Duration {
+    fn needed_buf_size(&self) -> usize {
+        let value_len = self.to_string().len();
+
+        Self::TAG.encoded_len() + len_size(value_len) + value_len
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        let value = self.to_string();
+
+        Self::TAG.to_writer(writer)?;
+        write_len(value.len(), writer)?;
+
+        writer.write_slice(value.as_bytes())
+    }
+}
@@ -1,174 +1,154 @@
 use alloc::format;
-use alloc::string::String;
-use core::str::from_utf8;
 
-#[cfg(not(feature = "std"))]
-use num_traits::float::FloatCore;
-
-use super::{read_number, Day, Hour, Minute, Month};
+use super::{read_number, unix_timestamp, validate_date_time, Day, Hour, LocalTimeDiffFactor, Minute, Month, Second};
 use crate::length::{len_size, write_len};
 use crate::reader::Reader;
 use crate::writer::Writer;
 use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
 
+/// Max number of the fractional second digits: nanoseconds precision.
+const MAX_FRACTION_DIGITS: u8 = 9;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Year(u16);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FullYear(u16);
 
-impl Year {
-    pub fn new(year: u16) -> Self {
-        Self(year)
+impl FullYear {
+    pub fn new(year: u16) -> Asn1Result<Self> {
+        if year > 9999 {
+            return Err(Error::from("Year should be in range 0..=9999"));
+        }
+
+        Ok(Self(year))
     }
 
-    fn from_reader(reader: &mut Reader) -> Asn1Result<Self> {
-        Ok(Self(from_utf8(reader.read(4)?)?.parse::<u16>()?))
+    pub(super) fn from_reader(reader: &mut Reader) -> Asn1Result<Self> {
+        Ok(Self(
+            u16::from(read_number(reader)?) * 100 + u16::from(read_number(reader)?),
+        ))
     }
 
-    fn to_writer(&self, writer: &mut Writer) -> Asn1Result<()> {
+    pub(super) fn to_writer(&self, writer: &mut Writer) -> Asn1Result<()> {
         writer.write_slice(format!("{:04}", self.0).as_bytes())?;
 
         Ok(())
     }
 }
 
-impl AsRef<u16> for Year {
+impl AsRef<u16> for FullYear {
     fn as_ref(&self) -> &u16 {
         &self.0
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct Second(f32);
-
-impl PartialEq for Second {
-    fn eq(&self, other: &Self) -> bool {
-        (self.0 - other.0).abs() <= f32::EPSILON
-    }
+/// Fractional part of the second.
+///
+/// The number of digits is preserved: `.5` and `.500` are different values in the encoded form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SecondFraction {
+    value: u32,
+    digits: u8,
 }
 
-impl Eq for Second {}
+impl SecondFraction {
+    /// Creates a new fraction from its decimal digits: `SecondFraction::new(25, 3)` is `.025`.
+    pub fn new(value: u32, digits: u8) -> Asn1Result<Self> {
+        if digits == 0 || digits > MAX_FRACTION_DIGITS {
+            return Err(Error::from("Fraction of the second should have 1..=9 digits"));
+        }
+
+        if value >= 10_u32.pow(u32::from(digits)) {
+            return Err(Error::from("Fraction of the second value does not fit in the digits"));
+        }
 
-impl From<Second> for f32 {
-    fn from(value: Second) -> Self {
-        value.0
+        Ok(Self { value, digits })
     }
-}
 
-impl AsRef<f32> for Second {
-    fn as_ref(&self) -> &f32 {
-        &self.0
+    pub fn value(&self) -> u32 {
+        self.value
     }
-}
 
-impl TryFrom<u8> for Second {
-    type Error = crate::Error;
-
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        if value < 60 {
-            Ok(Self(value.into()))
-        } else {
-            Err("invalid value".into())
-        }
+    pub fn digits(&self) -> u8 {
+        self.digits
     }
-}
 
-impl TryFrom<f32> for Second {
-    type Error = crate::Error;
+    pub fn nanoseconds(&self) -> u32 {
+        self.value * 10_u32.pow(u32::from(MAX_FRACTION_DIGITS - self.digits))
+    }
 
-    fn try_from(value: f32) -> Result<Self, Self::Error> {
-        if value < 60.0 {
-            Ok(Self(value))
-        } else {
-            Err("invalid value".into())
-        }
+    /// DER requires the fraction to be omitted if it is zero and to have no trailing zeros.
+    fn is_canonical(&self) -> bool {
+        self.value % 10 != 0
     }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum LocalTimeDirection {
-    Plus,
-    Minus,
-}
+    fn from_reader(reader: &mut Reader) -> Asn1Result<Self> {
+        let mut value: u32 = 0;
+        let mut digits: u8 = 0;
 
-impl From<LocalTimeDirection> for char {
-    fn from(value: LocalTimeDirection) -> Self {
-        match value {
-            LocalTimeDirection::Minus => '-',
-            LocalTimeDirection::Plus => '+',
-        }
-    }
-}
+        while !reader.empty() && reader.peek_byte()?.is_ascii_digit() {
+            if digits == MAX_FRACTION_DIGITS {
+                return Err(Error::from("Fraction of the second has too many digits"));
+            }
 
-impl From<LocalTimeDirection> for u8 {
-    fn from(value: LocalTimeDirection) -> Self {
-        match value {
-            LocalTimeDirection::Minus => b'-',
-            LocalTimeDirection::Plus => b'+',
+            value = value * 10 + u32::from(reader.read_byte()? - b'0');
+            digits += 1;
         }
+
+        Self::new(value, digits)
     }
-}
 
-impl TryFrom<u8> for LocalTimeDirection {
-    type Error = Error;
+    fn to_writer(&self, writer: &mut Writer) -> Asn1Result<()> {
+        writer.write_byte(b'.')?;
+        writer.write_slice(format!("{:0width$}", self.value, width = usize::from(self.digits)).as_bytes())?;
 
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            b'-' => Ok(Self::Minus),
-            b'+' => Ok(Self::Plus),
-            _ => Err("invalid GeneralTime data".into()),
-        }
+        Ok(())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct LocalTimeDiffFactor {
-    pub time_direction: LocalTimeDirection,
-    pub hour: Hour,
-    pub minute: Minute,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeZone {
+    /// The value ends with 'Z'.
+    Utc,
+    /// The value has no time zone designator. Not allowed in DER.
+    Local,
+    /// The value ends with the local time differential. Not allowed in DER.
+    Offset(LocalTimeDiffFactor),
 }
 
-impl LocalTimeDiffFactor {
-    const ENCODED_LEN: usize = 1 /* sign */ + 2 /* hour */ + 2 /* minute */;
-
-    fn from_reader(reader: &mut Reader) -> Asn1Result<Self> {
-        Ok(Self {
-            time_direction: reader.read_byte()?.try_into()?,
-            hour: Hour::try_from(read_number(reader)?)?,
-            minute: Minute::try_from(read_number(reader)?)?,
-        })
-    }
-
-    fn to_writer(&self, writer: &mut Writer) -> Asn1Result<()> {
-        writer.write_byte(self.time_direction.into())?;
-        writer.write_slice(format!("{:02}", self.hour.as_ref()).as_bytes())?;
-        writer.write_slice(format!("{:02}", self.minute.as_ref()).as_bytes())?;
-
-        Ok(())
+impl TimeZone {
+    fn encoded_len(&self) -> usize {
+        match self {
+            TimeZone::Utc => 1,
+            TimeZone::Local => 0,
+            TimeZone::Offset(_) => LocalTimeDiffFactor::ENCODED_LEN,
+        }
     }
 }
 
+/// [GeneralizedTime](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/generalizedtime.html)
+///
+/// Supported format: `YYYYMMDDhhmmss[(.|,)f+][Z|(+|-)hhmm]`.
+/// In DER, the value must end with 'Z', use '.' as the decimal mark, and have no trailing zeros in the fraction.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GeneralizedTime {
-    pub year: Year,
+    pub year: FullYear,
     pub month: Month,
     pub day: Day,
     pub hour: Hour,
     pub minute: Minute,
     pub second: Second,
-    pub local_time: Option<LocalTimeDiffFactor>,
+    pub fraction: Option<SecondFraction>,
+    pub time_zone: TimeZone,
 }
 
 impl GeneralizedTime {
-    pub const TAG: Tag = Tag(24);
-
-    pub fn new(
-        year: Year,
-        month: Month,
-        day: Day,
-        hour: Hour,
-        minute: Minute,
-        second: Second,
-        local_time: Option<LocalTimeDiffFactor>,
-    ) -> Self {
+    pub const TAG: Tag = Tag::from_octet(24);
+
+    /// Creates a new UTC [GeneralizedTime] without the fractional seconds.
+    pub fn new(year: FullYear, month: Month, day: Day, hour: Hour, minute: Minute, second: Second) -> Self {
         Self {
             year,
             month,
@@ -176,18 +156,69 @@ impl GeneralizedTime {
             hour,
             minute,
             second,
-            local_time,
+            fraction: None,
+            time_zone: TimeZone::Utc,
         }
     }
 
-    fn calc_data_len(&self) -> usize {
-        let second_len = if self.second.as_ref().fract() > f32::EPSILON {
-            2 /* int part */ + 1 /* dot */ + 3 /* fract part */
-        } else {
-            2
+    pub fn with_fraction(self, fraction: SecondFraction) -> Self {
+        Self {
+            fraction: Some(fraction),
+            ..self
+        }
+    }
+
+    pub fn with_time_zone(self, time_zone: TimeZone) -> Self {
+        Self { time_zone, ..self }
+    }
+
+    /// Returns the number of seconds since 1970-01-01 00:00:00 UTC.
+    ///
+    /// The local time (without time zone designator) is treated as UTC.
+    /// The fractional part of the second is not included. Use [SecondFraction::nanoseconds] to get it.
+    pub fn to_unix_timestamp(&self) -> i64 {
+        let offset = match &self.time_zone {
+            TimeZone::Offset(local_time) => local_time.offset_seconds(),
+            TimeZone::Utc | TimeZone::Local => 0,
         };
 
-        2 /* year */ + 2 /* month */ + 2 /* day */ + 2 /* hour */ + 2 /* minute */ + second_len + LocalTimeDiffFactor::ENCODED_LEN
+        unix_timestamp(
+            self.year.0,
+            *self.month.as_ref(),
+            *self.day.as_ref(),
+            *self.hour.as_ref(),
+            *self.minute.as_ref(),
+            *self.second.as_ref(),
+        ) - offset
+    }
+
+    fn validate_der(&self) -> Asn1Result<()> {
+        if self.time_zone != TimeZone::Utc {
+            return Err(Error::from("DER GeneralizedTime should end with 'Z'"));
+        }
+
+        if self
+            .fraction
+            .as_ref()
+            .map(|fraction| !fraction.is_canonical())
+            .unwrap_or(false)
+        {
+            return Err(Error::from(
+                "DER GeneralizedTime fraction of the second should not have trailing zeros",
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn calc_data_len(&self) -> usize {
+        let fraction_len = self
+            .fraction
+            .as_ref()
+            .map(|fraction| 1 /* dot */ + usize::from(fraction.digits))
+            .unwrap_or_default();
+
+        4 /* year */ + 2 /* month */ + 2 /* day */ + 2 /* hour */ + 2 /* minute */ + 2 /* second */ + fraction_len + self.time_zone.encoded_len()
     }
 }
 
@@ -199,96 +230,56 @@ impl Taggable for GeneralizedTime {
 
 impl<'data> Asn1ValueDecoder<'data> for GeneralizedTime {
     fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
-        let year = Year::from_reader(reader)?;
-        let month = Month::try_from(read_number(reader)?)?;
-        let day = Day::try_from(read_number(reader)?)?;
-        let hour = Hour::try_from(read_number(reader)?)?;
-        let minute = Minute::try_from(read_number(reader)?)?;
-
-        let second_int_part = read_number(reader)?;
-
-        if reader.empty() {
-            return Ok(Self {
-                year,
-                month,
-                day,
-                hour,
-                minute,
-                second: second_int_part.try_into().unwrap(),
-                local_time: None,
-            });
+        let mut generalized_time = Self::new(
+            FullYear::from_reader(reader)?,
+            Month::try_from(read_number(reader)?)?,
+            Day::try_from(read_number(reader)?)?,
+            Hour::try_from(read_number(reader)?)?,
+            Minute::try_from(read_number(reader)?)?,
+            Second::try_from(read_number(reader)?)?,
+        );
+
+        let mut comma_decimal_mark = false;
+        if !reader.empty() && matches!(reader.peek_byte()?, b'.' | b',') {
+            comma_decimal_mark = reader.read_byte()? == b',';
+            generalized_time.fraction = Some(SecondFraction::from_reader(reader)?);
         }
 
-        match char::from(reader.peek_byte()?) {
-            'Z' => Ok(Self {
-                year,
-                month,
-                day,
-                hour,
-                minute,
-                second: second_int_part.try_into().unwrap(),
-                local_time: None,
-            }),
-            '+' | '-' => Ok(Self {
-                year,
-                month,
-                day,
-                hour,
-                minute,
-                second: second_int_part.try_into().unwrap(),
-                local_time: Some(LocalTimeDiffFactor::from_reader(reader)?),
-            }),
-            '.' => {
-                // sorry for this code
-                let _ = reader.read_byte()?; // read the dot byte
-                let mut frac = String::new();
-
-                let mut c = char::from(reader.peek_byte()?);
-                while c.is_ascii_digit() {
+        generalized_time.time_zone = if reader.empty() {
+            TimeZone::Local
+        } else {
+            match reader.peek_byte()? {
+                b'Z' => {
                     reader.read_byte()?;
-                    frac.push(c);
-
-                    if reader.empty() {
-                        let seconds = format!("{}.{}", second_int_part, frac).parse::<f32>()?;
-                        return Ok(Self {
-                            year,
-                            month,
-                            day,
-                            hour,
-                            minute,
-                            second: seconds.try_into().unwrap(),
-                            local_time: None,
-                        });
-                    }
-
-                    c = char::from(reader.peek_byte()?);
-                }
-                let seconds = format!("{}.{}", second_int_part, frac).parse::<f32>()?;
-
-                match c {
-                    'Z' => Ok(Self {
-                        year,
-                        month,
-                        day,
-                        hour,
-                        minute,
-                        second: seconds.try_into().unwrap(),
-                        local_time: None,
-                    }),
-                    '+' | '-' => Ok(Self {
-                        year,
-                        month,
-                        day,
-                        hour,
-                        minute,
-                        second: seconds.try_into().unwrap(),
-                        local_time: Some(LocalTimeDiffFactor::from_reader(reader)?),
-                    }),
-                    _ => Err("invalid GeneralTime data: invalid char after second frac part".into()),
+                    TimeZone::Utc
                 }
+                b'+' | b'-' => TimeZone::Offset(LocalTimeDiffFactor::from_reader(reader)?),
+                _ => return Err("invalid GeneralTime data: invalid char after seconds".into()),
             }
-            _ => Err("invalid GeneralTime data: invalid char after second int part".into()),
+        };
+
+        if !reader.empty() {
+            return Err("invalid GeneralTime data: trailing data after time zone".into());
         }
+
+        validate_date_time(
+            generalized_time.year.0,
+            *generalized_time.month.as_ref(),
+            *generalized_time.day.as_ref(),
+            *generalized_time.hour.as_ref(),
+            *generalized_time.minute.as_ref(),
+            *generalized_time.second.as_ref(),
+        )?;
+
+        if reader.profile().is_canonical() {
+            if comma_decimal_mark {
+                return Err(Error::from("DER GeneralizedTime should use '.' as the decimal mark"));
+            }
+
+            generalized_time.validate_der()?;
+        }
+
+        Ok(generalized_time)
     }
 
     fn compare_tags(tag: Tag) -> bool {
@@ -312,17 +303,16 @@ impl Asn1Encoder for GeneralizedTime {
         writer.write_slice(format!("{:02}", self.day.as_ref()).as_bytes())?;
         writer.write_slice(format!("{:02}", self.hour.as_ref()).as_bytes())?;
         writer.write_slice(format!("{:02}", self.minute.as_ref()).as_bytes())?;
+        writer.write_slice(format!("{:02}", self.second.as_ref()).as_bytes())?;
 
-        if self.second.as_ref().fract() > f32::EPSILON {
-            writer.write_slice(format!("{:06.3}", self.second.as_ref()).as_bytes())?;
-        } else {
-            writer.write_slice(format!("{:02}", *self.second.as_ref() as u8).as_bytes())?;
+        if let Some(fraction) = self.fraction.as_ref() {
+            fraction.to_writer(writer)?;
         }
 
-        if let Some(local_time) = self.local_time.as_ref() {
-            local_time.to_writer(writer)
-        } else {
-            writer.write_byte(b'Z')
+        match &self.time_zone {
+            TimeZone::Utc => writer.write_byte(b'Z'),
+            TimeZone::Local => Ok(()),
+            TimeZone::Offset(local_time) => local_time.to_writer(writer),
         }
     }
 }
@@ -1,15 +1,27 @@
+mod date;
+mod date_time;
+mod duration;
 mod generalized_time;
+mod time_of_day;
 mod utc_time;
 
-pub use generalized_time::GeneralizedTime;
+use alloc::format;
+
+pub use date::Date;
+pub use date_time::DateTime;
+pub use duration::Duration;
+pub use generalized_time::{FullYear, GeneralizedTime, SecondFraction, TimeZone};
+pub use time_of_day::TimeOfDay;
 pub use utc_time::UtcTime;
 
 use crate::reader::Reader;
-use crate::Asn1Result;
+use crate::writer::Writer;
+use crate::{Asn1Result, Error};
 
 macro_rules! define_nt {
     ($name:ident) => {
         #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $name(u8);
 
         impl From<$name> for u8 {
@@ -51,9 +63,147 @@ fn read_number(reader: &mut Reader<'_>) -> Asn1Result<u8> {
     let f = char::from(reader.read_byte()?);
     let s = char::from(reader.read_byte()?);
 
-    if !f.is_numeric() || !s.is_numeric() {
+    if !f.is_ascii_digit() || !s.is_ascii_digit() {
         return Err("invalid bytes for utctime".into());
     }
 
     Ok((f as u8 - ASCII_SHIFT) * 10 + (s as u8 - ASCII_SHIFT))
 }
+
+/// Reads the separator of the ISO 8601 date and time values, e.g. `-` or `:`.
+fn read_separator(reader: &mut Reader<'_>, separator: u8) -> Asn1Result<()> {
+    if reader.read_byte()? != separator {
+        return Err(Error::from("Invalid separator in the date or time value"));
+    }
+
+    Ok(())
+}
+
+fn is_leap_year(year: u16) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Checks that the date and time values are in their valid ranges.
+fn validate_date_time(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Asn1Result<()> {
+    if !(1..=12).contains(&month) {
+        return Err(Error::from("Month should be in range 1..=12"));
+    }
+
+    if day == 0 || day > days_in_month(year, month) {
+        return Err(Error::from("Invalid day of the month"));
+    }
+
+    if hour > 23 || minute > 59 || second > 59 {
+        return Err(Error::from("Invalid time of the day"));
+    }
+
+    Ok(())
+}
+
+/// Returns the number of days since 1970-01-01.
+// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: u16, month: u8, day: u8) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+/// Converts the date and time in UTC to the Unix timestamp (seconds since 1970-01-01 00:00:00 UTC).
+fn unix_timestamp(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> i64 {
+    days_from_civil(year, month, day) * 86400 + i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LocalTimeDirection {
+    Plus,
+    Minus,
+}
+
+impl From<LocalTimeDirection> for char {
+    fn from(value: LocalTimeDirection) -> Self {
+        match value {
+            LocalTimeDirection::Minus => '-',
+            LocalTimeDirection::Plus => '+',
+        }
+    }
+}
+
+impl From<LocalTimeDirection> for u8 {
+    fn from(value: LocalTimeDirection) -> Self {
+        match value {
+            LocalTimeDirection::Minus => b'-',
+            LocalTimeDirection::Plus => b'+',
+        }
+    }
+}
+
+impl TryFrom<u8> for LocalTimeDirection {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'-' => Ok(Self::Minus),
+            b'+' => Ok(Self::Plus),
+            _ => Err("invalid GeneralTime data".into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalTimeDiffFactor {
+    pub time_direction: LocalTimeDirection,
+    pub hour: Hour,
+    pub minute: Minute,
+}
+
+impl LocalTimeDiffFactor {
+    const ENCODED_LEN: usize = 1 /* sign */ + 2 /* hour */ + 2 /* minute */;
+
+    /// Returns the offset from UTC in seconds.
+    pub fn offset_seconds(&self) -> i64 {
+        let offset = i64::from(*self.hour.as_ref()) * 3600 + i64::from(*self.minute.as_ref()) * 60;
+
+        match self.time_direction {
+            LocalTimeDirection::Plus => offset,
+            LocalTimeDirection::Minus => -offset,
+        }
+    }
+
+    fn from_reader(reader: &mut Reader) -> Asn1Result<Self> {
+        let local_time = Self {
+            time_direction: reader.read_byte()?.try_into()?,
+            hour: Hour::try_from(read_number(reader)?)?,
+            minute: Minute::try_from(read_number(reader)?)?,
+        };
+
+        if *local_time.hour.as_ref() > 23 || *local_time.minute.as_ref() > 59 {
+            return Err(Error::from("Invalid local time differential"));
+        }
+
+        Ok(local_time)
+    }
+
+    fn to_writer(&self, writer: &mut Writer) -> Asn1Result<()> {
+        writer.write_byte(self.time_direction.into())?;
+        writer.write_slice(format!("{:02}", self.hour.as_ref()).as_bytes())?;
+        writer.write_slice(format!("{:02}", self.minute.as_ref()).as_bytes())?;
+
+        Ok(())
+    }
+}
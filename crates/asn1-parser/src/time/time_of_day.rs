@@ -0,0 +1,99 @@
+use alloc::format;
+use core::fmt;
+
+use super::{read_number, read_separator, Hour, Minute, Second};
+use crate::length::{len_size, write_len};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
+
+/// TIME-OF-DAY (X.680 2008)
+///
+/// Supported format: `hh:mm:ss`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeOfDay {
+    pub hour: Hour,
+    pub minute: Minute,
+    pub second: Second,
+}
+
+impl TimeOfDay {
+    pub const TAG: Tag = Tag::universal(32, false);
+
+    pub(super) const ENCODED_LEN: usize = 2 /* hour */ + 1 + 2 /* minute */ + 1 + 2 /* second */;
+
+    pub fn new(hour: Hour, minute: Minute, second: Second) -> Self {
+        Self { hour, minute, second }
+    }
+
+    /// Returns the number of seconds since the start of the day.
+    pub fn seconds_from_midnight(&self) -> u32 {
+        u32::from(*self.hour.as_ref()) * 3600 + u32::from(*self.minute.as_ref()) * 60 + u32::from(*self.second.as_ref())
+    }
+
+    pub(super) fn from_reader(reader: &mut Reader) -> Asn1Result<Self> {
+        let hour = Hour::try_from(read_number(reader)?)?;
+        read_separator(reader, b':')?;
+        let minute = Minute::try_from(read_number(reader)?)?;
+        read_separator(reader, b':')?;
+        let second = Second::try_from(read_number(reader)?)?;
+
+        if *hour.as_ref() > 23 || *minute.as_ref() > 59 || *second.as_ref() > 59 {
+            return Err(Error::from("Invalid time of the day"));
+        }
+
+        Ok(Self { hour, minute, second })
+    }
+
+    pub(super) fn to_writer(&self, writer: &mut Writer) -> Asn1Result<()> {
+        writer.write_slice(format!("{}", self).as_bytes())
+    }
+}
+
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.hour.as_ref(),
+            self.minute.as_ref(),
+            self.second.as_ref()
+        )
+    }
+}
+
+impl Taggable for TimeOfDay {
+    fn tag(&self) -> Tag {
+        Self::TAG
+    }
+}
+
+impl<'data> Asn1ValueDecoder<'data> for TimeOfDay {
+    fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
+        let time_of_day = Self::from_reader(reader)?;
+
+        if !reader.empty() {
+            return Err(Error::from("TIME-OF-DAY value contains trailing data"));
+        }
+
+        Ok(time_of_day)
+    }
+
+    fn compare_tags(tag: Tag) -> bool {
+        Self::TAG == tag
+    }
+}
+
+impl Asn1Encoder for TimeOfDay {
+    fn needed_buf_size(&self) -> usize {
+        Self::TAG.encoded_len() + len_size(Self::ENCODED_LEN) + Self::ENCODED_LEN
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        Self::TAG.to_writer(writer)?;
+        write_len(Self::ENCODED_LEN, writer)?;
+
+        self.to_writer(writer)
+    }
+}
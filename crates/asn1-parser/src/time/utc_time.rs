@@ -1,12 +1,18 @@
 use alloc::format;
 
-use super::{read_number, Day, Hour, Minute, Month, Second, Year};
+use super::{
+    read_number, unix_timestamp, validate_date_time, Day, Hour, LocalTimeDiffFactor, Minute, Month, Second, Year,
+};
 use crate::length::{len_size, write_len};
 use crate::reader::Reader;
 use crate::writer::Writer;
-use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Tag, Taggable};
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
 
+/// [UtcTime](https://www.oss.com/asn1/resources/asn1-made-simple/asn1-quick-reference/utctime.html)
+///
+/// Supported formats: `YYMMDDhhmm[ss]Z` and `YYMMDDhhmm[ss](+|-)hhmm`.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UtcTime {
     pub year: Year,
     pub month: Month,
@@ -14,10 +20,11 @@ pub struct UtcTime {
     pub hour: Hour,
     pub minute: Minute,
     pub second: Option<Second>,
+    pub local_time: Option<LocalTimeDiffFactor>,
 }
 
 impl UtcTime {
-    pub const TAG: Tag = Tag(23);
+    pub const TAG: Tag = Tag::from_octet(23);
 
     pub fn new(year: Year, month: Month, day: Day, hour: Hour, minute: Minute, second: Option<Second>) -> Self {
         Self {
@@ -27,32 +34,112 @@ impl UtcTime {
             hour,
             minute,
             second,
+            local_time: None,
         }
     }
 
+    /// Sets the local time differential instead of the 'Z' (UTC) designator.
+    pub fn with_local_time(self, local_time: LocalTimeDiffFactor) -> Self {
+        Self {
+            local_time: Some(local_time),
+            ..self
+        }
+    }
+
+    /// Returns the four-digit year: `YY` values in range 50..=99 are 19YY, otherwise 20YY (RFC 5280).
+    pub fn full_year(&self) -> u16 {
+        let year = u16::from(*self.year.as_ref());
+
+        if year >= 50 {
+            1900 + year
+        } else {
+            2000 + year
+        }
+    }
+
+    /// Returns the number of seconds since 1970-01-01 00:00:00 UTC.
+    pub fn to_unix_timestamp(&self) -> i64 {
+        unix_timestamp(
+            self.full_year(),
+            *self.month.as_ref(),
+            *self.day.as_ref(),
+            *self.hour.as_ref(),
+            *self.minute.as_ref(),
+            self.second.as_ref().map(|second| *second.as_ref()).unwrap_or_default(),
+        ) - self
+            .local_time
+            .as_ref()
+            .map(LocalTimeDiffFactor::offset_seconds)
+            .unwrap_or_default()
+    }
+
+    fn validate_der(&self) -> Asn1Result<()> {
+        if self.second.is_none() {
+            return Err(Error::from("DER UtcTime should include seconds"));
+        }
+
+        if self.local_time.is_some() {
+            return Err(Error::from("DER UtcTime should end with 'Z'"));
+        }
+
+        Ok(())
+    }
+
     fn calc_data_len(&self) -> usize {
-        2 /* year */ + 2 /* month */ + 2 /* day */ + 2 /* hour */ + 2 /* minute */ + self.second.is_some().then_some(2).unwrap_or_default() + 1
-        /* 'Z' */
+        let second_len = if self.second.is_some() { 2 } else { 0 };
+        let zone_len = if self.local_time.is_some() {
+            LocalTimeDiffFactor::ENCODED_LEN
+        } else {
+            1 /* 'Z' */
+        };
+
+        2 /* year */ + 2 /* month */ + 2 /* day */ + 2 /* hour */ + 2 /* minute */ + second_len + zone_len
     }
 }
 
 impl<'data> Asn1ValueDecoder<'data> for UtcTime {
     fn decode(_: Tag, reader: &mut Reader<'data>) -> Asn1Result<Self> {
-        let utc_time = UtcTime {
+        let mut utc_time = UtcTime {
             year: Year::try_from(read_number(reader)?)?,
             month: Month::try_from(read_number(reader)?)?,
             day: Day::try_from(read_number(reader)?)?,
             hour: Hour::try_from(read_number(reader)?)?,
             minute: Minute::try_from(read_number(reader)?)?,
-            second: if reader.peek_byte()? != b'Z' {
+            second: if reader.peek_byte()?.is_ascii_digit() {
                 Some(Second::try_from(read_number(reader)?)?)
             } else {
                 None
             },
+            local_time: None,
         };
 
-        if reader.read_byte()? != b'Z' {
-            return Err("utctime value should end with 'Z'".into());
+        match reader.peek_byte()? {
+            b'Z' => {
+                reader.read_byte()?;
+            }
+            b'+' | b'-' => utc_time.local_time = Some(LocalTimeDiffFactor::from_reader(reader)?),
+            _ => return Err("utctime value should end with 'Z' or the local time differential".into()),
+        }
+
+        if !reader.empty() {
+            return Err("utctime value contains trailing data".into());
+        }
+
+        validate_date_time(
+            utc_time.full_year(),
+            *utc_time.month.as_ref(),
+            *utc_time.day.as_ref(),
+            *utc_time.hour.as_ref(),
+            *utc_time.minute.as_ref(),
+            utc_time
+                .second
+                .as_ref()
+                .map(|second| *second.as_ref())
+                .unwrap_or_default(),
+        )?;
+
+        if reader.profile().is_canonical() {
+            utc_time.validate_der()?;
         }
 
         Ok(utc_time)
@@ -90,6 +177,10 @@ impl Asn1Encoder for UtcTime {
             writer.write_slice(format!("{:02}", second.as_ref()).as_bytes())?;
         }
 
-        writer.write_byte(b'Z')
+        if let Some(local_time) = self.local_time.as_ref() {
+            local_time.to_writer(writer)
+        } else {
+            writer.write_byte(b'Z')
+        }
     }
 }
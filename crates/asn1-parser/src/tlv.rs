@@ -1,13 +1,19 @@
 use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
 
 use crate::length::read_len;
-use crate::reader::{read_data, Reader};
+use crate::reader::{read_value_data, Reader};
 use crate::writer::Writer;
 use crate::{
-    Asn1Decoder, Asn1Encoder, Asn1Entity, Asn1Result, Asn1ValueDecoder, MetaInfo, RawAsn1EntityData, Tag, Taggable,
+    Asn1Decoder, Asn1Encoder, Asn1Entity, Asn1Result, Asn1ValueDecoder, DecodeError, Error, ErrorKind, MetaInfo,
+    RawAsn1EntityData, Tag, Taggable,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tlv<'data, A> {
     id: u64,
     meta: RawAsn1EntityData<'data>,
@@ -29,6 +35,36 @@ impl<A> Tlv<'_, A> {
         &self.asn1
     }
 
+    /// Returns a mutable reference to the inner value, e.g. to change the primitive value.
+    ///
+    /// The raw data is not updated. Encode the tree to get the bytes of the edited structure.
+    pub fn inner_asn1_mut(&mut self) -> &mut A {
+        &mut self.asn1
+    }
+
+    pub fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.meta.annotations
+    }
+
+    pub fn annotation(&self, key: &str) -> Option<&str> {
+        self.meta.annotations.get(key).map(String::as_str)
+    }
+
+    /// Attaches the annotation to the node. The previous value of the same key is replaced.
+    ///
+    /// Annotations are kept by [Tlv::to_owned_with_asn1] and removed by [MetaInfo::clear_meta].
+    pub fn annotate(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.meta.annotations.insert(key.into(), value.into());
+    }
+
+    pub fn remove_annotation(&mut self, key: &str) -> Option<String> {
+        self.meta.annotations.remove(key)
+    }
+
+    pub fn into_inner(self) -> A {
+        self.asn1
+    }
+
     pub fn to_owned_with_asn1<B>(&self, asn1: B) -> OwnedTlv<B> {
         OwnedTlv {
             id: self.id,
@@ -38,6 +74,17 @@ impl<A> Tlv<'_, A> {
     }
 }
 
+/// Wraps the value into a [Tlv] without the raw data, e.g. to compose a new tree. Encode the tree to get its bytes.
+impl<A> From<A> for Tlv<'_, A> {
+    fn from(asn1: A) -> Self {
+        Tlv {
+            id: 0,
+            meta: Default::default(),
+            asn1,
+        }
+    }
+}
+
 impl<A: MetaInfo> MetaInfo for Tlv<'_, A> {
     fn clear_meta(&mut self) {
         self.id = Default::default();
@@ -65,36 +112,115 @@ impl<'data, A: Asn1ValueDecoder<'data>> Asn1Decoder<'data> for Tlv<'data, A> {
         let tag_position = reader.full_offset();
         let data_start = reader.position();
 
-        let tag = Tag(reader.read_byte()?);
-
-        let (len, len_range) = read_len(reader)?;
-
-        let (data, data_range) = read_data(reader, len)?;
-
-        let mut inner_reader = Reader::new(data);
+        let Header {
+            tag,
+            len_range,
+            data,
+            data_range,
+        } = match read_header(reader) {
+            Ok(header) => header,
+            Err(error) if is_recoverable(&error, reader) => {
+                // The value boundaries are unknown, so the rest of the data is undecodable.
+                reader.read_remaining();
+                let raw_data = reader.data_in_range(data_start..reader.position())?;
+                let tag = Tag::from_octet(raw_data.first().copied().unwrap_or_default());
+                let meta = RawAsn1EntityData {
+                    raw_data: Cow::Borrowed(raw_data),
+                    tag: tag_position,
+                    length: 0..0,
+                    data: 0..raw_data.len(),
+                    segments: Vec::new(),
+                    annotations: Default::default(),
+                };
+
+                return Self::from_decode_error(tag, error, meta, reader);
+            }
+            Err(error) => return Err(error),
+        };
+
+        let mut inner_reader = reader.nested(data)?;
         inner_reader.set_next_id(reader.next_id());
-        inner_reader.set_offset(reader.full_offset() - data.len());
-        let asn1 = A::decode(tag, &mut inner_reader)?;
+        inner_reader.set_offset(reader.offset_of(data_range.start)?);
+        let asn1 = A::decode(tag, &mut inner_reader);
+        let segments = inner_reader
+            .take_segments()
+            .into_iter()
+            .map(|segment| (segment.start - tag_position)..(segment.end - tag_position))
+            .collect();
 
         reader.set_next_id(inner_reader.next_id());
 
-        let raw_data = Cow::Borrowed(reader.data_in_range(data_start..data_range.end)?);
+        // The end-of-contents octets of the indefinite length encoding are included in the raw data.
+        let raw_data = Cow::Borrowed(reader.data_in_range(data_start..reader.position())?);
         let length = (len_range.start - data_start)..(len_range.end - data_start);
         let data = (data_range.start - data_start)..(data_range.end - data_start);
+        let meta = RawAsn1EntityData {
+            raw_data,
+            tag: tag_position,
+            length,
+            data,
+            segments,
+            annotations: Default::default(),
+        };
+
+        match asn1 {
+            Ok(asn1) => Ok(Tlv {
+                id: reader.next_node_id()?,
+                meta,
+                asn1,
+            }),
+            Err(error) if is_recoverable(&error, reader) => Self::from_decode_error(tag, error, meta, reader),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl<'data, A: Asn1ValueDecoder<'data>> Tlv<'data, A> {
+    fn from_decode_error(
+        tag: Tag,
+        error: Error,
+        meta: RawAsn1EntityData<'data>,
+        reader: &mut Reader<'data>,
+    ) -> Asn1Result<Self> {
+        let Cow::Borrowed(raw_data) = &meta.raw_data else {
+            return Err(error);
+        };
+        let asn1 = A::from_decode_error(DecodeError::new(tag, raw_data, meta.tag, &error)).ok_or(error)?;
 
         Ok(Tlv {
-            id: reader.next_id(),
-            meta: RawAsn1EntityData {
-                raw_data,
-                tag: tag_position,
-                length,
-                data,
-            },
+            id: reader.next_node_id()?,
+            meta,
             asn1,
         })
     }
 }
 
+struct Header<'data> {
+    tag: Tag,
+    len_range: Range<usize>,
+    data: &'data [u8],
+    data_range: Range<usize>,
+}
+
+/// Reads the tag, length, and value data.
+fn read_header<'data>(reader: &mut Reader<'data>) -> Asn1Result<Header<'data>> {
+    let tag = Tag::from_reader(reader)?;
+    let (len, len_range) = read_len(reader)?;
+    let (data, data_range) = read_value_data(tag, len, reader)?;
+
+    Ok(Header {
+        tag,
+        len_range,
+        data,
+        data_range,
+    })
+}
+
+/// Returns `true` if the error can be replaced with the [DecodeError] node.
+fn is_recoverable(error: &Error, reader: &Reader) -> bool {
+    reader.is_lenient() && error.kind() != ErrorKind::LimitExceeded
+}
+
 impl<A: Asn1Encoder> Asn1Encoder for Tlv<'_, A> {
     fn needed_buf_size(&self) -> usize {
         self.asn1.needed_buf_size()
@@ -0,0 +1,162 @@
+use crate::asn1::{Asn1, Asn1Type};
+use crate::{
+    ApplicationTag, BitString, BmpString, Bool, Date, DateTime, DecodeError, Duration, EmbeddedPdv, Enumerated,
+    ExplicitTag, External, GeneralString, GeneralizedTime, GraphicString, IA5String, ImplicitTag, Integer, Null,
+    NumericString, ObjectIdentifier, OctetString, PrintableString, PrivateTag, Real, RelativeOid, Sequence, Set,
+    TimeOfDay, UniversalString, UtcTime, Utf8String, VideotexString, VisibleString,
+};
+
+macro_rules! visit_leaf {
+    ($($method:ident($ty:ty),)*) => {
+        $(
+            fn $method(&mut self, asn1: &Asn1<'_>, _value: &$ty) {
+                self.visit_leaf(asn1)
+            }
+        )*
+    };
+}
+
+/// Asn1 tree visitor.
+///
+/// Every method has a default implementation: constructed values visit their child nodes using [Asn1Visitor::visit_asn1]
+/// and all other values are passed to [Asn1Visitor::visit_leaf]. Override only the methods you need.
+pub trait Asn1Visitor {
+    /// Called for every node. The default implementation calls the type-specific method using [walk_asn1].
+    fn visit_asn1(&mut self, asn1: &Asn1<'_>) {
+        walk_asn1(self, asn1)
+    }
+
+    /// Called for every node without child nodes.
+    fn visit_leaf(&mut self, _asn1: &Asn1<'_>) {}
+
+    fn visit_sequence(&mut self, _asn1: &Asn1<'_>, sequence: &Sequence<'_>) {
+        sequence.fields().iter().for_each(|field| self.visit_asn1(field))
+    }
+
+    fn visit_set(&mut self, _asn1: &Asn1<'_>, set: &Set<'_>) {
+        set.fields().iter().for_each(|field| self.visit_asn1(field))
+    }
+
+    /// Visits the encapsulated value if the octets contain a valid asn1 structure.
+    fn visit_octet_string(&mut self, asn1: &Asn1<'_>, octet_string: &OctetString<'_>) {
+        match octet_string.inner() {
+            Some(inner) => self.visit_asn1(inner),
+            None => self.visit_leaf(asn1),
+        }
+    }
+
+    /// Visits the encapsulated value if the bits contain a valid asn1 structure.
+    fn visit_bit_string(&mut self, asn1: &Asn1<'_>, bit_string: &BitString<'_>) {
+        match bit_string.inner() {
+            Some(inner) => self.visit_asn1(inner),
+            None => self.visit_leaf(asn1),
+        }
+    }
+
+    fn visit_explicit_tag(&mut self, _asn1: &Asn1<'_>, explicit_tag: &ExplicitTag<'_>) {
+        explicit_tag.inner().iter().for_each(|inner| self.visit_asn1(inner))
+    }
+
+    /// Visits the encapsulated value if the octets contain a valid asn1 structure.
+    fn visit_implicit_tag(&mut self, asn1: &Asn1<'_>, implicit_tag: &ImplicitTag<'_>) {
+        match implicit_tag.inner_asn1() {
+            Some(inner) => self.visit_asn1(inner),
+            None => self.visit_leaf(asn1),
+        }
+    }
+
+    /// Visits the data value if it is encoded as a single asn1 value.
+    fn visit_external(&mut self, asn1: &Asn1<'_>, external: &External<'_>) {
+        match external.inner() {
+            Some(inner) => self.visit_asn1(inner),
+            None => self.visit_leaf(asn1),
+        }
+    }
+
+    fn visit_application_tag(&mut self, _asn1: &Asn1<'_>, application_tag: &ApplicationTag<'_>) {
+        application_tag.inner().iter().for_each(|inner| self.visit_asn1(inner))
+    }
+
+    fn visit_private_tag(&mut self, asn1: &Asn1<'_>, private_tag: &PrivateTag<'_>) {
+        if private_tag.is_constructed() {
+            private_tag.inner().iter().for_each(|inner| self.visit_asn1(inner))
+        } else {
+            self.visit_leaf(asn1)
+        }
+    }
+
+    visit_leaf! {
+        visit_utf8_string(Utf8String<'_>),
+        visit_bmp_string(BmpString<'_>),
+        visit_universal_string(UniversalString<'_>),
+        visit_ia5_string(IA5String<'_>),
+        visit_printable_string(PrintableString<'_>),
+        visit_general_string(GeneralString<'_>),
+        visit_graphic_string(GraphicString<'_>),
+        visit_videotex_string(VideotexString<'_>),
+        visit_numeric_string(NumericString<'_>),
+        visit_visible_string(VisibleString<'_>),
+        visit_utc_time(UtcTime),
+        visit_generalized_time(GeneralizedTime),
+        visit_date(Date),
+        visit_time_of_day(TimeOfDay),
+        visit_date_time(DateTime),
+        visit_duration(Duration),
+        visit_embedded_pdv(EmbeddedPdv<'_>),
+        visit_bool(Bool),
+        visit_null(Null),
+        visit_integer(Integer<'_>),
+        visit_enumerated(Enumerated<'_>),
+        visit_real(Real<'_>),
+        visit_object_identifier(ObjectIdentifier),
+        visit_relative_oid(RelativeOid),
+        visit_decode_error(DecodeError<'_>),
+    }
+}
+
+/// Calls the type-specific visitor method for the node.
+pub fn walk_asn1<V: Asn1Visitor + ?Sized>(visitor: &mut V, asn1: &Asn1<'_>) {
+    match asn1.inner_asn1() {
+        Asn1Type::Sequence(sequence) => visitor.visit_sequence(asn1, sequence),
+        Asn1Type::Set(set) => visitor.visit_set(asn1, set),
+        Asn1Type::OctetString(octet_string) => visitor.visit_octet_string(asn1, octet_string),
+        Asn1Type::Utf8String(utf8_string) => visitor.visit_utf8_string(asn1, utf8_string),
+        Asn1Type::BitString(bit_string) => visitor.visit_bit_string(asn1, bit_string),
+        Asn1Type::BmpString(bmp_string) => visitor.visit_bmp_string(asn1, bmp_string),
+        Asn1Type::UniversalString(universal_string) => visitor.visit_universal_string(asn1, universal_string),
+        Asn1Type::IA5String(ia5_string) => visitor.visit_ia5_string(asn1, ia5_string),
+        Asn1Type::PrintableString(printable_string) => visitor.visit_printable_string(asn1, printable_string),
+        Asn1Type::GeneralString(general_string) => visitor.visit_general_string(asn1, general_string),
+        Asn1Type::GraphicString(graphic_string) => visitor.visit_graphic_string(asn1, graphic_string),
+        Asn1Type::VideotexString(videotex_string) => visitor.visit_videotex_string(asn1, videotex_string),
+        Asn1Type::NumericString(numeric_string) => visitor.visit_numeric_string(asn1, numeric_string),
+        Asn1Type::VisibleString(visible_string) => visitor.visit_visible_string(asn1, visible_string),
+        Asn1Type::UtcTime(utc_time) => visitor.visit_utc_time(asn1, utc_time),
+        Asn1Type::GeneralizedTime(generalized_time) => visitor.visit_generalized_time(asn1, generalized_time),
+        Asn1Type::Date(date) => visitor.visit_date(asn1, date),
+        Asn1Type::TimeOfDay(time_of_day) => visitor.visit_time_of_day(asn1, time_of_day),
+        Asn1Type::DateTime(date_time) => visitor.visit_date_time(asn1, date_time),
+        Asn1Type::Duration(duration) => visitor.visit_duration(asn1, duration),
+        Asn1Type::External(external) => visitor.visit_external(asn1, external),
+        Asn1Type::EmbeddedPdv(embedded_pdv) => visitor.visit_embedded_pdv(asn1, embedded_pdv),
+        Asn1Type::Bool(bool) => visitor.visit_bool(asn1, bool),
+        Asn1Type::Null(null) => visitor.visit_null(asn1, null),
+        Asn1Type::Integer(integer) => visitor.visit_integer(asn1, integer),
+        Asn1Type::Enumerated(enumerated) => visitor.visit_enumerated(asn1, enumerated),
+        Asn1Type::Real(real) => visitor.visit_real(asn1, real),
+        Asn1Type::ObjectIdentifier(object_identifier) => visitor.visit_object_identifier(asn1, object_identifier),
+        Asn1Type::RelativeOid(relative_oid) => visitor.visit_relative_oid(asn1, relative_oid),
+        Asn1Type::ExplicitTag(explicit_tag) => visitor.visit_explicit_tag(asn1, explicit_tag),
+        Asn1Type::ImplicitTag(implicit_tag) => visitor.visit_implicit_tag(asn1, implicit_tag),
+        Asn1Type::ApplicationTag(application_tag) => visitor.visit_application_tag(asn1, application_tag),
+        Asn1Type::PrivateTag(private_tag) => visitor.visit_private_tag(asn1, private_tag),
+        Asn1Type::DecodeError(decode_error) => visitor.visit_decode_error(asn1, decode_error),
+    }
+}
+
+impl Asn1<'_> {
+    /// Walks the tree starting from this node.
+    pub fn accept(&self, visitor: &mut impl Asn1Visitor) {
+        visitor.visit_asn1(self)
+    }
+}
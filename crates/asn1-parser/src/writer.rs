@@ -1,39 +1,55 @@
+use alloc::vec::Vec;
+
 use crate::{Asn1Result, Error};
 
+#[derive(Debug)]
+enum Buffer<'data> {
+    /// Pre-sized buffer. Writing fails when it is full.
+    Slice { position: usize, inner: &'data mut [u8] },
+    /// Growable buffer. Written bytes are appended to the vector.
+    Vec(&'data mut Vec<u8>),
+}
+
 #[derive(Debug)]
 pub struct Writer<'data> {
-    position: usize,
-    inner: &'data mut [u8],
+    buffer: Buffer<'data>,
 }
 
 impl<'data> Writer<'data> {
     pub fn new(data: &'data mut [u8]) -> Self {
         Self {
-            position: 0,
-            inner: data,
+            buffer: Buffer::Slice {
+                position: 0,
+                inner: data,
+            },
         }
     }
 
-    pub fn write_byte(&mut self, byte: u8) -> Asn1Result<()> {
-        if self.position == self.inner.len() {
-            return Err(Error::from("Buffer is too small"));
+    /// Creates a writer that appends the written bytes to the vector.
+    pub fn from_vec(data: &'data mut Vec<u8>) -> Self {
+        Self {
+            buffer: Buffer::Vec(data),
         }
+    }
 
-        self.inner[self.position] = byte;
-        self.position += 1;
-
-        Ok(())
+    pub fn write_byte(&mut self, byte: u8) -> Asn1Result<()> {
+        self.write_slice(&[byte])
     }
 
     pub fn write_slice(&mut self, slice: &[u8]) -> Asn1Result<()> {
-        let slice_len = slice.len();
-        if self.position + slice_len > self.inner.len() {
-            return Err(Error::from("Buffer is too small"));
+        match &mut self.buffer {
+            Buffer::Slice { position, inner } => {
+                let slice_len = slice.len();
+                if *position + slice_len > inner.len() {
+                    return Err(Error::from("Buffer is too small"));
+                }
+
+                inner[*position..*position + slice_len].copy_from_slice(slice);
+                *position += slice_len;
+            }
+            Buffer::Vec(inner) => inner.extend_from_slice(slice),
         }
 
-        self.inner[self.position..self.position + slice_len].copy_from_slice(slice);
-        self.position += slice_len;
-
         Ok(())
     }
 }
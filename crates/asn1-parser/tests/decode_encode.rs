@@ -1,4 +1,10 @@
-use asn1_parser::{Asn1, Asn1Decoder, Asn1Encoder, Asn1Type, MetaInfo, ObjectIdentifier, Taggable};
+use asn1_parser::{
+    asn1_diff, decode_any, walk_asn1, ApplicationTag, Asn1, Asn1Decoder, Asn1Encoder, Asn1Entity, Asn1Type,
+    Asn1Visitor, Bool, Choice3, DecodeLimits, DecodeProfile, DerViolationKind, DiffEntry, DiffKind, EmbeddedPdv,
+    EncodeOptions, Enumerated, ErrorKind, ExplicitTag, ExternalEncoding, GraphicString, IA5String, Implicit, Integer,
+    Lint, LintKind, MetaInfo, NodePath, Null, ObjectIdentifier, OctetString, OwnedEnumerated, OwnedInteger, OwnedReal,
+    PdvIdentification, PrivateTag, RelativeOid, Sequence, SequenceOf, SetOf, Tag, TagClass, Taggable, Tlv, Utf8String,
+};
 use prop_strategies::any_asn1_type;
 use proptest::proptest;
 
@@ -17,7 +23,10 @@ fn asn1() {
         let decoded_meta = decoded.meta();
 
         assert_eq!(decoded_inner_asn1.needed_buf_size(), buff_len);
-        assert_eq!(1 + decoded_meta.length_bytes().len() + decoded_meta.data_bytes().len(), buff_len);
+        assert_eq!(
+            decoded_meta.tag_bytes().len() + decoded_meta.length_bytes().len() + decoded_meta.data_bytes().len(),
+            buff_len
+        );
         assert_eq!(decoded_inner_asn1.tag(), asn1_tag);
         assert_eq!(decoded_meta.tag_position(), 0);
         assert_eq!(decoded_meta.raw_bytes(), buff);
@@ -28,6 +37,678 @@ fn asn1() {
     })
 }
 
+#[test]
+fn integer() {
+    for value in [0, 1, 127, 128, 256, -1, -128, -129, i64::MAX, i64::MIN] {
+        let integer = OwnedInteger::from(value);
+
+        let mut buff = vec![0; integer.needed_buf_size()];
+        integer.encode_buff(&mut buff).unwrap();
+
+        let decoded = Asn1::decode_buff(&buff).unwrap();
+        let Asn1Type::Integer(decoded) = decoded.inner_asn1() else {
+            panic!("expected integer");
+        };
+        assert_eq!(decoded.as_i64(), Some(value));
+        assert_eq!(decoded.as_big_int(), value.into());
+    }
+
+    // 2^63 does not fit into i64
+    let raw = [2, 9, 0, 0x80, 0, 0, 0, 0, 0, 0, 0];
+    let decoded = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::Integer(decoded) = decoded.inner_asn1() else {
+        panic!("expected integer");
+    };
+    assert_eq!(decoded.as_i64(), None);
+    assert_eq!(decoded.as_big_uint(), 0x8000_0000_0000_0000_u64.into());
+}
+
+#[test]
+fn boolean() {
+    for (raw, value) in [([1, 1, 0xff], true), ([1, 1, 0], false)] {
+        let decoded = Asn1::decode_buff(&raw).unwrap();
+        assert_eq!(decoded.inner_asn1(), &Asn1Type::Bool(value.into()));
+
+        let mut buff = vec![0; decoded.needed_buf_size()];
+        decoded.encode_buff(&mut buff).unwrap();
+        assert_eq!(buff, raw);
+    }
+
+    // non-canonical TRUE value
+    let raw = [1, 1, 0x01];
+    assert!(Asn1::decode_buff(&raw).is_err());
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Der).is_err());
+
+    let decoded = Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).unwrap();
+    assert_eq!(decoded.inner_asn1(), &Asn1Type::Bool(Bool::from(true)));
+
+    // the profile is applied to nested values too
+    let raw = [0x30, 3, 1, 1, 0x2a];
+    assert!(Asn1::decode_buff(&raw).is_err());
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).is_ok());
+}
+
+#[test]
+fn enumerated() {
+    // LDAPResult resultCode: noSuchObject (32)
+    let raw = [0x0a, 1, 0x20];
+    let decoded = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::Enumerated(enumerated) = decoded.inner_asn1() else {
+        panic!("expected enumerated");
+    };
+    assert_eq!(enumerated.as_i64(), Some(32));
+    assert_eq!(decoded.inner_asn1().tag(), Enumerated::TAG);
+
+    let mut buff = vec![0; decoded.needed_buf_size()];
+    decoded.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    assert_eq!(OwnedEnumerated::from(-1).raw_data(), &[0xff]);
+}
+
+#[test]
+fn constructed_strings() {
+    // OCTET STRING segments: "ab", nested ("c"), "de"
+    let raw = [
+        0x24, 0x0d, 0x04, 0x02, b'a', b'b', 0x24, 0x03, 0x04, 0x01, b'c', 0x04, 0x02, b'd', b'e',
+    ];
+    assert!(Asn1::decode_buff(&raw).is_err());
+
+    let decoded = Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).unwrap();
+    let Asn1Type::OctetString(octet_string) = decoded.inner_asn1() else {
+        panic!("expected octet string");
+    };
+    assert_eq!(octet_string.octets(), b"abcde");
+    assert_eq!(decoded.meta().segment_ranges(), &[4..6, 10..11, 13..15]);
+
+    // BIT STRING segments: 0b1010_1010 and 0b1100 with 4 unused bits
+    let raw = [0x23, 0x08, 0x03, 0x02, 0x00, 0xaa, 0x03, 0x02, 0x04, 0xc0];
+    let decoded = Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).unwrap();
+    let Asn1Type::BitString(bit_string) = decoded.inner_asn1() else {
+        panic!("expected bit string");
+    };
+    assert_eq!(bit_string.raw_bits(), &[0x04, 0xaa, 0xc0]);
+    assert_eq!(bit_string.bits_amount(), 12);
+
+    // only the last segment can have unused bits
+    let raw = [0x23, 0x08, 0x03, 0x02, 0x04, 0xa0, 0x03, 0x02, 0x00, 0xc0];
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).is_err());
+
+    // segments must have the same type
+    let raw = [0x24, 0x03, 0x0c, 0x01, b'a'];
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).is_err());
+}
+
+#[test]
+fn decode_profiles() {
+    // SEQUENCE { INTEGER 5 } with the indefinite length
+    let raw = [0x30, 0x80, 0x02, 0x01, 0x05, 0x00, 0x00];
+    assert!(Asn1::decode_buff(&raw).is_err());
+
+    for profile in [DecodeProfile::Ber, DecodeProfile::Cer] {
+        let decoded = Asn1::decode_buff_with_profile(&raw, profile).unwrap();
+        let Asn1Type::Sequence(sequence) = decoded.inner_asn1() else {
+            panic!("expected sequence");
+        };
+        assert_eq!(sequence.fields().len(), 1);
+        assert_eq!(decoded.meta().raw_bytes(), raw);
+        assert_eq!(decoded.meta().data_bytes(), &[0x02, 0x01, 0x05]);
+        assert_eq!(decoded.meta().end_of_contents_bytes(), &[0x00, 0x00]);
+
+        // the definite length is used for encoding
+        let mut buff = vec![0; decoded.needed_buf_size()];
+        decoded.encode_buff(&mut buff).unwrap();
+        assert_eq!(buff, [0x30, 0x03, 0x02, 0x01, 0x05]);
+    }
+
+    // CER requires the indefinite length for constructed values
+    assert!(Asn1::decode_buff_with_profile(&[0x30, 0x03, 0x02, 0x01, 0x05], DecodeProfile::Cer).is_err());
+
+    // primitive values can not use the indefinite length
+    assert!(Asn1::decode_buff_with_profile(&[0x04, 0x80, 0x00, 0x00], DecodeProfile::Ber).is_err());
+
+    // the length is not minimally encoded
+    let raw = [0x04, 0x81, 0x01, 0xaa];
+    assert!(Asn1::decode_buff(&raw).is_err());
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Cer).is_err());
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).is_ok());
+
+    // CER OCTET STRING segments: 1000 octets and 1 octet
+    let mut raw = vec![0x24, 0x80, 0x04, 0x82, 0x03, 0xe8];
+    raw.extend_from_slice(&[0xaa; 1000]);
+    raw.extend_from_slice(&[0x04, 0x01, 0xbb, 0x00, 0x00]);
+    let decoded = Asn1::decode_buff_with_profile(&raw, DecodeProfile::Cer).unwrap();
+    let Asn1Type::OctetString(octet_string) = decoded.inner_asn1() else {
+        panic!("expected octet string");
+    };
+    assert_eq!(octet_string.octets().len(), 1001);
+    assert_eq!(decoded.meta().segment_ranges(), &[6..1006, 1008..1009]);
+
+    // CER segments should contain 1000 octets
+    let raw = [0x24, 0x80, 0x04, 0x01, 0xaa, 0x04, 0x01, 0xbb, 0x00, 0x00];
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Cer).is_err());
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).is_ok());
+
+    // CER strings longer than 1000 octets should be segmented
+    let mut raw = vec![0x04, 0x82, 0x03, 0xe9];
+    raw.extend_from_slice(&[0xaa; 1001]);
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Cer).is_err());
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).is_ok());
+}
+
+#[test]
+fn encode_cer() {
+    let decoded = Asn1::decode_buff(&[0x30, 0x03, 0x02, 0x01, 0x05]).unwrap();
+    assert_eq!(
+        decoded.encode_cer().unwrap(),
+        [0x30, 0x80, 0x02, 0x01, 0x05, 0x00, 0x00]
+    );
+
+    // SET fields are sorted
+    let raw = [0x31, 0x06, 0x02, 0x01, 0x05, 0x01, 0x01, 0xff];
+    let decoded = Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).unwrap();
+    assert_eq!(
+        decoded.encode_cer().unwrap(),
+        [0x31, 0x80, 0x01, 0x01, 0xff, 0x02, 0x01, 0x05, 0x00, 0x00]
+    );
+
+    // OCTET STRING is split into 1000-octet segments
+    let mut raw = vec![0x04, 0x82, 0x03, 0xe9];
+    raw.extend_from_slice(&[0xaa; 1001]);
+    let encoded = Asn1::decode_buff(&raw).unwrap().encode_cer().unwrap();
+    assert_eq!(&encoded[..6], &[0x24, 0x80, 0x04, 0x82, 0x03, 0xe8]);
+    assert_eq!(&encoded[1006..], &[0x04, 0x01, 0xaa, 0x00, 0x00]);
+    let decoded = Asn1::decode_buff_with_profile(&encoded, DecodeProfile::Cer).unwrap();
+    let Asn1Type::OctetString(octet_string) = decoded.inner_asn1() else {
+        panic!("expected octet string");
+    };
+    assert_eq!(octet_string.octets(), &[0xaa; 1001]);
+
+    // BIT STRING keeps the unused bits in the last segment
+    let mut raw = vec![0x03, 0x82, 0x03, 0xea, 0x04];
+    raw.extend_from_slice(&[0xf0; 1001]);
+    let encoded = Asn1::decode_buff(&raw).unwrap().encode_cer().unwrap();
+    let decoded = Asn1::decode_buff_with_profile(&encoded, DecodeProfile::Cer).unwrap();
+    let Asn1Type::BitString(bit_string) = decoded.inner_asn1() else {
+        panic!("expected bit string");
+    };
+    assert_eq!(bit_string.raw_bits(), &raw[4..]);
+
+    // Restricted strings are split into OCTET STRING segments
+    let mut raw = vec![0x0c, 0x82, 0x03, 0xe9];
+    raw.extend_from_slice(&[b'a'; 1001]);
+    let encoded = Asn1::decode_buff(&raw).unwrap().encode_cer().unwrap();
+    assert_eq!(&encoded[..6], &[0x2c, 0x80, 0x04, 0x82, 0x03, 0xe8]);
+    assert_eq!(&encoded[1006..], &[0x04, 0x01, b'a', 0x00, 0x00]);
+    let decoded = Asn1::decode_buff_with_profile(&encoded, DecodeProfile::Cer).unwrap();
+    let Asn1Type::Utf8String(utf8_string) = decoded.inner_asn1() else {
+        panic!("expected utf8 string");
+    };
+    assert_eq!(utf8_string.string(), "a".repeat(1001));
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Cer).is_err());
+
+    let mut raw = vec![0x1e, 0x82, 0x03, 0xea];
+    raw.extend_from_slice(&[0x00, b'b'].repeat(501));
+    let encoded = Asn1::decode_buff(&raw).unwrap().encode_cer().unwrap();
+    let decoded = Asn1::decode_buff_with_profile(&encoded, DecodeProfile::Cer).unwrap();
+    let Asn1Type::BmpString(bmp_string) = decoded.inner_asn1() else {
+        panic!("expected bmp string");
+    };
+    assert_eq!(bmp_string.string(), "b".repeat(501));
+
+    // EXTERNAL { direct-reference 1.2, encoding octet-aligned: '05'H }
+    let raw = [0x28, 0x06, 0x06, 0x01, 0x2a, 0x81, 0x01, 0x05];
+    let encoded = Asn1::decode_buff(&raw).unwrap().encode_cer().unwrap();
+    assert_eq!(encoded, [0x28, 0x80, 0x06, 0x01, 0x2a, 0x81, 0x01, 0x05, 0x00, 0x00]);
+    assert!(Asn1::decode_buff_with_profile(&encoded, DecodeProfile::Cer).is_ok());
+
+    // EXTERNAL { direct-reference 1.2, encoding single-ASN1-type: NULL }
+    let raw = [0x28, 0x07, 0x06, 0x01, 0x2a, 0xa0, 0x02, 0x05, 0x00];
+    let encoded = Asn1::decode_buff(&raw).unwrap().encode_cer().unwrap();
+    assert_eq!(
+        encoded,
+        [0x28, 0x80, 0x06, 0x01, 0x2a, 0xa0, 0x80, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+    assert!(Asn1::decode_buff_with_profile(&encoded, DecodeProfile::Cer).is_ok());
+
+    // EMBEDDED PDV { identification fixed: NULL, data-value: '01'H }
+    let raw = [0x2b, 0x07, 0xa0, 0x02, 0x85, 0x00, 0x82, 0x01, 0x01];
+    let encoded = Asn1::decode_buff(&raw).unwrap().encode_cer().unwrap();
+    assert_eq!(
+        encoded,
+        [0x2b, 0x80, 0xa0, 0x80, 0x85, 0x00, 0x00, 0x00, 0x82, 0x01, 0x01, 0x00, 0x00]
+    );
+    assert!(Asn1::decode_buff_with_profile(&encoded, DecodeProfile::Cer).is_ok());
+}
+
+#[test]
+fn edit_tree() {
+    // SEQUENCE { INTEGER 5, BOOLEAN TRUE }
+    let raw = [0x30, 0x06, 0x02, 0x01, 0x05, 0x01, 0x01, 0xff];
+    let mut asn1 = Asn1::decode_buff(&raw[..]).unwrap();
+
+    let Asn1Type::Sequence(sequence) = asn1.inner_asn1_mut() else {
+        panic!("expected SEQUENCE");
+    };
+
+    *sequence.field_mut(0).unwrap().inner_asn1_mut() = Asn1Type::Integer(OwnedInteger::from(300));
+    let removed = sequence.remove_field(1).unwrap();
+    assert_eq!(removed.inner_asn1(), &Asn1Type::Bool(Bool::from(true)));
+
+    sequence
+        .insert_field(0, Tlv::new(0, Default::default(), Asn1Type::Null(Null)))
+        .unwrap();
+    sequence
+        .replace_field(1, Tlv::new(0, Default::default(), Asn1Type::Bool(Bool::from(false))))
+        .unwrap();
+    sequence
+        .insert_field(
+            2,
+            Tlv::new(0, Default::default(), Asn1Type::Integer(OwnedInteger::from(300))),
+        )
+        .unwrap();
+    assert!(sequence
+        .insert_field(4, Tlv::new(0, Default::default(), Asn1Type::Null(Null)))
+        .is_err());
+    assert!(sequence.remove_field(3).is_err());
+
+    // SEQUENCE { NULL, BOOLEAN FALSE, INTEGER 300 }
+    let mut encoded = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut encoded).unwrap();
+    assert_eq!(
+        encoded,
+        [0x30, 0x09, 0x05, 0x00, 0x01, 0x01, 0x00, 0x02, 0x02, 0x01, 0x2c]
+    );
+
+    let mut private_tag = PrivateTag::new_primitive(1, vec![1, 2]);
+    assert!(private_tag
+        .insert_field(0, Tlv::new(0, Default::default(), Asn1Type::Null(Null)))
+        .is_err());
+}
+
+#[test]
+fn recompute() {
+    // SEQUENCE { INTEGER 5, BOOLEAN TRUE }
+    let raw = [0x30, 0x06, 0x02, 0x01, 0x05, 0x01, 0x01, 0xff];
+    let mut asn1 = Asn1::decode_buff(&raw[..]).unwrap();
+
+    let Asn1Type::Sequence(sequence) = asn1.inner_asn1_mut() else {
+        panic!("expected SEQUENCE");
+    };
+    *sequence.field_mut(0).unwrap().inner_asn1_mut() = Asn1Type::Integer(OwnedInteger::from(300));
+
+    // The cached raw data is stale after editing.
+    assert_eq!(asn1.meta().raw_bytes(), raw);
+
+    let expected = [0x30, 0x07, 0x02, 0x02, 0x01, 0x2c, 0x01, 0x01, 0xff];
+    assert_eq!(asn1.encode_to_vec().unwrap(), expected);
+
+    let recomputed = asn1.recompute().unwrap();
+    assert_eq!(recomputed.meta().raw_bytes(), expected);
+
+    let Asn1Type::Sequence(sequence) = recomputed.inner_asn1() else {
+        panic!("expected SEQUENCE");
+    };
+    assert_eq!(sequence.fields()[0].meta().raw_bytes(), [0x02, 0x02, 0x01, 0x2c]);
+    assert_eq!(sequence.fields()[1].meta().tag_position(), 6);
+}
+
+#[test]
+fn find_by_id_mut() {
+    // SEQUENCE { INTEGER 5, OCTET STRING { BOOLEAN TRUE } }
+    let raw = [0x30, 0x08, 0x02, 0x01, 0x05, 0x04, 0x03, 0x01, 0x01, 0xff];
+    let mut asn1 = Asn1::decode_buff(&raw[..]).unwrap();
+
+    let Asn1Type::Sequence(sequence) = asn1.inner_asn1() else {
+        panic!("expected SEQUENCE");
+    };
+    let integer_id = sequence.fields()[0].id();
+    let Asn1Type::OctetString(octet_string) = sequence.fields()[1].inner_asn1() else {
+        panic!("expected OCTET STRING");
+    };
+    let encapsulated_id = octet_string.inner().unwrap().id();
+
+    // encapsulated values are not editable
+    assert!(asn1.find_by_id(encapsulated_id).is_some());
+    assert!(asn1.find_by_id_mut(encapsulated_id).is_none());
+
+    *asn1.find_by_id_mut(integer_id).unwrap().inner_asn1_mut() = Asn1Type::Integer(OwnedInteger::from(6));
+    assert_eq!(
+        asn1.encode_to_vec().unwrap(),
+        [0x30, 0x08, 0x02, 0x01, 0x06, 0x04, 0x03, 0x01, 0x01, 0xff]
+    );
+}
+
+#[test]
+fn node_path() {
+    // SEQUENCE { INTEGER 5, [0] { BOOLEAN TRUE, NULL } }
+    let raw = [0x30, 0x0a, 0x02, 0x01, 0x05, 0xa0, 0x05, 0x01, 0x01, 0xff, 0x05, 0x00];
+    let asn1 = Asn1::decode_buff(&raw[..]).unwrap();
+
+    let Asn1Type::Sequence(sequence) = asn1.inner_asn1() else {
+        panic!("expected SEQUENCE");
+    };
+    let Asn1Type::ExplicitTag(explicit) = sequence.fields()[1].inner_asn1() else {
+        panic!("expected [0]");
+    };
+    let null = &explicit.inner()[1];
+
+    let path = asn1.path_of(null.id()).unwrap();
+    assert_eq!(path.to_string(), "/1[tag=0xA0]/1");
+    assert_eq!(asn1.find_by_node_path(&path).unwrap().id(), null.id());
+    assert_eq!(asn1.path_of(asn1.id()).unwrap().to_string(), "/");
+    assert_eq!(asn1.find_by_node_path(&"/".parse().unwrap()).unwrap().id(), asn1.id());
+
+    // the tag does not match the INTEGER
+    let path: NodePath = "/0[tag=0xa0]".parse().unwrap();
+    assert!(asn1.find_by_node_path(&path).is_none());
+
+    assert!("1/0".parse::<NodePath>().is_err());
+    assert!("/1[0xa0]".parse::<NodePath>().is_err());
+}
+
+#[test]
+fn remove_by_id() {
+    // SEQUENCE { INTEGER 5, [0] { BOOLEAN TRUE, NULL } }
+    let raw = [0x30, 0x0a, 0x02, 0x01, 0x05, 0xa0, 0x05, 0x01, 0x01, 0xff, 0x05, 0x00];
+    let mut asn1 = Asn1::decode_buff(&raw[..]).unwrap();
+
+    let Asn1Type::Sequence(sequence) = asn1.inner_asn1() else {
+        panic!("expected SEQUENCE");
+    };
+    let Asn1Type::ExplicitTag(explicit_tag) = sequence.fields()[1].inner_asn1() else {
+        panic!("expected [0]");
+    };
+    let bool_id = explicit_tag.inner()[0].id();
+    let root_id = asn1.id();
+
+    assert!(asn1.remove_by_id(root_id).is_none());
+
+    let removed = asn1.remove_by_id(bool_id).unwrap();
+    assert_eq!(removed.meta().raw_bytes(), [0x01, 0x01, 0xff]);
+    assert!(asn1.remove_by_id(bool_id).is_none());
+    assert_eq!(
+        asn1.encode_to_vec().unwrap(),
+        [0x30, 0x07, 0x02, 0x01, 0x05, 0xa0, 0x02, 0x05, 0x00]
+    );
+}
+
+#[test]
+fn builders() {
+    let asn1 = Asn1::from(Sequence::of([
+        Asn1::from(ExplicitTag::wrap(0, OwnedInteger::from(5))),
+        Asn1::from(OctetString::from_hex("01 01 ff").unwrap()),
+        Asn1::from(ApplicationTag::wrap(1, Null)),
+        Asn1::from(Utf8String::from("hi")),
+    ]));
+
+    // SEQUENCE { [0] { INTEGER 5 }, OCTET STRING { BOOLEAN TRUE }, [APPLICATION 1] { NULL }, UTF8String "hi" }
+    let raw = [
+        0x30, 0x12, 0xa0, 0x03, 0x02, 0x01, 0x05, 0x04, 0x03, 0x01, 0x01, 0xff, 0x61, 0x02, 0x05, 0x00, 0x0c, 0x02,
+        0x68, 0x69,
+    ];
+    assert_eq!(asn1.encode_to_vec().unwrap(), raw);
+
+    let mut decoded = Asn1::decode_buff(&raw).unwrap();
+    decoded.clear_meta();
+    let mut asn1 = asn1;
+    asn1.clear_meta();
+    assert_eq!(decoded, asn1);
+
+    assert!(OctetString::from_hex("010").is_err());
+    assert!(OctetString::from_hex("0g").is_err());
+}
+
+#[test]
+fn diff() {
+    // SEQUENCE { INTEGER 5, [0] { BOOLEAN TRUE }, NULL }
+    let a_raw = [0x30, 0x0a, 0x02, 0x01, 0x05, 0xa0, 0x03, 0x01, 0x01, 0xff, 0x05, 0x00];
+    // SEQUENCE { INTEGER 5, [0] { BOOLEAN FALSE, NULL }, OCTET STRING }
+    let b_raw = [
+        0x30, 0x0c, 0x02, 0x01, 0x05, 0xa0, 0x05, 0x01, 0x01, 0x00, 0x05, 0x00, 0x04, 0x00,
+    ];
+    let a = Asn1::decode_buff(&a_raw[..]).unwrap();
+    let b = Asn1::decode_buff(&b_raw[..]).unwrap();
+
+    assert!(asn1_diff(&a, &a).is_empty());
+    assert_eq!(
+        asn1_diff(&a, &b),
+        [
+            DiffEntry {
+                kind: DiffKind::Changed,
+                path: vec![1, 0],
+                left: Some(7..10),
+                right: Some(7..10),
+            },
+            DiffEntry {
+                kind: DiffKind::Added,
+                path: vec![1, 1],
+                left: None,
+                right: Some(10..12),
+            },
+            DiffEntry {
+                kind: DiffKind::Changed,
+                path: vec![2],
+                left: Some(10..12),
+                right: Some(12..14),
+            },
+        ]
+    );
+
+    for entry in asn1_diff(&a, &b) {
+        let right = b.find_by_path(&entry.path).unwrap();
+        assert_eq!(Some(right.meta().tag_position()), entry.right.map(|range| range.start));
+    }
+    assert_eq!(a.find_by_path(&[1, 0]).unwrap().tag(), Tag::from(0x01));
+    assert!(a.find_by_path(&[1, 1]).is_none());
+    assert!(std::ptr::eq(a.find_by_path(&[]).unwrap(), &a));
+}
+
+#[test]
+fn text_dump() {
+    // SEQUENCE { INTEGER 5, [0] { BOOLEAN TRUE }, OCTET STRING { NULL }, UTF8String "hi" }
+    let raw = [
+        0x30, 0x10, 0x02, 0x01, 0x05, 0xa0, 0x03, 0x01, 0x01, 0xff, 0x04, 0x02, 0x05, 0x00, 0x0c, 0x02, 0x68, 0x69,
+    ];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+
+    assert_eq!(
+        asn1.to_text_dump(),
+        "    0:d=0  hl=2 l=  16 cons: SEQUENCE\n\
+        \x20   2:d=1  hl=2 l=   1 prim:  INTEGER           :05\n\
+        \x20   5:d=1  hl=2 l=   3 cons:  cont [ 0 ]\n\
+        \x20   7:d=2  hl=2 l=   1 prim:   BOOLEAN           :255\n\
+        \x20  10:d=1  hl=2 l=   2 prim:  OCTET STRING\n\
+        \x20  12:d=2  hl=2 l=   0 prim:   NULL\n\
+        \x20  14:d=1  hl=2 l=   2 prim:  UTF8STRING        :hi\n"
+    );
+}
+
+#[test]
+fn decode_limits() {
+    // 200 nested SEQUENCEs with the indefinite length
+    let mut deep = [0x30, 0x80].repeat(200);
+    deep.extend([0x00, 0x00].repeat(200));
+    let error = Asn1::decode_buff_with_profile(&deep, DecodeProfile::Ber).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::LimitExceeded);
+
+    // SEQUENCE { SEQUENCE { NULL } }
+    let raw = [0x30, 0x04, 0x30, 0x02, 0x05, 0x00];
+    let limits = DecodeLimits::default();
+    assert!(Asn1::decode_buff_with_limits(&raw, DecodeProfile::Der, limits).is_ok());
+
+    let max_depth = DecodeLimits { max_depth: 1, ..limits };
+    let max_nodes = DecodeLimits { max_nodes: 2, ..limits };
+    let max_len = DecodeLimits { max_len: 3, ..limits };
+    for limits in [max_depth, max_nodes, max_len] {
+        let error = Asn1::decode_buff_with_limits(&raw, DecodeProfile::Der, limits).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::LimitExceeded);
+    }
+
+    // OCTET STRING { NULL } is not decoded as encapsulated when the limit is reached.
+    let raw = [0x04, 0x02, 0x05, 0x00];
+    let asn1 =
+        Asn1::decode_buff_with_limits(&raw, DecodeProfile::Der, DecodeLimits { max_depth: 1, ..limits }).unwrap();
+    let Asn1Type::OctetString(octet_string) = asn1.inner_asn1() else {
+        panic!("expected OCTET STRING");
+    };
+    assert!(octet_string.inner().is_none());
+}
+
+#[test]
+fn decode_lenient() {
+    // SEQUENCE { INTEGER 5, BOOLEAN with two octets, NULL, truncated OCTET STRING }
+    let raw = [
+        0x30, 0x0c, 0x02, 0x01, 0x05, 0x01, 0x02, 0xff, 0xff, 0x05, 0x00, 0x04, 0x05, 0x01,
+    ];
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).is_err());
+
+    let asn1 = Asn1::decode_buff_lenient(&raw, DecodeProfile::Ber).unwrap();
+    let Asn1Type::Sequence(sequence) = asn1.inner_asn1() else {
+        panic!("expected SEQUENCE");
+    };
+    let fields = sequence.fields();
+    assert_eq!(fields.len(), 4);
+    assert!(matches!(fields[0].inner_asn1(), Asn1Type::Integer(_)));
+    assert!(matches!(fields[2].inner_asn1(), Asn1Type::Null(_)));
+
+    let Asn1Type::DecodeError(error) = fields[1].inner_asn1() else {
+        panic!("expected DecodeError");
+    };
+    assert_eq!(error.range(), 5..9);
+    assert_eq!(error.raw_data(), [0x01, 0x02, 0xff, 0xff]);
+    assert_eq!(error.reason(), "Bool data len should be equal to 1");
+    assert_eq!(fields[1].meta().data_bytes(), [0xff, 0xff]);
+
+    let Asn1Type::DecodeError(error) = fields[3].inner_asn1() else {
+        panic!("expected DecodeError");
+    };
+    assert_eq!(error.range(), 11..14);
+    assert_eq!(error.tag(), OctetString::TAG);
+
+    // Undecodable data is encoded as is.
+    assert_eq!(asn1.encode_to_vec().unwrap(), raw);
+}
+
+#[test]
+fn visitor() {
+    #[derive(Default)]
+    struct Counter {
+        integers: Vec<i64>,
+        leaves: usize,
+        nodes: usize,
+    }
+
+    impl Asn1Visitor for Counter {
+        fn visit_asn1(&mut self, asn1: &Asn1<'_>) {
+            self.nodes += 1;
+            walk_asn1(self, asn1)
+        }
+
+        fn visit_leaf(&mut self, _asn1: &Asn1<'_>) {
+            self.leaves += 1;
+        }
+
+        fn visit_integer(&mut self, _asn1: &Asn1<'_>, integer: &Integer<'_>) {
+            self.integers.push(integer.as_i64().unwrap());
+        }
+    }
+
+    // SEQUENCE { INTEGER 5, [0] { INTEGER 6 }, OCTET STRING { NULL }, BOOLEAN TRUE }
+    let raw = [
+        0x30, 0x0f, 0x02, 0x01, 0x05, 0xa0, 0x03, 0x02, 0x01, 0x06, 0x04, 0x02, 0x05, 0x00, 0x01, 0x01, 0xff,
+    ];
+    let decoded = Asn1::decode_buff(&raw[..]).unwrap();
+
+    let mut counter = Counter::default();
+    decoded.accept(&mut counter);
+
+    assert_eq!(counter.integers, [5, 6]);
+    // NULL and BOOLEAN
+    assert_eq!(counter.leaves, 2);
+    // SEQUENCE, INTEGER, [0], INTEGER, OCTET STRING, NULL, BOOLEAN
+    assert_eq!(counter.nodes, 7);
+}
+
+#[test]
+fn iterators() {
+    // SEQUENCE { INTEGER 5, [0] { INTEGER 6 }, OCTET STRING { NULL }, BOOLEAN TRUE }
+    let raw = [
+        0x30, 0x0f, 0x02, 0x01, 0x05, 0xa0, 0x03, 0x02, 0x01, 0x06, 0x04, 0x02, 0x05, 0x00, 0x01, 0x01, 0xff,
+    ];
+    let decoded = Asn1::decode_buff(&raw[..]).unwrap();
+
+    let dfs = decoded
+        .iter()
+        .map(|(depth, node)| (depth, node.meta().tag_position()))
+        .collect::<Vec<_>>();
+    assert_eq!(dfs, [(0, 0), (1, 2), (1, 5), (2, 7), (1, 10), (2, 12), (1, 14)]);
+
+    let bfs = decoded
+        .bfs_iter()
+        .map(|(depth, node)| (depth, node.meta().tag_position()))
+        .collect::<Vec<_>>();
+    assert_eq!(bfs, [(0, 0), (1, 2), (1, 5), (1, 10), (1, 14), (2, 7), (2, 12)]);
+
+    let leaves = decoded
+        .leaves()
+        .map(|node| node.meta().tag_position())
+        .collect::<Vec<_>>();
+    assert_eq!(leaves, [2, 7, 12, 14]);
+
+    let integers = decoded
+        .leaves()
+        .filter_map(|node| match node.inner_asn1() {
+            Asn1Type::Integer(integer) => integer.as_i64(),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(integers, [5, 6]);
+}
+
+#[test]
+fn node_lookup() {
+    // SEQUENCE { INTEGER 5, [0] { INTEGER 6 }, OCTET STRING { NULL }, BOOLEAN TRUE }
+    let raw = [
+        0x30, 0x0f, 0x02, 0x01, 0x05, 0xa0, 0x03, 0x02, 0x01, 0x06, 0x04, 0x02, 0x05, 0x00, 0x01, 0x01, 0xff,
+    ];
+    let decoded = Asn1::decode_buff(&raw[..]).unwrap();
+
+    for (_, node) in decoded.iter() {
+        assert!(std::ptr::eq(decoded.find_by_id(node.id()).unwrap(), node));
+    }
+    let max_id = decoded.iter().map(|(_, node)| node.id()).max().unwrap();
+    assert!(decoded.find_by_id(max_id + 1).is_none());
+
+    let position = |offset| decoded.node_at_offset(offset).map(|node| node.meta().tag_position());
+    // SEQUENCE header
+    assert_eq!(position(1), Some(0));
+    // INTEGER 6 inside [0]
+    assert_eq!(position(8), Some(7));
+    // NULL inside OCTET STRING
+    assert_eq!(position(13), Some(12));
+    // BOOLEAN
+    assert_eq!(position(16), Some(14));
+    assert_eq!(position(17), None);
+}
+
+#[test]
+fn null() {
+    let raw = [5, 0];
+    let decoded = Asn1::decode_buff(&raw).unwrap();
+    assert_eq!(decoded.inner_asn1(), &Asn1Type::Null(Null));
+
+    let mut buff = vec![0; decoded.needed_buf_size()];
+    decoded.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    let raw = [5, 1, 0];
+    assert!(Asn1::decode_buff(&raw).is_err());
+
+    let decoded = Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).unwrap();
+    assert_eq!(decoded.inner_asn1(), &Asn1Type::Null(Null));
+}
+
 #[test]
 fn generalized_time() {
     let raw = [
@@ -36,8 +717,10 @@ fn generalized_time() {
     let asn1 = Asn1::decode_buff(&raw).unwrap();
     println!("{:?}", asn1);
 
+    // local time is not allowed in DER
     let raw = [24, 16, 49, 57, 56, 53, 49, 49, 48, 54, 50, 49, 48, 54, 50, 55, 46, 51];
-    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    assert!(Asn1::decode_buff(&raw).is_err());
+    let asn1 = Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).unwrap();
     println!("{:?}", asn1);
 
     let raw = [
@@ -46,11 +729,387 @@ fn generalized_time() {
     let asn1 = Asn1::decode_buff(&raw).unwrap();
     println!("{:?}", asn1);
 
+    // the local time differential is not allowed in DER
     let raw = [
         24, 21, 49, 57, 56, 53, 49, 49, 48, 54, 50, 49, 48, 54, 50, 55, 46, 51, 45, 48, 53, 48, 48,
     ];
-    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    assert!(Asn1::decode_buff(&raw).is_err());
+    let asn1 = Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).unwrap();
     println!("{:?}", asn1);
+    let Asn1Type::GeneralizedTime(generalized_time) = asn1.inner_asn1() else {
+        panic!("expected generalized time");
+    };
+    // 1985-11-06 21:06:27 -05:00
+    assert_eq!(generalized_time.to_unix_timestamp(), 500177187);
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // 20500101000000.123456789Z
+    let raw = b"\x18\x1920500101000000.123456789Z";
+    let asn1 = Asn1::decode_buff(raw).unwrap();
+    let Asn1Type::GeneralizedTime(generalized_time) = asn1.inner_asn1() else {
+        panic!("expected generalized time");
+    };
+    assert_eq!(generalized_time.to_unix_timestamp(), 2524608000);
+    assert_eq!(generalized_time.fraction.as_ref().unwrap().nanoseconds(), 123456789);
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // trailing zeros in the fraction
+    let raw = b"\x18\x1120500101000000.50Z";
+    assert!(Asn1::decode_buff(raw).is_err());
+    assert!(Asn1::decode_buff_with_profile(raw, DecodeProfile::Ber).is_ok());
+
+    // comma as the decimal mark
+    let raw = b"\x18\x1020500101000000,5Z";
+    assert!(Asn1::decode_buff(raw).is_err());
+    assert!(Asn1::decode_buff_with_profile(raw, DecodeProfile::Ber).is_ok());
+
+    // 2050-02-29 does not exist
+    assert!(Asn1::decode_buff(b"\x18\x0f20500229000000Z").is_err());
+}
+
+#[test]
+fn iso_time_types() {
+    let cases: [(&[u8], &str); 5] = [
+        (b"\x1f\x1f\x0a2024-02-29", "2024-02-29"),
+        (b"\x1f\x20\x0823:59:01", "23:59:01"),
+        (b"\x1f\x21\x132024-02-29T23:59:01", "2024-02-29T23:59:01"),
+        (b"\x1f\x22\x09P1Y2MT30S", "P1Y2MT30S"),
+        (b"\x1f\x22\x03P3W", "P3W"),
+    ];
+
+    for (raw, expected) in cases {
+        let asn1 = Asn1::decode_buff(raw).unwrap();
+
+        let formatted = match asn1.inner_asn1() {
+            Asn1Type::Date(date) => date.to_string(),
+            Asn1Type::TimeOfDay(time_of_day) => time_of_day.to_string(),
+            Asn1Type::DateTime(date_time) => date_time.to_string(),
+            Asn1Type::Duration(duration) => duration.to_string(),
+            asn1 => panic!("unexpected asn1 type: {:?}", asn1),
+        };
+        assert_eq!(formatted, expected);
+        assert_eq!(asn1.encode_to_vec().unwrap(), raw);
+    }
+
+    let Asn1Type::DateTime(date_time) = Asn1::decode_buff(cases[2].0).unwrap().into_inner() else {
+        panic!("expected DATE-TIME");
+    };
+    assert_eq!(date_time.to_unix_timestamp(), 1709251141);
+
+    let invalid: [&[u8]; 6] = [
+        b"\x1f\x1f\x0a2023-02-29",
+        b"\x1f\x20\x0824:00:00",
+        b"\x1f\x21\x132024-02-29 23:59:01",
+        b"\x1f\x22\x01P",
+        b"\x1f\x22\x05P1D1Y",
+        b"\x1f\x22\x05P1W1D",
+    ];
+    for raw in invalid {
+        assert!(Asn1::decode_buff(raw).is_err(), "{:?}", raw);
+    }
+}
+
+#[test]
+fn external_embedded_pdv() {
+    let raw = [
+        0x28, 0x0d, 0x06, 0x03, 0x2a, 0x03, 0x04, 0x02, 0x01, 0x05, 0xa0, 0x03, 0x01, 0x01, 0xff,
+    ];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+
+    let Asn1Type::External(external) = asn1.inner_asn1() else {
+        panic!("expected EXTERNAL");
+    };
+    assert_eq!(external.direct_reference().unwrap().format(), "1.2.3.4");
+    assert_eq!(external.indirect_reference().unwrap().as_big_int(), 5.into());
+    assert!(matches!(external.inner().unwrap().inner_asn1(), Asn1Type::Bool(_)));
+    assert_eq!(asn1.encode_to_vec().unwrap(), raw);
+
+    let raw = [0x28, 0x08, 0x02, 0x01, 0x01, 0x81, 0x03, 0x01, 0x02, 0x03];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+
+    let Asn1Type::External(external) = asn1.inner_asn1() else {
+        panic!("expected EXTERNAL");
+    };
+    assert!(external.direct_reference().is_none());
+    let ExternalEncoding::OctetAligned(octets) = external.encoding() else {
+        panic!("expected octet-aligned encoding");
+    };
+    assert_eq!(octets.octets(), &[1, 2, 3]);
+    assert_eq!(asn1.encode_to_vec().unwrap(), raw);
+
+    let raw = [
+        0x2b, 0x0b, 0xa0, 0x05, 0x81, 0x03, 0x2a, 0x03, 0x04, 0x82, 0x02, 0xab, 0xcd,
+    ];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+
+    let Asn1Type::EmbeddedPdv(embedded_pdv) = asn1.inner_asn1() else {
+        panic!("expected EMBEDDED PDV");
+    };
+    let PdvIdentification::Syntax(syntax) = embedded_pdv.identification() else {
+        panic!("expected syntax identification");
+    };
+    assert_eq!(syntax.format(), "1.2.3.4");
+    assert_eq!(embedded_pdv.data_value().octets(), &[0xab, 0xcd]);
+    assert_eq!(asn1.encode_to_vec().unwrap(), raw);
+
+    let identifications: [&[u8]; 2] = [
+        &[0xa0, 0x02, 0x85, 0x00],
+        &[
+            0xa0, 0x0c, 0xa0, 0x0a, 0x80, 0x03, 0x2a, 0x03, 0x04, 0x81, 0x03, 0x2a, 0x03, 0x05,
+        ],
+    ];
+    for identification in identifications {
+        let mut raw = vec![0x2b, identification.len() as u8 + 2];
+        raw.extend_from_slice(identification);
+        raw.extend_from_slice(&[0x82, 0x00]);
+
+        let asn1 = Asn1::decode_buff(&raw).unwrap();
+        assert!(matches!(asn1.inner_asn1(), Asn1Type::EmbeddedPdv(_)));
+        assert_eq!(asn1.encode_to_vec().unwrap(), raw);
+    }
+
+    let built = EmbeddedPdv::new(PdvIdentification::Fixed, OctetString::from(vec![1]));
+    assert_eq!(
+        Asn1::from(built).encode_to_vec().unwrap(),
+        [0x2b, 0x07, 0xa0, 0x02, 0x85, 0x00, 0x82, 0x01, 0x01]
+    );
+
+    // EXTERNAL without the encoding
+    assert!(Asn1::decode_buff(&[0x28, 0x03, 0x02, 0x01, 0x01]).is_err());
+}
+
+#[test]
+fn choice() {
+    type Value<'data> = Choice3<Integer<'data>, Utf8String<'data>, Bool>;
+
+    let raw = [0x0c, 0x02, b'h', b'i'];
+    let value = Tlv::<Value>::decode_buff(&raw).unwrap().into_inner();
+    let Choice3::B(string) = &value else {
+        panic!("expected the second alternative");
+    };
+    assert_eq!(string.string(), "hi");
+    assert_eq!(value.tag(), Utf8String::TAG);
+    assert_eq!(value.encode_to_vec().unwrap(), raw);
+
+    let value = Tlv::<Value>::decode_buff(&[0x01, 0x01, 0xff]).unwrap().into_inner();
+    assert!(matches!(value, Choice3::C(_)));
+
+    assert!(Tlv::<Value>::decode_buff(&[0x05, 0x00]).is_err());
+}
+
+#[test]
+fn malformed_lengths() {
+    let error = decode_any(&[0x04, 0x88, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]).unwrap_err();
+    assert_eq!(error.message(), "Value length exceeds the remaining input");
+
+    let error = decode_any(&[0x04, 0x89, 0x01, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow);
+
+    let error = decode_any(&[0x04, 0x82, 0x01]).unwrap_err();
+    assert_eq!(error.message(), "Long-form length octets are truncated");
+
+    proptest!(|(data in proptest::collection::vec(proptest::num::u8::ANY, 0..64))| {
+        let _ = decode_any(&data);
+    });
+}
+
+#[test]
+fn shallow_decoding() {
+    let raw = [0x30, 0x07, 0x04, 0x05, 0x30, 0x03, 0x02, 0x01, 0x01];
+
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::Sequence(sequence) = asn1.inner_asn1() else {
+        panic!("expected sequence");
+    };
+    let Asn1Type::OctetString(octet_string) = sequence.fields()[0].inner_asn1() else {
+        panic!("expected octet string");
+    };
+    assert!(octet_string.inner().is_some());
+
+    let asn1 = Asn1::decode_buff_shallow(&raw, DecodeProfile::Der).unwrap();
+    let Asn1Type::Sequence(sequence) = asn1.inner_asn1() else {
+        panic!("expected sequence");
+    };
+    let Asn1Type::OctetString(octet_string) = sequence.fields()[0].inner_asn1() else {
+        panic!("expected octet string");
+    };
+    assert!(octet_string.inner().is_none());
+    assert_eq!(octet_string.octets(), &raw[4..]);
+    assert_eq!(asn1.encode_to_vec().unwrap(), raw);
+}
+
+#[test]
+fn tag_api() {
+    let tag = Tag::application(10, true);
+    assert_eq!(tag.class(), TagClass::Application);
+    assert!(tag.is_constructed());
+    assert_eq!(tag.number(), 10);
+    assert_eq!(u8::from(tag), 0x6a);
+    assert_eq!(tag.to_string(), "[APPLICATION 10]");
+
+    let tag = Tag::from(0x81);
+    assert_eq!(tag, Tag::context_specific(1, false));
+    assert!(tag.is_primitive());
+    assert_eq!(tag.to_string(), "[1]");
+
+    assert_eq!(
+        Tag::from_parts(TagClass::Private, false, 40).to_string(),
+        "[PRIVATE 40]"
+    );
+    assert_eq!(Sequence::TAG, Tag::universal(16, true));
+    assert_eq!(ExplicitTag::new(3, Vec::new()).tag(), Tag::from(0xa3));
+}
+
+#[test]
+fn annotations() {
+    let raw = [0x30, 0x03, 0x02, 0x01, 0x05];
+    let mut asn1 = Asn1::decode_buff(&raw).unwrap();
+    assert!(asn1.annotations().is_empty());
+
+    asn1.annotate("field", "version");
+    asn1.annotate("comment", "first");
+    asn1.annotate("comment", "certificate version");
+
+    let owned = asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned());
+    assert_eq!(owned.annotation("field"), Some("version"));
+    assert_eq!(owned.annotation("comment"), Some("certificate version"));
+    assert_eq!(owned.annotations().len(), 2);
+    assert_eq!(owned.encode_to_vec().unwrap(), raw);
+
+    asn1.clear_meta();
+    assert!(asn1.annotation("field").is_none());
+}
+
+#[test]
+fn structural_eq() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    let der = [0x30, 0x06, 0x04, 0x04, 0x02, 0x02, 0x01, 0x00];
+    let ber = [
+        0x30, 0x80, 0x24, 0x80, 0x04, 0x04, 0x02, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    let nested = [0x30, 0x0a, 0x05, 0x00, 0x30, 0x06, 0x04, 0x04, 0x02, 0x02, 0x01, 0x00];
+
+    let der = Asn1::decode_buff(&der).unwrap();
+    let ber = Asn1::decode_buff_with_profile(&ber, DecodeProfile::Ber).unwrap();
+    let nested = Asn1::decode_buff(&nested).unwrap();
+    let Asn1Type::Sequence(nested) = nested.inner_asn1() else {
+        panic!("expected sequence");
+    };
+
+    assert_ne!(der, ber);
+    assert!(der.structural_eq(&ber));
+    assert_ne!(der.id(), nested.fields()[1].id());
+    assert!(der.structural_eq(&nested.fields()[1]));
+
+    let hash = |asn1: &Asn1| {
+        let mut hasher = DefaultHasher::new();
+        asn1.structural_hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(&der), hash(&ber));
+
+    let other = Asn1::decode_buff(&[0x30, 0x03, 0x02, 0x01, 0x01]).unwrap();
+    assert!(!der.structural_eq(&other));
+    assert!(other.structural_eq(&other));
+    assert_ne!(hash(&der), hash(&other));
+
+    // The same undecodable BOOLEAN at different offsets
+    let shifted = [0x30, 0x06, 0x05, 0x00, 0x01, 0x02, 0xff, 0xff];
+    let shifted = Asn1::decode_buff_lenient(&shifted, DecodeProfile::Ber).unwrap();
+    let Asn1Type::Sequence(shifted) = shifted.inner_asn1() else {
+        panic!("expected sequence");
+    };
+    let invalid = Asn1::decode_buff_lenient(&[0x01, 0x02, 0xff, 0xff], DecodeProfile::Ber).unwrap();
+    assert!(invalid.structural_eq(&invalid));
+    assert!(invalid.structural_eq(&shifted.fields()[1]));
+    assert_eq!(hash(&invalid), hash(&shifted.fields()[1]));
+    assert!(!invalid.structural_eq(&shifted.fields()[0]));
+}
+
+#[test]
+fn indefinite_length_per_node() {
+    // SEQUENCE { NULL, SEQUENCE { INTEGER 1 } }
+    let der = [0x30, 0x07, 0x05, 0x00, 0x30, 0x03, 0x02, 0x01, 0x01];
+    let asn1 = Asn1::decode_buff(&der).unwrap();
+    let Asn1Type::Sequence(sequence) = asn1.inner_asn1() else {
+        panic!("expected sequence");
+    };
+    let inner_id = sequence.fields()[1].id();
+
+    assert_eq!(asn1.encode_with_options(&EncodeOptions::default()).unwrap(), der);
+
+    let encoded = asn1
+        .encode_with_options(&EncodeOptions::default().with_indefinite_length(inner_id))
+        .unwrap();
+    assert_eq!(
+        encoded,
+        [0x30, 0x09, 0x05, 0x00, 0x30, 0x80, 0x02, 0x01, 0x01, 0x00, 0x00]
+    );
+
+    let encoded = asn1
+        .encode_with_options(
+            &EncodeOptions::default()
+                .with_indefinite_length(asn1.id())
+                .with_indefinite_length(inner_id),
+        )
+        .unwrap();
+    assert_eq!(
+        encoded,
+        [0x30, 0x80, 0x05, 0x00, 0x30, 0x80, 0x02, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00]
+    );
+
+    let decoded = Asn1::decode_buff_with_profile(&encoded, DecodeProfile::Ber).unwrap();
+    assert!(decoded.structural_eq(&asn1));
+}
+
+#[test]
+fn lints() {
+    let raw = [
+        0x30, 0x18, // SEQUENCE
+        0x02, 0x02, 0x00, 0x01, // INTEGER with leading zero
+        0x17, 0x0b, b'2', b'3', b'0', b'1', b'0', b'1', b'1', b'2', b'0', b'0', b'Z', // UTCTime without seconds
+        0x30, 0x00, // empty SEQUENCE
+        0x04, 0x03, 0x30, 0x05, 0x01, // OCTET STRING with truncated SEQUENCE
+    ];
+    let asn1 = Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).unwrap();
+    let Asn1Type::Sequence(sequence) = asn1.inner_asn1() else {
+        panic!("expected sequence");
+    };
+    let fields = sequence.fields();
+
+    assert_eq!(
+        asn1.lints(),
+        [
+            Lint {
+                node_id: fields[0].id(),
+                kind: LintKind::IntegerLeadingZero,
+            },
+            Lint {
+                node_id: fields[1].id(),
+                kind: LintKind::UtcTimeWithoutSeconds,
+            },
+            Lint {
+                node_id: fields[2].id(),
+                kind: LintKind::EmptySequence,
+            },
+            Lint {
+                node_id: fields[3].id(),
+                kind: LintKind::MalformedEncapsulatedValue,
+            },
+        ]
+    );
+    assert_eq!(LintKind::EmptySequence.to_string(), "empty SEQUENCE");
+
+    let encapsulated = Asn1::decode_buff(&[0x04, 0x04, 0x30, 0x02, 0x05, 0x00]).unwrap();
+    assert!(encapsulated.lints().is_empty());
 }
 
 #[test]
@@ -58,6 +1117,20 @@ fn ia5_string() {
     let raw = [22, 9, 65, 66, 67, 68, 32, 69, 70, 71, 72];
     let asn1 = Asn1::decode_buff(&raw).unwrap();
     println!("{:?}", asn1);
+
+    let Asn1Type::IA5String(ia5_string) = asn1.inner_asn1() else {
+        panic!("expected ia5 string");
+    };
+    assert_eq!(ia5_string.string(), "ABCD EFGH");
+    assert_eq!(ia5_string.tag(), IA5String::TAG);
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // non-ASCII character
+    let raw = [22, 2, 0xc3, 0xa9];
+    assert!(Asn1::decode_buff(&raw).is_err());
 }
 
 #[test]
@@ -65,20 +1138,506 @@ fn printable_string() {
     let raw = [19, 6, 80, 97, 114, 107, 101, 114];
     let asn1 = Asn1::decode_buff(&raw).unwrap();
     println!("{:?}", asn1);
+
+    // "user@example.com": '@' is not allowed in PrintableString
+    let raw = b"\x13\x10user@example.com";
+    assert!(Asn1::decode_buff(raw).is_err());
+
+    let asn1 = Asn1::decode_buff_with_profile(raw, DecodeProfile::Ber).unwrap();
+    let Asn1Type::PrintableString(printable_string) = asn1.inner_asn1() else {
+        panic!("expected printable string");
+    };
+    assert_eq!(printable_string.string(), "user@example.com");
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+}
+
+#[test]
+fn bmp_string() {
+    let raw = [
+        0x1e, 0x0c, 0x00, 0x54, 0x00, 0xeb, 0x00, 0x73, 0x00, 0x74, 0xd8, 0x3d, 0xde, 0x00,
+    ];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::BmpString(bmp_string) = asn1.inner_asn1() else {
+        panic!("expected bmp string");
+    };
+    assert_eq!(bmp_string.string(), "T\u{eb}st\u{1f600}");
+    assert_eq!(bmp_string.to_utf16_be(), &raw[2..]);
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // odd data length
+    assert!(Asn1::decode_buff(&[0x1e, 0x03, 0x00, 0x54, 0x00]).is_err());
+    // unpaired surrogate
+    assert!(Asn1::decode_buff(&[0x1e, 0x04, 0xd8, 0x3d, 0x00, 0x54]).is_err());
+}
+
+#[test]
+fn universal_string() {
+    let raw = [
+        0x1c, 0x0c, 0x00, 0x00, 0x00, 0x54, 0x00, 0x00, 0x00, 0xeb, 0x00, 0x01, 0xf6, 0x00,
+    ];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::UniversalString(universal_string) = asn1.inner_asn1() else {
+        panic!("expected universal string");
+    };
+    assert_eq!(universal_string.string(), "T\u{eb}\u{1f600}");
+    assert_eq!(universal_string.to_utf32_be(), &raw[2..]);
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // data length is not a multiple of 4
+    assert!(Asn1::decode_buff(&[0x1c, 0x03, 0x00, 0x00, 0x54]).is_err());
+    // surrogate code point
+    assert!(Asn1::decode_buff(&[0x1c, 0x04, 0x00, 0x00, 0xd8, 0x00]).is_err());
+    // code point is out of the Unicode range
+    assert!(Asn1::decode_buff(&[0x1c, 0x04, 0x00, 0x11, 0x00, 0x00]).is_err());
+}
+
+#[test]
+fn graphic_string() {
+    let raw = [0x19, 0x05, 0x48, 0x65, 0x6c, 0x6c, 0x6f];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::GraphicString(graphic_string) = asn1.inner_asn1() else {
+        panic!("expected graphic string");
+    };
+    assert_eq!(graphic_string.as_ascii(), Some("Hello"));
+    assert_eq!(graphic_string.tag(), GraphicString::TAG);
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+}
+
+#[test]
+fn videotex_string() {
+    // T.61 encoded "Caf\u{e9}": the accent is the separate non-spacing character 0xc2
+    let raw = [0x15, 0x05, 0x43, 0x61, 0x66, 0xc2, 0x65];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::VideotexString(videotex_string) = asn1.inner_asn1() else {
+        panic!("expected videotex string");
+    };
+    assert_eq!(videotex_string.raw_data(), &raw[2..]);
+    assert_eq!(videotex_string.as_ascii(), None);
+    assert_eq!(videotex_string.string(), "Caf\u{fffd}e");
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+}
+
+#[test]
+fn general_string() {
+    // Kerberos principal name component: "krbtgt"
+    let raw = [0x1b, 0x06, 0x6b, 0x72, 0x62, 0x74, 0x67, 0x74];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::GeneralString(general_string) = asn1.inner_asn1() else {
+        panic!("expected general string");
+    };
+    assert_eq!(general_string.as_ascii(), Some("krbtgt"));
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // Latin-1 encoded "caf\u{e9}" is not valid UTF-8, but it is still decoded
+    let raw = [0x1b, 0x04, 0x63, 0x61, 0x66, 0xe9];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::GeneralString(general_string) = asn1.inner_asn1() else {
+        panic!("expected general string");
+    };
+    assert_eq!(general_string.as_ascii(), None);
+    assert_eq!(general_string.raw_data(), &raw[2..]);
+    assert_eq!(general_string.string(), "caf\u{fffd}");
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+}
+
+#[test]
+fn sequence_of() {
+    // SEQUENCE OF INTEGER: { 1, 256 }
+    let raw = [0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x02, 0x01, 0x00];
+    let decoded = Tlv::<SequenceOf<Integer>>::decode_buff(&raw).unwrap();
+    let elements = decoded
+        .inner_asn1()
+        .elements()
+        .iter()
+        .map(|integer| integer.as_i64().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(elements, [1, 256]);
+
+    let mut buff = vec![0; decoded.needed_buf_size()];
+    decoded.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    let sequence_of = SequenceOf::new(vec![OwnedInteger::from(1), OwnedInteger::from(256)]);
+    let mut buff = vec![0; sequence_of.needed_buf_size()];
+    sequence_of.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // the second element is BOOLEAN
+    let raw = [0x30, 0x06, 0x02, 0x01, 0x01, 0x01, 0x01, 0xff];
+    assert!(Tlv::<SequenceOf<Integer>>::decode_buff(&raw).is_err());
+}
+
+#[test]
+fn set() {
+    // SET { INTEGER 1, BOOLEAN TRUE }: BOOLEAN should go first in DER
+    let raw = [0x31, 0x06, 0x02, 0x01, 0x01, 0x01, 0x01, 0xff];
+    assert!(Asn1::decode_buff(&raw).is_err());
+
+    let asn1 = Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).unwrap();
+    let Asn1Type::Set(set) = asn1.inner_asn1() else {
+        panic!("expected set");
+    };
+
+    let mut set = set.to_owned();
+    set.sort_der().unwrap();
+    let mut buff = vec![0; set.needed_buf_size()];
+    set.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, [0x31, 0x06, 0x01, 0x01, 0xff, 0x02, 0x01, 0x01]);
+    assert!(Asn1::decode_buff(&buff).is_ok());
+
+    // SET OF INTEGER: { 256, 1 } is sorted by encodings: 02 01 01 < 02 02 01 00
+    let raw = [0x31, 0x07, 0x02, 0x02, 0x01, 0x00, 0x02, 0x01, 0x01];
+    assert!(Tlv::<SetOf<Integer>>::decode_buff(&raw).is_err());
+
+    let decoded = Tlv::<SetOf<Integer>>::decode_buff_with_profile(&raw, DecodeProfile::Ber).unwrap();
+    let mut set_of = decoded.into_inner();
+    set_of.sort_der().unwrap();
+    let elements = set_of
+        .elements()
+        .iter()
+        .map(|integer| integer.as_i64().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(elements, [1, 256]);
+
+    let mut buff = vec![0; set_of.needed_buf_size()];
+    set_of.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, [0x31, 0x07, 0x02, 0x01, 0x01, 0x02, 0x02, 0x01, 0x00]);
+    assert!(Tlv::<SetOf<Integer>>::decode_buff(&buff).is_ok());
+}
+
+#[test]
+fn implicit() {
+    // [1] IMPLICIT INTEGER: 5
+    let raw = [0x81, 0x01, 0x05];
+    let decoded = Tlv::<Implicit<Integer, 1>>::decode_buff(&raw).unwrap();
+    assert_eq!(decoded.inner_asn1().inner().as_i64(), Some(5));
+
+    let mut buff = vec![0; decoded.needed_buf_size()];
+    decoded.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // tag number mismatch
+    assert!(Tlv::<Implicit<Integer, 2>>::decode_buff(&raw).is_err());
+
+    // the same value in the untyped tree
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::ImplicitTag(implicit_tag) = asn1.inner_asn1() else {
+        panic!("expected implicit tag");
+    };
+    assert_eq!(implicit_tag.decode_as::<Integer>().unwrap().as_i64(), Some(5));
+
+    // [0] IMPLICIT SEQUENCE OF INTEGER: { 7 }
+    let raw = [0xa0, 0x03, 0x02, 0x01, 0x07];
+    let decoded = Tlv::<Implicit<SequenceOf<Integer>, 0>>::decode_buff(&raw).unwrap();
+    assert_eq!(decoded.inner_asn1().inner().elements()[0].as_i64(), Some(7));
+
+    let implicit = Implicit::<_, 0>::new(SequenceOf::new(vec![OwnedInteger::from(7)]));
+    let mut buff = vec![0; implicit.needed_buf_size()];
+    implicit.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+}
+
+#[test]
+fn validate_der() {
+    let raw = [
+        0x30, 0x80, // SEQUENCE with the indefinite length
+        0x01, 0x01, 0x01, // BOOLEAN TRUE encoded as 0x01
+        0x02, 0x02, 0x00, 0x05, // INTEGER 5 with the leading zero
+        0x31, 0x06, 0x02, 0x01, 0x02, 0x01, 0x01, 0xff, // SET { INTEGER, BOOLEAN }
+        0x04, 0x81, 0x01, 0xaa, // OCTET STRING with the non-minimal length
+        0x00, 0x00,
+    ];
+    assert!(Asn1::decode_buff(&raw).is_err());
+
+    let asn1 = Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).unwrap();
+    let violations = asn1
+        .validate_der()
+        .into_iter()
+        .map(|violation| (violation.offset, violation.kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        violations,
+        [
+            (1, DerViolationKind::IndefiniteLength),
+            (4, DerViolationKind::NonCanonicalBool),
+            (7, DerViolationKind::IntegerLeadingZero),
+            (9, DerViolationKind::UnsortedSet),
+            (18, DerViolationKind::NonMinimalLength),
+        ]
+    );
+    assert_eq!(DerViolationKind::UnsortedSet.to_string(), "SET elements are not sorted");
+
+    let raw = [0x30, 0x06, 0x01, 0x01, 0xff, 0x02, 0x01, 0x05];
+    assert!(Asn1::decode_buff(&raw).unwrap().validate_der().is_empty());
+}
+
+#[test]
+fn high_tag_number() {
+    // [APPLICATION 100] { INTEGER 5 }
+    let raw = [0x7f, 0x64, 0x03, 0x02, 0x01, 0x05];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::ApplicationTag(application_tag) = asn1.inner_asn1() else {
+        panic!("expected application tag");
+    };
+    assert_eq!(application_tag.tag_number(), 100);
+    assert_eq!(asn1.inner_asn1().tag().number(), 100);
+    assert_eq!(asn1.meta().tag_bytes(), &[0x7f, 0x64]);
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // [201] IMPLICIT INTEGER: 5
+    let raw = [0x9f, 0x81, 0x49, 0x01, 0x05];
+    let decoded = Tlv::<Implicit<Integer, 201>>::decode_buff(&raw).unwrap();
+    assert_eq!(decoded.inner_asn1().inner().as_i64(), Some(5));
+
+    let mut buff = vec![0; decoded.needed_buf_size()];
+    decoded.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // tag number 5 in the high tag number form
+    let raw = [0x9f, 0x05, 0x01, 0x05];
+    assert!(Asn1::decode_buff(&raw).is_err());
+    assert_eq!(
+        Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber)
+            .unwrap()
+            .inner_asn1()
+            .tag()
+            .number(),
+        5
+    );
+
+    // the tag number is not minimally encoded
+    assert!(Asn1::decode_buff_with_profile(&[0x9f, 0x80, 0x40, 0x01, 0x05], DecodeProfile::Ber).is_err());
+}
+
+#[test]
+fn private_tag() {
+    // [PRIVATE 1] primitive
+    let raw = [0xc1, 0x02, 0xab, 0xcd];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::PrivateTag(private_tag) = asn1.inner_asn1() else {
+        panic!("expected private tag");
+    };
+    assert_eq!(private_tag.tag_number(), 1);
+    assert!(!private_tag.is_constructed());
+    assert_eq!(private_tag.octets(), &[0xab, 0xcd]);
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    let private_tag = PrivateTag::new_primitive(1, vec![0xab, 0xcd]);
+    let mut buff = vec![0; private_tag.needed_buf_size()];
+    private_tag.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // [PRIVATE 2] constructed: { INTEGER 5 }
+    let raw = [0xe2, 0x03, 0x02, 0x01, 0x05];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::PrivateTag(private_tag) = asn1.inner_asn1() else {
+        panic!("expected private tag");
+    };
+    assert_eq!(private_tag.tag_number(), 2);
+    assert!(private_tag.is_constructed());
+    assert_eq!(private_tag.inner().len(), 1);
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
 }
 
 #[test]
 fn utc_time() {
+    // seconds are required in DER
     let raw = [23, 11, 57, 54, 48, 52, 49, 53, 50, 48, 51, 48, 90];
-    let asn1 = Asn1::decode_buff(&raw).unwrap();
+    assert!(Asn1::decode_buff(&raw).is_err());
+    assert!(Asn1::decode_buff_with_profile(&raw, DecodeProfile::Cer).is_err());
+    let asn1 = Asn1::decode_buff_with_profile(&raw, DecodeProfile::Ber).unwrap();
     println!("{:?}", asn1);
 
     let raw = [23, 13, 49, 57, 49, 48, 49, 55, 49, 55, 52, 49, 50, 56, 90];
     let asn1 = Asn1::decode_buff(&raw).unwrap();
     println!("{:?}", asn1);
+
+    // 491231235959Z
+    let raw = b"\x17\x0d491231235959Z";
+    let asn1 = Asn1::decode_buff(raw).unwrap();
+    let Asn1Type::UtcTime(utc_time) = asn1.inner_asn1() else {
+        panic!("expected utc time");
+    };
+    assert_eq!(utc_time.full_year(), 2049);
+    assert_eq!(utc_time.to_unix_timestamp(), 2524607999);
+
+    // 9912312359-0130: the local time differential is not allowed in DER
+    let raw = b"\x17\x0f9912312359-0130";
+    assert!(Asn1::decode_buff(raw).is_err());
+    let asn1 = Asn1::decode_buff_with_profile(raw, DecodeProfile::Ber).unwrap();
+    let Asn1Type::UtcTime(utc_time) = asn1.inner_asn1() else {
+        panic!("expected utc time");
+    };
+    assert_eq!(utc_time.full_year(), 1999);
+    assert!(utc_time.second.is_none());
+    assert_eq!(utc_time.local_time.as_ref().unwrap().offset_seconds(), -5400);
+    assert_eq!(utc_time.to_unix_timestamp(), 946684740 + 5400);
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // 991231235959+0100
+    let raw = b"\x17\x11991231235959+0100";
+    assert!(Asn1::decode_buff(raw).is_err());
+    assert!(Asn1::decode_buff_with_profile(raw, DecodeProfile::Ber).is_ok());
+
+    // February 30
+    assert!(Asn1::decode_buff(b"\x17\x0d990230000000Z").is_err());
+    // missing time zone
+    assert!(Asn1::decode_buff(b"\x17\x0c990101000000").is_err());
+}
+
+#[test]
+fn object_identifier() {
+    // rsaEncryption
+    let raw = [6, 9, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01];
+    let decoded = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::ObjectIdentifier(oid) = decoded.inner_asn1() else {
+        panic!("expected object identifier");
+    };
+    assert_eq!(oid.arcs(), &[1, 2, 840, 113549, 1, 1, 1]);
+    assert_eq!(oid.format(), "1.2.840.113549.1.1.1");
+    assert_eq!(oid, &"1.2.840.113549.1.1.1".parse::<ObjectIdentifier>().unwrap());
+
+    let mut buff = vec![0; decoded.needed_buf_size()];
+    decoded.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
+
+    // the second arc of the joint-iso-itu-t arc can be greater than 39
+    let oid = "2.999.3".parse::<ObjectIdentifier>().unwrap();
+    let mut buff = vec![0; oid.needed_buf_size()];
+    oid.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, [6, 3, 0x88, 0x37, 0x03]);
+
+    assert!("1.40".parse::<ObjectIdentifier>().is_err());
+    assert!("3.1".parse::<ObjectIdentifier>().is_err());
+    assert!("1".parse::<ObjectIdentifier>().is_err());
+    assert!("1..2".parse::<ObjectIdentifier>().is_err());
+    // non-minimal subidentifier encoding
+    assert!(Asn1::decode_buff(&[6, 3, 0x2a, 0x80, 0x01]).is_err());
+    // incomplete subidentifier
+    assert!(Asn1::decode_buff(&[6, 2, 0x2a, 0x86]).is_err());
+}
+
+#[test]
+fn real() {
+    let cases: [(&[u8], f64); 9] = [
+        (&[9, 0], 0.0),
+        (&[9, 1, 0x40], f64::INFINITY),
+        (&[9, 1, 0x41], f64::NEG_INFINITY),
+        // base 2: 0x03 * 2^-1
+        (&[9, 3, 0x80, 0xff, 0x03], 1.5),
+        // base 8: 0x01 * 8^1
+        (&[9, 3, 0x90, 0x01, 0x01], 8.0),
+        // base 16, scaling factor 1: -0x01 * 2^1 * 16^1
+        (&[9, 3, 0xe4, 0x01, 0x01], -32.0),
+        // NR1
+        (b"\x09\x04\x01 12", 12.0),
+        // NR2 with a comma decimal mark
+        (b"\x09\x05\x02-1,5", -1.5),
+        // NR3
+        (b"\x09\x07\x0315.E-1", 1.5),
+    ];
+    for (raw, value) in cases {
+        let decoded = Asn1::decode_buff_with_profile(raw, DecodeProfile::Ber).unwrap();
+        let Asn1Type::Real(real) = decoded.inner_asn1() else {
+            panic!("expected real");
+        };
+        assert_eq!(real.to_f64().unwrap(), value);
+
+        let mut buff = vec![0; decoded.needed_buf_size()];
+        decoded.encode_buff(&mut buff).unwrap();
+        assert_eq!(buff, raw);
+    }
+
+    let nan = Asn1::decode_buff(&[9, 1, 0x42]).unwrap();
+    let Asn1Type::Real(nan) = nan.inner_asn1() else {
+        panic!("expected real");
+    };
+    assert!(nan.to_f64().unwrap().is_nan());
+
+    for value in [
+        1.0,
+        -1.5,
+        0.1,
+        1e300,
+        -1e-300,
+        f64::MIN_POSITIVE,
+        5e-324,
+        -0.0,
+        f64::MAX,
+    ] {
+        let real = OwnedReal::from(value);
+        assert_eq!(real.to_f64().unwrap().to_bits(), value.to_bits());
+    }
+    // 1.5 = 3 * 2^-1: odd mantissa and minimal exponent
+    assert_eq!(OwnedReal::from(1.5).raw_data(), &[0x80, 0xff, 0x03]);
+
+    // reserved base
+    assert!(Asn1::decode_buff(&[9, 3, 0xb0, 0x01, 0x01]).is_err());
+    // invalid decimal form
+    assert!(Asn1::decode_buff(b"\x09\x02\x041").is_err());
+
+    // DER allows only the base 2 binary and the NR3 decimal encodings
+    assert!(Asn1::decode_buff(&[9, 3, 0x80, 0xff, 0x03]).is_ok());
+    assert!(Asn1::decode_buff(b"\x09\x07\x0315.E-1").is_ok());
+    for raw in [
+        &[9, 3, 0x90, 0x01, 0x01][..],
+        &[9, 3, 0xe4, 0x01, 0x01],
+        b"\x09\x04\x01 12",
+        b"\x09\x05\x02-1,5",
+    ] {
+        assert!(Asn1::decode_buff(raw).is_err());
+        assert!(Asn1::decode_buff_with_profile(raw, DecodeProfile::Cer).is_err());
+    }
+}
+
+#[test]
+fn relative_oid() {
+    let raw = [0x0d, 4, 0xc2, 0x7b, 0x03, 0x02];
+    let decoded = Asn1::decode_buff(&raw).unwrap();
+    let Asn1Type::RelativeOid(relative_oid) = decoded.inner_asn1() else {
+        panic!("expected relative oid");
+    };
+    assert_eq!(relative_oid.arcs(), &[8571, 3, 2]);
+    assert_eq!(relative_oid, &"8571.3.2".parse::<RelativeOid>().unwrap());
+
+    let mut buff = vec![0; decoded.needed_buf_size()];
+    decoded.encode_buff(&mut buff).unwrap();
+    assert_eq!(buff, raw);
 }
 
-// TODO: bug. need to be fixed
 #[test]
 fn oi() {
     let asn1 = Asn1Type::ObjectIdentifier(ObjectIdentifier::from(oid::ObjectIdentifier::try_from("2.29.1432919503.268680342.2607450773.2297838964.2800989460.3536442839.826751377.97234221.883516388.2427681722").unwrap()));
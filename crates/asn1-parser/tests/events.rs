@@ -0,0 +1,75 @@
+#![cfg(feature = "no-alloc")]
+
+use asn1_parser::{Asn1Event, Asn1Events, DecodeProfile, ErrorKind, Integer, Null, Sequence, Tag};
+
+#[test]
+fn events() {
+    // SEQUENCE { INTEGER 5, [0] { INTEGER 6 }, NULL } with the indefinite length [0]
+    let raw = [
+        0x30, 0x0c, 0x02, 0x01, 0x05, 0xa0, 0x80, 0x02, 0x01, 0x06, 0x00, 0x00, 0x05, 0x00,
+    ];
+    let explicit = Tag::from(0xa0);
+
+    let events = Asn1Events::<4>::new(&raw, DecodeProfile::Ber)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(
+        events,
+        [
+            Asn1Event::Start {
+                tag: Sequence::TAG,
+                offset: 0,
+                depth: 0,
+                data: &raw[2..],
+            },
+            Asn1Event::Primitive {
+                tag: Integer::TAG,
+                offset: 2,
+                depth: 1,
+                data: &[0x05],
+            },
+            Asn1Event::Start {
+                tag: explicit,
+                offset: 5,
+                depth: 1,
+                data: &raw[7..10],
+            },
+            Asn1Event::Primitive {
+                tag: Integer::TAG,
+                offset: 7,
+                depth: 2,
+                data: &[0x06],
+            },
+            Asn1Event::End {
+                tag: explicit,
+                depth: 1
+            },
+            Asn1Event::Primitive {
+                tag: Null::TAG,
+                offset: 12,
+                depth: 1,
+                data: &[],
+            },
+            Asn1Event::End {
+                tag: Sequence::TAG,
+                depth: 0,
+            },
+        ]
+    );
+
+    // The indefinite length is not allowed in DER.
+    let mut events = Asn1Events::<4>::new(&raw, DecodeProfile::Der);
+    assert!(events.next().unwrap().is_ok());
+    assert!(events.next().unwrap().is_ok());
+    assert!(events.next().unwrap().is_err());
+    assert!(events.next().is_none());
+
+    // SEQUENCE { SEQUENCE { BOOLEAN TRUE } } does not fit into one frame.
+    let raw = [0x30, 0x05, 0x30, 0x03, 0x01, 0x01, 0xff];
+    let error = Asn1Events::<1>::new(&raw, DecodeProfile::Der)
+        .find_map(Result::err)
+        .unwrap();
+    assert_eq!(error.kind(), ErrorKind::LimitExceeded);
+    assert!(Asn1Events::<2>::new(&raw, DecodeProfile::Der).all(|event| event.is_ok()));
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "std")]
+
+use std::io::{self, Write};
+
+use asn1_parser::{Asn1, Asn1Decoder, Asn1Encoder};
+
+struct FullWriter;
+
+impl Write for FullWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::WriteZero, "full"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn encode_to_io() {
+    // SEQUENCE { INTEGER 5, OCTET STRING { BOOLEAN TRUE } }
+    let raw = [0x30, 0x08, 0x02, 0x01, 0x05, 0x04, 0x03, 0x01, 0x01, 0xff];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+
+    assert_eq!(asn1.encode_to_vec().unwrap(), raw);
+
+    let mut encoded = vec![0xaa];
+    asn1.encode_to_io(&mut encoded).unwrap();
+    assert_eq!(encoded[1..], raw);
+
+    let error = asn1.encode_to_io(&mut FullWriter).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+}
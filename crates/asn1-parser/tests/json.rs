@@ -0,0 +1,68 @@
+#![cfg(feature = "json")]
+
+use asn1_parser::{Asn1, Asn1Decoder};
+use serde_json::json;
+
+#[test]
+fn to_json() {
+    let raw = [
+        0x30, 0x0c, 0x02, 0x01, 0x05, 0x04, 0x03, 0x01, 0x01, 0xff, 0x0c, 0x02, 0x68, 0x69,
+    ];
+    let asn1 = Asn1::decode_buff(&raw).unwrap();
+
+    assert_eq!(
+        asn1.to_json(),
+        json!({
+            "type": "Sequence",
+            "tag": { "class": "universal", "constructed": true, "number": 16 },
+            "offset": 0,
+            "length": 14,
+            "data_offset": 2,
+            "data_length": 12,
+            "hex": "02010504030101ff0c026869",
+            "children": [
+                {
+                    "type": "Integer",
+                    "tag": { "class": "universal", "constructed": false, "number": 2 },
+                    "offset": 2,
+                    "length": 3,
+                    "data_offset": 4,
+                    "data_length": 1,
+                    "hex": "05",
+                    "value": "5",
+                },
+                {
+                    "type": "OctetString",
+                    "tag": { "class": "universal", "constructed": false, "number": 4 },
+                    "offset": 5,
+                    "length": 5,
+                    "data_offset": 7,
+                    "data_length": 3,
+                    "hex": "0101ff",
+                    "children": [
+                        {
+                            "type": "Bool",
+                            "tag": { "class": "universal", "constructed": false, "number": 1 },
+                            "offset": 7,
+                            "length": 3,
+                            "data_offset": 9,
+                            "data_length": 1,
+                            "hex": "ff",
+                            "value": true,
+                        }
+                    ],
+                },
+                {
+                    "type": "Utf8String",
+                    "tag": { "class": "universal", "constructed": false, "number": 12 },
+                    "offset": 10,
+                    "length": 4,
+                    "data_offset": 12,
+                    "data_length": 2,
+                    "hex": "6869",
+                    "value": "hi",
+                }
+            ],
+        })
+    );
+}
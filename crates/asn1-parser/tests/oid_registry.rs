@@ -0,0 +1,21 @@
+#![cfg(feature = "oid-registry")]
+
+use asn1_parser::{oid_name, ObjectIdentifier, OidRegistry};
+
+#[test]
+fn oid_registry() {
+    let rsa: ObjectIdentifier = "1.2.840.113549.1.1.1".parse().unwrap();
+    let krb5: ObjectIdentifier = "1.2.840.113554.1.2.2".parse().unwrap();
+    let custom: ObjectIdentifier = "1.3.6.1.4.1.99999.1".parse().unwrap();
+
+    assert_eq!(oid_name(&rsa), Some("rsaEncryption"));
+    assert_eq!(oid_name(&krb5), Some("krb5"));
+    assert_eq!(oid_name(&custom), None);
+
+    let mut registry = OidRegistry::new();
+    registry.register(custom.clone(), "my-extension");
+    registry.register(rsa.clone(), "RSA");
+    assert_eq!(registry.name(&custom), Some("my-extension"));
+    assert_eq!(registry.name(&rsa), Some("RSA"));
+    assert_eq!(registry.name(&krb5), Some("krb5"));
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "pem")]
+
+use asn1_parser::{Asn1, Asn1Decoder, Pem};
+
+#[test]
+fn pem() {
+    let pem = "Subject: CN=test\n-----BEGIN TEST-----\nMAMCAQU=\n-----END TEST-----\n";
+    let decoded = Pem::decode(pem).unwrap();
+    assert_eq!(decoded.label(), "TEST");
+    assert_eq!(decoded.data(), &[0x30, 0x03, 0x02, 0x01, 0x05]);
+    assert!(Asn1::decode_buff(decoded.data()).is_ok());
+    assert_eq!(decoded.encode(), pem["Subject: CN=test\n".len()..]);
+
+    let encoded = Pem::new("LONG", vec![0xaa; 100]).encode();
+    assert!(encoded.lines().all(|line| line.len() <= 64));
+    assert_eq!(Pem::decode(&encoded).unwrap().data(), &[0xaa; 100]);
+
+    assert!(Pem::decode("-----BEGIN TEST-----\nMAMCAQU=\n-----END OTHER-----").is_err());
+    assert!(Pem::decode("-----BEGIN TEST-----\nMAMCAQU=\n").is_err());
+    assert!(Pem::decode("-----BEGIN TEST-----\nMAMC!QU=\n-----END TEST-----").is_err());
+}
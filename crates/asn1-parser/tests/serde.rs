@@ -0,0 +1,35 @@
+#![cfg(feature = "serde")]
+
+use asn1_parser::{Asn1, Asn1Decoder, ObjectIdentifier, OwnedAsn1, Tag};
+
+#[test]
+fn serde() {
+    // SEQUENCE { INTEGER 5, [PRIVATE 31] { BOOLEAN TRUE }, UTF8String "ab" }
+    let raw = [
+        0x30, 0x0d, 0x02, 0x01, 0x05, 0xff, 0x1f, 0x03, 0x01, 0x01, 0xff, 0x0c, 0x02, 0x61, 0x62,
+    ];
+    let decoded = Asn1::decode_buff(&raw).unwrap();
+
+    let json = serde_json::to_string(&decoded).unwrap();
+    let deserialized: OwnedAsn1 = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, decoded);
+    assert_eq!(deserialized.meta().raw_bytes(), raw);
+
+    let tag = Tag::from_octet(0x30);
+    let deserialized: Tag = serde_json::from_str(&serde_json::to_string(&tag).unwrap()).unwrap();
+    assert_eq!(deserialized, tag);
+}
+
+#[test]
+fn serde_object_identifier() {
+    let oid: ObjectIdentifier = "1.2.840.113549".parse().unwrap();
+    let json = serde_json::to_string(&oid).unwrap();
+    assert_eq!(json, "[1,2,840,113549]");
+    assert_eq!(serde_json::from_str::<ObjectIdentifier>(&json).unwrap(), oid);
+
+    // the arcs are validated as in `ObjectIdentifier::new`
+    assert!(serde_json::from_str::<ObjectIdentifier>("[5]").is_err());
+    assert!(serde_json::from_str::<ObjectIdentifier>("[3,1]").is_err());
+    assert!(serde_json::from_str::<ObjectIdentifier>("[1,40]").is_err());
+    assert!(serde_json::from_str::<ObjectIdentifier>(&format!("[2,{}]", u64::MAX)).is_err());
+}
@@ -1,20 +1,33 @@
-use asn1_parser::{Asn1Type, OwnedApplicationTag, OwnedAsn1, OwnedAsn1Type, OwnedExplicitTag, OwnedSequence, OwnedSet};
+use asn1_parser::{
+    Asn1Type, OwnedApplicationTag, OwnedAsn1, OwnedAsn1Type, OwnedExplicitTag, OwnedPrivateTag, OwnedSequence, OwnedSet,
+};
 use proptest::collection::vec;
 use proptest::prop_oneof;
 use proptest::strategy::{Just, Strategy};
 
-use crate::{any_bit_string, any_bmp_string, any_bool, any_integer, any_null, any_octet_string, any_utf8_string};
+use crate::{
+    any_bit_string, any_bmp_string, any_bool, any_enumerated, any_graphic_string, any_ia5_string, any_integer,
+    any_null, any_object_identifier, any_octet_string, any_real, any_relative_oid, any_universal_string,
+    any_utf8_string, any_videotex_string,
+};
 
 fn any_leaf_asn1_type() -> impl Strategy<Value = OwnedAsn1Type> {
     prop_oneof![
         any_octet_string().prop_map(Asn1Type::OctetString),
         any_utf8_string().prop_map(Asn1Type::Utf8String),
+        any_ia5_string().prop_map(Asn1Type::IA5String),
         any_bit_string().prop_map(Asn1Type::BitString),
         any_bmp_string().prop_map(Asn1Type::BmpString),
+        any_graphic_string().prop_map(Asn1Type::GraphicString),
+        any_videotex_string().prop_map(Asn1Type::VideotexString),
+        any_universal_string().prop_map(Asn1Type::UniversalString),
         any_bool().prop_map(Asn1Type::Bool),
         any_null().prop_map(Asn1Type::Null),
         any_integer().prop_map(Asn1Type::Integer),
-        // any_object_identifier().prop_map(Asn1Type::ObjectIdentifier),
+        any_enumerated().prop_map(Asn1Type::Enumerated),
+        any_real().prop_map(Asn1Type::Real),
+        any_object_identifier().prop_map(Asn1Type::ObjectIdentifier),
+        any_relative_oid().prop_map(Asn1Type::RelativeOid),
     ]
     .no_shrink()
 }
@@ -23,6 +36,7 @@ pub fn recursive_empty_asn1_type() -> impl Strategy<Value = OwnedAsn1Type> {
     any_leaf_asn1_type().prop_recursive(16, 64, 32, |inner| {
         let explicit_tag_inner = inner.clone();
         let application_tag_inner = inner.clone();
+        let private_tag_inner = inner.clone();
         prop_oneof![
             vec(inner.clone(), 1..16).prop_map(|fields| {
                 Asn1Type::Sequence(OwnedSequence::new(
@@ -33,25 +47,34 @@ pub fn recursive_empty_asn1_type() -> impl Strategy<Value = OwnedAsn1Type> {
                 ))
             }),
             vec(inner, 1..16).prop_map(|fields| {
-                Asn1Type::Set(OwnedSet::new(
+                let mut set = OwnedSet::new(
                     fields
                         .into_iter()
                         .map(|asn1_type| OwnedAsn1::new(0, Default::default(), asn1_type))
                         .collect::<Vec<_>>(),
-                ))
+                );
+                set.sort_der().expect("SET fields encoding should not fail");
+
+                Asn1Type::Set(set)
             }),
-            (0_u8..31)
+            (0_u32..256)
                 .prop_flat_map(move |tag| (Just(tag), explicit_tag_inner.clone()))
                 .prop_map(|(tag, inner)| Asn1Type::ExplicitTag(OwnedExplicitTag::new(
                     tag,
                     vec![OwnedAsn1::new(0, Default::default(), inner)]
                 ))),
-            (0_u8..31)
+            (0_u32..256)
                 .prop_flat_map(move |tag| (Just(tag), application_tag_inner.clone()))
                 .prop_map(|(tag, inner)| Asn1Type::ApplicationTag(OwnedApplicationTag::new(
                     tag,
                     vec![OwnedAsn1::new(0, Default::default(), inner)]
                 ))),
+            (0_u32..256)
+                .prop_flat_map(move |tag| (Just(tag), private_tag_inner.clone()))
+                .prop_map(|(tag, inner)| Asn1Type::PrivateTag(OwnedPrivateTag::new(
+                    tag,
+                    vec![OwnedAsn1::new(0, Default::default(), inner)]
+                ))),
         ]
     })
 }
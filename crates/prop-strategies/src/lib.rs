@@ -22,17 +22,30 @@ pub fn string(len: usize) -> impl Strategy<Value = String> {
         .no_shrink()
 }
 
+pub fn ascii_string(len: usize) -> impl Strategy<Value = String> {
+    vec(0..0x80_u8, len)
+        .prop_map(|v| v.into_iter().map(char::from).collect::<String>())
+        .no_shrink()
+}
+
 #[allow(clippy::arc_with_non_send_sync)]
 pub fn any_asn1_type() -> impl Strategy<Value = OwnedAsn1Type> {
     prop_oneof![
         any_octet_string().prop_map(Asn1Type::OctetString),
         any_utf8_string().prop_map(Asn1Type::Utf8String),
+        any_ia5_string().prop_map(Asn1Type::IA5String),
         any_bit_string().prop_map(Asn1Type::BitString),
         any_bmp_string().prop_map(Asn1Type::BmpString),
+        any_graphic_string().prop_map(Asn1Type::GraphicString),
+        any_videotex_string().prop_map(Asn1Type::VideotexString),
+        any_universal_string().prop_map(Asn1Type::UniversalString),
         any_bool().prop_map(Asn1Type::Bool),
         any_null().prop_map(Asn1Type::Null),
         any_integer().prop_map(Asn1Type::Integer),
-        // any_object_identifier().prop_map(Asn1Type::ObjectIdentifier),
+        any_enumerated().prop_map(Asn1Type::Enumerated),
+        any_real().prop_map(Asn1Type::Real),
+        any_object_identifier().prop_map(Asn1Type::ObjectIdentifier),
+        any_relative_oid().prop_map(Asn1Type::RelativeOid),
         recursive_empty_asn1_type(),
     ]
     .no_shrink()
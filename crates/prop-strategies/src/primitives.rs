@@ -1,4 +1,4 @@
-use asn1_parser::{Bool, Null, ObjectIdentifier, OwnedInteger};
+use asn1_parser::{Bool, Null, ObjectIdentifier, OwnedEnumerated, OwnedInteger, OwnedReal, RelativeOid};
 use proptest::collection::vec;
 use proptest::prelude::any;
 use proptest::prop_compose;
@@ -18,22 +18,28 @@ pub fn any_integer() -> impl Strategy<Value = OwnedInteger> {
     bytes(1024).prop_map(|bytes| bytes.into())
 }
 
+pub fn any_enumerated() -> impl Strategy<Value = OwnedEnumerated> {
+    any::<i64>().prop_map(|value| value.into())
+}
+
+pub fn any_real() -> impl Strategy<Value = OwnedReal> {
+    any::<f64>().prop_map(|value| value.into())
+}
+
 prop_compose! {
     pub fn any_object_identifier()
         (
-            first_node in 0..3,
-            second_node in 0..40,
-            all_nodes in 2_usize..11,
-        )
-        (
-            first_node in Just(first_node),
-            second_node in Just(second_node),
-            nodes in vec(0..u32::MAX - 1, all_nodes)
+            first_node in 0_u64..3,
+            second_node in 0_u64..40,
+            nodes in vec(any::<u64>(), 0..11),
         ) -> ObjectIdentifier {
-        let mut formatted_oid = format!("{}.{}", first_node, second_node);
-        for node in nodes {
-            formatted_oid.push_str(&format!(".{}", node));
-        }
-        ObjectIdentifier::from(oid::ObjectIdentifier::try_from(dbg!(formatted_oid)).expect("Valid object identifier."))
+        let mut arcs = vec![first_node, second_node];
+        arcs.extend(nodes);
+
+        ObjectIdentifier::new(arcs).expect("Valid object identifier.")
     }
 }
+
+pub fn any_relative_oid() -> impl Strategy<Value = RelativeOid> {
+    vec(any::<u64>(), 1..11).prop_map(RelativeOid::from)
+}
@@ -1,7 +1,10 @@
-use asn1_parser::{BitString, OwnedBitString, OwnedBmpString, OwnedOctetString, OwnedUtf8String};
+use asn1_parser::{
+    BitString, OwnedBitString, OwnedBmpString, OwnedGraphicString, OwnedIA5String, OwnedOctetString,
+    OwnedUniversalString, OwnedUtf8String, OwnedVideotexString,
+};
 use proptest::prop_compose;
 
-use crate::{bytes, string};
+use crate::{ascii_string, bytes, string};
 
 const STRING_LEN: usize = 32;
 
@@ -19,6 +22,13 @@ prop_compose! {
     }
 }
 
+prop_compose! {
+    pub fn any_ia5_string()
+        (data in ascii_string(STRING_LEN)) -> OwnedIA5String {
+        data.into()
+    }
+}
+
 prop_compose! {
     pub fn any_bit_string()
         (
@@ -35,6 +45,27 @@ prop_compose! {
 prop_compose! {
     pub fn any_bmp_string()
         (data in string(STRING_LEN)) -> OwnedBmpString {
-            data.as_str().into()
+            data.into()
+        }
+}
+
+prop_compose! {
+    pub fn any_universal_string()
+        (data in string(STRING_LEN)) -> OwnedUniversalString {
+            data.into()
         }
 }
+
+prop_compose! {
+    pub fn any_graphic_string()
+        (data in bytes(STRING_LEN)) -> OwnedGraphicString {
+        OwnedGraphicString::new_owned(data)
+    }
+}
+
+prop_compose! {
+    pub fn any_videotex_string()
+        (data in bytes(STRING_LEN)) -> OwnedVideotexString {
+        OwnedVideotexString::new_owned(data)
+    }
+}
@@ -15,6 +15,7 @@ pub fn about() -> Html {
                 <li>{"Kerberos HMAC: HMAC-SHA1-96-AES128/HMAC-SHA1-96-AES256"}</li>
                 <li>{"RSA"}</li>
                 <li>{"Compression: ZLIB"}</li>
+                <li>{"Encoding: URL percent-encoding/HTML entities/Quoted-Printable"}</li>
                 <li>{"JWT debugger. Supported signature algorithms:"}</li>
                 <ul>
                     <li>{"none"}</li>
@@ -29,8 +30,20 @@ pub fn about() -> Html {
                     <li>{"ES512"}</li>
                 </ul>
                 <li>{"ASN1 decoder"}</li>
-                <li>{"Diff checker"}</li>
+                <li>{"Kerberos keytab parser"}</li>
+                <li>{"Kerberos credential cache (ccache) parser with kirbi export"}</li>
+                <li>{"SPNEGO (HTTP Negotiate) token parser"}</li>
+                <li>{"NTLM messages parser with NTLMv2 response verification"}</li>
+                <li>{"TLS records and handshake messages parser"}</li>
+                <li>{"Schema-less protobuf decoder"}</li>
+                <li>{"CBOR (with COSE, CWT, and WebAuthn labeling) and MessagePack decoder"}</li>
+                <li>{"SSH signature (sshsig) verifier and host keys fingerprints"}</li>
+                <li>{"Windows DPAPI blob parser"}</li>
+                <li>{"HTTP Message Signatures (RFC 9421) signer and verifier"}</li>
+                <li>{"Diff checker (text and bytes)"}</li>
+                <li>{"Timestamp converter: Unix, Windows FILETIME, LDAP/AD, GeneralizedTime, KerberosTime"}</li>
                 <li>{"Ability to share the sample by url"}</li>
+                <li>{"QR code generation for outputs"}</li>
             </ul>
             <span>{"All computations are performed on the client side."}</span>
             <ul>
@@ -1,24 +1,67 @@
 #[macro_use]
 mod macros;
 
+mod annotations;
 mod asn1_viewer;
+mod breadcrumbs;
+mod collapse;
+mod decode_selection;
+mod der_warnings;
+mod diff_view;
+mod editor;
 mod hex_view;
+mod history;
+mod input;
+mod jump_to_offset;
+mod node_details;
+mod node_menu;
 mod node_options;
+mod schema_validation;
 mod scheme;
+mod search;
+mod tabs;
+mod virtual_tree;
+mod x509;
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::rc::Rc;
 
-use asn1_parser::{Asn1, Asn1Decoder, Asn1Encoder};
-use web_sys::KeyboardEvent;
-use yew::{classes, function_component, html, use_effect_with, use_reducer, use_state, Callback, Html, Reducible};
+use asn1_parser::{Asn1, Asn1Decoder, Asn1Encoder, DecodeProfile, Pem};
+use web_sys::{DragEvent, Element, HtmlInputElement, KeyboardEvent};
+use yew::html::onchange::Event;
+use yew::platform::spawn_local;
+use yew::virtual_dom::VNode;
+use yew::{
+    classes, function_component, html, use_effect_with, use_memo, use_node_ref, use_reducer, use_state, Callback, Html,
+    NodeRef, Properties, Reducible, TargetCast,
+};
 use yew_hooks::{use_clipboard, use_local_storage, use_location};
 use yew_notifications::{use_notification, Notification, NotificationType};
 
-use crate::asn1::asn1_viewer::Asn1Viewer;
+use crate::asn1::annotations::{decode_notes, encode_notes, set_note, Annotations, ASN1_NOTES_LOCAL_STORAGE_KEY};
+use crate::asn1::asn1_viewer::{Asn1Viewer, HoveredNode};
+use crate::asn1::breadcrumbs::Asn1Breadcrumbs;
+use crate::asn1::collapse::{ancestors, collapsible_nodes, CollapseState};
+use crate::asn1::decode_selection::Asn1SelectionTree;
+use crate::asn1::der_warnings::Asn1DerWarnings;
+use crate::asn1::diff_view::Asn1DiffView;
+use crate::asn1::editor::{apply_edit, Asn1Edit};
 use crate::asn1::hex_view::HexViewer;
-use crate::common::{encode_bytes, ByteInput, BytesFormat};
+use crate::asn1::history::{
+    decode_history, encode_history, record_history, Asn1History, HistoryEntry, ASN1_HISTORY_LOCAL_STORAGE_KEY,
+};
+use crate::asn1::input::Asn1Input;
+use crate::asn1::jump_to_offset::Asn1JumpToOffset;
+use crate::asn1::node_details::Asn1NodeDetails;
+use crate::asn1::schema_validation::Asn1SchemaValidation;
+use crate::asn1::search::{search, Asn1Search, SearchMatches};
+use crate::asn1::tabs::{Asn1Tabs, PRIMARY_TAB};
+use crate::asn1::virtual_tree::{VirtualTree, VIRTUAL_TREE_THRESHOLD};
+use crate::asn1::x509::{field_labels, named_bits, FieldLabels, NamedBits};
+use crate::common::{encode_bytes, read_file, BytesFormat, FileInput};
 use crate::url_query_params;
-use crate::url_query_params::generate_asn1_link;
+use crate::url_query_params::generate_asn1_link_with_node;
+use crate::utils::download_bytes;
 
 pub const TEST_ASN1: &[u8] = &[
     48, 87, 1, 1, 255, 1, 1, 0, 160, 17, 12, 15, 84, 98, 101, 66, 101, 115, 116, 84, 118, 97, 114, 121, 110, 107, 97,
@@ -28,18 +71,84 @@ pub const TEST_ASN1: &[u8] = &[
 ];
 const ASN1_LOCAL_STORAGE_KEY: &str = "ASN1_DATA";
 
+const DECODE_PROFILES: &[(DecodeProfile, &str)] = &[
+    (DecodeProfile::Der, "DER"),
+    (DecodeProfile::Cer, "CER"),
+    (DecodeProfile::Ber, "BER"),
+];
+
+/// Labels offered for the PEM encoding of the structure.
+pub const PEM_LABELS: &[&str] = &[
+    "CERTIFICATE",
+    "CERTIFICATE REQUEST",
+    "X509 CRL",
+    "PKCS7",
+    "CMS",
+    "PRIVATE KEY",
+    "PUBLIC KEY",
+];
+
+fn profile_name(profile: DecodeProfile) -> &'static str {
+    DECODE_PROFILES
+        .iter()
+        .find(|(known, _)| *known == profile)
+        .map(|(_, name)| *name)
+        .unwrap_or("DER")
+}
+
+fn profile_by_name(name: &str) -> Option<DecodeProfile> {
+    DECODE_PROFILES
+        .iter()
+        .find(|(_, known)| *known == name)
+        .map(|(profile, _)| *profile)
+}
+
+fn render_profile_options(current_profile: DecodeProfile) -> Vec<VNode> {
+    DECODE_PROFILES
+        .iter()
+        .map(|(profile, name)| {
+            html! {
+                <option selected={current_profile == *profile} value={*name}>{*name}</option>
+            }
+        })
+        .collect()
+}
+
 pub fn compare_ids(asn1_node_id: u64, cur_node: &Option<u64>) -> bool {
     matches!(cur_node, Some(node_id) if *node_id == asn1_node_id)
 }
 
+/// Returns the id of the tree node element. It is used to scroll the tree to the selected node.
+pub fn asn1_node_element_id(asn1_node_id: u64) -> String {
+    format!("asn1-node-{}", asn1_node_id)
+}
+
+/// Scrolls the tree to the node with the provided id. The node is looked up inside the document element
+/// because every opened tab has its own tree.
+fn scroll_to_node(document: &NodeRef, asn1_node_id: u64) {
+    if let Some(node) = document.cast::<Element>().and_then(|document| {
+        document
+            .query_selector(&format!("#{}", asn1_node_element_id(asn1_node_id)))
+            .ok()
+            .flatten()
+    }) {
+        node.scroll_into_view();
+    }
+}
+
 pub enum HighlightAction {
     Show(u64),
     Hide(u64),
+    /// Selects the node or clears the selection if the node is already selected.
+    Select(u64),
+    /// Selects the node.
+    Focus(u64),
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct Highlight {
     nodes: Vec<u64>,
+    selected: Option<u64>,
 }
 
 impl Highlight {
@@ -54,7 +163,21 @@ impl Highlight {
         }
     }
 
+    fn select(&mut self, id: u64) {
+        self.selected = if self.selected == Some(id) { None } else { Some(id) };
+    }
+
+    fn focus(&mut self, id: u64) {
+        self.selected = Some(id);
+    }
+
+    /// Returns the hovered node or the selected one when nothing is hovered.
     fn current(&self) -> Option<u64> {
+        self.hovered().or(self.selected)
+    }
+
+    /// Returns the innermost hovered node.
+    fn hovered(&self) -> Option<u64> {
         self.nodes.last().copied()
     }
 }
@@ -67,23 +190,73 @@ impl Reducible for Highlight {
         match action {
             HighlightAction::Show(id) => highlight.show(id),
             HighlightAction::Hide(id) => highlight.hide(id),
+            HighlightAction::Select(id) => highlight.select(id),
+            HighlightAction::Focus(id) => highlight.focus(id),
         }
         Rc::new(highlight)
     }
 }
 
-#[function_component(Asn1ParserPage)]
-pub fn asn1_parser_page() -> Html {
+#[derive(PartialEq, Properties, Clone)]
+struct Asn1DocumentProps {
+    /// The primary document is loaded from the URL or the local storage and is saved to the local storage.
+    primary: bool,
+    history: Vec<HistoryEntry>,
+    record_history: Callback<Vec<u8>>,
+    clear_history: Callback<()>,
+}
+
+#[function_component(Asn1Document)]
+fn asn1_document(props: &Asn1DocumentProps) -> Html {
     let notification_manager = use_notification::<Notification>();
 
     let raw_asn1 = use_state(|| TEST_ASN1.to_vec());
-    let parsed_asn1 = use_state(|| Asn1::decode_buff(TEST_ASN1).unwrap());
+    let parsed_asn1 = use_state(|| Asn1::decode_buff_with_profile(TEST_ASN1, DecodeProfile::Der).unwrap());
+    let profile = use_state(|| DecodeProfile::Der);
+    let ctx = use_reducer(Highlight::default);
+    let notes = use_state(|| Rc::new(BTreeMap::<u64, String>::new()));
+
+    let document_ref = use_node_ref();
+    let scroll_target = use_state(|| None::<u64>);
+    let scroll_document = document_ref.clone();
+    use_effect_with(*scroll_target, move |scroll_target| {
+        if let Some(id) = scroll_target {
+            scroll_to_node(&scroll_document, *id);
+        }
+    });
+
+    let profile_setter = profile.setter();
+    let asn1_setter = parsed_asn1.setter();
+    let raw_data = (*raw_asn1).clone();
+    let on_profile_change = Callback::from(move |event: Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        let Some(profile) = profile_by_name(&input.value()) else {
+            return;
+        };
+        profile_setter.set(profile);
+
+        // The current data is decoded again, so DER violations are shown (or hidden in the BER mode) right away.
+        if let Ok(asn1) = Asn1::decode_buff_lenient(&raw_data, profile) {
+            asn1_setter.set(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned()));
+        }
+    });
 
     let notifications = use_notification::<Notification>();
     let asn1_setter = parsed_asn1.setter();
     let raw_data = (*raw_asn1).clone();
-    let parse_asn1 = Callback::from(move |_| match Asn1::decode_buff(&raw_data) {
-        Ok(asn1) => asn1_setter.set(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned())),
+    let decode_profile = *profile;
+    let record = props.record_history.clone();
+    let notes_setter = notes.setter();
+    let decoded_data = parsed_asn1.meta().raw_bytes().to_vec();
+    let parse_asn1 = Callback::from(move |_| match Asn1::decode_buff_lenient(&raw_data, decode_profile) {
+        Ok(asn1) => {
+            // Notes belong to the nodes of the previous data.
+            if decoded_data != raw_data {
+                notes_setter.set(Default::default());
+            }
+            asn1_setter.set(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned()));
+            record.emit(raw_data.clone());
+        }
         Err(error) => notifications.spawn(Notification::new(
             NotificationType::Error,
             "Invalid asn1 data",
@@ -92,6 +265,97 @@ pub fn asn1_parser_page() -> Html {
         )),
     });
 
+    let notifications = notification_manager.clone();
+    let asn1_setter = parsed_asn1.setter();
+    let raw_asn1_setter = raw_asn1.setter();
+    let decode_profile = *profile;
+    let record = props.record_history.clone();
+    let notes_setter = notes.setter();
+    let on_file = Callback::from(move |data: Vec<u8>| {
+        // PEM files (.cer, .p7b) are often text, so the DER bytes are extracted from them first.
+        let data = match std::str::from_utf8(&data).ok().filter(|text| Pem::is_pem(text)) {
+            Some(text) => match Pem::decode(text) {
+                Ok(pem) => pem.into_data(),
+                Err(error) => {
+                    notifications.spawn(Notification::new(
+                        NotificationType::Error,
+                        "Invalid PEM file",
+                        error.message(),
+                        Notification::NOTIFICATION_LIFETIME,
+                    ));
+                    return;
+                }
+            },
+            None => data,
+        };
+
+        match Asn1::decode_buff_lenient(&data, decode_profile) {
+            Ok(asn1) => {
+                notes_setter.set(Default::default());
+                asn1_setter.set(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned()));
+                record.emit(data.clone());
+            }
+            Err(error) => notifications.spawn(Notification::new(
+                NotificationType::Error,
+                "Invalid asn1 data",
+                error.message(),
+                Notification::NOTIFICATION_LIFETIME,
+            )),
+        }
+        raw_asn1_setter.set(data);
+    });
+
+    let notifications = notification_manager.clone();
+    let asn1_setter = parsed_asn1.setter();
+    let raw_asn1_setter = raw_asn1.setter();
+    let decode_profile = *profile;
+    let notes_setter = notes.setter();
+    let load_history_entry = Callback::from(move |data: Vec<u8>| {
+        match Asn1::decode_buff_lenient(&data, decode_profile) {
+            Ok(asn1) => {
+                notes_setter.set(Default::default());
+                asn1_setter.set(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned()));
+            }
+            Err(error) => notifications.spawn(Notification::new(
+                NotificationType::Error,
+                "Invalid asn1 data",
+                error.message(),
+                Notification::NOTIFICATION_LIFETIME,
+            )),
+        }
+        raw_asn1_setter.set(data);
+    });
+
+    let dragging = use_state(|| false);
+    let dragging_setter = dragging.setter();
+    let ondragover = Callback::from(move |event: DragEvent| {
+        // The drop event is fired only when the default handling of the dragover is prevented.
+        event.prevent_default();
+        dragging_setter.set(true);
+    });
+    let dragging_setter = dragging.setter();
+    let ondragleave = Callback::from(move |_: DragEvent| dragging_setter.set(false));
+    let dragging_setter = dragging.setter();
+    let on_dropped_file = on_file.clone();
+    let ondrop = Callback::from(move |event: DragEvent| {
+        event.prevent_default();
+        dragging_setter.set(false);
+
+        if let Some(file) = event
+            .data_transfer()
+            .and_then(|data_transfer| data_transfer.files())
+            .and_then(|files| files.get(0))
+        {
+            let on_file = on_dropped_file.clone();
+            spawn_local(async move {
+                match read_file(file).await {
+                    Ok(data) => on_file.emit(data),
+                    Err(err) => error!("{}", err),
+                }
+            });
+        }
+    });
+
     let process = parse_asn1.clone();
     let onkeydown = Callback::from(move |event: KeyboardEvent| {
         if event.ctrl_key() && event.code() == "Enter" {
@@ -107,15 +371,25 @@ pub fn asn1_parser_page() -> Html {
     let notifications = notification_manager.clone();
     let raw_asn1_setter = raw_asn1.setter();
     let asn1_setter = parsed_asn1.setter();
+    let url_dispatcher = ctx.dispatcher();
+    let scroll_target_setter = scroll_target.setter();
+    let profile_setter = profile.setter();
+    let notes_setter = notes.setter();
     let local_storage = use_local_storage::<String>(ASN1_LOCAL_STORAGE_KEY.to_owned());
+    let notes_local_storage = use_local_storage::<String>(ASN1_NOTES_LOCAL_STORAGE_KEY.to_owned());
+    let primary = props.primary;
     use_effect_with([], move |_: &[(); 0]| {
+        if !primary {
+            return;
+        }
         let query = &location.search;
 
         if query.len() < 2 {
             // URL query params is empty. We try to load ASN1 from local storage.
             if let Some(raw_asn1) = (*local_storage).as_ref() {
                 if let Ok(bytes) = hex::decode(raw_asn1) {
-                    match Asn1::decode_buff(&bytes) {
+                    // The stored data is re-encoded by the app, so it is decoded using the most permissive rules.
+                    match Asn1::decode_buff_with_profile(&bytes, DecodeProfile::Ber) {
                         Ok(asn1) => {
                             asn1_setter.set(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned()));
                         }
@@ -124,17 +398,31 @@ pub fn asn1_parser_page() -> Html {
                         }
                     }
                     raw_asn1_setter.set(bytes);
+                    if let Some(notes) = (*notes_local_storage).as_deref() {
+                        notes_setter.set(Rc::new(decode_notes(notes)));
+                    }
                 }
             }
             return;
         }
 
-        match serde_qs::from_str(&query[1..]) {
-            Ok(asn1) => {
-                let url_query_params::Asn1 { asn1: asn1_data } = asn1;
-                match Asn1::decode_buff(&asn1_data) {
+        match serde_qs::from_str::<url_query_params::Asn1>(&query[1..])
+            .map_err(|err| err.to_string())
+            .and_then(|asn1| Ok((asn1.bytes()?, asn1.node, asn1.profile, asn1.notes)))
+        {
+            Ok((asn1_data, node, profile, notes)) => {
+                let profile = profile.as_deref().and_then(profile_by_name).unwrap_or_default();
+                profile_setter.set(profile);
+                notes_setter.set(Rc::new(notes.as_deref().map(decode_notes).unwrap_or_default()));
+
+                // Undecodable parts of the shared data are shown as error nodes.
+                match Asn1::decode_buff_lenient(&asn1_data, profile) {
                     Ok(asn1) => {
                         asn1_setter.set(asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned()));
+                        if let Some(node) = node {
+                            url_dispatcher.dispatch(HighlightAction::Focus(node));
+                            scroll_target_setter.set(Some(node));
+                        }
                     }
                     Err(error) => notifications.spawn(Notification::new(
                         NotificationType::Error,
@@ -148,7 +436,7 @@ pub fn asn1_parser_page() -> Html {
             Err(err) => notifications.spawn(Notification::new(
                 NotificationType::Error,
                 "Can not load data from url",
-                err.to_string(),
+                err,
                 Notification::NOTIFICATION_LIFETIME,
             )),
         }
@@ -156,15 +444,93 @@ pub fn asn1_parser_page() -> Html {
 
     let local_storage = use_local_storage::<String>(ASN1_LOCAL_STORAGE_KEY.to_owned());
     use_effect_with(parsed_asn1.clone(), move |asn1| {
-        let mut encoded = vec![0; asn1.needed_buf_size()];
-        asn1.encode_buff(&mut encoded).expect("ASN1 encoding should not fail");
+        if !primary {
+            return;
+        }
+        let encoded = asn1.encode_to_vec().expect("ASN1 encoding should not fail");
         local_storage.set(encode_bytes(encoded, BytesFormat::Hex));
     });
 
+    let local_storage = use_local_storage::<String>(ASN1_NOTES_LOCAL_STORAGE_KEY.to_owned());
+    use_effect_with(notes.clone(), move |notes| {
+        if primary {
+            local_storage.set(encode_notes(notes));
+        }
+    });
+
+    let notes_setter = notes.setter();
+    let current_notes = (*notes).clone();
+    let annotations = Annotations {
+        notes: (*notes).clone(),
+        set: Callback::from(move |(id, note)| notes_setter.set(Rc::new(set_note(&current_notes, id, note)))),
+    };
+
     let clipboard = use_clipboard();
+    let json_clipboard = clipboard.clone();
+    let json_notifications = notification_manager.clone();
+    let asn1_to_export = parsed_asn1.clone();
+    let copy_json = Callback::from(move |_| {
+        json_clipboard.write_text(asn1_to_export.to_json().to_string());
+
+        json_notifications.spawn(Notification::from_description_and_type(
+            NotificationType::Info,
+            "JSON copied",
+        ));
+    });
+
+    let json_notifications = notification_manager.clone();
+    let asn1_to_export = parsed_asn1.clone();
+    let export_json = Callback::from(move |_| {
+        let result = serde_json::to_string_pretty(&asn1_to_export.to_json())
+            .map_err(|err| err.to_string())
+            .and_then(|json| download_bytes("asn1.json", "application/json", json.as_bytes()));
+        if let Err(err) = result {
+            json_notifications.spawn(Notification::new(
+                NotificationType::Error,
+                "Can not export asn1 as JSON",
+                err,
+                Notification::NOTIFICATION_LIFETIME,
+            ));
+        }
+    });
+
+    let dump_clipboard = clipboard.clone();
+    let dump_notifications = notification_manager.clone();
+    let asn1_to_export = parsed_asn1.clone();
+    let copy_dump = Callback::from(move |_| {
+        dump_clipboard.write_text(asn1_to_export.to_text_dump());
+
+        dump_notifications.spawn(Notification::from_description_and_type(
+            NotificationType::Info,
+            "Text dump copied",
+        ));
+    });
+
+    let dump_notifications = notification_manager.clone();
+    let asn1_to_export = parsed_asn1.clone();
+    let export_dump = Callback::from(move |_| {
+        let dump = asn1_to_export.to_text_dump();
+        if let Err(err) = download_bytes("asn1.txt", "text/plain", dump.as_bytes()) {
+            dump_notifications.spawn(Notification::new(
+                NotificationType::Error,
+                "Can not export asn1 as text dump",
+                err,
+                Notification::NOTIFICATION_LIFETIME,
+            ));
+        }
+    });
+
     let raw_asn1_data = (*raw_asn1).clone();
+    let selected_node = ctx.selected;
+    let share_profile = profile_name(*profile);
+    let share_notes = (!notes.is_empty()).then(|| encode_notes(&notes));
     let share_by_link = Callback::from(move |_| {
-        clipboard.write_text(generate_asn1_link(raw_asn1_data.clone()));
+        clipboard.write_text(generate_asn1_link_with_node(
+            &raw_asn1_data,
+            selected_node,
+            Some(share_profile),
+            share_notes.clone(),
+        ));
 
         notification_manager.spawn(Notification::from_description_and_type(
             NotificationType::Info,
@@ -172,41 +538,337 @@ pub fn asn1_parser_page() -> Html {
         ));
     });
 
+    let pem_label = use_state(|| PEM_LABELS[0]);
+    let pem_label_setter = pem_label.setter();
+    let on_pem_label_change = Callback::from(move |event: Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        if let Some(label) = PEM_LABELS.iter().find(|label| **label == input.value()) {
+            pem_label_setter.set(*label);
+        }
+    });
+
+    let download_notifications = notification_manager.clone();
+    let asn1_to_download = parsed_asn1.clone();
+    let download = move |pem_label: Option<&'static str>| {
+        let notifications = download_notifications.clone();
+        let asn1 = asn1_to_download.clone();
+        Callback::from(move |_| {
+            let result = asn1
+                .encode_to_vec()
+                .map_err(|err| err.message().to_owned())
+                .and_then(|der| match pem_label {
+                    Some(label) => download_bytes(
+                        "asn1.pem",
+                        "application/x-pem-file",
+                        Pem::new(label, der).encode().as_bytes(),
+                    ),
+                    None => download_bytes("asn1.der", "application/octet-stream", &der),
+                });
+            if let Err(err) = result {
+                notifications.spawn(Notification::new(
+                    NotificationType::Error,
+                    "Can not download asn1",
+                    err,
+                    Notification::NOTIFICATION_LIFETIME,
+                ));
+            }
+        })
+    };
+    let download_der = download(None);
+    let download_pem = download(Some(*pem_label));
+
+    let notifications = use_notification::<Notification>();
+    let edited_asn1 = parsed_asn1.clone();
+    let raw_asn1_setter = raw_asn1.setter();
+    let on_edit = Callback::from(move |edit: Asn1Edit| {
+        let mut asn1 = (*edited_asn1).clone();
+
+        if let Err(error) = apply_edit(&mut asn1, edit) {
+            notifications.spawn(Notification::from_description_and_type(NotificationType::Error, error));
+            return;
+        }
+
+        match asn1.recompute() {
+            Ok(asn1) => {
+                raw_asn1_setter.set(asn1.meta().raw_bytes().to_vec());
+                edited_asn1.set(asn1);
+            }
+            Err(error) => notifications.spawn(Notification::new(
+                NotificationType::Error,
+                "Can not encode the edited asn1",
+                error.message(),
+                Notification::NOTIFICATION_LIFETIME,
+            )),
+        }
+    });
+
     let raw_asn1_setter = raw_asn1.setter();
 
-    let ctx = use_reducer(Highlight::default);
     let asn1_dispatcher = ctx.dispatcher();
-    let hex_dispatcher = ctx.dispatcher();
+
+    let collapsible = use_memo(parsed_asn1.clone(), |asn1| collapsible_nodes(asn1));
+    let collapsed = use_state(|| Rc::new(BTreeSet::new()));
+    let collapsed_setter = collapsed.setter();
+    // Node ids of the new tree have nothing in common with the collapsed ones.
+    use_effect_with(parsed_asn1.clone(), move |_| {
+        collapsed_setter.set(Rc::new(BTreeSet::new()))
+    });
+
+    let toggle_collapsed = collapsed.clone();
+    let collapse = CollapseState {
+        collapsible: collapsible.clone(),
+        collapsed: (*collapsed).clone(),
+        toggle: Callback::from(move |id| {
+            let mut nodes = (**toggle_collapsed).clone();
+            if !nodes.remove(&id) {
+                nodes.insert(id);
+            }
+            toggle_collapsed.set(Rc::new(nodes));
+        }),
+    };
+    let collapsed_setter = collapsed.setter();
+    let expand_all = Callback::from(move |_| collapsed_setter.set(Rc::new(BTreeSet::new())));
+    let collapsed_setter = collapsed.setter();
+    let collapse_all = Callback::from(move |_| collapsed_setter.set(collapsible.clone()));
+
+    // Nodes selected outside the tree are expanded and scrolled into view.
+    let revealed_asn1 = parsed_asn1.clone();
+    let revealed_collapsed = collapsed.clone();
+    let scroll_target_setter = scroll_target.setter();
+    let reveal_dispatcher = ctx.dispatcher();
+    let reveal_node = Callback::from(move |action: HighlightAction| {
+        if let HighlightAction::Select(id) | HighlightAction::Focus(id) = action {
+            let parents = ancestors(&revealed_asn1, id);
+            if parents.iter().any(|parent| revealed_collapsed.contains(parent)) {
+                let mut nodes = (**revealed_collapsed).clone();
+                for parent in parents {
+                    nodes.remove(&parent);
+                }
+                revealed_collapsed.set(Rc::new(nodes));
+            }
+            scroll_target_setter.set(Some(id));
+        }
+        reveal_dispatcher.dispatch(action);
+    });
+
+    let search_query = use_state(String::new);
+    let search_matches = SearchMatches(use_memo(
+        (parsed_asn1.clone(), (*search_query).clone()),
+        |(asn1, query)| search(asn1, query),
+    ));
+    let search_query_setter = search_query.setter();
+
+    let field_labels = FieldLabels(use_memo(parsed_asn1.clone(), |asn1| field_labels(asn1)));
+    let named_bits = NamedBits(use_memo(parsed_asn1.clone(), |asn1| named_bits(asn1)));
+    let nodes_count = use_memo(parsed_asn1.clone(), |asn1| asn1.iter().count());
+    // The virtual tree is rendered on every scroll, so it receives the shared structure instead of a deep copy.
+    let shared_asn1 = use_memo(parsed_asn1.clone(), |asn1| (**asn1).clone());
+
+    let decoded_selection = use_state(|| None::<(usize, Vec<u8>)>);
+    let decoded_selection_setter = decoded_selection.setter();
+    let on_decode_selection = Callback::from(move |selection| decoded_selection_setter.set(Some(selection)));
+    let selection_tree = match &*decoded_selection {
+        Some((offset, bytes)) => {
+            let decoded_selection_setter = decoded_selection.setter();
+            html! {
+                <Asn1SelectionTree
+                    key={format!("{}-{}", offset, bytes.len())}
+                    offset={*offset}
+                    bytes={bytes.clone()}
+                    profile={*profile}
+                    on_close={Callback::from(move |_| decoded_selection_setter.set(None))}
+                />
+            }
+        }
+        None => html! {},
+    };
+
+    let diff_mode = use_state(|| false);
+    let diff_mode_setter = diff_mode.setter();
+    let diff_mode_enabled = *diff_mode;
+    let toggle_diff_mode = Callback::from(move |_| diff_mode_setter.set(!diff_mode_enabled));
 
     html! {
-        <div class={classes!("vertical", "asn1-page")} {onkeydown}>
-            <span>
-                {"Still in β (beta). See "}
-                    <a href="https://github.com/TheBestTvarynka/crypto-helper/tree/main/crates/asn1-parser#supported-asn1-types" class="a-link">
-                        {"list of supported asn1 types"}
-                    </a>
-                {". Report a bug/feature "}<a href="https://github.com/TheBestTvarynka/crypto-helper/issues/new" class="a-link">{"here"}</a>{"."}
-            </span>
-            <ByteInput bytes={(*raw_asn1).clone()} setter={Callback::from(move |data| raw_asn1_setter.set(data))} placeholder={"asn1 data".to_owned()} rows={10} />
+        <div
+            class={classes!("vertical", "asn1-document", (*dragging).then_some("asn1-drop-target"))}
+            ref={document_ref}
+            {onkeydown}
+            {ondragover}
+            {ondragleave}
+            {ondrop}
+        >
+            <div class="horizontal">
+                <span>{"Upload file:"}</span>
+                <FileInput setter={on_file} accept=".der,.cer,.crt,.pem,.p7b,.p7c,.p7s,.p12,.pfx,.csr,.crl" />
+            </div>
+            <Asn1Input bytes={(*raw_asn1).clone()} setter={Callback::from(move |data| raw_asn1_setter.set(data))} />
+            <Asn1History history={props.history.clone()} load={load_history_entry} clear={props.clear_history.clone()} />
             <div class="horizontal">
                 <button class="action-button" {onclick}>{"Decode"}</button>
                 <span class="total">{"(ctrl+enter)"}</span>
+                <div>
+                    <select class="base-input" title="DER flags non-canonical encodings, BER accepts indefinite lengths and non-minimal encodings" onchange={on_profile_change}>
+                        {render_profile_options(*profile)}
+                    </select>
+                </div>
+                <button class="action-button" onclick={export_json}>{"Export JSON"}</button>
+                <button class="action-button" onclick={copy_json}>{"Copy JSON"}</button>
+                <button class="action-button" onclick={export_dump}>{"Export dump"}</button>
+                <button class="action-button" onclick={copy_dump}>{"Copy dump"}</button>
+                <button class="action-button" onclick={download_der}>{"Download DER"}</button>
+                <div>
+                    <select class="base-input" onchange={on_pem_label_change}>
+                        {PEM_LABELS.iter().map(|label| html! {
+                            <option selected={*pem_label == *label} value={*label}>{*label}</option>
+                        }).collect::<Html>()}
+                    </select>
+                </div>
+                <button class="action-button" onclick={download_pem}>{"Download PEM"}</button>
+                <button class="action-button" onclick={toggle_diff_mode}>{if *diff_mode { "Close diff" } else { "Diff" }}</button>
                 <button class="button-with-icon" onclick={share_by_link}>
                     <img src="/public/img/icons/share_by_link.png" />
                 </button>
             </div>
-            <div class="asn1-viewers">
-                <Asn1Viewer
-                    structure={(*parsed_asn1).clone()}
-                    cur_node={(*ctx).current()}
-                    set_cur_node={move |action| asn1_dispatcher.dispatch(action)}
-                />
-                <HexViewer
-                    structure={(*parsed_asn1).clone()}
-                    cur_node={(*ctx).current()}
-                    set_cur_node={move |action| hex_dispatcher.dispatch(action)}
-                />
-            </div>
+            {if *diff_mode {html! {
+                <Asn1DiffView bytes={(*raw_asn1).clone()} profile={*profile} />
+            }} else {html! {
+                <>
+                    <Asn1Search
+                        query={(*search_query).clone()}
+                        set_query={Callback::from(move |query| search_query_setter.set(query))}
+                        matches={search_matches.clone()}
+                        set_cur_node={reveal_node.clone()}
+                    />
+                    <Asn1JumpToOffset structure={(*parsed_asn1).clone()} set_cur_node={reveal_node.clone()} />
+                    <Asn1SchemaValidation structure={(*parsed_asn1).clone()} set_cur_node={reveal_node.clone()} />
+                    <Asn1DerWarnings structure={(*parsed_asn1).clone()} set_cur_node={reveal_node.clone()} />
+                    <div class="horizontal">
+                        <button class="jwt-util-button" onclick={expand_all}>{"Expand all"}</button>
+                        <button class="jwt-util-button" onclick={collapse_all}>{"Collapse all"}</button>
+                    </div>
+                    <Asn1Breadcrumbs
+                        structure={(*parsed_asn1).clone()}
+                        selected={ctx.selected}
+                        set_cur_node={reveal_node.clone()}
+                    />
+                    <div class="asn1-viewers">
+                        {if *nodes_count > VIRTUAL_TREE_THRESHOLD {html! {
+                            <VirtualTree
+                                structure={shared_asn1.clone()}
+                                cur_node={(*ctx).current()}
+                                set_cur_node={reveal_node.clone()}
+                                {search_matches}
+                                {collapse}
+                                scroll_target={*scroll_target}
+                                {field_labels}
+                                {annotations}
+                                {named_bits}
+                            />
+                        }} else {html! {
+                            <Asn1Viewer
+                                structure={(*parsed_asn1).clone()}
+                                cur_node={ctx.selected}
+                                hovered_node={HoveredNode(ctx.hovered())}
+                                set_cur_node={move |action| asn1_dispatcher.dispatch(action)}
+                                {on_edit}
+                                {search_matches}
+                                {collapse}
+                                {field_labels}
+                                {annotations}
+                                {named_bits}
+                            />
+                        }}}
+                        <div class="vertical">
+                            <HexViewer
+                                structure={(*parsed_asn1).clone()}
+                                cur_node={ctx.selected}
+                                hovered_node={ctx.hovered()}
+                                set_cur_node={reveal_node}
+                                {on_decode_selection}
+                            />
+                            <Asn1NodeDetails structure={(*parsed_asn1).clone()} selected={ctx.selected} />
+                            {selection_tree}
+                        </div>
+                    </div>
+                </>
+            }}}
+        </div>
+    }
+}
+
+#[function_component(Asn1ParserPage)]
+pub fn asn1_parser_page() -> Html {
+    let tabs = use_state(|| vec![PRIMARY_TAB]);
+    let active = use_state(|| PRIMARY_TAB);
+    let next_tab = use_state(|| PRIMARY_TAB + 1);
+
+    let active_setter = active.setter();
+    let select = Callback::from(move |id| active_setter.set(id));
+
+    let history_storage = use_local_storage::<String>(ASN1_HISTORY_LOCAL_STORAGE_KEY.to_owned());
+    let history = use_state(|| (*history_storage).as_deref().map(decode_history).unwrap_or_default());
+    use_effect_with(history.clone(), move |history| {
+        history_storage.set(encode_history(history));
+    });
+
+    let history_setter = history.setter();
+    let recorded = (*history).clone();
+    let record = Callback::from(move |data: Vec<u8>| {
+        history_setter.set(record_history(&recorded, &data));
+    });
+    let history_setter = history.setter();
+    let clear_history = Callback::from(move |_| history_setter.set(Vec::new()));
+
+    let tabs_setter = tabs.setter();
+    let active_setter = active.setter();
+    let next_tab_setter = next_tab.setter();
+    let opened_tabs = (*tabs).clone();
+    let new_tab = *next_tab;
+    let add = Callback::from(move |_| {
+        let mut opened_tabs = opened_tabs.clone();
+        opened_tabs.push(new_tab);
+        tabs_setter.set(opened_tabs);
+        active_setter.set(new_tab);
+        next_tab_setter.set(new_tab + 1);
+    });
+
+    let tabs_setter = tabs.setter();
+    let active_setter = active.setter();
+    let opened_tabs = (*tabs).clone();
+    let active_tab = *active;
+    let close = Callback::from(move |id| {
+        let Some(index) = opened_tabs.iter().position(|tab| *tab == id) else {
+            return;
+        };
+        let mut opened_tabs = opened_tabs.clone();
+        opened_tabs.remove(index);
+        if active_tab == id {
+            active_setter.set(opened_tabs[index - 1]);
+        }
+        tabs_setter.set(opened_tabs);
+    });
+
+    html! {
+        <div class={classes!("vertical", "asn1-page")}>
+            <span>
+                {"Still in β (beta). See "}
+                    <a href="https://github.com/TheBestTvarynka/crypto-helper/tree/main/crates/asn1-parser#supported-asn1-types" class="a-link">
+                        {"list of supported asn1 types"}
+                    </a>
+                {". Report a bug/feature "}<a href="https://github.com/TheBestTvarynka/crypto-helper/issues/new" class="a-link">{"here"}</a>{"."}
+            </span>
+            <Asn1Tabs tabs={(*tabs).clone()} active={*active} {select} {add} {close} />
+            // Inactive documents are hidden instead of unmounted, so every tab keeps its input, tree, and selection.
+            {tabs.iter().map(|id| html! {
+                <div key={*id} hidden={*id != *active}>
+                    <Asn1Document
+                        primary={*id == PRIMARY_TAB}
+                        history={(*history).clone()}
+                        record_history={record.clone()}
+                        clear_history={clear_history.clone()}
+                    />
+                </div>
+            }).collect::<Html>()}
         </div>
     }
 }
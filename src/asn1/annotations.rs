@@ -0,0 +1,44 @@
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use yew::Callback;
+
+pub const ASN1_NOTES_LOCAL_STORAGE_KEY: &str = "ASN1_NOTES";
+
+/// User notes attached to the tree nodes.
+#[derive(Default, PartialEq, Clone)]
+pub struct Annotations {
+    /// Notes keyed by the node id.
+    pub notes: Rc<BTreeMap<u64, String>>,
+    /// Sets the note of the node. An empty note removes it.
+    pub set: Callback<(u64, String)>,
+}
+
+impl Annotations {
+    pub fn get(&self, id: u64) -> Option<&str> {
+        self.notes.get(&id).map(String::as_str)
+    }
+}
+
+/// Returns notes with the provided note set or removed if it is empty.
+pub fn set_note(notes: &BTreeMap<u64, String>, id: u64, note: String) -> BTreeMap<u64, String> {
+    let mut notes = notes.clone();
+    let note = note.trim();
+
+    if note.is_empty() {
+        notes.remove(&id);
+    } else {
+        notes.insert(id, note.to_owned());
+    }
+
+    notes
+}
+
+/// Notes are stored in the local storage and share links as JSON.
+pub fn decode_notes(raw: &str) -> BTreeMap<u64, String> {
+    serde_json::from_str(raw).unwrap_or_default()
+}
+
+pub fn encode_notes(notes: &BTreeMap<u64, String>) -> String {
+    serde_json::to_string(notes).expect("Notes serialization should not fail")
+}
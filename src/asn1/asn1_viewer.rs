@@ -1,22 +1,66 @@
 use asn1_parser::OwnedAsn1;
-use yew::{function_component, html, Callback, Html, Properties};
+use yew::{function_component, html, Callback, ContextProvider, Html, Properties};
 
+use crate::asn1::annotations::Annotations;
+use crate::asn1::collapse::CollapseState;
+use crate::asn1::diff_view::DiffMarks;
+use crate::asn1::editor::Asn1Edit;
 use crate::asn1::scheme::build_asn1_schema;
+use crate::asn1::search::SearchMatches;
+use crate::asn1::x509::{FieldLabels, NamedBits};
 use crate::asn1::HighlightAction;
 
+/// The node under the cursor in the tree or the hex viewer. It is highlighted softer than the selected one.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct HoveredNode(pub Option<u64>);
+
+impl HoveredNode {
+    pub fn is(&self, id: u64) -> bool {
+        self.0 == Some(id)
+    }
+}
+
 #[derive(PartialEq, Properties, Clone)]
 pub struct Asn1ViewerProps {
     pub structure: OwnedAsn1,
 
     pub cur_node: Option<u64>,
     pub set_cur_node: Callback<HighlightAction>,
+    pub on_edit: Callback<Asn1Edit>,
+    pub search_matches: SearchMatches,
+    pub collapse: CollapseState,
+    pub field_labels: FieldLabels,
+    #[prop_or_default]
+    pub diff_marks: DiffMarks,
+    #[prop_or_default]
+    pub annotations: Annotations,
+    #[prop_or_default]
+    pub hovered_node: HoveredNode,
+    #[prop_or_default]
+    pub named_bits: NamedBits,
 }
 
 #[function_component(Asn1Viewer)]
 pub fn asn1_viewer(props: &Asn1ViewerProps) -> Html {
     html! {
-        <div>
-            {build_asn1_schema(&props.structure, &props.cur_node, &props.set_cur_node)}
-        </div>
+        <ContextProvider<Callback<Asn1Edit>> context={props.on_edit.clone()}>
+            <ContextProvider<SearchMatches> context={props.search_matches.clone()}>
+                <ContextProvider<CollapseState> context={props.collapse.clone()}>
+                    <ContextProvider<FieldLabels> context={props.field_labels.clone()}>
+                        <ContextProvider<DiffMarks> context={props.diff_marks.clone()}>
+                            <ContextProvider<Annotations> context={props.annotations.clone()}>
+                                <ContextProvider<HoveredNode> context={props.hovered_node}>
+                                    <ContextProvider<NamedBits> context={props.named_bits.clone()}>
+                                        <div>
+                                            {build_asn1_schema(&props.structure, &props.cur_node, &props.set_cur_node)}
+                                        </div>
+                                    </ContextProvider<NamedBits>>
+                                </ContextProvider<HoveredNode>>
+                            </ContextProvider<Annotations>>
+                        </ContextProvider<DiffMarks>>
+                    </ContextProvider<FieldLabels>>
+                </ContextProvider<CollapseState>>
+            </ContextProvider<SearchMatches>>
+        </ContextProvider<Callback<Asn1Edit>>>
     }
 }
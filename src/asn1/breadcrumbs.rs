@@ -0,0 +1,99 @@
+use asn1_parser::{Asn1, Asn1Type, OwnedAsn1};
+use yew::{function_component, html, Callback, Html, MouseEvent, Properties};
+use yew_hooks::use_clipboard;
+use yew_notifications::{use_notification, Notification};
+
+use crate::asn1::collapse::ancestors;
+use crate::asn1::HighlightAction;
+use crate::utils::copy_to_clipboard_with_notification;
+
+/// Returns the node type name in the ASN.1 notation, e.g. `OCTET STRING` or `[0]`.
+pub fn node_name(asn1: &Asn1<'_>) -> String {
+    match asn1.inner_asn1() {
+        Asn1Type::Sequence(_) => "SEQUENCE".into(),
+        Asn1Type::Set(_) => "SET".into(),
+        Asn1Type::OctetString(_) => "OCTET STRING".into(),
+        Asn1Type::Utf8String(_) => "UTF8String".into(),
+        Asn1Type::BitString(_) => "BIT STRING".into(),
+        Asn1Type::BmpString(_) => "BMPString".into(),
+        Asn1Type::UniversalString(_) => "UniversalString".into(),
+        Asn1Type::IA5String(_) => "IA5String".into(),
+        Asn1Type::PrintableString(_) => "PrintableString".into(),
+        Asn1Type::GeneralString(_) => "GeneralString".into(),
+        Asn1Type::GraphicString(_) => "GraphicString".into(),
+        Asn1Type::VideotexString(_) => "VideotexString".into(),
+        Asn1Type::NumericString(_) => "NumericString".into(),
+        Asn1Type::VisibleString(_) => "VisibleString".into(),
+        Asn1Type::UtcTime(_) => "UTCTime".into(),
+        Asn1Type::GeneralizedTime(_) => "GeneralizedTime".into(),
+        Asn1Type::Date(_) => "DATE".into(),
+        Asn1Type::TimeOfDay(_) => "TIME-OF-DAY".into(),
+        Asn1Type::DateTime(_) => "DATE-TIME".into(),
+        Asn1Type::Duration(_) => "DURATION".into(),
+        Asn1Type::External(_) => "EXTERNAL".into(),
+        Asn1Type::EmbeddedPdv(_) => "EMBEDDED PDV".into(),
+        Asn1Type::Bool(_) => "BOOLEAN".into(),
+        Asn1Type::Null(_) => "NULL".into(),
+        Asn1Type::Integer(_) => "INTEGER".into(),
+        Asn1Type::Enumerated(_) => "ENUMERATED".into(),
+        Asn1Type::Real(_) => "REAL".into(),
+        Asn1Type::ObjectIdentifier(_) => "OBJECT IDENTIFIER".into(),
+        Asn1Type::RelativeOid(_) => "RELATIVE-OID".into(),
+        Asn1Type::ExplicitTag(explicit) => format!("[{}]", explicit.tag_number()),
+        Asn1Type::ImplicitTag(implicit) => format!("[{}] IMPLICIT", implicit.tag_number()),
+        Asn1Type::ApplicationTag(application) => format!("[APPLICATION {}]", application.tag_number()),
+        Asn1Type::PrivateTag(private) => format!("[PRIVATE {}]", private.tag_number()),
+        Asn1Type::DecodeError(_) => "decode error".into(),
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct Asn1BreadcrumbsProps {
+    pub structure: OwnedAsn1,
+    pub selected: Option<u64>,
+    pub set_cur_node: Callback<HighlightAction>,
+}
+
+/// Path from the root to the selected node. Every crumb selects the corresponding ancestor.
+#[function_component(Asn1Breadcrumbs)]
+pub fn asn1_breadcrumbs(props: &Asn1BreadcrumbsProps) -> Html {
+    let clipboard = use_clipboard();
+    let notifications = use_notification::<Notification>();
+
+    let Some(selected) = props.selected else {
+        return html! {};
+    };
+    let mut path = ancestors(&props.structure, selected);
+    path.push(selected);
+
+    let crumbs = path
+        .into_iter()
+        .filter_map(|id| props.structure.find_by_id(id).map(|node| (id, node_name(node))))
+        .enumerate()
+        .map(|(index, (id, name))| {
+            let set_cur_node = props.set_cur_node.clone();
+            let onclick = Callback::from(move |_: MouseEvent| set_cur_node.emit(HighlightAction::Focus(id)));
+
+            html! {
+                <>
+                    {if index > 0 { html! { <span class="asn1-breadcrumb-separator">{"›"}</span> } } else { html! {} }}
+                    <span class="asn1-breadcrumb" {onclick}>{name}</span>
+                </>
+            }
+        })
+        .collect::<Html>();
+
+    let node_path = props
+        .structure
+        .path_of(selected)
+        .map(|path| path.to_string())
+        .unwrap_or_default();
+    let onclick = copy_to_clipboard_with_notification(node_path, clipboard, "Node path", notifications);
+
+    html! {
+        <div class="horizontal asn1-breadcrumbs">
+            {crumbs}
+            <button class="jwt-util-button" title="Copy the path that can be used in the jump box" {onclick}>{"Copy path"}</button>
+        </div>
+    }
+}
@@ -0,0 +1,49 @@
+use std::collections::BTreeSet;
+use std::rc::Rc;
+
+use asn1_parser::{Asn1, Asn1Entity};
+use yew::Callback;
+
+/// Collapse state of the tree nodes shared with the scheme components.
+#[derive(PartialEq, Clone)]
+pub struct CollapseState {
+    /// Ids of the nodes that have child nodes.
+    pub collapsible: Rc<BTreeSet<u64>>,
+    pub collapsed: Rc<BTreeSet<u64>>,
+    /// Collapses or expands the node with the provided id.
+    pub toggle: Callback<u64>,
+}
+
+impl CollapseState {
+    pub fn is_collapsible(&self, id: u64) -> bool {
+        self.collapsible.contains(&id)
+    }
+
+    pub fn is_collapsed(&self, id: u64) -> bool {
+        self.collapsed.contains(&id)
+    }
+}
+
+/// Returns ids of the nodes that have child nodes.
+pub fn collapsible_nodes(asn1: &Asn1<'_>) -> BTreeSet<u64> {
+    asn1.iter()
+        // The first node of the subtree iterator is the node itself.
+        .filter(|(_, node)| node.iter().nth(1).is_some())
+        .map(|(_, node)| node.id())
+        .collect()
+}
+
+/// Returns ids of all parents of the node with the provided id starting from the root.
+pub fn ancestors(asn1: &Asn1<'_>, id: u64) -> Vec<u64> {
+    let mut path = Vec::new();
+
+    for (depth, node) in asn1.iter() {
+        path.truncate(depth);
+        if node.id() == id {
+            return path;
+        }
+        path.push(node.id());
+    }
+
+    Vec::new()
+}
@@ -0,0 +1,83 @@
+use std::collections::BTreeSet;
+use std::rc::Rc;
+
+use asn1_parser::{Asn1Entity, DecodeProfile};
+use web_sys::MouseEvent;
+use yew::{function_component, html, use_memo, use_reducer, use_state, Callback, Html, Properties};
+use yew_notifications::{use_notification, Notification, NotificationType};
+
+use crate::asn1::asn1_viewer::Asn1Viewer;
+use crate::asn1::collapse::CollapseState;
+use crate::asn1::diff_view::decode;
+use crate::asn1::editor::{apply_edit, Asn1Edit};
+use crate::asn1::search::SearchMatches;
+use crate::asn1::x509::FieldLabels;
+use crate::asn1::Highlight;
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct Asn1SelectionTreeProps {
+    /// Offset of the selection in the main structure.
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+    pub profile: DecodeProfile,
+    pub on_close: Callback<()>,
+}
+
+/// Secondary tree of the bytes selected in the hex viewer decoded as a standalone structure.
+#[function_component(Asn1SelectionTree)]
+pub fn asn1_selection_tree(props: &Asn1SelectionTreeProps) -> Html {
+    let ctx = use_reducer(Highlight::default);
+    let notifications = use_notification::<Notification>();
+
+    let raw = use_state(|| props.bytes.clone());
+    let asn1 = use_memo(((*raw).clone(), props.profile), |(raw, profile)| decode(raw, *profile));
+
+    let raw_setter = raw.setter();
+    let edited_asn1 = asn1.clone();
+    let on_edit = Callback::from(move |edit: Asn1Edit| {
+        let Ok(asn1) = &*edited_asn1 else {
+            return;
+        };
+        let mut asn1 = asn1.clone();
+
+        match apply_edit(&mut asn1, edit).and_then(|_| asn1.recompute().map_err(|err| err.message().to_owned())) {
+            Ok(asn1) => raw_setter.set(asn1.meta().raw_bytes().to_vec()),
+            Err(error) => notifications.spawn(Notification::from_description_and_type(NotificationType::Error, error)),
+        }
+    });
+
+    let tree = match &*asn1 {
+        Ok(asn1) => {
+            let dispatcher = ctx.dispatcher();
+            html! {
+                <Asn1Viewer
+                    structure={asn1.clone()}
+                    cur_node={ctx.current()}
+                    set_cur_node={move |action| dispatcher.dispatch(action)}
+                    {on_edit}
+                    search_matches={SearchMatches::default()}
+                    collapse={CollapseState {
+                        collapsible: Rc::new(BTreeSet::new()),
+                        collapsed: Rc::new(BTreeSet::new()),
+                        toggle: Callback::from(|_| {}),
+                    }}
+                    field_labels={FieldLabels::default()}
+                />
+            }
+        }
+        Err(error) => html! { <span class="input-error">{format!("Invalid asn1 data: {}", error)}</span> },
+    };
+
+    let on_close = props.on_close.clone();
+    let onclick = Callback::from(move |_: MouseEvent| on_close.emit(()));
+
+    html! {
+        <div class="vertical asn1-selection-tree">
+            <div class="horizontal">
+                <span class="total">{format!("Decoded selection at offset {} ({} bytes)", props.offset, props.bytes.len())}</span>
+                <button class="jwt-util-button" {onclick}>{"Close"}</button>
+            </div>
+            {tree}
+        </div>
+    }
+}
@@ -0,0 +1,80 @@
+use asn1_parser::OwnedAsn1;
+use yew::{function_component, html, use_memo, Callback, Html, MouseEvent, Properties};
+
+use crate::asn1::breadcrumbs::node_name;
+use crate::asn1::HighlightAction;
+
+#[derive(Debug, PartialEq, Clone)]
+struct DerWarning {
+    node: u64,
+    message: String,
+}
+
+/// Collects DER violations and parser lints. The same issue reported by both checks is listed once.
+fn der_warnings(asn1: &OwnedAsn1) -> Vec<DerWarning> {
+    let mut warnings: Vec<DerWarning> = asn1
+        .validate_der()
+        .into_iter()
+        .map(|violation| DerWarning {
+            node: violation.node_id,
+            message: format!("{} at offset {}", violation.kind, violation.offset),
+        })
+        .collect();
+
+    for lint in asn1.lints() {
+        let message = lint.kind.to_string();
+        if !warnings
+            .iter()
+            .any(|warning| warning.node == lint.node_id && warning.message.starts_with(&message))
+        {
+            warnings.push(DerWarning {
+                node: lint.node_id,
+                message,
+            });
+        }
+    }
+
+    warnings
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct Asn1DerWarningsProps {
+    pub structure: OwnedAsn1,
+    pub set_cur_node: Callback<HighlightAction>,
+}
+
+/// Collapsible list of the suspicious encodings. Clicking the warning selects its node.
+#[function_component(Asn1DerWarnings)]
+pub fn asn1_der_warnings(props: &Asn1DerWarningsProps) -> Html {
+    let warnings = use_memo(props.structure.clone(), der_warnings);
+
+    if warnings.is_empty() {
+        return html! {};
+    }
+
+    let entries = warnings
+        .iter()
+        .map(|warning| {
+            let node = warning.node;
+            let name = props.structure.find_by_id(node).map(node_name).unwrap_or_default();
+            let set_cur_node = props.set_cur_node.clone();
+            let onclick = Callback::from(move |_: MouseEvent| set_cur_node.emit(HighlightAction::Focus(node)));
+
+            html! {
+                <div class="horizontal asn1-der-warning" {onclick}>
+                    <span class="asn1-breadcrumb">{name}</span>
+                    <span>{warning.message.clone()}</span>
+                </div>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <details class="asn1-der-warnings">
+            <summary>{format!("DER warnings ({})", warnings.len())}</summary>
+            <div class="vertical">
+                {entries}
+            </div>
+        </details>
+    }
+}
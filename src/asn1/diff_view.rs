@@ -0,0 +1,184 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+
+use asn1_parser::{asn1_diff, Asn1, Asn1Entity, DecodeProfile, DiffKind, OwnedAsn1};
+use web_sys::{Element, Event};
+use yew::{
+    function_component, html, use_memo, use_node_ref, use_reducer, use_state, Callback, Html, NodeRef, Properties,
+    UseStateHandle,
+};
+use yew_notifications::{use_notification, Notification, NotificationType};
+
+use crate::asn1::asn1_viewer::Asn1Viewer;
+use crate::asn1::collapse::CollapseState;
+use crate::asn1::editor::{apply_edit, Asn1Edit};
+use crate::asn1::input::Asn1Input;
+use crate::asn1::search::SearchMatches;
+use crate::asn1::x509::FieldLabels;
+use crate::asn1::Highlight;
+
+/// Kinds of the differing nodes keyed by the node id.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DiffMarks(pub Rc<BTreeMap<u64, DiffKind>>);
+
+impl DiffMarks {
+    pub fn get(&self, id: u64) -> Option<DiffKind> {
+        self.0.get(&id).copied()
+    }
+}
+
+/// Decodes the data leniently: undecodable parts are shown as error nodes.
+pub fn decode(raw: &[u8], profile: DecodeProfile) -> Result<OwnedAsn1, String> {
+    Asn1::decode_buff_lenient(raw, profile)
+        .map(|asn1| asn1.to_owned_with_asn1(asn1.inner_asn1().to_owned()))
+        .map_err(|err| err.message().to_owned())
+}
+
+/// Returns marks of the left and right trees and the number of differences.
+/// Removed nodes exist only in the left tree, added ones only in the right tree.
+fn diff_marks(left: &OwnedAsn1, right: &OwnedAsn1) -> (DiffMarks, DiffMarks, usize) {
+    let mut left_marks = BTreeMap::new();
+    let mut right_marks = BTreeMap::new();
+
+    let entries = asn1_diff(left, right);
+    let differences = entries.len();
+    for entry in entries {
+        if let Some(node) = entry.left.and(left.find_by_path(&entry.path)) {
+            left_marks.insert(node.id(), entry.kind);
+        }
+        if let Some(node) = entry.right.and(right.find_by_path(&entry.path)) {
+            right_marks.insert(node.id(), entry.kind);
+        }
+    }
+
+    (
+        DiffMarks(Rc::new(left_marks)),
+        DiffMarks(Rc::new(right_marks)),
+        differences,
+    )
+}
+
+/// Scrolls the other tree to the same position.
+fn sync_scroll(source: NodeRef, target: NodeRef) -> Callback<Event> {
+    Callback::from(move |_| {
+        if let (Some(source), Some(target)) = (source.cast::<Element>(), target.cast::<Element>()) {
+            target.set_scroll_top(source.scroll_top());
+        }
+    })
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct DiffTreeProps {
+    pub raw: UseStateHandle<Vec<u8>>,
+    pub asn1: Rc<Result<OwnedAsn1, String>>,
+    pub marks: DiffMarks,
+    pub tree_ref: NodeRef,
+    pub onscroll: Callback<Event>,
+}
+
+#[function_component(DiffTree)]
+fn diff_tree(props: &DiffTreeProps) -> Html {
+    let ctx = use_reducer(Highlight::default);
+    let notifications = use_notification::<Notification>();
+
+    let raw_setter = props.raw.setter();
+    let edited_asn1 = props.asn1.clone();
+    let on_edit = Callback::from(move |edit: Asn1Edit| {
+        let Ok(asn1) = &*edited_asn1 else {
+            return;
+        };
+        let mut asn1 = asn1.clone();
+
+        match apply_edit(&mut asn1, edit).and_then(|_| asn1.recompute().map_err(|err| err.message().to_owned())) {
+            Ok(asn1) => raw_setter.set(asn1.meta().raw_bytes().to_vec()),
+            Err(error) => notifications.spawn(Notification::from_description_and_type(NotificationType::Error, error)),
+        }
+    });
+
+    let raw_setter = props.raw.setter();
+    let tree = match &*props.asn1 {
+        Ok(asn1) => {
+            let dispatcher = ctx.dispatcher();
+            html! {
+                <Asn1Viewer
+                    structure={asn1.clone()}
+                    cur_node={ctx.current()}
+                    set_cur_node={move |action| dispatcher.dispatch(action)}
+                    {on_edit}
+                    search_matches={SearchMatches::default()}
+                    collapse={CollapseState {
+                        collapsible: Rc::new(BTreeSet::new()),
+                        collapsed: Rc::new(BTreeSet::new()),
+                        toggle: Callback::from(|_| {}),
+                    }}
+                    field_labels={FieldLabels::default()}
+                    diff_marks={props.marks.clone()}
+                />
+            }
+        }
+        Err(error) => html! { <span class="input-error">{format!("Invalid asn1 data: {}", error)}</span> },
+    };
+
+    html! {
+        <div class="vertical asn1-diff-side">
+            <Asn1Input bytes={(*props.raw).clone()} setter={Callback::from(move |data| raw_setter.set(data))} />
+            <div class="asn1-diff-tree" ref={props.tree_ref.clone()} onscroll={props.onscroll.clone()}>
+                {tree}
+            </div>
+        </div>
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct Asn1DiffViewProps {
+    /// Initial data of the left input.
+    pub bytes: Vec<u8>,
+    pub profile: DecodeProfile,
+}
+
+/// Two ASN.1 inputs with their trees rendered side by side. Added, removed, and changed nodes are highlighted.
+#[function_component(Asn1DiffView)]
+pub fn asn1_diff_view(props: &Asn1DiffViewProps) -> Html {
+    let left_raw = use_state(|| props.bytes.clone());
+    let right_raw = use_state(|| props.bytes.clone());
+
+    let left = use_memo(((*left_raw).clone(), props.profile), |(raw, profile)| {
+        decode(raw, *profile)
+    });
+    let right = use_memo(((*right_raw).clone(), props.profile), |(raw, profile)| {
+        decode(raw, *profile)
+    });
+
+    let marks = use_memo((left.clone(), right.clone()), |(left, right)| {
+        match (&**left, &**right) {
+            (Ok(left), Ok(right)) => diff_marks(left, right),
+            _ => Default::default(),
+        }
+    });
+    let (left_marks, right_marks, differences) = (*marks).clone();
+
+    let left_ref = use_node_ref();
+    let right_ref = use_node_ref();
+
+    html! {
+        <div class="vertical">
+            <span class="total">{if differences == 0 { "No differences".to_owned() } else { format!("{} differences", differences) }}</span>
+            <div class="asn1-diff-viewers">
+                <DiffTree
+                    raw={left_raw}
+                    asn1={left}
+                    marks={left_marks}
+                    tree_ref={left_ref.clone()}
+                    onscroll={sync_scroll(left_ref.clone(), right_ref.clone())}
+                />
+                <DiffTree
+                    raw={right_raw}
+                    asn1={right}
+                    marks={right_marks}
+                    tree_ref={right_ref.clone()}
+                    onscroll={sync_scroll(right_ref, left_ref)}
+                />
+            </div>
+        </div>
+    }
+}
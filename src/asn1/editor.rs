@@ -0,0 +1,113 @@
+use std::mem;
+use std::str::FromStr;
+
+use asn1_parser::{
+    Asn1, Asn1Type, ExplicitTag, Null, ObjectIdentifier, OwnedAsn1, OwnedAsn1Type, OwnedInteger, OwnedOctetString,
+    OwnedUtf8String, Sequence,
+};
+
+/// Change of the ASN.1 tree requested from the viewer.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Asn1Edit {
+    /// Replaces the value of the node with the provided id.
+    ReplaceValue(u64, OwnedAsn1Type),
+    /// Appends the new field to the SEQUENCE, SET, or explicit tag.
+    AppendChild(u64, OwnedAsn1Type),
+    /// Wraps the node into the explicit context-specific tag with the provided number.
+    WrapExplicit(u64, u32),
+    Delete(u64),
+}
+
+/// Nodes that can be appended from the viewer. Their values are edited afterwards.
+pub const NEW_NODES: &[(&str, fn() -> OwnedAsn1Type)] = &[
+    ("NULL", || OwnedAsn1Type::Null(Null)),
+    ("INTEGER", || OwnedAsn1Type::Integer(OwnedInteger::from(0))),
+    ("UTF8String", || {
+        OwnedAsn1Type::Utf8String(OwnedUtf8String::from(String::new()))
+    }),
+    ("OCTET STRING", || {
+        OwnedAsn1Type::OctetString(OwnedOctetString::from(Vec::new()))
+    }),
+    ("SEQUENCE", || {
+        OwnedAsn1Type::Sequence(Sequence::of(Vec::<OwnedAsn1>::new()))
+    }),
+];
+
+/// Applies the edit to the tree. The tree should be [recomputed](Asn1::recompute) afterwards.
+pub fn apply_edit(asn1: &mut OwnedAsn1, edit: Asn1Edit) -> Result<(), String> {
+    const ENCAPSULATED_ERROR: &str = "Encapsulated values can not be edited";
+
+    match edit {
+        Asn1Edit::ReplaceValue(id, value) => {
+            *asn1.find_by_id_mut(id).ok_or(ENCAPSULATED_ERROR)?.inner_asn1_mut() = value;
+        }
+        Asn1Edit::AppendChild(id, value) => {
+            let field = Asn1::from(value);
+            match asn1.find_by_id_mut(id).ok_or(ENCAPSULATED_ERROR)?.inner_asn1_mut() {
+                Asn1Type::Sequence(sequence) => sequence.insert_field(sequence.fields().len(), field),
+                Asn1Type::Set(set) => set.insert_field(set.fields().len(), field),
+                Asn1Type::ExplicitTag(explicit_tag) => explicit_tag.insert_field(explicit_tag.inner().len(), field),
+                _ => return Err("Only SEQUENCE, SET, and explicit tags can have new fields".into()),
+            }
+            .map_err(|err| err.message().to_owned())?;
+        }
+        Asn1Edit::WrapExplicit(id, tag_number) => {
+            let node = asn1.find_by_id_mut(id).ok_or(ENCAPSULATED_ERROR)?;
+            let value = mem::replace(node, Asn1::from(Null));
+            *node = Asn1::from(ExplicitTag::wrap(tag_number, value));
+        }
+        Asn1Edit::Delete(id) => {
+            asn1.remove_by_id(id)
+                .ok_or("The root node and encapsulated values can not be deleted")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Primitive node which value can be edited in the viewer.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum EditableKind {
+    /// Hex-encoded octets.
+    OctetString,
+    Utf8String,
+    /// Decimal value or hex-encoded two's complement bytes prefixed with `0x`.
+    Integer,
+    /// Dot-separated arcs.
+    ObjectIdentifier,
+}
+
+impl EditableKind {
+    /// Parses the user input into the new node value.
+    pub fn parse(self, value: &str) -> Result<OwnedAsn1Type, String> {
+        let value = value.trim();
+
+        Ok(match self {
+            EditableKind::OctetString => {
+                OwnedAsn1Type::OctetString(OwnedOctetString::from_hex(value).map_err(|err| err.message().to_owned())?)
+            }
+            EditableKind::Utf8String => OwnedAsn1Type::Utf8String(OwnedUtf8String::from(value.to_owned())),
+            EditableKind::Integer => OwnedAsn1Type::Integer(match value.strip_prefix("0x") {
+                Some(hex_value) => {
+                    let bytes = hex::decode(hex_value).map_err(|err| err.to_string())?;
+                    if bytes.is_empty() {
+                        return Err("INTEGER should have at least one byte".into());
+                    }
+
+                    OwnedInteger::from(bytes)
+                }
+                None => OwnedInteger::from(value.parse::<i64>().map_err(|err| err.to_string())?),
+            }),
+            EditableKind::ObjectIdentifier => OwnedAsn1Type::ObjectIdentifier(
+                ObjectIdentifier::from_str(value).map_err(|err| err.message().to_owned())?,
+            ),
+        })
+    }
+}
+
+/// Value of the editable node and its kind.
+#[derive(Debug, PartialEq, Clone)]
+pub struct NodeEditor {
+    pub kind: EditableKind,
+    pub value: String,
+}
@@ -1,7 +1,7 @@
-use asn1_parser::{Asn1, Asn1Entity, Asn1Type, OwnedAsn1, RawAsn1EntityData};
+use asn1_parser::{walk_asn1, Asn1, Asn1Entity, Asn1Type, Asn1Visitor, OwnedAsn1, RawAsn1EntityData};
 use web_sys::MouseEvent;
 use yew::virtual_dom::VNode;
-use yew::{function_component, html, Callback, Classes, Html, Properties};
+use yew::{classes, function_component, html, use_state, Callback, Classes, Html, Properties};
 
 use crate::asn1::node_options::NodeOptions;
 use crate::asn1::{compare_ids, HighlightAction};
@@ -12,35 +12,136 @@ pub struct HexViewerProps {
     pub structure: OwnedAsn1,
 
     pub cur_node: Option<u64>,
+    /// Bytes of the hovered node are highlighted softer than the selected ones.
+    #[prop_or_default]
+    pub hovered_node: Option<u64>,
     pub set_cur_node: Callback<HighlightAction>,
+    /// Decodes the selected byte range: the offset of the first byte and the selected bytes.
+    #[prop_or_default]
+    pub on_decode_selection: Callback<(usize, Vec<u8>)>,
 }
 
 #[function_component(HexViewer)]
 pub fn hex_viewer(props: &HexViewerProps) -> Html {
+    // The first and the last shift+clicked bytes.
+    let selection = use_state(|| None::<(usize, usize)>);
+
+    let set_cur_node = props.set_cur_node.clone();
+    let mut bytes = Vec::with_capacity(props.structure.meta().raw_data.len());
+    build_hex_bytes(
+        &props.structure,
+        &props.cur_node.clone(),
+        set_cur_node,
+        &mut bytes,
+        false,
+    );
+
+    let rows_count = bytes.len() / BYTES_PER_ROW + 1;
+    let hovered_range = props
+        .hovered_node
+        .filter(|id| props.cur_node != Some(*id))
+        .and_then(|id| props.structure.find_by_id(id))
+        .map(|node| node.meta().tag_position()..node.meta().tag_position() + node.meta().raw_bytes().len());
+    let selected_range = (*selection).map(|(first, last)| first.min(last)..=first.max(last));
+    let bytes = bytes.into_iter().map(|(offset, byte)| {
+        let class = classes!(
+            hovered_range
+                .as_ref()
+                .is_some_and(|range| range.contains(&offset))
+                .then_some("asn1-hex-byte-hovered"),
+            selected_range
+                .as_ref()
+                .is_some_and(|range| range.contains(&offset))
+                .then_some("asn1-hex-byte-range"),
+        );
+
+        let selection_setter = selection.setter();
+        let current_selection = *selection;
+        let onclick = Callback::from(move |event: MouseEvent| {
+            if event.shift_key() {
+                selection_setter.set(Some(match current_selection {
+                    Some((first, _)) => (first, offset),
+                    None => (offset, offset),
+                }));
+            }
+        });
+
+        (offset, html! { <span {class} {onclick}>{byte}</span> })
+    });
+
+    let selection_actions = match selected_range {
+        Some(range) => {
+            let start = props.structure.meta().tag_position();
+            let selected_bytes = props
+                .structure
+                .meta()
+                .raw_bytes()
+                .get(range.start() - start..=range.end() - start)
+                .unwrap_or_default()
+                .to_vec();
+            let selected_len = selected_bytes.len();
+
+            let on_decode_selection = props.on_decode_selection.clone();
+            let first = *range.start();
+            let decode = Callback::from(move |_: MouseEvent| on_decode_selection.emit((first, selected_bytes.clone())));
+            let selection_setter = selection.setter();
+            let clear = Callback::from(move |_: MouseEvent| selection_setter.set(None));
+
+            html! {
+                <div class="horizontal asn1-hex-selection">
+                    <span class="total">{format!("Selection: {}..{} ({} bytes)", range.start(), range.end() + 1, selected_len)}</span>
+                    <button class="jwt-util-button" onclick={decode}>{"Decode selection"}</button>
+                    <button class="jwt-util-button" onclick={clear}>{"Clear"}</button>
+                </div>
+            }
+        }
+        None => html! {
+            <span class="total">{"shift+click bytes to select a range"}</span>
+        },
+    };
+
+    let mut rows = Vec::with_capacity(rows_count);
+    let mut bytes = bytes.peekable();
+    while let Some((offset, _)) = bytes.peek() {
+        let offset = *offset;
+        let row = bytes
+            .by_ref()
+            .take(BYTES_PER_ROW)
+            .map(|(_, byte)| byte)
+            .collect::<Vec<_>>();
+
+        rows.push(html! {
+            <div class="asn1-hex-row">
+                <span class="asn1-hex-offset">{format!("{:08x}", offset)}</span>
+                <div class="asn1-hex-node">{row}</div>
+            </div>
+        });
+    }
+
     html! {
-        <div class="asn1-hex-viewer">
-            <div class="asn1-hex-node">
-                {{
-                    let set_cur_node = props.set_cur_node.clone();
-                    let mut bytes = Vec::with_capacity(props.structure.meta().raw_data.len());
-                    build_hex_bytes(&props.structure, &props.cur_node.clone(), set_cur_node, &mut bytes, false);
-                    bytes
-                }}
+        <div class="vertical">
+            {selection_actions}
+            <div class="asn1-hex-viewer">
+                {rows}
             </div>
         </div>
     }
 }
 
 const MAX_BYTES_TO_RENDER: usize = 512;
+const BYTES_PER_ROW: usize = 16;
 
+/// Formats the `bytes` that start at the `bytes_offset` of the input and pushes them with their offsets.
+#[allow(clippy::too_many_arguments)]
 fn format_bytes(
     meta: &RawAsn1EntityData,
     raw_bytes: RcSlice,
     bytes: &[u8],
+    bytes_offset: usize,
     asn1_node_id: u64,
     class: &'static str,
     set_cur_node: Callback<HighlightAction>,
-    formatted_bytes: &mut Vec<VNode>,
+    formatted_bytes: &mut Vec<(usize, VNode)>,
 ) {
     let offset = meta.tag_position();
     let length_len = meta.length_range().len();
@@ -52,6 +153,13 @@ fn format_bytes(
     let set_cur_node_leave = set_cur_node.clone();
     let onmouseleave =
         Callback::from(move |_: MouseEvent| set_cur_node_leave.emit(HighlightAction::Hide(asn1_node_id)));
+    let set_cur_node_click = set_cur_node.clone();
+    let onclick = Callback::from(move |event: MouseEvent| {
+        // Shift+click selects the byte range instead of the node.
+        if !event.shift_key() {
+            set_cur_node_click.emit(HighlightAction::Select(asn1_node_id));
+        }
+    });
     let yew_class = Classes::from(&["asn1-hex-byte", class] as &[&'static str]);
 
     let bytes_len = bytes.len();
@@ -60,34 +168,42 @@ fn format_bytes(
             meta,
             raw_bytes.clone(),
             &bytes[0..MAX_BYTES_TO_RENDER / 2],
+            bytes_offset,
             asn1_node_id,
             class,
             set_cur_node.clone(),
             formatted_bytes,
         );
 
-        formatted_bytes.push(html! {
-            <span class={yew_class} {onmouseenter} {onmouseleave}>
-                <NodeOptions node_bytes={raw_bytes.clone()} {offset} {length_len} {data_len} name={".."} />
-            </span>
-        });
+        formatted_bytes.push((
+            bytes_offset + MAX_BYTES_TO_RENDER / 2,
+            html! {
+                <span class={yew_class} {onmouseenter} {onmouseleave} {onclick}>
+                    <NodeOptions node_bytes={raw_bytes.clone()} {offset} {length_len} {data_len} name={".."} />
+                </span>
+            },
+        ));
 
         format_bytes(
             meta,
             raw_bytes,
             &bytes[bytes_len - MAX_BYTES_TO_RENDER / 2..],
+            bytes_offset + bytes_len - MAX_BYTES_TO_RENDER / 2,
             asn1_node_id,
             class,
             set_cur_node.clone(),
             formatted_bytes,
         );
     } else {
-        bytes.iter().for_each(|byte| {
-            formatted_bytes.push(html! {
-                <span class={yew_class.clone()} onmouseenter={onmouseenter.clone()} onmouseleave={onmouseleave.clone()}>
-                    <NodeOptions node_bytes={raw_bytes.clone()} {offset} {length_len} {data_len} name={hex_format_byte(*byte)}/>
-                </span>
-            })
+        bytes.iter().enumerate().for_each(|(index, byte)| {
+            formatted_bytes.push((
+                bytes_offset + index,
+                html! {
+                    <span class={yew_class.clone()} onmouseenter={onmouseenter.clone()} onmouseleave={onmouseleave.clone()} onclick={onclick.clone()}>
+                        <NodeOptions node_bytes={raw_bytes.clone()} {offset} {length_len} {data_len} name={hex_format_byte(*byte)}/>
+                    </span>
+                },
+            ))
         });
     }
 }
@@ -96,17 +212,22 @@ fn build_hex_bytes(
     asn1: &Asn1<'_>,
     cur_node: &Option<u64>,
     set_cur_node: Callback<HighlightAction>,
-    bytes: &mut Vec<VNode>,
+    bytes: &mut Vec<(usize, VNode)>,
     select_all: bool,
 ) {
     let asn1_node_id = asn1.id();
     let if_selected = compare_ids(asn1_node_id, cur_node);
 
-    let tag: u8 = asn1.tag().into();
     let tag_set_cur_node = set_cur_node.clone();
     let onmouseenter = Callback::from(move |_: MouseEvent| tag_set_cur_node.emit(HighlightAction::Show(asn1_node_id)));
     let tag_set_cur_node = set_cur_node.clone();
     let onmouseleave = Callback::from(move |_: MouseEvent| tag_set_cur_node.emit(HighlightAction::Hide(asn1_node_id)));
+    let tag_set_cur_node = set_cur_node.clone();
+    let onclick = Callback::from(move |event: MouseEvent| {
+        if !event.shift_key() {
+            tag_set_cur_node.emit(HighlightAction::Select(asn1_node_id));
+        }
+    });
 
     let meta = asn1.meta();
     let raw_bytes = RcSlice::new(meta.raw_bytes().to_vec(), 0, meta.raw_bytes().len());
@@ -115,26 +236,31 @@ fn build_hex_bytes(
     let length_len = meta.length_range().len();
     let data_len = meta.data_range().len();
 
-    bytes.push(html! {
-        <span
-            class={if select_all {
-                "asn1-hex-byte asn1-hex-byte-data-selected"
-            } else if if_selected {
-                "asn1-hex-byte asn1-hex-byte-tag-selected"
-            } else {
-                "asn1-hex-byte asn1-hex-byte-tag"
-            }}
-            {onmouseenter}
-            {onmouseleave}
-        >
-            <NodeOptions node_bytes={raw_bytes.clone()} {offset} {length_len} {data_len} name={hex_format_byte(tag)}/>
-        </span>
+    // High tag numbers take more than one identifier octet.
+    meta.tag_bytes().iter().enumerate().for_each(|(index, tag)| {
+        bytes.push((offset + index, html! {
+            <span
+                class={if select_all {
+                    "asn1-hex-byte asn1-hex-byte-data-selected"
+                } else if if_selected {
+                    "asn1-hex-byte asn1-hex-byte-tag-selected"
+                } else {
+                    "asn1-hex-byte asn1-hex-byte-tag"
+                }}
+                onmouseenter={onmouseenter.clone()}
+                onmouseleave={onmouseleave.clone()}
+                onclick={onclick.clone()}
+            >
+                <NodeOptions node_bytes={raw_bytes.clone()} {offset} {length_len} {data_len} name={hex_format_byte(*tag)}/>
+            </span>
+        }))
     });
 
     format_bytes(
         meta,
         raw_bytes.clone(),
         asn1.meta().length_bytes(),
+        offset + meta.length_range().start,
         asn1_node_id,
         if select_all {
             "asn1-hex-byte-data-selected"
@@ -147,122 +273,76 @@ fn build_hex_bytes(
         bytes,
     );
 
-    build_data_bytes(
+    walk_asn1(
+        &mut DataBytesBuilder {
+            cur_node,
+            set_cur_node: set_cur_node.clone(),
+            bytes,
+            select_all: if_selected || select_all,
+        },
         asn1,
+    );
+
+    // The indefinite length encoding ends with the end-of-contents octets.
+    format_bytes(
+        meta,
         raw_bytes,
+        meta.end_of_contents_bytes(),
+        offset + meta.data_range().end,
         asn1_node_id,
-        cur_node,
+        if select_all {
+            "asn1-hex-byte-data-selected"
+        } else if if_selected {
+            "asn1-hex-byte-len-selected"
+        } else {
+            "asn1-hex-byte-len"
+        },
         set_cur_node,
         bytes,
-        if_selected || select_all,
     );
 }
 
-fn build_data_bytes(
-    asn1: &Asn1<'_>,
-    raw_bytes: RcSlice,
-    asn1_node_id: u64,
-    cur_node: &Option<u64>,
+/// Builds the data bytes of the node: child nodes or the raw data bytes.
+struct DataBytesBuilder<'a> {
+    cur_node: &'a Option<u64>,
     set_cur_node: Callback<HighlightAction>,
-    bytes: &mut Vec<VNode>,
+    bytes: &'a mut Vec<(usize, VNode)>,
     select_all: bool,
-) {
-    fn default_bytes(
-        asn1_node_id: u64,
-        cur_node: &Option<u64>,
-        set_cur_node: Callback<HighlightAction>,
-        asn1: &Asn1<'_>,
-        raw_bytes: RcSlice,
-        bytes: &mut Vec<VNode>,
-        select_all: bool,
-    ) {
-        let if_selected = compare_ids(asn1_node_id, cur_node);
+}
+
+impl Asn1Visitor for DataBytesBuilder<'_> {
+    fn visit_asn1(&mut self, asn1: &Asn1<'_>) {
+        build_hex_bytes(
+            asn1,
+            self.cur_node,
+            self.set_cur_node.clone(),
+            self.bytes,
+            self.select_all,
+        );
+    }
+
+    fn visit_leaf(&mut self, asn1: &Asn1<'_>) {
+        let asn1_node_id = asn1.id();
+        let if_selected = compare_ids(asn1_node_id, self.cur_node);
+
+        let meta = asn1.meta();
+        let raw_bytes = RcSlice::new(meta.raw_bytes().to_vec(), 0, meta.raw_bytes().len());
 
         format_bytes(
-            asn1.meta(),
+            meta,
             raw_bytes,
-            asn1.meta().data_bytes(),
+            meta.data_bytes(),
+            meta.tag_position() + meta.data_range().start,
             asn1_node_id,
-            if if_selected || select_all {
+            if if_selected || self.select_all {
                 "asn1-hex-byte-data-selected"
+            } else if matches!(asn1.inner_asn1(), Asn1Type::DecodeError(_)) {
+                "asn1-hex-byte-error"
             } else {
                 "asn1-hex-byte-data"
             },
-            set_cur_node,
-            bytes,
+            self.set_cur_node.clone(),
+            self.bytes,
         );
     }
-
-    match asn1.inner_asn1() {
-        Asn1Type::Sequence(sequence) => {
-            let set_cur_node = set_cur_node.clone();
-            sequence
-                .fields()
-                .iter()
-                .for_each(move |asn1| build_hex_bytes(asn1, cur_node, set_cur_node.clone(), bytes, select_all));
-        }
-        Asn1Type::Set(set) => {
-            let set_cur_node = set_cur_node.clone();
-            set.fields()
-                .iter()
-                .for_each(move |asn1| build_hex_bytes(asn1, cur_node, set_cur_node.clone(), bytes, select_all));
-        }
-        Asn1Type::OctetString(octet) => match octet.inner() {
-            Some(asn1) => build_hex_bytes(asn1, cur_node, set_cur_node.clone(), bytes, select_all),
-            None => default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all),
-        },
-        Asn1Type::Utf8String(_) => {
-            default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all)
-        }
-        Asn1Type::IA5String(_) => {
-            default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all)
-        }
-        Asn1Type::PrintableString(_) => {
-            default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all)
-        }
-        Asn1Type::GeneralString(_) => {
-            default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all)
-        }
-        Asn1Type::NumericString(_) => {
-            default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all)
-        }
-        Asn1Type::VisibleString(_) => {
-            default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all)
-        }
-        Asn1Type::UtcTime(_) => default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all),
-        Asn1Type::GeneralizedTime(_) => {
-            default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all)
-        }
-        Asn1Type::BitString(bit) => match bit.inner() {
-            Some(asn1) => build_hex_bytes(asn1, cur_node, set_cur_node.clone(), bytes, select_all),
-            None => default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all),
-        },
-        Asn1Type::BmpString(_) => {
-            default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all)
-        }
-        Asn1Type::Bool(_) => default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all),
-        Asn1Type::Null(_) => default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all),
-        Asn1Type::Integer(_) => default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all),
-        Asn1Type::ObjectIdentifier(_) => {
-            default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all)
-        }
-        Asn1Type::ExplicitTag(explicit) => {
-            let set_cur_node = set_cur_node.clone();
-            explicit
-                .inner()
-                .iter()
-                .for_each(move |asn1| build_hex_bytes(asn1, cur_node, set_cur_node.clone(), bytes, select_all));
-        }
-        Asn1Type::ImplicitTag(implicit) => match implicit.inner_asn1() {
-            Some(asn1) => build_hex_bytes(asn1, cur_node, set_cur_node.clone(), bytes, select_all),
-            None => default_bytes(asn1_node_id, cur_node, set_cur_node, asn1, raw_bytes, bytes, select_all),
-        },
-        Asn1Type::ApplicationTag(application) => {
-            let set_cur_node = set_cur_node.clone();
-            application
-                .inner()
-                .iter()
-                .for_each(move |asn1| build_hex_bytes(asn1, cur_node, set_cur_node.clone(), bytes, select_all));
-        }
-    }
 }
@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use time::{OffsetDateTime, UtcOffset};
+use yew::{function_component, html, Callback, Html, MouseEvent, Properties};
+
+use crate::timestamp::format_date_time;
+
+pub const ASN1_HISTORY_LOCAL_STORAGE_KEY: &str = "ASN1_HISTORY";
+
+const HISTORY_MAX_ENTRIES: usize = 20;
+/// Larger inputs are not saved because the local storage size is limited.
+const HISTORY_MAX_DATA_LEN: usize = 64 * 1024;
+const PREVIEW_BYTES: usize = 16;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct HistoryEntry {
+    /// Unix timestamp of the parsing.
+    pub timestamp: i64,
+    /// Hex-encoded input.
+    pub data: String,
+}
+
+impl HistoryEntry {
+    fn size(&self) -> usize {
+        self.data.len() / 2
+    }
+}
+
+pub fn decode_history(raw: &str) -> Vec<HistoryEntry> {
+    serde_json::from_str(raw).unwrap_or_default()
+}
+
+pub fn encode_history(history: &[HistoryEntry]) -> String {
+    serde_json::to_string(history).expect("History serialization should not fail")
+}
+
+/// Puts the parsed input on top of the history. The same input parsed earlier is moved instead of duplicated.
+pub fn record_history(history: &[HistoryEntry], data: &[u8]) -> Vec<HistoryEntry> {
+    if data.is_empty() || data.len() > HISTORY_MAX_DATA_LEN {
+        return history.to_vec();
+    }
+
+    let data = hex::encode(data);
+    let mut recorded = vec![HistoryEntry {
+        timestamp: OffsetDateTime::now_utc().unix_timestamp(),
+        data: data.clone(),
+    }];
+    recorded.extend(
+        history
+            .iter()
+            .filter(|entry| entry.data != data)
+            .take(HISTORY_MAX_ENTRIES - 1)
+            .cloned(),
+    );
+
+    recorded
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    match OffsetDateTime::from_unix_timestamp(timestamp) {
+        Ok(date_time) => format_date_time(&match UtcOffset::current_local_offset() {
+            Ok(offset) => date_time.to_offset(offset),
+            Err(_) => date_time,
+        }),
+        Err(_) => timestamp.to_string(),
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct Asn1HistoryProps {
+    pub history: Vec<HistoryEntry>,
+    pub load: Callback<Vec<u8>>,
+    pub clear: Callback<()>,
+}
+
+/// Recently parsed inputs. Clicking the entry loads it into the document.
+#[function_component(Asn1History)]
+pub fn asn1_history(props: &Asn1HistoryProps) -> Html {
+    if props.history.is_empty() {
+        return html! {};
+    }
+
+    let entries = props
+        .history
+        .iter()
+        .map(|entry| {
+            let data = hex::decode(&entry.data).unwrap_or_default();
+            let preview = &entry.data[..entry.data.len().min(PREVIEW_BYTES * 2)];
+            let preview = if entry.data.len() > preview.len() {
+                format!("{}…", preview)
+            } else {
+                preview.to_owned()
+            };
+
+            let load = props.load.clone();
+            let onclick = Callback::from(move |_: MouseEvent| load.emit(data.clone()));
+
+            html! {
+                <div class="horizontal asn1-history-entry" {onclick}>
+                    <span class="total">{format_timestamp(entry.timestamp)}</span>
+                    <span class="total">{format!("{} bytes", entry.size())}</span>
+                    <span class="asn-simple-value">{preview}</span>
+                </div>
+            }
+        })
+        .collect::<Html>();
+
+    let clear = props.clear.clone();
+    let onclick = Callback::from(move |_: MouseEvent| clear.emit(()));
+
+    html! {
+        <details class="asn1-history">
+            <summary>{format!("History ({})", props.history.len())}</summary>
+            <div class="vertical">
+                {entries}
+                <div class="horizontal">
+                    <button class="jwt-util-button" {onclick}>{"Clear history"}</button>
+                </div>
+            </div>
+        </details>
+    }
+}
@@ -0,0 +1,179 @@
+use asn1_parser::Pem;
+use base64::Engine;
+use web_sys::HtmlInputElement;
+use yew::html::onchange::Event;
+use yew::{classes, function_component, html, use_effect_with, use_state, Callback, Html, Properties, TargetCast};
+
+use crate::asn1::PEM_LABELS;
+use crate::utils::decode_base64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputEncoding {
+    Hex,
+    Base64,
+    Base64Url,
+    Pem,
+}
+
+impl InputEncoding {
+    fn name(self) -> &'static str {
+        match self {
+            InputEncoding::Hex => "hex",
+            InputEncoding::Base64 => "base64",
+            InputEncoding::Base64Url => "base64url",
+            InputEncoding::Pem => "PEM",
+        }
+    }
+
+    fn decode(self, input: &str) -> Result<Vec<u8>, String> {
+        let compact = input.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+
+        match self {
+            InputEncoding::Hex => hex::decode(compact).map_err(|err| format!("invalid hex: {}", err)),
+            InputEncoding::Base64 | InputEncoding::Base64Url => {
+                let symbols = if self == InputEncoding::Base64 {
+                    ['+', '/']
+                } else {
+                    ['-', '_']
+                };
+                let data = compact.trim_end_matches('=');
+                if let Some(c) = data
+                    .chars()
+                    .find(|c| !c.is_ascii_alphanumeric() && !symbols.contains(c))
+                {
+                    return Err(format!("invalid {}: unexpected character '{}'", self.name(), c));
+                }
+
+                decode_base64(data)
+            }
+            InputEncoding::Pem => Pem::decode(input)
+                .map(Pem::into_data)
+                .map_err(|err| format!("invalid PEM: {}", err.message())),
+        }
+    }
+
+    fn encode(self, bytes: &[u8]) -> String {
+        match self {
+            InputEncoding::Hex => hex::encode(bytes),
+            InputEncoding::Base64 => base64::engine::general_purpose::STANDARD.encode(bytes),
+            InputEncoding::Base64Url => base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes),
+            InputEncoding::Pem => Pem::new(PEM_LABELS[0], bytes.to_vec()).encode(),
+        }
+    }
+}
+
+/// Encodings in the detection order. Hex goes before base64 because every hex string is also valid base64.
+const INPUT_ENCODINGS: [InputEncoding; 4] = [
+    InputEncoding::Pem,
+    InputEncoding::Hex,
+    InputEncoding::Base64,
+    InputEncoding::Base64Url,
+];
+
+/// Decodes the input using the provided encoding or the first matching one when the encoding is `None`.
+pub fn decode_input(input: &str, encoding: Option<InputEncoding>) -> Result<(InputEncoding, Vec<u8>), String> {
+    match encoding {
+        Some(encoding) => encoding.decode(input).map(|bytes| (encoding, bytes)),
+        None if Pem::is_pem(input) => InputEncoding::Pem
+            .decode(input)
+            .map(|bytes| (InputEncoding::Pem, bytes)),
+        None => INPUT_ENCODINGS[1..]
+            .iter()
+            .find_map(|encoding| encoding.decode(input).ok().map(|bytes| (*encoding, bytes)))
+            .ok_or_else(|| "The input is not valid hex, base64, base64url, or PEM".to_owned()),
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct Asn1InputProps {
+    pub bytes: Vec<u8>,
+    pub setter: Callback<Vec<u8>>,
+}
+
+/// Input of the encoded ASN.1 data. The encoding is detected automatically unless it is selected manually.
+#[function_component(Asn1Input)]
+pub fn asn1_input(props: &Asn1InputProps) -> Html {
+    let encoding = use_state(|| None::<InputEncoding>);
+    let raw_value = use_state(|| hex::encode(&props.bytes));
+    let status = use_state(|| Ok::<_, String>(InputEncoding::Hex));
+    // The last bytes decoded from the input. Other bytes are set outside: file upload, editing, etc.
+    let decoded_bytes = use_state(|| props.bytes.clone());
+
+    let raw_value_setter = raw_value.setter();
+    let status_setter = status.setter();
+    let last_decoded = decoded_bytes.clone();
+    let selected_encoding = *encoding;
+    use_effect_with(props.bytes.clone(), move |bytes| {
+        if *bytes != *last_decoded {
+            let encoding = selected_encoding.unwrap_or(InputEncoding::Hex);
+            raw_value_setter.set(encoding.encode(bytes));
+            status_setter.set(Ok(encoding));
+            last_decoded.set(bytes.clone());
+        }
+    });
+
+    let decode = {
+        let setter = props.setter.clone();
+        let status = status.setter();
+        let decoded_bytes = decoded_bytes.setter();
+        move |value: &str, encoding: Option<InputEncoding>| match decode_input(value, encoding) {
+            Ok((encoding, bytes)) => {
+                status.set(Ok(encoding));
+                decoded_bytes.set(bytes.clone());
+                setter.emit(bytes);
+            }
+            Err(error) => status.set(Err(error)),
+        }
+    };
+
+    let raw_value_setter = raw_value.setter();
+    let oninput_decode = decode.clone();
+    let oninput = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        let value = input.value();
+
+        oninput_decode(&value, selected_encoding);
+        raw_value_setter.set(value);
+    });
+
+    let encoding_setter = encoding.setter();
+    let value = (*raw_value).clone();
+    let onchange = Callback::from(move |event: Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        let encoding = INPUT_ENCODINGS
+            .iter()
+            .copied()
+            .find(|encoding| encoding.name() == input.value());
+
+        decode(&value, encoding);
+        encoding_setter.set(encoding);
+    });
+
+    let status_message = match (&*status, *encoding) {
+        (Ok(detected), None) => format!("detected: {}", detected.name()),
+        (Ok(_), Some(_)) => String::new(),
+        (Err(error), _) => error.clone(),
+    };
+
+    html! {
+        <div class={classes!("bytes-input", "vertical")}>
+            <div class="horizontal">
+                <select class="base-input" {onchange}>
+                    <option selected={encoding.is_none()} value="auto">{"auto"}</option>
+                    {INPUT_ENCODINGS.iter().map(|input_encoding| html! {
+                        <option selected={*encoding == Some(*input_encoding)} value={input_encoding.name()}>{input_encoding.name()}</option>
+                    }).collect::<Html>()}
+                </select>
+                <span class="total">{status_message}</span>
+            </div>
+            <textarea
+                rows="10"
+                placeholder="asn1 data: place hex, base64, base64url, or PEM encoded input here"
+                class={classes!("base-input", if status.is_err() { "input-error" } else { "" })}
+                value={(*raw_value).clone()}
+                {oninput}
+            />
+            <span class="total">{format!("total: {}", decoded_bytes.len())}</span>
+        </div>
+    }
+}
@@ -0,0 +1,88 @@
+use asn1_parser::{Asn1Entity, NodePath, OwnedAsn1};
+use web_sys::{HtmlInputElement, KeyboardEvent, MouseEvent};
+use yew::{function_component, html, use_state, Callback, Html, InputEvent, Properties, TargetCast};
+use yew_notifications::{use_notification, Notification, NotificationType};
+
+use crate::asn1::HighlightAction;
+
+/// Parses the decimal offset or the hex one prefixed with `0x`.
+fn parse_offset(offset: &str) -> Result<usize, String> {
+    let offset = offset.trim();
+
+    match offset.strip_prefix("0x").or_else(|| offset.strip_prefix("0X")) {
+        Some(hex_offset) => usize::from_str_radix(hex_offset, 16),
+        None => offset.parse(),
+    }
+    .map_err(|err| format!("Invalid offset '{}': {}", offset, err))
+}
+
+/// Finds the node by the path copied from the breadcrumbs, e.g. `/0/2[tag=0xA3]/0`.
+fn find_by_path(structure: &OwnedAsn1, path: &str) -> Result<u64, String> {
+    let node_path = path
+        .parse::<NodePath>()
+        .map_err(|err| format!("Invalid path '{}': {}", path.trim(), err.message()))?;
+
+    structure
+        .find_by_node_path(&node_path)
+        .map(|node| node.id())
+        .ok_or_else(|| format!("No node at the path {}", node_path))
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct Asn1JumpToOffsetProps {
+    pub structure: OwnedAsn1,
+    pub set_cur_node: Callback<HighlightAction>,
+}
+
+/// Selects the innermost node that contains the byte at the entered offset or the node at the entered path.
+#[function_component(Asn1JumpToOffset)]
+pub fn asn1_jump_to_offset(props: &Asn1JumpToOffsetProps) -> Html {
+    let offset = use_state(String::new);
+    let notifications = use_notification::<Notification>();
+
+    let offset_setter = offset.setter();
+    let oninput = Callback::from(move |event: InputEvent| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        offset_setter.set(input.value());
+    });
+
+    let jump = {
+        let structure = props.structure.clone();
+        let set_cur_node = props.set_cur_node.clone();
+        let offset = (*offset).clone();
+        Callback::from(move |_: ()| {
+            let node = if offset.trim_start().starts_with('/') {
+                find_by_path(&structure, &offset)
+            } else {
+                parse_offset(&offset).and_then(|offset| {
+                    structure
+                        .node_at_offset(offset)
+                        .map(|node| node.id())
+                        .ok_or_else(|| format!("No node covers the offset {}", offset))
+                })
+            };
+
+            match node {
+                Ok(id) => set_cur_node.emit(HighlightAction::Focus(id)),
+                Err(error) => {
+                    notifications.spawn(Notification::from_description_and_type(NotificationType::Error, error))
+                }
+            }
+        })
+    };
+
+    let onkeydown_jump = jump.clone();
+    let onkeydown = Callback::from(move |event: KeyboardEvent| {
+        if event.key() == "Enter" {
+            onkeydown_jump.emit(());
+        }
+    });
+    let onclick = Callback::from(move |_: MouseEvent| jump.emit(()));
+
+    html! {
+        <div class="horizontal asn1-jump-to-offset">
+            <input class="base-input" placeholder="offset: 42, 0x2a, or path: /1/0" value={(*offset).clone()} {oninput} {onkeydown} />
+            <button class="jwt-util-button" {onclick}>{"Go"}</button>
+        </div>
+    }
+}
@@ -0,0 +1,94 @@
+use asn1_parser::{Asn1, Asn1Entity, OwnedAsn1, TagClass};
+use yew::{function_component, html, Html, Properties};
+
+use crate::asn1::breadcrumbs::node_name;
+use crate::asn1::search::node_text;
+
+/// Value bytes shown in the preview. Longer values are truncated.
+const PREVIEW_BYTES: usize = 64;
+
+fn tag_class(class: TagClass) -> &'static str {
+    match class {
+        TagClass::Universal => "universal",
+        TagClass::Application => "application",
+        TagClass::ContextSpecific => "context-specific",
+        TagClass::Private => "private",
+    }
+}
+
+/// Describes the length octets: short form, long form, or indefinite length.
+fn length_form(length_bytes: &[u8]) -> String {
+    match length_bytes {
+        [] => "none".into(),
+        [0x80] => "indefinite".into(),
+        [first] if first & 0x80 == 0 => "short form".into(),
+        [_, rest @ ..] => format!("long form ({} length octets)", rest.len()),
+    }
+}
+
+fn value_preview(asn1: &Asn1<'_>) -> String {
+    let data = asn1.meta().data_bytes();
+    let hex = if data.len() > PREVIEW_BYTES {
+        format!("{}… ({} bytes)", hex::encode(&data[..PREVIEW_BYTES]), data.len())
+    } else {
+        hex::encode(data)
+    };
+
+    match node_text(asn1) {
+        Some(text) => format!("{} ({})", text, hex),
+        None => hex,
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct Asn1NodeDetailsProps {
+    pub structure: OwnedAsn1,
+    pub selected: Option<u64>,
+}
+
+/// Encoding details of the selected node: identifier and length octets, offsets, and the value preview.
+#[function_component(Asn1NodeDetails)]
+pub fn asn1_node_details(props: &Asn1NodeDetailsProps) -> Html {
+    let Some(node) = props.selected.and_then(|id| props.structure.find_by_id(id)) else {
+        return html! {};
+    };
+
+    let meta = node.meta();
+    let tag = node.tag();
+    let start = meta.tag_position();
+    let data_start = start + meta.data_range().start;
+    let end = start + meta.raw_bytes().len();
+
+    let details = [
+        ("Type", node_name(node)),
+        ("Tag bytes", hex::encode(meta.tag_bytes())),
+        ("Class", tag_class(tag.class()).to_owned()),
+        ("Tag number", tag.number().to_string()),
+        ("Constructed", tag.is_constructed().to_string()),
+        ("Length bytes", hex::encode(meta.length_bytes())),
+        ("Length form", length_form(meta.length_bytes())),
+        ("Value length", meta.data_range().len().to_string()),
+        (
+            "Offsets",
+            format!(
+                "{}..{} (value {}..{})",
+                start,
+                end,
+                data_start,
+                start + meta.data_range().end
+            ),
+        ),
+        ("Value", value_preview(node)),
+    ];
+
+    html! {
+        <div class="vertical asn1-node-details">
+            {details.into_iter().map(|(name, value)| html! {
+                <div class="horizontal">
+                    <span class="asn1-node-details-name">{name}</span>
+                    <span class="asn-simple-value">{value}</span>
+                </div>
+            }).collect::<Html>()}
+        </div>
+    }
+}
@@ -0,0 +1,165 @@
+use asn1_parser::{OwnedRawAsn1EntityData, Pem};
+use web_sys::{Event, HtmlInputElement, KeyboardEvent, MouseEvent};
+use yew::{function_component, html, use_context, use_state, Callback, Html, InputEvent, Properties, TargetCast};
+use yew_hooks::use_clipboard;
+use yew_notifications::{use_notification, Notification};
+
+use crate::asn1::annotations::Annotations;
+use crate::asn1::editor::{Asn1Edit, NEW_NODES};
+use crate::asn1::PEM_LABELS;
+use crate::common::{encode_bytes, BytesFormat};
+use crate::utils::copy_to_clipboard_with_notification;
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct NodeMenuProps {
+    pub id: u64,
+    pub meta: OwnedRawAsn1EntityData,
+    /// New fields can be appended to the node.
+    pub container: bool,
+    pub on_close: Callback<()>,
+}
+
+/// Context menu of the tree node: copying the node bytes, editing the tree, and the node note.
+#[function_component(NodeMenu)]
+pub fn node_menu(props: &NodeMenuProps) -> Html {
+    let asn1_node_id = props.id;
+
+    let on_edit = use_context::<Callback<Asn1Edit>>();
+    let annotations = use_context::<Annotations>();
+    let note = use_state(|| {
+        annotations
+            .as_ref()
+            .and_then(|annotations| annotations.get(asn1_node_id))
+            .unwrap_or_default()
+            .to_owned()
+    });
+    let wrap_tag_number = use_state(|| 0_u32);
+    let pem_label = use_state(|| PEM_LABELS[0]);
+    let clipboard = use_clipboard();
+    let notifications = use_notification::<Notification>();
+
+    let on_close = props.on_close.clone();
+    let onmouseleave = Callback::from(move |_: MouseEvent| on_close.emit(()));
+    // Clicks inside the menu should not select the node.
+    let onclick = Callback::from(|event: MouseEvent| event.stop_propagation());
+
+    let copy = |data: String, name: &str| {
+        copy_to_clipboard_with_notification(data, clipboard.clone(), name, notifications.clone())
+    };
+    let value = props.meta.data_bytes();
+    let node = props.meta.raw_bytes();
+    let pem = Pem::new(*pem_label, node.to_vec()).encode();
+
+    let pem_label_setter = pem_label.setter();
+    let on_pem_label_change = Callback::from(move |event: Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        if let Some(label) = PEM_LABELS.iter().find(|label| **label == input.value()) {
+            pem_label_setter.set(*label);
+        }
+    });
+
+    let edit_actions = match on_edit {
+        Some(on_edit) => {
+            let emit_edit = |edit: Asn1Edit| {
+                let on_edit = on_edit.clone();
+                let on_close = props.on_close.clone();
+                Callback::from(move |_: MouseEvent| {
+                    on_close.emit(());
+                    on_edit.emit(edit.clone());
+                })
+            };
+
+            let append_buttons = if props.container {
+                NEW_NODES
+                    .iter()
+                    .map(|(name, new_node)| {
+                        let onclick = emit_edit(Asn1Edit::AppendChild(asn1_node_id, new_node()));
+                        html! {
+                            <button class="jwt-util-button" {onclick}>{format!("+ {}", name)}</button>
+                        }
+                    })
+                    .collect::<Html>()
+            } else {
+                html! {}
+            };
+
+            let wrap_tag_number_setter = wrap_tag_number.setter();
+            let onchange = Callback::from(move |event: Event| {
+                let input: HtmlInputElement = event.target_unchecked_into();
+                if let Ok(tag_number) = input.value().parse::<u32>() {
+                    wrap_tag_number_setter.set(tag_number);
+                }
+            });
+
+            html! {
+                <>
+                    {append_buttons}
+                    <div class="horizontal">
+                        <input class="base-input asn1-node-menu-tag" type="number" min="0" value={wrap_tag_number.to_string()} {onchange} />
+                        <button class="jwt-util-button" onclick={emit_edit(Asn1Edit::WrapExplicit(asn1_node_id, *wrap_tag_number))}>{"Wrap in explicit tag"}</button>
+                    </div>
+                    <button class="jwt-util-button" onclick={emit_edit(Asn1Edit::Delete(asn1_node_id))}>{"Delete"}</button>
+                </>
+            }
+        }
+        None => html! {},
+    };
+
+    let note_editor = match annotations {
+        Some(annotations) => {
+            let note_setter = note.setter();
+            let oninput = Callback::from(move |event: InputEvent| {
+                let input: HtmlInputElement = event.target_unchecked_into();
+                note_setter.set(input.value());
+            });
+
+            let save = {
+                let note = (*note).clone();
+                let on_close = props.on_close.clone();
+                Callback::from(move |_: ()| {
+                    annotations.set.emit((asn1_node_id, note.clone()));
+                    on_close.emit(());
+                })
+            };
+            let onkeydown_save = save.clone();
+            let onkeydown = Callback::from(move |event: KeyboardEvent| {
+                if event.key() == "Enter" {
+                    onkeydown_save.emit(());
+                }
+            });
+
+            html! {
+                <div class="horizontal">
+                    <input class="base-input" placeholder="note" value={(*note).clone()} {oninput} {onkeydown} />
+                    <button class="jwt-util-button" onclick={Callback::from(move |_: MouseEvent| save.emit(()))}>{"Save note"}</button>
+                </div>
+            }
+        }
+        None => html! {},
+    };
+
+    html! {
+        <div style="position: relative">
+            <div class="asn1-node-options" {onmouseleave} {onclick}>
+                <div class="horizontal">
+                    <button class="jwt-util-button" onclick={copy(hex::encode(value), "Value hex")}>{"Value hex"}</button>
+                    <button class="jwt-util-button" onclick={copy(encode_bytes(value, BytesFormat::Base64), "Value base64")}>{"Value base64"}</button>
+                </div>
+                <div class="horizontal">
+                    <button class="jwt-util-button" onclick={copy(hex::encode(node), "Node hex")}>{"Node hex"}</button>
+                    <button class="jwt-util-button" onclick={copy(encode_bytes(node, BytesFormat::Base64), "Node base64")}>{"Node base64"}</button>
+                </div>
+                <div class="horizontal">
+                    <select class="base-input" onchange={on_pem_label_change}>
+                        {PEM_LABELS.iter().map(|label| html! {
+                            <option selected={*pem_label == *label} value={*label}>{*label}</option>
+                        }).collect::<Html>()}
+                    </select>
+                    <button class="jwt-util-button" onclick={copy(pem, "PEM")}>{"PEM"}</button>
+                </div>
+                {note_editor}
+                {edit_actions}
+            </div>
+        </div>
+    }
+}
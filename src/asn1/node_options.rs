@@ -13,6 +13,14 @@ pub struct NodeOptionsProps {
     pub data_len: usize,
 }
 
+/// Returns the number of the identifier octets. High tag numbers take more than one octet.
+fn identifier_len(node_bytes: &[u8]) -> usize {
+    match node_bytes.split_first() {
+        Some((first, rest)) if first & 0x1f == 0x1f => 2 + rest.iter().take_while(|octet| *octet & 0x80 != 0).count(),
+        _ => 1,
+    }
+}
+
 #[function_component(NodeOptions)]
 pub fn node_options(props: &NodeOptionsProps) -> Html {
     let show_options = use_state(|| false);
@@ -30,8 +38,8 @@ pub fn node_options(props: &NodeOptionsProps) -> Html {
 
     let clipboard = use_clipboard();
     let notifications = use_notification::<Notification>();
-    let node_bytes_len = props.node_bytes.len();
-    let value_raw = props.node_bytes.with_range(props.length_len + 1, node_bytes_len);
+    let value_start = identifier_len(props.node_bytes.data()) + props.length_len;
+    let value_raw = props.node_bytes.with_range(value_start, value_start + props.data_len);
     let copy_value = Callback::from(move |_| {
         clipboard.write_text(hex::encode(value_raw.data()));
 
@@ -0,0 +1,523 @@
+use std::rc::Rc;
+
+use asn1_kerberos::KrbMessage;
+use asn1_parser::{Asn1, Asn1Decoder, Asn1Encoder, Asn1Entity, Asn1Result, Asn1Type, OwnedAsn1, Tlv};
+use web_sys::{HtmlInputElement, MouseEvent};
+use yew::html::onchange::Event;
+use yew::{function_component, html, use_memo, use_state, Callback, Html, Properties, TargetCast};
+
+use crate::asn1::breadcrumbs::node_name;
+use crate::asn1::x509::{
+    field, optional, Field, Shape, ALGORITHM_IDENTIFIER, CERTIFICATE, NAME, SUBJECT_PUBLIC_KEY_INFO,
+};
+use crate::asn1::HighlightAction;
+
+const CERTIFICATION_REQUEST: Field = field(
+    "certificationRequest",
+    Shape::Sequence(&[
+        field(
+            "certificationRequestInfo",
+            Shape::Sequence(&[
+                field("version", Shape::Integer),
+                field("subject", NAME),
+                field("subjectPKInfo", Shape::Sequence(SUBJECT_PUBLIC_KEY_INFO)),
+                field("attributes", Shape::ContextTag(0)),
+            ]),
+        ),
+        field("signatureAlgorithm", Shape::Sequence(ALGORITHM_IDENTIFIER)),
+        field("signature", Shape::BitString),
+    ]),
+);
+
+const DIGEST_ALGORITHM: Field = field("digestAlgorithm", Shape::Sequence(ALGORITHM_IDENTIFIER));
+
+const SIGNER_INFO: Field = field(
+    "signerInfo",
+    Shape::Sequence(&[
+        field("version", Shape::Integer),
+        field("sid", Shape::Any),
+        DIGEST_ALGORITHM,
+        optional("signedAttrs", Shape::ContextTag(0)),
+        field("signatureAlgorithm", Shape::Sequence(ALGORITHM_IDENTIFIER)),
+        field("signature", Shape::OctetString),
+        optional("unsignedAttrs", Shape::ContextTag(1)),
+    ]),
+);
+
+const SIGNED_DATA: Field = field(
+    "contentInfo",
+    Shape::Sequence(&[
+        field("contentType", Shape::ObjectIdentifier),
+        field(
+            "content",
+            Shape::Explicit(
+                0,
+                &field(
+                    "signedData",
+                    Shape::Sequence(&[
+                        field("version", Shape::Integer),
+                        field("digestAlgorithms", Shape::SetOf(&DIGEST_ALGORITHM)),
+                        field(
+                            "encapContentInfo",
+                            Shape::Sequence(&[
+                                field("eContentType", Shape::ObjectIdentifier),
+                                optional("eContent", Shape::Explicit(0, &field("eContent", Shape::OctetString))),
+                            ]),
+                        ),
+                        optional("certificates", Shape::ContextTag(0)),
+                        optional("crls", Shape::ContextTag(1)),
+                        field("signerInfos", Shape::SetOf(&SIGNER_INFO)),
+                    ]),
+                ),
+            ),
+        ),
+    ]),
+);
+
+const PRINCIPAL_NAME: Shape = Shape::Sequence(&[
+    field("name-type", Shape::Explicit(0, &field("name-type", Shape::Integer))),
+    field(
+        "name-string",
+        Shape::Explicit(
+            1,
+            &field(
+                "name-string",
+                Shape::SequenceOf(&field("KerberosString", Shape::GeneralString)),
+            ),
+        ),
+    ),
+]);
+
+const PA_DATA: Field = field(
+    "PA-DATA",
+    Shape::Sequence(&[
+        field("padata-type", Shape::Explicit(1, &field("padata-type", Shape::Integer))),
+        field(
+            "padata-value",
+            Shape::Explicit(2, &field("padata-value", Shape::OctetString)),
+        ),
+    ]),
+);
+
+const KDC_REQ_BODY: Shape = Shape::Sequence(&[
+    field(
+        "kdc-options",
+        Shape::Explicit(0, &field("kdc-options", Shape::BitString)),
+    ),
+    optional("cname", Shape::Explicit(1, &field("cname", PRINCIPAL_NAME))),
+    field("realm", Shape::Explicit(2, &field("realm", Shape::GeneralString))),
+    optional("sname", Shape::Explicit(3, &field("sname", PRINCIPAL_NAME))),
+    optional("from", Shape::Explicit(4, &field("from", Shape::Time))),
+    field("till", Shape::Explicit(5, &field("till", Shape::Time))),
+    optional("rtime", Shape::Explicit(6, &field("rtime", Shape::Time))),
+    field("nonce", Shape::Explicit(7, &field("nonce", Shape::Integer))),
+    field(
+        "etype",
+        Shape::Explicit(8, &field("etype", Shape::SequenceOf(&field("etype", Shape::Integer)))),
+    ),
+    optional("addresses", Shape::ContextTag(9)),
+    optional("enc-authorization-data", Shape::ContextTag(10)),
+    optional("additional-tickets", Shape::ContextTag(11)),
+]);
+
+const AS_REQ: Field = field(
+    "AS-REQ",
+    Shape::Application(
+        10,
+        &field(
+            "KDC-REQ",
+            Shape::Sequence(&[
+                field("pvno", Shape::Explicit(1, &field("pvno", Shape::Integer))),
+                field("msg-type", Shape::Explicit(2, &field("msg-type", Shape::Integer))),
+                optional(
+                    "padata",
+                    Shape::Explicit(3, &field("padata", Shape::SequenceOf(&PA_DATA))),
+                ),
+                field("req-body", Shape::Explicit(4, &field("req-body", KDC_REQ_BODY))),
+            ]),
+        ),
+    ),
+);
+
+fn decode_as_req(raw: &[u8]) -> Asn1Result<()> {
+    // `Tlv::<AsReq>` does not check the application tag, so the message is decoded by its tag first.
+    match Tlv::<KrbMessage>::decode_buff(raw)?.into_inner() {
+        KrbMessage::AsReq(_) => Ok(()),
+        _ => Err("expected [APPLICATION 10] tag".into()),
+    }
+}
+
+/// Decodes the raw structure using the typed schema definitions.
+pub type SchemaDecoder = fn(&[u8]) -> Asn1Result<()>;
+
+/// Named field template of the known structure.
+pub struct Schema {
+    pub name: &'static str,
+    pub root: &'static Field,
+    /// Decodes the structure using the typed definitions of the same schema, e.g. from the `asn1-kerberos` crate.
+    ///
+    /// The template points to the mismatched nodes and the typed decoding checks that the schema implementations agree.
+    pub decode: Option<SchemaDecoder>,
+}
+
+/// Schemas the structure can be validated against.
+pub const SCHEMAS: &[Schema] = &[
+    Schema {
+        name: "X.509 Certificate",
+        root: &CERTIFICATE,
+        decode: None,
+    },
+    Schema {
+        name: "PKCS#7 SignedData",
+        root: &SIGNED_DATA,
+        decode: None,
+    },
+    Schema {
+        name: "PKCS#10 CSR",
+        root: &CERTIFICATION_REQUEST,
+        decode: None,
+    },
+    Schema {
+        name: "Kerberos AS-REQ",
+        root: &AS_REQ,
+        decode: Some(decode_as_req),
+    },
+];
+
+/// Mismatch between the structure and the schema.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SchemaIssue {
+    /// The unexpected node or the parent of the missing one.
+    pub node: u64,
+    /// Field names from the schema root, e.g. `certificate.tbsCertificate.validity`.
+    pub path: String,
+    pub message: String,
+}
+
+fn expected_type(shape: &Shape) -> String {
+    match shape {
+        Shape::Any => "any value".into(),
+        Shape::Boolean => "BOOLEAN".into(),
+        Shape::Integer => "INTEGER".into(),
+        Shape::BitString => "BIT STRING".into(),
+        Shape::OctetString => "OCTET STRING".into(),
+        Shape::ObjectIdentifier => "OBJECT IDENTIFIER".into(),
+        Shape::GeneralString => "GeneralString".into(),
+        Shape::Time => "UTCTime or GeneralizedTime".into(),
+        Shape::Sequence(_) | Shape::SequenceOf(_) => "SEQUENCE".into(),
+        Shape::SetOf(_) => "SET".into(),
+        Shape::Explicit(tag_number, _) | Shape::ContextTag(tag_number) => format!("[{}]", tag_number),
+        Shape::Implicit(tag_number) => format!("[{}] IMPLICIT", tag_number),
+        Shape::Application(tag_number, _) => format!("[APPLICATION {}]", tag_number),
+    }
+}
+
+/// Checks only the type and the tag number of the node, not its content.
+fn matches_type(asn1: &Asn1<'_>, shape: &Shape) -> bool {
+    match (shape, asn1.inner_asn1()) {
+        (Shape::Any, _)
+        | (Shape::Boolean, Asn1Type::Bool(_))
+        | (Shape::Integer, Asn1Type::Integer(_))
+        | (Shape::BitString, Asn1Type::BitString(_))
+        | (Shape::OctetString, Asn1Type::OctetString(_))
+        | (Shape::ObjectIdentifier, Asn1Type::ObjectIdentifier(_))
+        | (Shape::GeneralString, Asn1Type::GeneralString(_))
+        | (Shape::Time, Asn1Type::UtcTime(_) | Asn1Type::GeneralizedTime(_))
+        | (Shape::Sequence(_) | Shape::SequenceOf(_), Asn1Type::Sequence(_))
+        | (Shape::SetOf(_), Asn1Type::Set(_)) => true,
+        (Shape::Explicit(tag_number, _) | Shape::ContextTag(tag_number), Asn1Type::ExplicitTag(explicit)) => {
+            explicit.tag_number() == *tag_number
+        }
+        (Shape::Implicit(tag_number) | Shape::ContextTag(tag_number), Asn1Type::ImplicitTag(implicit)) => {
+            implicit.tag_number() == *tag_number
+        }
+        (Shape::Application(tag_number, _), Asn1Type::ApplicationTag(application)) => {
+            application.tag_number() == *tag_number
+        }
+        _ => false,
+    }
+}
+
+/// Appends the field name to the path. Tagged fields have the same name as their inner value, so it is not repeated.
+fn join_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_owned()
+    } else if path.rsplit('.').next() == Some(name) {
+        path.to_owned()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+fn validate_field(asn1: &Asn1<'_>, field: &Field, path: &str, issues: &mut Vec<SchemaIssue>) {
+    let path = join_path(path, field.label.name());
+
+    if !matches_type(asn1, &field.shape) {
+        issues.push(SchemaIssue {
+            node: asn1.id(),
+            message: format!("expected {}, found {}", expected_type(&field.shape), node_name(asn1)),
+            path,
+        });
+        return;
+    }
+
+    let inner_field = |inner: &[Asn1<'_>], field: &Field, issues: &mut Vec<SchemaIssue>| match inner {
+        [inner] => validate_field(inner, field, &path, issues),
+        _ => issues.push(SchemaIssue {
+            node: asn1.id(),
+            path: path.clone(),
+            message: format!("expected one inner value, found {}", inner.len()),
+        }),
+    };
+
+    match (&field.shape, asn1.inner_asn1()) {
+        (Shape::Sequence(templates), Asn1Type::Sequence(sequence)) => {
+            validate_fields(asn1.id(), sequence.fields(), templates, &path, issues)
+        }
+        (Shape::SequenceOf(field), Asn1Type::Sequence(sequence)) => sequence
+            .fields()
+            .iter()
+            .for_each(|asn1| validate_field(asn1, field, &path, issues)),
+        (Shape::SetOf(field), Asn1Type::Set(set)) => set
+            .fields()
+            .iter()
+            .for_each(|asn1| validate_field(asn1, field, &path, issues)),
+        (Shape::Explicit(_, field), Asn1Type::ExplicitTag(explicit)) => inner_field(explicit.inner(), field, issues),
+        (Shape::Application(_, field), Asn1Type::ApplicationTag(application)) => {
+            inner_field(application.inner(), field, issues)
+        }
+        _ => {}
+    }
+}
+
+/// Validates the SEQUENCE fields. The optional template is skipped when the field type does not match it.
+fn validate_fields(parent: u64, fields: &[Asn1<'_>], templates: &[Field], path: &str, issues: &mut Vec<SchemaIssue>) {
+    let mut fields = fields.iter().peekable();
+
+    for template in templates {
+        match fields.peek() {
+            Some(asn1) if matches_type(asn1, &template.shape) => {
+                validate_field(asn1, template, path, issues);
+                fields.next();
+            }
+            _ if template.optional => {}
+            Some(asn1) => {
+                // Reports the type mismatch.
+                validate_field(asn1, template, path, issues);
+                fields.next();
+            }
+            None => issues.push(SchemaIssue {
+                node: parent,
+                path: join_path(path, template.label.name()),
+                message: format!("missing {} field", expected_type(&template.shape)),
+            }),
+        }
+    }
+
+    for asn1 in fields {
+        issues.push(SchemaIssue {
+            node: asn1.id(),
+            path: path.to_owned(),
+            message: format!("unexpected {} field", node_name(asn1)),
+        });
+    }
+}
+
+/// Returns all mismatches between the structure and the schema.
+///
+/// The typed decoding error is reported only when the template does not find any mismatch.
+pub fn validate(asn1: &Asn1<'_>, schema: &Schema) -> Vec<SchemaIssue> {
+    let mut issues = Vec::new();
+    validate_field(asn1, schema.root, "", &mut issues);
+
+    if let Some(decode) = schema.decode.filter(|_| issues.is_empty()) {
+        if let Err(err) = asn1.encode_to_vec().and_then(|raw| decode(&raw)) {
+            issues.push(SchemaIssue {
+                node: asn1.id(),
+                path: schema.root.label.name().to_owned(),
+                message: format!("can not decode {}: {}", schema.name, err.message()),
+            });
+        }
+    }
+
+    issues
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct Asn1SchemaValidationProps {
+    pub structure: OwnedAsn1,
+    pub set_cur_node: Callback<HighlightAction>,
+}
+
+/// Validates the structure against the selected schema. Every issue selects the offending node.
+#[function_component(Asn1SchemaValidation)]
+pub fn asn1_schema_validation(props: &Asn1SchemaValidationProps) -> Html {
+    let schema = use_state(|| None::<usize>);
+    let issues: Rc<Option<Vec<SchemaIssue>>> = use_memo((props.structure.clone(), *schema), |(structure, schema)| {
+        schema.map(|index| validate(structure, &SCHEMAS[index]))
+    });
+
+    let schema_setter = schema.setter();
+    let onchange = Callback::from(move |event: Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        schema_setter.set(SCHEMAS.iter().position(|schema| schema.name == input.value()));
+    });
+
+    let result = match &*issues {
+        Some(issues) if issues.is_empty() => html! {
+            <span class="total">{"The structure matches the schema"}</span>
+        },
+        Some(issues) => issues
+            .iter()
+            .map(|issue| {
+                let node = issue.node;
+                let set_cur_node = props.set_cur_node.clone();
+                let onclick = Callback::from(move |_: MouseEvent| set_cur_node.emit(HighlightAction::Focus(node)));
+
+                html! {
+                    <div class="horizontal asn1-schema-issue">
+                        <span class="asn1-breadcrumb" {onclick}>{issue.path.clone()}</span>
+                        <span>{issue.message.clone()}</span>
+                    </div>
+                }
+            })
+            .collect::<Html>(),
+        None => html! {},
+    };
+
+    html! {
+        <div class="vertical asn1-schema-validation">
+            <div class="horizontal">
+                <span>{"Validate against:"}</span>
+                <select class="base-input" {onchange}>
+                    <option selected={schema.is_none()} value="">{"No schema"}</option>
+                    {SCHEMAS.iter().enumerate().map(|(index, Schema { name, .. })| html! {
+                        <option selected={*schema == Some(index)} value={*name}>{*name}</option>
+                    }).collect::<Html>()}
+                </select>
+            </div>
+            {result}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: Field = field(
+        "test",
+        Shape::Sequence(&[
+            field("version", Shape::Integer),
+            optional("flags", Shape::BitString),
+            field("name", Shape::OctetString),
+        ]),
+    );
+
+    const TEST_SCHEMA: Schema = Schema {
+        name: "Test",
+        root: &TEST,
+        decode: None,
+    };
+
+    // Encoded by the asn1-kerberos crate: kinit-like request with PA-PAC-REQUEST.
+    const AS_REQ_DER: &[u8] = &[
+        0x6a, 0x81, 0xb3, 0x30, 0x81, 0xb0, 0xa1, 0x03, 0x02, 0x01, 0x05, 0xa2, 0x03, 0x02, 0x01, 0x0a, 0xa3, 0x15,
+        0x30, 0x13, 0x30, 0x11, 0xa1, 0x04, 0x02, 0x02, 0x00, 0x80, 0xa2, 0x09, 0x04, 0x07, 0x30, 0x05, 0xa0, 0x03,
+        0x01, 0x01, 0xff, 0xa4, 0x81, 0x8c, 0x30, 0x81, 0x89, 0xa0, 0x07, 0x03, 0x05, 0x00, 0x40, 0x81, 0x00, 0x10,
+        0xa1, 0x12, 0x30, 0x10, 0xa0, 0x03, 0x02, 0x01, 0x01, 0xa1, 0x09, 0x30, 0x07, 0x1b, 0x05, 0x61, 0x6c, 0x69,
+        0x63, 0x65, 0xa2, 0x0d, 0x1b, 0x0b, 0x45, 0x58, 0x41, 0x4d, 0x50, 0x4c, 0x45, 0x2e, 0x43, 0x4f, 0x4d, 0xa3,
+        0x20, 0x30, 0x1e, 0xa0, 0x03, 0x02, 0x01, 0x02, 0xa1, 0x17, 0x30, 0x15, 0x1b, 0x06, 0x6b, 0x72, 0x62, 0x74,
+        0x67, 0x74, 0x1b, 0x0b, 0x45, 0x58, 0x41, 0x4d, 0x50, 0x4c, 0x45, 0x2e, 0x43, 0x4f, 0x4d, 0xa5, 0x11, 0x18,
+        0x0f, 0x32, 0x30, 0x33, 0x37, 0x30, 0x39, 0x31, 0x33, 0x30, 0x32, 0x34, 0x38, 0x30, 0x35, 0x5a, 0xa6, 0x11,
+        0x18, 0x0f, 0x32, 0x30, 0x33, 0x37, 0x30, 0x39, 0x31, 0x33, 0x30, 0x32, 0x34, 0x38, 0x30, 0x35, 0x5a, 0xa7,
+        0x06, 0x02, 0x04, 0x2a, 0x8f, 0x5d, 0x41, 0xa8, 0x0b, 0x30, 0x09, 0x02, 0x01, 0x12, 0x02, 0x01, 0x11, 0x02,
+        0x01, 0x17,
+    ];
+
+    /// Offset of the `realm` GeneralString tag in [AS_REQ_DER].
+    const REALM_TAG_OFFSET: usize = 76;
+
+    fn validate_raw(raw: &[u8], schema: &Schema) -> Vec<SchemaIssue> {
+        validate(&Asn1::decode_buff(raw).unwrap(), schema)
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(join_path("", "certificate"), "certificate");
+        assert_eq!(join_path("certificate", "tbsCertificate"), "certificate.tbsCertificate");
+        // tagged fields repeat the name of the inner value
+        assert_eq!(join_path("KDC-REQ.pvno", "pvno"), "KDC-REQ.pvno");
+    }
+
+    #[test]
+    fn matching_structure() {
+        // with and without the optional field
+        assert!(validate_raw(
+            &[0x30, 0x09, 0x02, 0x01, 0x01, 0x03, 0x01, 0x00, 0x04, 0x01, 0x61],
+            &TEST_SCHEMA
+        )
+        .is_empty());
+        assert!(validate_raw(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x04, 0x01, 0x61], &TEST_SCHEMA).is_empty());
+    }
+
+    #[test]
+    fn missing_required_field() {
+        let asn1 = Asn1::decode_buff(&[0x30, 0x03, 0x02, 0x01, 0x01]).unwrap();
+
+        assert_eq!(
+            validate(&asn1, &TEST_SCHEMA),
+            [SchemaIssue {
+                node: asn1.id(),
+                path: "test.name".into(),
+                message: "missing OCTET STRING field".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn mismatched_field() {
+        let issues = validate_raw(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x0c, 0x01, 0x61], &TEST_SCHEMA);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "test.name");
+        assert_eq!(issues[0].message, "expected OCTET STRING, found UTF8String");
+    }
+
+    #[test]
+    fn unexpected_trailing_field() {
+        let issues = validate_raw(
+            &[0x30, 0x08, 0x02, 0x01, 0x01, 0x04, 0x01, 0x61, 0x05, 0x00],
+            &TEST_SCHEMA,
+        );
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "test");
+        assert_eq!(issues[0].message, "unexpected NULL field");
+    }
+
+    #[test]
+    fn as_req_template_agrees_with_decoder() {
+        let schema = SCHEMAS
+            .iter()
+            .find(|schema| schema.root.label.name() == "AS-REQ")
+            .unwrap();
+
+        assert!(decode_as_req(AS_REQ_DER).is_ok());
+        assert!(validate_raw(AS_REQ_DER, schema).is_empty());
+
+        // realm as UTF8String
+        let mut as_req = AS_REQ_DER.to_vec();
+        as_req[REALM_TAG_OFFSET] = 0x0c;
+        assert!(decode_as_req(&as_req).is_err());
+        let issues = validate_raw(&as_req, schema);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "AS-REQ.KDC-REQ.req-body.realm");
+
+        // AP-REQ tag with the AS-REQ content
+        let mut as_req = AS_REQ_DER.to_vec();
+        as_req[0] = 0x6e;
+        assert!(decode_as_req(&as_req).is_err());
+        assert!(!validate_raw(&as_req, schema).is_empty());
+    }
+}
@@ -1,3 +1,6 @@
+mod decode_error;
+mod external;
+mod kerberos;
 mod oid;
 mod primitive;
 mod sequence;
@@ -6,41 +9,67 @@ mod strings;
 mod tag;
 mod time;
 
-use asn1_parser::{Asn1, Asn1Entity, Asn1Type};
-use web_sys::MouseEvent;
+use asn1_parser::{Asn1, Asn1Entity, Asn1Type, DiffKind, OwnedRawAsn1EntityData};
+use web_sys::{HtmlInputElement, KeyboardEvent, MouseEvent};
 use yew::virtual_dom::VNode;
-use yew::{classes, function_component, html, Callback, Children, Classes, Html, Properties};
+use yew::{
+    classes, function_component, html, use_context, use_state, Callback, Children, Classes, Html, Properties,
+    TargetCast,
+};
+use yew_notifications::{use_notification, Notification, NotificationType};
 
-use self::oid::ObjectIdentifierNode;
-use self::primitive::{BoolNode, IntegerNode, NullNode};
+use self::decode_error::DecodeErrorNode;
+use self::external::{EmbeddedPdvNode, ExternalNode};
+use self::oid::{ObjectIdentifierNode, RelativeOidNode};
+use self::primitive::{BoolNode, EnumeratedNode, IntegerNode, NullNode, RealNode};
 use self::sequence::SequenceNode;
 use self::strings::{
-    BitStringNode, BmpStringNode, GeneralStringNode, IA5StringNode, NumericStringNode, OctetStringNode,
-    PrintableStringNode, Utf8StringNode, VisibleStringNode,
+    BitStringNode, BmpStringNode, GeneralStringNode, GraphicStringNode, IA5StringNode, NumericStringNode,
+    OctetStringNode, PrintableStringNode, UniversalStringNode, Utf8StringNode, VideotexStringNode, VisibleStringNode,
 };
-use self::tag::{ApplicationTagNode, ExplicitTagNode, ImplicitTagNode};
-use self::time::{GeneralizedTimeNode, UtcTimeNode};
+use self::tag::{ApplicationTagNode, ExplicitTagNode, ImplicitTagNode, PrivateTagNode};
+use self::time::{GeneralizedTimeNode, IsoTimeNode, UtcTimeNode};
+use crate::asn1::annotations::Annotations;
+use crate::asn1::asn1_viewer::HoveredNode;
+use crate::asn1::collapse::CollapseState;
+use crate::asn1::diff_view::DiffMarks;
+use crate::asn1::editor::{Asn1Edit, EditableKind, NodeEditor};
+use crate::asn1::node_menu::NodeMenu;
 use crate::asn1::scheme::set::SetNode;
-use crate::asn1::HighlightAction;
+use crate::asn1::search::SearchMatches;
+use crate::asn1::x509::FieldLabels;
+use crate::asn1::{asn1_node_element_id, HighlightAction};
 
 #[derive(PartialEq, Properties, Clone)]
 pub struct Asn1NodeProps {
     pub id: u64,
+    pub meta: OwnedRawAsn1EntityData,
     pub cur_id: Option<u64>,
     pub set_cur_node: Callback<HighlightAction>,
+    /// Current value of the editable primitive node. The value is edited by the double-click.
+    #[prop_or_default]
+    pub editor: Option<NodeEditor>,
+    /// New fields can be appended to the node using the context menu.
+    #[prop_or_default]
+    pub container: bool,
 
     pub children: Children,
 }
 
 #[function_component(Asn1Node)]
 pub fn asn1_node(props: &Asn1NodeProps) -> Html {
-    fn get_node_class(id: u64, cur_id: &Option<u64>) -> Classes {
-        match cur_id {
+    fn get_node_class(id: u64, cur_id: &Option<u64>, search_matches: &Option<SearchMatches>) -> Classes {
+        let mut class = match cur_id {
             Some(cur_id) if *cur_id == id => {
                 classes!("hover_node", "asn1-node-container")
             }
             _ => classes!("asn1-node-container"),
+        };
+        if search_matches.as_ref().is_some_and(|matches| matches.contains(id)) {
+            class.push("asn1-search-match");
         }
+
+        class
     }
 
     let asn1_node_id = props.id;
@@ -52,10 +81,134 @@ pub fn asn1_node(props: &Asn1NodeProps) -> Html {
     let onmouseleave = Callback::from(move |_: MouseEvent| {
         set_cur_node.emit(HighlightAction::Hide(asn1_node_id));
     });
+    let set_cur_node = props.set_cur_node.clone();
+    let onclick = Callback::from(move |event: MouseEvent| {
+        // Only the innermost node under the cursor is selected.
+        event.stop_propagation();
+        set_cur_node.emit(HighlightAction::Select(asn1_node_id));
+    });
+
+    let editing = use_state(|| false);
+    let search_matches = use_context::<SearchMatches>();
+    let collapse = use_context::<CollapseState>();
+    let field_labels = use_context::<FieldLabels>();
+    let diff_marks = use_context::<DiffMarks>();
+    let annotations = use_context::<Annotations>();
+    let hovered_node = use_context::<HoveredNode>();
+    let on_edit = use_context::<Callback<Asn1Edit>>();
+    let notifications = use_notification::<Notification>();
+
+    let ondblclick = match (&props.editor, &on_edit) {
+        (Some(_), Some(_)) => {
+            let editing = editing.setter();
+            Some(Callback::from(move |event: MouseEvent| {
+                event.stop_propagation();
+                editing.set(true);
+            }))
+        }
+        _ => None,
+    };
+
+    let show_menu = use_state(|| false);
+    let show_menu_setter = show_menu.setter();
+    let oncontextmenu = Callback::from(move |event: MouseEvent| {
+        event.prevent_default();
+        event.stop_propagation();
+        show_menu_setter.set(true);
+    });
+
+    let menu = if *show_menu {
+        let show_menu_setter = show_menu.setter();
+        html! {
+            <NodeMenu
+                id={asn1_node_id}
+                meta={props.meta.clone()}
+                container={props.container}
+                on_close={Callback::from(move |_| show_menu_setter.set(false))}
+            />
+        }
+    } else {
+        html! {}
+    };
+
+    let content = match (&props.editor, on_edit) {
+        (Some(editor), Some(on_edit)) if *editing => {
+            let kind = editor.kind;
+            let editing_setter = editing.setter();
+            let onkeydown = Callback::from(move |event: KeyboardEvent| match event.key().as_str() {
+                "Enter" => {
+                    let input: HtmlInputElement = event.target_unchecked_into();
+                    match kind.parse(&input.value()) {
+                        Ok(value) => {
+                            on_edit.emit(Asn1Edit::ReplaceValue(asn1_node_id, value));
+                            editing_setter.set(false);
+                        }
+                        Err(error) => notifications.spawn(Notification::new(
+                            NotificationType::Error,
+                            "Invalid value",
+                            error,
+                            Notification::NOTIFICATION_LIFETIME,
+                        )),
+                    }
+                }
+                "Escape" => editing_setter.set(false),
+                _ => {}
+            });
+            let onclick = Callback::from(|event: MouseEvent| event.stop_propagation());
+
+            html! {
+                <input class="base-input asn1-node-editor" value={editor.value.clone()} {onkeydown} {onclick} />
+            }
+        }
+        _ => html! { {props.children.clone()} },
+    };
+
+    let mut class = get_node_class(props.id, &props.cur_id, &search_matches);
+    if hovered_node.is_some_and(|hovered| hovered.is(asn1_node_id)) && props.cur_id != Some(asn1_node_id) {
+        class.push("asn1-hovered-node");
+    }
+    match diff_marks.and_then(|marks| marks.get(asn1_node_id)) {
+        Some(DiffKind::Added) => class.push("asn1-diff-added"),
+        Some(DiffKind::Removed) => class.push("asn1-diff-removed"),
+        Some(DiffKind::Changed) => class.push("asn1-diff-changed"),
+        None => {}
+    }
+    let collapse_toggle = match collapse {
+        Some(collapse) if collapse.is_collapsible(asn1_node_id) => {
+            let collapsed = collapse.is_collapsed(asn1_node_id);
+            if collapsed {
+                class.push("asn1-node-collapsed");
+            }
+            let onclick = Callback::from(move |event: MouseEvent| {
+                event.stop_propagation();
+                collapse.toggle.emit(asn1_node_id);
+            });
+
+            html! {
+                <span class="asn1-collapse-toggle" {onclick}>{if collapsed { "▸" } else { "▾" }}</span>
+            }
+        }
+        _ => html! {},
+    };
+    let field_label = match field_labels.as_ref().and_then(|labels| labels.get(asn1_node_id)) {
+        Some(label) => html! { <span class="asn1-field-label">{label}</span> },
+        None => html! {},
+    };
+    let note = match annotations
+        .as_ref()
+        .and_then(|annotations| annotations.get(asn1_node_id))
+    {
+        Some(note) => html! { <span class="asn1-node-note" title={note.to_owned()}>{note}</span> },
+        None => html! {},
+    };
 
     html! {
-        <div class={get_node_class(props.id, &props.cur_id)} {onmouseenter} {onmouseleave}>
-            {props.children.clone()}
+        <div id={asn1_node_element_id(asn1_node_id)} {class} {onmouseenter} {onmouseleave} {onclick} {ondblclick} {oncontextmenu}>
+            {collapse_toggle}
+            {field_label}
+            {note}
+            {menu}
+            {content}
         </div>
     }
 }
@@ -63,104 +216,174 @@ pub fn asn1_node(props: &Asn1NodeProps) -> Html {
 pub fn build_asn1_schema(asn1: &Asn1<'_>, cur_id: &Option<u64>, set_cur_node: &Callback<HighlightAction>) -> VNode {
     match asn1.inner_asn1() {
         Asn1Type::OctetString(octet) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()} editor={octet.inner().is_none().then(|| NodeEditor { kind: EditableKind::OctetString, value: hex::encode(octet.octets()) })}>
                 <OctetStringNode node={octet.to_owned()} meta={asn1.meta().to_owned()} cur_node={cur_id} set_cur_node={set_cur_node.clone()} />
             </Asn1Node>
         },
         Asn1Type::Utf8String(utf8) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()} editor={NodeEditor { kind: EditableKind::Utf8String, value: utf8.as_str().to_owned() }}>
                 <Utf8StringNode node={utf8.to_owned()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
         Asn1Type::IA5String(ia5) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
                 <IA5StringNode node={ia5.to_owned()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
         Asn1Type::PrintableString(printable) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
                 <PrintableStringNode node={printable.to_owned()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
         Asn1Type::GeneralString(general) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
                 <GeneralStringNode node={general.to_owned()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
+        Asn1Type::GraphicString(graphic) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <GraphicStringNode node={graphic.to_owned()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
+        Asn1Type::VideotexString(videotex) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <VideotexStringNode node={videotex.to_owned()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
         Asn1Type::NumericString(numeric) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
                 <NumericStringNode node={numeric.to_owned()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
         Asn1Type::VisibleString(visible) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
                 <VisibleStringNode node={visible.to_owned()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
         Asn1Type::Sequence(sequence) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()} container=true>
                 <SequenceNode node={sequence.to_owned()} cur_node={cur_id} set_cur_node={set_cur_node.clone()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
         Asn1Type::Set(set) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()} container=true>
                 <SetNode node={set.to_owned()} cur_node={cur_id} set_cur_node={set_cur_node.clone()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
         Asn1Type::BitString(bit) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
-                <BitStringNode node={bit.to_owned()} meta={asn1.meta().to_owned()} cur_node={cur_id} set_cur_node={set_cur_node.clone()} />
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <BitStringNode id={asn1.id()} node={bit.to_owned()} meta={asn1.meta().to_owned()} cur_node={cur_id} set_cur_node={set_cur_node.clone()} />
             </Asn1Node>
         },
         Asn1Type::Bool(boolean) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
                 <BoolNode node={boolean.to_owned()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
         Asn1Type::BmpString(bmp) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
                 <BmpStringNode node={bmp.to_owned()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
+        Asn1Type::UniversalString(universal) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <UniversalStringNode node={universal.to_owned()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
         Asn1Type::Null(_) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
                 <NullNode meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
         Asn1Type::Integer(integer) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()} editor={NodeEditor { kind: EditableKind::Integer, value: integer.as_big_int().to_string() }}>
                 <IntegerNode node={integer.to_owned()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
+        Asn1Type::Enumerated(enumerated) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <EnumeratedNode node={enumerated.to_owned()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
+        Asn1Type::Real(real) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <RealNode node={real.to_owned()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
         Asn1Type::ObjectIdentifier(object_identifier) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()} editor={NodeEditor { kind: EditableKind::ObjectIdentifier, value: object_identifier.format() }}>
                 <ObjectIdentifierNode node={object_identifier.clone()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
+        Asn1Type::RelativeOid(relative_oid) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <RelativeOidNode node={relative_oid.clone()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
         Asn1Type::ExplicitTag(explicit) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()} container=true>
                 <ExplicitTagNode node={explicit.to_owned()} cur_node={cur_id} set_cur_node={set_cur_node.clone()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
         Asn1Type::ImplicitTag(implicit) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
                 <ImplicitTagNode node={implicit.to_owned()} cur_node={cur_id} set_cur_node={set_cur_node.clone()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
         Asn1Type::ApplicationTag(application) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
                 <ApplicationTagNode node={application.to_owned()} cur_node={cur_id} set_cur_node={set_cur_node.clone()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
+        Asn1Type::PrivateTag(private) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <PrivateTagNode node={private.to_owned()} cur_node={cur_id} set_cur_node={set_cur_node.clone()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
         Asn1Type::UtcTime(utc_time) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
                 <UtcTimeNode node={utc_time.to_owned()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
         Asn1Type::GeneralizedTime(generalized_time) => html! {
-            <Asn1Node id={asn1.id()} {cur_id} set_cur_node={set_cur_node.clone()}>
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
                 <GeneralizedTimeNode node={generalized_time.to_owned()} meta={asn1.meta().to_owned()} />
             </Asn1Node>
         },
+        Asn1Type::Date(value) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <IsoTimeNode name={String::from("Date")} value={value.to_string()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
+        Asn1Type::TimeOfDay(value) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <IsoTimeNode name={String::from("TimeOfDay")} value={value.to_string()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
+        Asn1Type::DateTime(value) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <IsoTimeNode name={String::from("DateTime")} value={value.to_string()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
+        Asn1Type::Duration(value) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <IsoTimeNode name={String::from("Duration")} value={value.to_string()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
+        Asn1Type::External(external) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <ExternalNode node={external.to_owned()} cur_node={cur_id} set_cur_node={set_cur_node.clone()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
+        Asn1Type::EmbeddedPdv(embedded_pdv) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <EmbeddedPdvNode node={embedded_pdv.to_owned()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
+        Asn1Type::DecodeError(decode_error) => html! {
+            <Asn1Node id={asn1.id()} meta={asn1.meta().to_owned()} {cur_id} set_cur_node={set_cur_node.clone()}>
+                <DecodeErrorNode node={decode_error.to_owned()} meta={asn1.meta().to_owned()} />
+            </Asn1Node>
+        },
     }
 }
@@ -0,0 +1,29 @@
+use asn1_parser::{OwnedDecodeError, OwnedRawAsn1EntityData};
+use yew::{function_component, html, Html, Properties};
+
+use crate::asn1::node_options::NodeOptions;
+use crate::common::RcSlice;
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct DecodeErrorNodeProps {
+    pub node: OwnedDecodeError,
+    pub meta: OwnedRawAsn1EntityData,
+}
+
+/// Undecodable region of the input produced by the lenient decoding. The rest of the tree is rendered as usual.
+#[function_component(DecodeErrorNode)]
+pub fn decode_error(props: &DecodeErrorNodeProps) -> Html {
+    let offset = props.meta.tag_position();
+    let length_len = props.meta.length_range().len();
+    let data_len = props.meta.data_range().len();
+    let range = props.node.range();
+
+    html! {
+        <div class="terminal-asn1-node asn1-decode-error-node">
+            <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("DecodeError")}/>
+            <span class="asn1-node-info-label">{format!("(offset {}..{})", range.start, range.end)}</span>
+            <span class="asn-decode-error">{props.node.reason().to_owned()}</span>
+            <span class="asn-simple-value">{hex::encode(props.node.raw_data())}</span>
+        </div>
+    }
+}
@@ -0,0 +1,95 @@
+use asn1_parser::{ExternalEncoding, OwnedEmbeddedPdv, OwnedExternal, OwnedRawAsn1EntityData, PdvIdentification};
+use yew::{function_component, html, Callback, Html, Properties};
+
+use crate::asn1::node_options::NodeOptions;
+use crate::asn1::scheme::build_asn1_schema;
+use crate::asn1::HighlightAction;
+use crate::common::RcSlice;
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct ExternalNodeProps {
+    pub node: OwnedExternal,
+    pub cur_node: Option<u64>,
+    pub set_cur_node: Callback<HighlightAction>,
+    pub meta: OwnedRawAsn1EntityData,
+}
+
+#[function_component(ExternalNode)]
+pub fn external(props: &ExternalNodeProps) -> Html {
+    let offset = props.meta.tag_position();
+    let length_len = props.meta.length_range().len();
+    let data_len = props.meta.data_range().len();
+
+    let mut references = Vec::new();
+    if let Some(direct_reference) = props.node.direct_reference() {
+        references.push(direct_reference.format());
+    }
+    if let Some(indirect_reference) = props.node.indirect_reference() {
+        references.push(indirect_reference.as_big_int().to_string());
+    }
+    if let Some(descriptor) = props.node.data_value_descriptor() {
+        references.push(descriptor.string());
+    }
+
+    let encoding = match props.node.encoding() {
+        ExternalEncoding::SingleAsn1Type(asn1) => build_asn1_schema(asn1, &props.cur_node, &props.set_cur_node),
+        ExternalEncoding::OctetAligned(octets) => html! {
+            <span class="asn-simple-value">{hex::encode(octets.octets())}</span>
+        },
+        ExternalEncoding::Arbitrary(bits) => html! {
+            <span class="asn-simple-value">{hex::encode(bits.raw_bits())}</span>
+        },
+    };
+
+    html! {
+        <div style="cursor: crosshair; width: 100%">
+            <div class="asn1-constructor-header">
+                <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("External")}/>
+                <span class="asn1-node-info-label">{references.join(" ")}</span>
+            </div>
+            <div class="asn1-constructor-body">
+                {encoding}
+            </div>
+        </div>
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct EmbeddedPdvNodeProps {
+    pub node: OwnedEmbeddedPdv,
+    pub meta: OwnedRawAsn1EntityData,
+}
+
+#[function_component(EmbeddedPdvNode)]
+pub fn embedded_pdv(props: &EmbeddedPdvNodeProps) -> Html {
+    let offset = props.meta.tag_position();
+    let length_len = props.meta.length_range().len();
+    let data_len = props.meta.data_range().len();
+
+    let identification = match props.node.identification() {
+        PdvIdentification::Syntaxes {
+            abstract_syntax,
+            transfer_syntax,
+        } => format!("syntaxes: {} {}", abstract_syntax.format(), transfer_syntax.format()),
+        PdvIdentification::Syntax(syntax) => format!("syntax: {}", syntax.format()),
+        PdvIdentification::PresentationContextId(id) => format!("presentation-context-id: {}", id.as_big_int()),
+        PdvIdentification::ContextNegotiation {
+            presentation_context_id,
+            transfer_syntax,
+        } => format!(
+            "context-negotiation: {} {}",
+            presentation_context_id.as_big_int(),
+            transfer_syntax.format()
+        ),
+        PdvIdentification::TransferSyntax(syntax) => format!("transfer-syntax: {}", syntax.format()),
+        PdvIdentification::Fixed => String::from("fixed"),
+    };
+
+    html! {
+        <div class="terminal-asn1-node">
+            <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("EmbeddedPdv")}/>
+            <span class="asn1-node-info-label">{identification}</span>
+            <span class="asn-simple-value">{hex::encode(props.node.data_value().octets())}</span>
+        </div>
+    }
+}
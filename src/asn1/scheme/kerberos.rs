@@ -0,0 +1,116 @@
+use asn1_kerberos::{KdcReqBody, KrbMessage, PrincipalName, Ticket};
+use asn1_parser::{Asn1Decoder, Integer, Tlv};
+use yew::{function_component, html, Html, Properties};
+
+use crate::asn1::scheme::time::format_generalized_time;
+
+fn int(value: &Integer) -> String {
+    value.as_big_int().to_string()
+}
+
+fn principal(name: &PrincipalName) -> String {
+    name.to_string()
+}
+
+fn ticket(ticket: &Ticket) -> String {
+    format!("{}@{}", ticket.sname, ticket.realm.string())
+}
+
+fn req_body(fields: &mut Vec<(&'static str, String)>, body: &KdcReqBody) {
+    if let Some(cname) = body.cname.as_ref() {
+        fields.push(("cname", principal(cname)));
+    }
+    fields.push(("realm", body.realm.string()));
+    if let Some(sname) = body.sname.as_ref() {
+        fields.push(("sname", principal(sname)));
+    }
+    fields.push(("till", format_generalized_time(&body.till)));
+    fields.push(("nonce", int(&body.nonce)));
+    fields.push((
+        "etype",
+        body.etype.elements().iter().map(int).collect::<Vec<_>>().join(", "),
+    ));
+}
+
+/// Tries to decode the node as a Kerberos message and returns its name and labeled fields.
+pub fn kerberos_message(raw: &[u8]) -> Option<(&'static str, Vec<(&'static str, String)>)> {
+    let message = Tlv::<KrbMessage>::decode_buff(raw).ok()?.into_inner();
+
+    let mut fields = Vec::new();
+
+    match &message {
+        KrbMessage::AsReq(as_req) => {
+            fields.push(("pvno", int(&as_req.pvno)));
+            fields.push(("msg-type", int(&as_req.msg_type)));
+            req_body(&mut fields, &as_req.req_body);
+        }
+        KrbMessage::TgsReq(tgs_req) => {
+            fields.push(("pvno", int(&tgs_req.pvno)));
+            fields.push(("msg-type", int(&tgs_req.msg_type)));
+            req_body(&mut fields, &tgs_req.req_body);
+        }
+        KrbMessage::AsRep(as_rep) => {
+            fields.push(("pvno", int(&as_rep.pvno)));
+            fields.push(("msg-type", int(&as_rep.msg_type)));
+            fields.push(("crealm", as_rep.crealm.string()));
+            fields.push(("cname", principal(&as_rep.cname)));
+            fields.push(("ticket", ticket(&as_rep.ticket)));
+            fields.push(("enc-part etype", int(&as_rep.enc_part.etype)));
+        }
+        KrbMessage::TgsRep(tgs_rep) => {
+            fields.push(("pvno", int(&tgs_rep.pvno)));
+            fields.push(("msg-type", int(&tgs_rep.msg_type)));
+            fields.push(("crealm", tgs_rep.crealm.string()));
+            fields.push(("cname", principal(&tgs_rep.cname)));
+            fields.push(("ticket", ticket(&tgs_rep.ticket)));
+            fields.push(("enc-part etype", int(&tgs_rep.enc_part.etype)));
+        }
+        KrbMessage::ApReq(ap_req) => {
+            fields.push(("pvno", int(&ap_req.pvno)));
+            fields.push(("msg-type", int(&ap_req.msg_type)));
+            fields.push(("ap-options", hex::encode(ap_req.ap_options.raw_bits())));
+            fields.push(("ticket", ticket(&ap_req.ticket)));
+            fields.push(("authenticator etype", int(&ap_req.authenticator.etype)));
+        }
+        KrbMessage::KrbError(krb_error) => {
+            fields.push(("pvno", int(&krb_error.pvno)));
+            fields.push(("msg-type", int(&krb_error.msg_type)));
+            fields.push(("stime", format_generalized_time(&krb_error.stime)));
+            fields.push(("error-code", int(&krb_error.error_code)));
+            if let Some(crealm) = krb_error.crealm.as_ref() {
+                fields.push(("crealm", crealm.string()));
+            }
+            if let Some(cname) = krb_error.cname.as_ref() {
+                fields.push(("cname", principal(cname)));
+            }
+            fields.push(("realm", krb_error.realm.string()));
+            fields.push(("sname", principal(&krb_error.sname)));
+            if let Some(e_text) = krb_error.e_text.as_ref() {
+                fields.push(("e-text", e_text.string()));
+            }
+        }
+    }
+
+    Some((message.name(), fields))
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct KerberosFieldsProps {
+    pub fields: Vec<(&'static str, String)>,
+}
+
+#[function_component(KerberosFields)]
+pub fn kerberos_fields(props: &KerberosFieldsProps) -> Html {
+    props
+        .fields
+        .iter()
+        .map(|(label, value)| {
+            html! {
+                <div class="terminal-asn1-node">
+                    <span class="asn1-node-info-label">{label}</span>
+                    <span class="asn-simple-value">{value}</span>
+                </div>
+            }
+        })
+        .collect()
+}
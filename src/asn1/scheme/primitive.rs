@@ -1,4 +1,4 @@
-use asn1_parser::{Bool, OwnedInteger, OwnedRawAsn1EntityData};
+use asn1_parser::{Bool, OwnedEnumerated, OwnedInteger, OwnedRawAsn1EntityData, OwnedReal};
 use yew::{function_component, html, Html, Properties};
 
 use crate::asn1::node_options::NodeOptions;
@@ -61,7 +61,50 @@ pub fn integer(props: &IntegerNodeProps) -> Html {
     html! {
         <div class="terminal-asn1-node">
             <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("Integer")}/>
-            <span class="asn-simple-value">{format!("{}", props.node.as_big_uint())}</span>
+            <span class="asn-simple-value">{format!("{}", props.node.as_big_int())}</span>
+        </div>
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct EnumeratedNodeProps {
+    pub node: OwnedEnumerated,
+    pub meta: OwnedRawAsn1EntityData,
+}
+
+#[function_component(EnumeratedNode)]
+pub fn enumerated(props: &EnumeratedNodeProps) -> Html {
+    let offset = props.meta.tag_position();
+    let length_len = props.meta.length_range().len();
+    let data_len = props.meta.data_range().len();
+
+    html! {
+        <div class="terminal-asn1-node">
+            <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("Enumerated")}/>
+            <span class="asn-simple-value">{format!("{}", props.node.as_big_int())}</span>
+        </div>
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct RealNodeProps {
+    pub node: OwnedReal,
+    pub meta: OwnedRawAsn1EntityData,
+}
+
+#[function_component(RealNode)]
+pub fn real(props: &RealNodeProps) -> Html {
+    let offset = props.meta.tag_position();
+    let length_len = props.meta.length_range().len();
+    let data_len = props.meta.data_range().len();
+
+    html! {
+        <div class="terminal-asn1-node">
+            <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("Real")}/>
+            {match props.node.to_f64() {
+                Ok(value) => html! { <span class="asn-simple-value">{format!("{}", value)}</span> },
+                Err(err) => html! { <span class="asn-simple-value">{format!("invalid: {}", err.message())}</span> },
+            }}
         </div>
     }
 }
@@ -1,11 +1,14 @@
 use asn1_parser::{
-    OwnedBitString, OwnedBmpString, OwnedGeneralString, OwnedIA5String, OwnedNumericString, OwnedOctetString,
-    OwnedPrintableString, OwnedRawAsn1EntityData, OwnedUtf8String, OwnedVisibleString,
+    OwnedBitString, OwnedBmpString, OwnedGeneralString, OwnedGraphicString, OwnedIA5String, OwnedNumericString,
+    OwnedOctetString, OwnedPrintableString, OwnedRawAsn1EntityData, OwnedUniversalString, OwnedUtf8String,
+    OwnedVisibleString,
 };
-use yew::{function_component, html, Callback, Html, Properties};
+use web_sys::MouseEvent;
+use yew::{classes, function_component, html, use_context, use_state, Callback, Html, Properties};
 
 use crate::asn1::node_options::NodeOptions;
 use crate::asn1::scheme::build_asn1_schema;
+use crate::asn1::x509::NamedBits;
 use crate::asn1::HighlightAction;
 use crate::common::RcSlice;
 
@@ -20,33 +23,47 @@ pub struct OctetStringNodeProps {
 #[function_component(OctetStringNode)]
 pub fn octet_string(props: &OctetStringNodeProps) -> Html {
     let octets = props.node.octets();
+    // The nested structure is only a guess, so the raw bytes can be shown instead.
+    let show_raw = use_state(|| false);
 
     let offset = props.meta.tag_position();
     let length_len = props.meta.length_range().len();
     let data_len = props.meta.data_range().len();
 
+    let show_raw_setter = show_raw.setter();
+    let raw_shown = *show_raw;
+    let toggle_raw = Callback::from(move |event: MouseEvent| {
+        // The click should not select the node.
+        event.stop_propagation();
+        show_raw_setter.set(!raw_shown);
+    });
+
     match props.node.inner() {
-        Some(asn1) => html! {
+        Some(asn1) if !*show_raw => html! {
             <div style="cursor: crosshair; width: 100%;">
                 <div class="asn1-constructor-header">
                     <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("OctetString")}/>
                     <span class="asn1-node-info-label">{format!("({} bytes)", octets.len())}</span>
+                    <button class="jwt-util-button asn1-string-toggle" onclick={toggle_raw}>{"raw"}</button>
                 </div>
                 <div class="asn1-constructor-body">
                     {build_asn1_schema(asn1, &props.cur_node, &props.set_cur_node)}
                 </div>
             </div>
         },
-        None => {
+        inner => {
             let encoded_octets = match std::str::from_utf8(octets) {
-                Ok(s) => s.to_owned(),
-                Err(_) => hex::encode(octets),
+                Ok(s) if inner.is_none() => s.to_owned(),
+                _ => hex::encode(octets),
             };
             html! {
                 <div class="terminal-asn1-node">
                     <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("OctetString")} />
                     <span class="asn1-node-info-label">{format!("({} bytes)", octets.len())}</span>
                     <span class="asn-simple-value">{encoded_octets}</span>
+                    {if inner.is_some() { html! {
+                        <button class="jwt-util-button asn1-string-toggle" onclick={toggle_raw}>{"decoded"}</button>
+                    }} else { html! {} }}
                 </div>
             }
         }
@@ -54,6 +71,7 @@ pub fn octet_string(props: &OctetStringNodeProps) -> Html {
 }
 #[derive(PartialEq, Properties, Clone)]
 pub struct BitStringNodeProps {
+    pub id: u64,
     pub node: OwnedBitString,
     pub meta: OwnedRawAsn1EntityData,
     pub cur_node: Option<u64>,
@@ -62,6 +80,7 @@ pub struct BitStringNodeProps {
 
 #[function_component(BitStringNode)]
 pub fn bit_string(props: &BitStringNodeProps) -> Html {
+    let named_bits = use_context::<NamedBits>().and_then(|named_bits| named_bits.get(props.id));
     let bits = props.node.raw_bits()[1..]
         .iter()
         .map(|byte| format!("{:08b}", byte))
@@ -94,11 +113,100 @@ pub fn bit_string(props: &BitStringNodeProps) -> Html {
                     <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("BitString")} />
                     <span class="asn1-node-info-label">{format!("({} bits)", bits_amount)}</span>
                     <span class="asn-simple-value">{bits}</span>
+                    <BitGrid bits={bits.to_owned()} names={named_bits} />
                 </div>
             }
         }
     }
 }
+#[derive(PartialEq, Properties, Clone)]
+struct BitGridProps {
+    /// Bits as `0` and `1` characters starting from the bit 0.
+    bits: String,
+    /// Names of the bits when the BIT STRING is a known X.509 extension value, e.g. KeyUsage.
+    names: Option<&'static [&'static str]>,
+}
+
+/// Expandable grid with the index and value of every bit and the names of the set bits.
+#[function_component(BitGrid)]
+fn bit_grid(props: &BitGridProps) -> Html {
+    let expanded = use_state(|| false);
+
+    let expanded_setter = expanded.setter();
+    let is_expanded = *expanded;
+    let onclick = Callback::from(move |event: MouseEvent| {
+        // The click should not select the node.
+        event.stop_propagation();
+        expanded_setter.set(!is_expanded);
+    });
+
+    let names = props.names.unwrap_or_default();
+    let set_bits = props
+        .bits
+        .chars()
+        .enumerate()
+        .filter(|(_, bit)| *bit == '1')
+        .filter_map(|(index, _)| names.get(index).copied())
+        .collect::<Vec<_>>();
+
+    let grid = if *expanded {
+        let cells = props
+            .bits
+            .chars()
+            .enumerate()
+            .map(|(index, bit)| {
+                html! {
+                    <div class={classes!("asn1-bit", (bit == '1').then_some("asn1-bit-set"))} title={names.get(index).copied()}>
+                        <span class="asn1-bit-index">{index}</span>
+                        <span>{bit}</span>
+                    </div>
+                }
+            })
+            .collect::<Html>();
+
+        html! { <div class="asn1-bit-grid">{cells}</div> }
+    } else {
+        html! {}
+    };
+
+    html! {
+        <>
+            {if set_bits.is_empty() { html! {} } else { html! {
+                <span class="asn1-node-info-label">{set_bits.join(", ")}</span>
+            }}}
+            <button class="jwt-util-button asn1-string-toggle" {onclick}>{if *expanded { "hide bits" } else { "bits" }}</button>
+            {grid}
+        </>
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+struct WideStringValueProps {
+    text: String,
+    meta: OwnedRawAsn1EntityData,
+}
+
+/// Decoded text of the BMPString and UniversalString nodes. The raw UCS-2 or UTF-32 bytes can be shown instead.
+#[function_component(WideStringValue)]
+fn wide_string_value(props: &WideStringValueProps) -> Html {
+    let show_hex = use_state(|| false);
+
+    let show_hex_setter = show_hex.setter();
+    let hex_shown = *show_hex;
+    let onclick = Callback::from(move |event: MouseEvent| {
+        // The click should not select the node.
+        event.stop_propagation();
+        show_hex_setter.set(!hex_shown);
+    });
+
+    html! {
+        <>
+            <span class="asn-simple-value">{if *show_hex { hex::encode(props.meta.data_bytes()) } else { props.text.clone() }}</span>
+            <button class="jwt-util-button asn1-string-toggle" {onclick}>{if *show_hex { "text" } else { "hex" }}</button>
+        </>
+    }
+}
+
 #[derive(PartialEq, Properties, Clone)]
 pub struct BmpStringNodeProps {
     pub node: OwnedBmpString,
@@ -107,15 +215,6 @@ pub struct BmpStringNodeProps {
 
 #[function_component(BmpStringNode)]
 pub fn bmp_string(props: &BmpStringNodeProps) -> Html {
-    let s = String::from_utf16_lossy(
-        &props
-            .node
-            .raw_data()
-            .chunks(2)
-            .map(|bytes| u16::from_be_bytes(bytes.try_into().unwrap()))
-            .collect::<Vec<_>>(),
-    );
-
     let offset = props.meta.tag_position();
     let length_len = props.meta.length_range().len();
     let data_len = props.meta.data_range().len();
@@ -123,14 +222,36 @@ pub fn bmp_string(props: &BmpStringNodeProps) -> Html {
     html! {
         <div class="terminal-asn1-node">
             <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("BmpString")} />
-            <span class="asn-simple-value">{s}</span>
+            <WideStringValue text={props.node.string().to_owned()} meta={props.meta.clone()} />
+        </div>
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct UniversalStringNodeProps {
+    pub node: OwnedUniversalString,
+    pub meta: OwnedRawAsn1EntityData,
+}
+
+#[function_component(UniversalStringNode)]
+pub fn universal_string(props: &UniversalStringNodeProps) -> Html {
+    let offset = props.meta.tag_position();
+    let length_len = props.meta.length_range().len();
+    let data_len = props.meta.data_range().len();
+
+    html! {
+        <div class="terminal-asn1-node">
+            <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("UniversalString")} />
+            <WideStringValue text={props.node.string().to_owned()} meta={props.meta.clone()} />
         </div>
     }
 }
 
 define_string_node!(GeneralString);
+define_string_node!(GraphicString);
 define_string_node!(IA5String);
 define_string_node!(PrintableString);
+define_string_node!(VideotexString);
 define_string_node!(Utf8String);
 define_string_node!(NumericString);
 define_string_node!(VisibleString);
@@ -1,10 +1,11 @@
 use std::str::from_utf8;
 
-use asn1_parser::{OwnedApplicationTag, OwnedExplicitTag, OwnedImplicitTag, OwnedRawAsn1EntityData};
+use asn1_parser::{OwnedApplicationTag, OwnedExplicitTag, OwnedImplicitTag, OwnedPrivateTag, OwnedRawAsn1EntityData};
 use yew::{function_component, html, Callback, Html, Properties};
 
 use crate::asn1::node_options::NodeOptions;
 use crate::asn1::scheme::build_asn1_schema;
+use crate::asn1::scheme::kerberos::{kerberos_message, KerberosFields};
 use crate::asn1::HighlightAction;
 use crate::common::RcSlice;
 
@@ -64,12 +65,21 @@ pub fn application_tag(props: &ApplicationTagProps) -> Html {
     let length_len = props.meta.length_range().len();
     let data_len = props.meta.data_range().len();
 
+    let (name, kerberos_fields) = match kerberos_message(props.meta.raw_bytes()) {
+        Some((message_name, fields)) => (
+            format!("Application {} ({})", props.node.tag_number(), message_name),
+            html! { <KerberosFields {fields} /> },
+        ),
+        None => (format!("Application {}", props.node.tag_number()), html! {}),
+    };
+
     html! {
         <div style="cursor: crosshair; width: 100%">
             <div class="asn1-constructor-header">
-                <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={format!("Application {}", props.node.tag_number())}/>
+                <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} {name}/>
             </div>
             <div class="asn1-constructor-body">
+                {kerberos_fields}
                 {inner_components}
             </div>
         </div>
@@ -114,3 +124,54 @@ pub fn implicit_tag(props: &ImplicitTagProps) -> Html {
         },
     }
 }
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct PrivateTagProps {
+    pub node: OwnedPrivateTag,
+    pub cur_node: Option<u64>,
+    pub set_cur_node: Callback<HighlightAction>,
+    pub meta: OwnedRawAsn1EntityData,
+}
+
+#[function_component(PrivateTagNode)]
+pub fn private_tag(props: &PrivateTagProps) -> Html {
+    let offset = props.meta.tag_position();
+    let length_len = props.meta.length_range().len();
+    let data_len = props.meta.data_range().len();
+    let name = format!("Private {}", props.node.tag_number());
+
+    if props.node.is_constructed() {
+        let set_cur_node = &props.set_cur_node;
+        let inner_components = props
+            .node
+            .inner()
+            .iter()
+            .map(|f| build_asn1_schema(f, &props.cur_node, set_cur_node))
+            .collect::<Vec<_>>();
+
+        html! {
+            <div style="cursor: crosshair; width: 100%">
+                <div class="asn1-constructor-header">
+                    <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} {name}/>
+                </div>
+                <div class="asn1-constructor-body">
+                    {inner_components}
+                </div>
+            </div>
+        }
+    } else {
+        let octets = props.node.octets();
+
+        html! {
+            <div class="terminal-asn1-node">
+                <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} {name} />
+                <span class="asn1-node-info-label">{format!("({} bytes)", octets.len())}</span>
+                {if let Ok(s) = from_utf8(octets) { html! {
+                    <span class="asn-simple-value">{s}</span>
+                }} else { html!{
+                    <span class="asn-simple-value">{hex::encode(octets)}</span>
+                }}}
+            </div>
+        }
+    }
+}
@@ -1,8 +1,10 @@
-use asn1_parser::{GeneralizedTime, OwnedRawAsn1EntityData, UtcTime};
+use asn1_parser::{GeneralizedTime, OwnedRawAsn1EntityData, TimeZone, UtcTime};
+use time::{OffsetDateTime, UtcOffset};
 use yew::{function_component, html, Html, Properties};
 
 use crate::asn1::node_options::NodeOptions;
 use crate::common::RcSlice;
+use crate::timestamp::format_date_time;
 
 #[derive(PartialEq, Properties, Clone)]
 pub struct UtcTimeNodeProps {
@@ -20,6 +22,7 @@ pub fn utc_time_string(props: &UtcTimeNodeProps) -> Html {
         <div class="terminal-asn1-node">
             <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("UtcTime")}/>
             <span class="asn-simple-value">{format_utc_time(&props.node)}</span>
+            <TimeDetails timestamp={props.node.to_unix_timestamp()} raw={String::from_utf8_lossy(props.meta.data_bytes()).into_owned()} />
         </div>
     }
 }
@@ -40,22 +43,87 @@ pub fn general_time_string(props: &GeneralizedTimeNodeProps) -> Html {
         <div class="terminal-asn1-node">
             <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={String::from("GeneralizedTime")}/>
             <span class="asn-simple-value">{format_generalized_time(&props.node)}</span>
+            <TimeDetails timestamp={props.node.to_unix_timestamp()} raw={String::from_utf8_lossy(props.meta.data_bytes()).into_owned()} />
         </div>
     }
 }
 
-fn format_utc_time(utc_time: &UtcTime) -> String {
-    use time::OffsetDateTime;
+#[derive(PartialEq, Properties, Clone)]
+pub struct IsoTimeNodeProps {
+    /// Asn1 type name, e.g. `DateTime`.
+    pub name: String,
+    /// Time value in the ISO 8601 format.
+    pub value: String,
+    pub meta: OwnedRawAsn1EntityData,
+}
 
-    let mut formatted = String::new();
+/// Renders DATE, TIME-OF-DAY, DATE-TIME, and DURATION values.
+#[function_component(IsoTimeNode)]
+pub fn iso_time_string(props: &IsoTimeNodeProps) -> Html {
+    let offset = props.meta.tag_position();
+    let length_len = props.meta.length_range().len();
+    let data_len = props.meta.data_range().len();
+
+    html! {
+        <div class="terminal-asn1-node">
+            <NodeOptions node_bytes={RcSlice::from(props.meta.raw_bytes())} {offset} {length_len} {data_len} name={props.name.clone()}/>
+            <span class="asn-simple-value">{&props.value}</span>
+        </div>
+    }
+}
 
-    let current_year = (OffsetDateTime::now_utc().year() % 100) as u8;
-    if current_year < *utc_time.year.as_ref() {
-        formatted.push_str("19");
+/// Formats the difference between the time and the current time: `in 42 days`, `3 hours ago`.
+fn format_relative_time(difference: i64) -> String {
+    const MINUTE: u64 = 60;
+    const HOUR: u64 = 60 * MINUTE;
+    const DAY: u64 = 24 * HOUR;
+    const YEAR: u64 = 365 * DAY;
+
+    let (value, unit) = match difference.unsigned_abs() {
+        seconds if seconds < MINUTE => (seconds, "second"),
+        seconds if seconds < HOUR => (seconds / MINUTE, "minute"),
+        seconds if seconds < DAY => (seconds / HOUR, "hour"),
+        seconds if seconds < YEAR => (seconds / DAY, "day"),
+        seconds => (seconds / YEAR, "year"),
+    };
+    let plural = if value == 1 { "" } else { "s" };
+
+    if difference >= 0 {
+        format!("in {} {}{}", value, unit, plural)
     } else {
-        formatted.push_str("20");
+        format!("{} {}{} ago", value, unit, plural)
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+struct TimeDetailsProps {
+    /// Unix timestamp in seconds.
+    timestamp: i64,
+    /// Time string as it is encoded.
+    raw: String,
+}
+
+/// Renders the time relative to now and the raw time string. The local time is shown on hover.
+#[function_component(TimeDetails)]
+fn time_details(props: &TimeDetailsProps) -> Html {
+    let now = OffsetDateTime::now_utc();
+    let local_time = OffsetDateTime::from_unix_timestamp(props.timestamp)
+        .ok()
+        .zip(UtcOffset::current_local_offset().ok())
+        .map(|(date_time, offset)| format!("Local time: {}", format_date_time(&date_time.to_offset(offset))));
+
+    html! {
+        <>
+            <span class="asn1-node-info-label" title={local_time}>{format!("({})", format_relative_time(props.timestamp - now.unix_timestamp()))}</span>
+            <span class="asn1-node-info-label">{format!("raw: {}", props.raw)}</span>
+        </>
     }
-    formatted.push_str(&format!("{:02}", utc_time.year.as_ref()));
+}
+
+fn format_utc_time(utc_time: &UtcTime) -> String {
+    let mut formatted = String::new();
+
+    formatted.push_str(&format!("{:04}", utc_time.full_year()));
 
     formatted.push('-');
     formatted.push_str(&format!("{:02}", utc_time.month.as_ref()));
@@ -71,12 +139,20 @@ fn format_utc_time(utc_time: &UtcTime) -> String {
         formatted.push_str(&format!("{:02}", second.as_ref()));
     }
 
-    formatted.push_str(" UTC");
+    if let Some(local_time) = utc_time.local_time.as_ref() {
+        formatted.push(' ');
+        formatted.push(local_time.time_direction.into());
+        formatted.push_str(&format!("{:02}", local_time.hour.as_ref()));
+        formatted.push(':');
+        formatted.push_str(&format!("{:02}", local_time.minute.as_ref()));
+    } else {
+        formatted.push_str(" UTC");
+    }
 
     formatted
 }
 
-fn format_generalized_time(generalized_time: &GeneralizedTime) -> String {
+pub(crate) fn format_generalized_time(generalized_time: &GeneralizedTime) -> String {
     let mut formatted = String::new();
 
     formatted.push_str(&format!("{:04}", generalized_time.year.as_ref()));
@@ -93,14 +169,24 @@ fn format_generalized_time(generalized_time: &GeneralizedTime) -> String {
     formatted.push(':');
     formatted.push_str(&format!("{:02}", generalized_time.second.as_ref()));
 
-    if let Some(local_time) = generalized_time.local_time.as_ref() {
-        formatted.push(' ');
-        formatted.push(local_time.time_direction.into());
-        formatted.push_str(&format!("{:02}", local_time.hour.as_ref()));
-        formatted.push(':');
-        formatted.push_str(&format!("{:02}", local_time.minute.as_ref()));
-    } else {
-        formatted.push_str(" UTC");
+    if let Some(fraction) = generalized_time.fraction.as_ref() {
+        formatted.push_str(&format!(
+            ".{:0width$}",
+            fraction.value(),
+            width = usize::from(fraction.digits())
+        ));
+    }
+
+    match &generalized_time.time_zone {
+        TimeZone::Utc => formatted.push_str(" UTC"),
+        TimeZone::Local => formatted.push_str(" (local time)"),
+        TimeZone::Offset(local_time) => {
+            formatted.push(' ');
+            formatted.push(local_time.time_direction.into());
+            formatted.push_str(&format!("{:02}", local_time.hour.as_ref()));
+            formatted.push(':');
+            formatted.push_str(&format!("{:02}", local_time.minute.as_ref()));
+        }
     }
 
     formatted
@@ -0,0 +1,193 @@
+use std::rc::Rc;
+
+use asn1_parser::{Asn1, Asn1Entity, Asn1Type};
+use web_sys::{HtmlInputElement, KeyboardEvent, MouseEvent};
+use yew::{function_component, html, use_state, Callback, Html, InputEvent, Properties, TargetCast};
+
+use crate::asn1::HighlightAction;
+
+/// Ids of the nodes matched by the search query in the document order.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct SearchMatches(pub Rc<Vec<u64>>);
+
+impl SearchMatches {
+    pub fn contains(&self, id: u64) -> bool {
+        self.0.contains(&id)
+    }
+}
+
+enum SearchQuery {
+    /// `[N]`: tagged nodes with the tag number `N`.
+    TagNumber(u32),
+    Text {
+        /// Lowercase query.
+        text: String,
+        /// Query interpreted as a hex byte pattern, if possible.
+        bytes: Option<Vec<u8>>,
+    },
+}
+
+impl SearchQuery {
+    fn parse(query: &str) -> Option<Self> {
+        let query = query.trim();
+        if query.is_empty() {
+            return None;
+        }
+
+        if let Some(tag_number) = query
+            .strip_prefix('[')
+            .and_then(|query| query.strip_suffix(']'))
+            .and_then(|tag_number| tag_number.trim().parse().ok())
+        {
+            return Some(SearchQuery::TagNumber(tag_number));
+        }
+
+        let hex: String = query.chars().filter(|c| !c.is_whitespace()).collect();
+
+        Some(SearchQuery::Text {
+            text: query.to_lowercase(),
+            bytes: hex::decode(hex).ok(),
+        })
+    }
+}
+
+/// Returns the text of the string and OBJECT IDENTIFIER nodes.
+pub fn node_text(asn1: &Asn1<'_>) -> Option<String> {
+    Some(match asn1.inner_asn1() {
+        Asn1Type::ObjectIdentifier(object_identifier) => object_identifier.format(),
+        Asn1Type::Utf8String(utf8) => utf8.string().to_owned(),
+        Asn1Type::NumericString(numeric) => numeric.string().to_owned(),
+        Asn1Type::PrintableString(printable) => printable.string().to_owned(),
+        Asn1Type::IA5String(ia5) => ia5.string().to_owned(),
+        Asn1Type::VisibleString(visible) => visible.string().to_owned(),
+        Asn1Type::VideotexString(videotex) => videotex.string(),
+        Asn1Type::GraphicString(graphic) => graphic.string(),
+        Asn1Type::GeneralString(general) => general.string(),
+        Asn1Type::BmpString(bmp) => bmp.string().to_owned(),
+        Asn1Type::UniversalString(universal) => universal.string().to_owned(),
+        _ => return None,
+    })
+}
+
+fn tag_number(asn1: &Asn1<'_>) -> Option<u32> {
+    match asn1.inner_asn1() {
+        Asn1Type::ExplicitTag(explicit) => Some(explicit.tag_number()),
+        Asn1Type::ImplicitTag(implicit) => Some(implicit.tag_number()),
+        Asn1Type::ApplicationTag(application) => Some(application.tag_number()),
+        Asn1Type::PrivateTag(private) => Some(private.tag_number()),
+        _ => None,
+    }
+}
+
+fn is_match(asn1: &Asn1<'_>, query: &SearchQuery) -> bool {
+    match query {
+        SearchQuery::TagNumber(number) => tag_number(asn1) == Some(*number),
+        SearchQuery::Text { text, bytes } => {
+            if node_text(asn1).is_some_and(|node_text| node_text.to_lowercase().contains(text.as_str())) {
+                return true;
+            }
+
+            // Only nodes without child nodes are checked, otherwise every parent of the matched node would match too.
+            let is_leaf = asn1.iter().nth(1).is_none();
+            match bytes {
+                Some(bytes) if !bytes.is_empty() && is_leaf => asn1
+                    .meta()
+                    .data_bytes()
+                    .windows(bytes.len())
+                    .any(|window| window == bytes),
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Returns ids of the nodes that match the query.
+///
+/// The query matches OID values, string contents, and hex byte patterns of primitive nodes. `[N]` matches tagged
+/// nodes with the tag number `N`.
+pub fn search(asn1: &Asn1<'_>, query: &str) -> Vec<u64> {
+    let Some(query) = SearchQuery::parse(query) else {
+        return Vec::new();
+    };
+
+    asn1.iter()
+        .map(|(_, node)| node)
+        .filter(|node| is_match(node, &query))
+        .map(|node| node.id())
+        .collect()
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct Asn1SearchProps {
+    pub query: String,
+    pub set_query: Callback<String>,
+    pub matches: SearchMatches,
+    pub set_cur_node: Callback<HighlightAction>,
+}
+
+#[function_component(Asn1Search)]
+pub fn asn1_search(props: &Asn1SearchProps) -> Html {
+    // Index of the last visited match.
+    let current = use_state(|| None::<usize>);
+
+    let set_query = props.set_query.clone();
+    let current_setter = current.setter();
+    let oninput = Callback::from(move |event: InputEvent| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        set_query.emit(input.value());
+        current_setter.set(None);
+    });
+
+    let jump = {
+        let matches = props.matches.clone();
+        let set_cur_node = props.set_cur_node.clone();
+        let current = current.clone();
+        Callback::from(move |forward: bool| {
+            let len = matches.0.len();
+            if len == 0 {
+                return;
+            }
+
+            let index = match (*current, forward) {
+                (None, true) => 0,
+                (None, false) => len - 1,
+                (Some(index), true) => (index + 1) % len,
+                (Some(index), false) => (index + len - 1) % len,
+            };
+            current.set(Some(index));
+            set_cur_node.emit(HighlightAction::Focus(matches.0[index]));
+        })
+    };
+
+    let next = jump.clone();
+    let onkeydown = Callback::from(move |event: KeyboardEvent| {
+        if event.key() == "Enter" {
+            next.emit(!event.shift_key());
+        }
+    });
+    let prev = jump.clone();
+    let onclick_prev = Callback::from(move |_: MouseEvent| prev.emit(false));
+    let onclick_next = Callback::from(move |_: MouseEvent| jump.emit(true));
+
+    let status = match (props.query.trim().is_empty(), props.matches.0.len(), *current) {
+        (true, _, _) => String::new(),
+        (false, 0, _) => "No matches".to_owned(),
+        (false, len, None) => format!("{} matches", len),
+        (false, len, Some(index)) => format!("{}/{}", index + 1, len),
+    };
+
+    html! {
+        <div class="horizontal asn1-search">
+            <input
+                class="base-input"
+                placeholder="OID, string, hex bytes, or [tag number]"
+                value={props.query.clone()}
+                {oninput}
+                {onkeydown}
+            />
+            <button class="jwt-util-button" onclick={onclick_prev}>{"Prev"}</button>
+            <button class="jwt-util-button" onclick={onclick_next}>{"Next"}</button>
+            <span class="total">{status}</span>
+        </div>
+    }
+}
@@ -0,0 +1,57 @@
+use yew::{classes, function_component, html, Callback, Html, MouseEvent, Properties};
+
+/// Id of the tab that is opened by default. It can not be closed.
+pub const PRIMARY_TAB: u64 = 0;
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct Asn1TabsProps {
+    pub tabs: Vec<u64>,
+    pub active: u64,
+    pub select: Callback<u64>,
+    pub add: Callback<()>,
+    pub close: Callback<u64>,
+}
+
+/// Tab bar of the opened ASN.1 documents.
+#[function_component(Asn1Tabs)]
+pub fn asn1_tabs(props: &Asn1TabsProps) -> Html {
+    let tabs = props
+        .tabs
+        .iter()
+        .enumerate()
+        .map(|(index, id)| {
+            let id = *id;
+            let select = props.select.clone();
+            let onclick = Callback::from(move |_: MouseEvent| select.emit(id));
+
+            let close_button = if id != PRIMARY_TAB {
+                let close = props.close.clone();
+                let onclick = Callback::from(move |event: MouseEvent| {
+                    event.stop_propagation();
+                    close.emit(id);
+                });
+
+                html! { <span class="asn1-tab-close" {onclick}>{"×"}</span> }
+            } else {
+                html! {}
+            };
+
+            html! {
+                <div key={id} class={classes!("asn1-tab", (id == props.active).then_some("asn1-tab-active"))} {onclick}>
+                    <span>{format!("Document {}", index + 1)}</span>
+                    {close_button}
+                </div>
+            }
+        })
+        .collect::<Html>();
+
+    let add = props.add.clone();
+    let onclick = Callback::from(move |_: MouseEvent| add.emit(()));
+
+    html! {
+        <div class="horizontal asn1-tabs">
+            {tabs}
+            <button class="jwt-util-button" {onclick}>{"+ New tab"}</button>
+        </div>
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::BTreeSet;
+use std::rc::Rc;
+
+use asn1_parser::{Asn1, Asn1Entity, Asn1Type, BitString, OwnedAsn1};
+use web_sys::{Element, Event, MouseEvent};
+use yew::{
+    classes, function_component, html, use_effect_with, use_memo, use_node_ref, use_state, Callback, Html, Properties,
+};
+
+use crate::asn1::annotations::Annotations;
+use crate::asn1::breadcrumbs::node_name;
+use crate::asn1::collapse::CollapseState;
+use crate::asn1::search::{node_text, SearchMatches};
+use crate::asn1::x509::{FieldLabels, NamedBits};
+use crate::asn1::HighlightAction;
+
+/// Trees with more nodes are rendered by the [VirtualTree] instead of the full viewer.
+pub const VIRTUAL_TREE_THRESHOLD: usize = 3000;
+
+/// Height of the row in pixels. It should match the `.asn1-virtual-row` height.
+const ROW_HEIGHT: f64 = 22.0;
+/// Rows rendered above and below the visible area to avoid flickering during the scrolling.
+const OVERSCAN_ROWS: usize = 20;
+const PREVIEW_MAX_LEN: usize = 80;
+
+struct Row {
+    id: u64,
+    depth: usize,
+    name: String,
+    preview: String,
+}
+
+/// Returns the names of the set bits, e.g. `digitalSignature, keyCertSign`.
+fn set_bit_names(bit_string: &BitString<'_>, names: &[&str]) -> String {
+    let bits = &bit_string.raw_bits()[1..];
+
+    (0..bit_string.bits_amount())
+        .filter(|index| bits[index / 8] & (0x80 >> (index % 8)) != 0)
+        .filter_map(|index| names.get(index).copied())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn preview(asn1: &Asn1<'_>, named_bits: &NamedBits) -> String {
+    if let (Asn1Type::BitString(bit_string), Some(names)) = (asn1.inner_asn1(), named_bits.get(asn1.id())) {
+        return set_bit_names(bit_string, names);
+    }
+
+    let preview = match asn1.inner_asn1() {
+        Asn1Type::Integer(integer) => integer.as_big_int().to_string(),
+        Asn1Type::Enumerated(enumerated) => enumerated.as_big_int().to_string(),
+        Asn1Type::Bool(boolean) => boolean.value().to_string(),
+        Asn1Type::UtcTime(_) | Asn1Type::GeneralizedTime(_) => {
+            String::from_utf8_lossy(asn1.meta().data_bytes()).into_owned()
+        }
+        Asn1Type::Date(value) => value.to_string(),
+        Asn1Type::TimeOfDay(value) => value.to_string(),
+        Asn1Type::DateTime(value) => value.to_string(),
+        Asn1Type::Duration(value) => value.to_string(),
+        Asn1Type::Null(_) => String::new(),
+        _ => match node_text(asn1) {
+            Some(text) => text,
+            // Constructed nodes show their fields as separate rows.
+            None if asn1.iter().nth(1).is_some() => String::new(),
+            None => hex::encode(asn1.meta().data_bytes()),
+        },
+    };
+
+    match preview.char_indices().nth(PREVIEW_MAX_LEN) {
+        Some((end, _)) => format!("{}…", &preview[..end]),
+        None => preview,
+    }
+}
+
+/// Returns the visible rows: descendants of the collapsed nodes are skipped.
+fn visible_rows(asn1: &Asn1<'_>, collapsed: &BTreeSet<u64>, named_bits: &NamedBits) -> Vec<Row> {
+    let mut rows = Vec::new();
+    let mut collapsed_depth = None;
+
+    for (depth, node) in asn1.iter() {
+        match collapsed_depth {
+            Some(collapsed_depth) if depth > collapsed_depth => continue,
+            _ => collapsed_depth = None,
+        }
+        if collapsed.contains(&node.id()) {
+            collapsed_depth = Some(depth);
+        }
+
+        rows.push(Row {
+            id: node.id(),
+            depth,
+            name: node_name(node),
+            preview: preview(node, named_bits),
+        });
+    }
+
+    rows
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct VirtualTreeProps {
+    /// Rc comparison checks the pointers first, so the unchanged structure is not compared node by node.
+    pub structure: Rc<OwnedAsn1>,
+    pub cur_node: Option<u64>,
+    pub set_cur_node: Callback<HighlightAction>,
+    pub search_matches: SearchMatches,
+    pub collapse: CollapseState,
+    /// The tree is scrolled to this node when it changes.
+    pub scroll_target: Option<u64>,
+    pub field_labels: FieldLabels,
+    pub annotations: Annotations,
+    pub named_bits: NamedBits,
+}
+
+/// Simplified tree viewer for very large structures. Every node is a single row and only the rows
+/// in the visible area are mounted. The values can not be edited in this viewer.
+#[function_component(VirtualTree)]
+pub fn virtual_tree(props: &VirtualTreeProps) -> Html {
+    let rows: Rc<Vec<Row>> = use_memo(
+        (
+            props.structure.clone(),
+            props.collapse.collapsed.clone(),
+            props.named_bits.clone(),
+        ),
+        |(structure, collapsed, named_bits)| visible_rows(structure, collapsed, named_bits),
+    );
+
+    let container = use_node_ref();
+    let scroll_top = use_state(|| 0.0);
+    let viewport_height = use_state(|| 600.0);
+
+    let scroll_container = container.clone();
+    let scroll_top_setter = scroll_top.setter();
+    let viewport_height_setter = viewport_height.setter();
+    let onscroll = Callback::from(move |_: Event| {
+        if let Some(container) = scroll_container.cast::<Element>() {
+            scroll_top_setter.set(f64::from(container.scroll_top()));
+            viewport_height_setter.set(f64::from(container.client_height()));
+        }
+    });
+
+    let target_container = container.clone();
+    let target_rows = rows.clone();
+    use_effect_with(props.scroll_target, move |scroll_target| {
+        let index = scroll_target.and_then(|id| target_rows.iter().position(|row| row.id == id));
+        if let (Some(index), Some(container)) = (index, target_container.cast::<Element>()) {
+            container.set_scroll_top((index as f64 * ROW_HEIGHT) as i32);
+        }
+    });
+
+    let first = ((*scroll_top / ROW_HEIGHT) as usize).saturating_sub(OVERSCAN_ROWS);
+    let last = (((*scroll_top + *viewport_height) / ROW_HEIGHT) as usize + OVERSCAN_ROWS).min(rows.len());
+
+    let rendered_rows = rows
+        .iter()
+        .enumerate()
+        .take(last)
+        .skip(first)
+        .map(|(index, row)| {
+            let id = row.id;
+            let set_cur_node = props.set_cur_node.clone();
+            let onclick = Callback::from(move |_: MouseEvent| set_cur_node.emit(HighlightAction::Select(id)));
+
+            let toggle = if props.collapse.is_collapsible(id) {
+                let toggle = props.collapse.toggle.clone();
+                let onclick = Callback::from(move |event: MouseEvent| {
+                    event.stop_propagation();
+                    toggle.emit(id);
+                });
+
+                html! {
+                    <span class="asn1-collapse-toggle" {onclick}>{if props.collapse.is_collapsed(id) { "▸" } else { "▾" }}</span>
+                }
+            } else {
+                html! { <span class="asn1-collapse-toggle" /> }
+            };
+
+            let class = classes!(
+                "asn1-virtual-row",
+                (props.cur_node == Some(id)).then_some("hover_node"),
+                props.search_matches.contains(id).then_some("asn1-search-match"),
+            );
+            let style = format!(
+                "top: {}px; padding-left: {}em",
+                index as f64 * ROW_HEIGHT,
+                row.depth
+            );
+
+            let field_label = match props.field_labels.get(id) {
+                Some(label) => html! { <span class="asn1-field-label">{label}</span> },
+                None => html! {},
+            };
+            let note = match props.annotations.get(id) {
+                Some(note) => html! { <span class="asn1-node-note" title={note.to_owned()}>{note}</span> },
+                None => html! {},
+            };
+
+            html! {
+                <div key={id} {class} {style} {onclick}>
+                    {toggle}
+                    {field_label}
+                    {note}
+                    <span class="asn1-node-options-name">{row.name.clone()}</span>
+                    <span class="asn-simple-value">{row.preview.clone()}</span>
+                </div>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <div class="vertical">
+            <span class="asn1-virtual-tree-notice">
+                {format!("The structure has more than {} nodes, so it is shown as a simplified tree. Editing is disabled in this mode.", VIRTUAL_TREE_THRESHOLD)}
+            </span>
+            <div class="asn1-virtual-tree" ref={container} {onscroll}>
+                <div style={format!("position: relative; height: {}px", rows.len() as f64 * ROW_HEIGHT)}>
+                    {rendered_rows}
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use asn1_parser::{oid_name, Asn1, Asn1Entity, Asn1Type};
+
+/// Semantic labels of the nodes keyed by the node id.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FieldLabels(pub Rc<BTreeMap<u64, String>>);
+
+impl FieldLabels {
+    pub fn get(&self, id: u64) -> Option<&str> {
+        self.0.get(&id).map(String::as_str)
+    }
+}
+
+pub enum Label {
+    Name(&'static str),
+    /// The name followed by the name of the first OBJECT IDENTIFIER field: `extension: subjectAltName`.
+    ByOid(&'static str),
+}
+
+impl Label {
+    /// Returns the field name without the OID part.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Label::Name(name) | Label::ByOid(name) => name,
+        }
+    }
+
+    fn text(&self, asn1: &Asn1<'_>) -> String {
+        match self {
+            Label::Name(name) => (*name).to_owned(),
+            Label::ByOid(name) => {
+                let oid = match asn1.inner_asn1() {
+                    Asn1Type::Sequence(sequence) => {
+                        sequence.fields().first().and_then(|field| match field.inner_asn1() {
+                            Asn1Type::ObjectIdentifier(oid) => {
+                                Some(oid_name(oid).map(str::to_owned).unwrap_or_else(|| oid.format()))
+                            }
+                            _ => None,
+                        })
+                    }
+                    _ => None,
+                };
+
+                match oid {
+                    Some(oid) => format!("{}: {}", name, oid),
+                    None => (*name).to_owned(),
+                }
+            }
+        }
+    }
+}
+
+/// Expected structure of the node.
+pub enum Shape {
+    Any,
+    Boolean,
+    Integer,
+    BitString,
+    OctetString,
+    ObjectIdentifier,
+    GeneralString,
+    /// UTCTime or GeneralizedTime.
+    Time,
+    Sequence(&'static [Field]),
+    SequenceOf(&'static Field),
+    SetOf(&'static Field),
+    Explicit(u32, &'static Field),
+    Implicit(u32),
+    /// Context-specific tag with any content, e.g. `[0] IMPLICIT SET OF` that is decoded as the explicit tag.
+    ContextTag(u32),
+    Application(u32, &'static Field),
+}
+
+pub struct Field {
+    pub label: Label,
+    pub shape: Shape,
+    pub optional: bool,
+}
+
+pub const fn field(name: &'static str, shape: Shape) -> Field {
+    Field {
+        label: Label::Name(name),
+        shape,
+        optional: false,
+    }
+}
+
+pub const fn optional(name: &'static str, shape: Shape) -> Field {
+    Field {
+        label: Label::Name(name),
+        shape,
+        optional: true,
+    }
+}
+
+pub const ALGORITHM_IDENTIFIER: &[Field] = &[
+    field("algorithm", Shape::ObjectIdentifier),
+    optional("parameters", Shape::Any),
+];
+
+const ATTRIBUTE_TYPE_AND_VALUE: Field = Field {
+    label: Label::ByOid("attribute"),
+    shape: Shape::Sequence(&[field("type", Shape::ObjectIdentifier), field("value", Shape::Any)]),
+    optional: false,
+};
+
+const RELATIVE_DISTINGUISHED_NAME: Field = field("rdn", Shape::SetOf(&ATTRIBUTE_TYPE_AND_VALUE));
+
+pub const NAME: Shape = Shape::SequenceOf(&RELATIVE_DISTINGUISHED_NAME);
+
+pub const SUBJECT_PUBLIC_KEY_INFO: &[Field] = &[
+    field("algorithm", Shape::Sequence(ALGORITHM_IDENTIFIER)),
+    field("subjectPublicKey", Shape::BitString),
+];
+
+const EXTENSION: Field = Field {
+    label: Label::ByOid("extension"),
+    shape: Shape::Sequence(&[
+        field("extnID", Shape::ObjectIdentifier),
+        optional("critical", Shape::Boolean),
+        field("extnValue", Shape::OctetString),
+    ]),
+    optional: false,
+};
+
+const TBS_CERTIFICATE: Field = field(
+    "tbsCertificate",
+    Shape::Sequence(&[
+        optional("version", Shape::Explicit(0, &field("version", Shape::Integer))),
+        field("serialNumber", Shape::Integer),
+        field("signature", Shape::Sequence(ALGORITHM_IDENTIFIER)),
+        field("issuer", NAME),
+        field(
+            "validity",
+            Shape::Sequence(&[field("notBefore", Shape::Time), field("notAfter", Shape::Time)]),
+        ),
+        field("subject", NAME),
+        field("subjectPublicKeyInfo", Shape::Sequence(SUBJECT_PUBLIC_KEY_INFO)),
+        optional("issuerUniqueID", Shape::Implicit(1)),
+        optional("subjectUniqueID", Shape::Implicit(2)),
+        optional(
+            "extensions",
+            Shape::Explicit(3, &field("extensions", Shape::SequenceOf(&EXTENSION))),
+        ),
+    ]),
+);
+
+pub const CERTIFICATE: Field = field(
+    "certificate",
+    Shape::Sequence(&[
+        TBS_CERTIFICATE,
+        field("signatureAlgorithm", Shape::Sequence(ALGORITHM_IDENTIFIER)),
+        field("signatureValue", Shape::BitString),
+    ]),
+);
+
+fn match_shape(asn1: &Asn1<'_>, shape: &Shape, labels: &mut Vec<(u64, String)>) -> bool {
+    match (shape, asn1.inner_asn1()) {
+        (Shape::Any, _)
+        | (Shape::Boolean, Asn1Type::Bool(_))
+        | (Shape::Integer, Asn1Type::Integer(_))
+        | (Shape::BitString, Asn1Type::BitString(_))
+        | (Shape::OctetString, Asn1Type::OctetString(_))
+        | (Shape::ObjectIdentifier, Asn1Type::ObjectIdentifier(_))
+        | (Shape::GeneralString, Asn1Type::GeneralString(_))
+        | (Shape::Time, Asn1Type::UtcTime(_) | Asn1Type::GeneralizedTime(_)) => true,
+        (Shape::Sequence(fields), Asn1Type::Sequence(sequence)) => match_fields(sequence.fields(), fields, labels),
+        (Shape::SequenceOf(field), Asn1Type::Sequence(sequence)) => {
+            sequence.fields().iter().all(|asn1| match_field(asn1, field, labels))
+        }
+        (Shape::SetOf(field), Asn1Type::Set(set)) => set.fields().iter().all(|asn1| match_field(asn1, field, labels)),
+        (Shape::Explicit(tag_number, field), Asn1Type::ExplicitTag(explicit)) => {
+            explicit.tag_number() == *tag_number
+                && matches!(explicit.inner(), [inner] if match_field(inner, field, labels))
+        }
+        (Shape::Implicit(tag_number), Asn1Type::ImplicitTag(implicit)) => implicit.tag_number() == *tag_number,
+        (Shape::ContextTag(tag_number), Asn1Type::ExplicitTag(explicit)) => explicit.tag_number() == *tag_number,
+        (Shape::ContextTag(tag_number), Asn1Type::ImplicitTag(implicit)) => implicit.tag_number() == *tag_number,
+        (Shape::Application(tag_number, field), Asn1Type::ApplicationTag(application)) => {
+            application.tag_number() == *tag_number
+                && matches!(application.inner(), [inner] if match_field(inner, field, labels))
+        }
+        _ => false,
+    }
+}
+
+/// Matches the node against the field template. Labels of the partially matched subtree are discarded.
+fn match_field(asn1: &Asn1<'_>, field: &Field, labels: &mut Vec<(u64, String)>) -> bool {
+    let matched_len = labels.len();
+
+    if match_shape(asn1, &field.shape, labels) {
+        labels.push((asn1.id(), field.label.text(asn1)));
+        true
+    } else {
+        labels.truncate(matched_len);
+        false
+    }
+}
+
+/// Matches the SEQUENCE fields against the templates. Optional templates are skipped when the field does not match.
+fn match_fields(fields: &[Asn1<'_>], templates: &[Field], labels: &mut Vec<(u64, String)>) -> bool {
+    let mut fields = fields.iter().peekable();
+
+    for template in templates {
+        match fields.peek() {
+            Some(asn1) if match_field(asn1, template, labels) => {
+                fields.next();
+            }
+            _ if template.optional => {}
+            _ => return false,
+        }
+    }
+
+    fields.next().is_none()
+}
+
+/// Returns semantic labels of the nodes when the structure is an X.509 certificate.
+pub fn field_labels(asn1: &Asn1<'_>) -> BTreeMap<u64, String> {
+    let mut labels = Vec::new();
+
+    if match_field(asn1, &CERTIFICATE, &mut labels) {
+        labels.into_iter().collect()
+    } else {
+        BTreeMap::new()
+    }
+}
+
+/// Bit names of the BIT STRING extensions keyed by the extension OID.
+const NAMED_BITS: &[(&str, &[&str])] = &[
+    (
+        // keyUsage
+        "2.5.29.15",
+        &[
+            "digitalSignature",
+            "nonRepudiation",
+            "keyEncipherment",
+            "dataEncipherment",
+            "keyAgreement",
+            "keyCertSign",
+            "cRLSign",
+            "encipherOnly",
+            "decipherOnly",
+        ],
+    ),
+    (
+        // netscape-cert-type
+        "2.16.840.1.113730.1.1",
+        &[
+            "sslClient",
+            "sslServer",
+            "smime",
+            "objectSigning",
+            "reserved",
+            "sslCA",
+            "smimeCA",
+            "objectSigningCA",
+        ],
+    ),
+];
+
+/// Bit names of the BIT STRING nodes keyed by the node id.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct NamedBits(pub Rc<BTreeMap<u64, &'static [&'static str]>>);
+
+impl NamedBits {
+    pub fn get(&self, id: u64) -> Option<&'static [&'static str]> {
+        self.0.get(&id).copied()
+    }
+}
+
+/// Finds the BIT STRING values of the known extensions: `SEQUENCE { OBJECT IDENTIFIER, [BOOLEAN,] OCTET STRING }`.
+pub fn named_bits(asn1: &Asn1<'_>) -> BTreeMap<u64, &'static [&'static str]> {
+    asn1.iter()
+        .filter_map(|(_, node)| {
+            let Asn1Type::Sequence(extension) = node.inner_asn1() else {
+                return None;
+            };
+            let fields = extension.fields();
+            let Asn1Type::ObjectIdentifier(oid) = fields.first()?.inner_asn1() else {
+                return None;
+            };
+            let oid = oid.format();
+            let (_, names) = NAMED_BITS.iter().find(|(known, _)| *known == oid)?;
+            let Asn1Type::OctetString(value) = fields.last()?.inner_asn1() else {
+                return None;
+            };
+            let bits = value.inner()?;
+
+            matches!(bits.inner_asn1(), Asn1Type::BitString(_)).then_some((bits.id(), *names))
+        })
+        .collect()
+}
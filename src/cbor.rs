@@ -0,0 +1,250 @@
+mod cbor_decoder;
+mod cose;
+mod msgpack_decoder;
+mod value;
+
+use yew::{function_component, html, use_state, Callback, Html};
+
+use self::cbor_decoder::decode_cbor;
+use self::cose::{
+    cose_message_item, map_key_label, map_value_description, parse_authenticator_data, tag_context, tag_name,
+    AuthenticatorData, Context, AUTHENTICATOR_DATA_FLAGS,
+};
+use self::msgpack_decoder::decode_msgpack;
+use self::value::Value;
+use crate::common::{ByteInput, BytesFormat, Switch};
+use crate::url_query_params::generate_asn1_link;
+
+/// MessagePack extension type for timestamps.
+const MSGPACK_TIMESTAMP_EXT: i8 = -1;
+
+fn format_key(key: &Value) -> String {
+    match key {
+        Value::Integer(value) => value.to_string(),
+        Value::Text(value) => format!("{:?}", value),
+        Value::Bytes(value) => format!("0x{}", hex::encode(value)),
+        value => value.type_name().to_owned(),
+    }
+}
+
+fn render_node_header(label: &str, type_name: &str, description: String) -> Html {
+    html! {
+        <div class="asn1-constructor-header">
+            {if label.is_empty() {
+                html! {}
+            } else {
+                html! { <span class="cbor-label">{format!("{}:", label)}</span> }
+            }}
+            <span class="asn1-node-info-label">{type_name}</span>
+            <span>{description}</span>
+        </div>
+    }
+}
+
+fn render_container(label: &str, type_name: &str, description: String, children: Html) -> Html {
+    html! {
+        <div style="width: 100%">
+            {render_node_header(label, type_name, description)}
+            <div class="asn1-constructor-body">
+                {children}
+            </div>
+        </div>
+    }
+}
+
+fn render_bytes(label: &str, bytes: &[u8], context: Context) -> Html {
+    // COSE protected headers and payloads are byte strings with the encoded CBOR inside.
+    if context != Context::Generic {
+        let inner_context = match context {
+            Context::EncodedCbor => Context::Generic,
+            context => context,
+        };
+
+        if let Ok(values) = decode_cbor(bytes) {
+            if let [value] = values.as_slice() {
+                return render_container(
+                    label,
+                    "bytes",
+                    format!("({} bytes, encoded CBOR)", bytes.len()),
+                    render_value(value, "", inner_context),
+                );
+            }
+        }
+    }
+
+    html! {
+        <div class="vertical">
+            {render_node_header(label, "bytes", format!("({} bytes) {}", bytes.len(), hex::encode(bytes)))}
+            // x5c/x5chain certificates
+            {if bytes.first() == Some(&0x30) {
+                html! {
+                    <a class="a-link" href={generate_asn1_link(bytes.to_vec())} target="_blank">{"Open in ASN.1 viewer"}</a>
+                }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
+
+fn render_authenticator_data(label: &str, data: &AuthenticatorData) -> Html {
+    let flags = AUTHENTICATOR_DATA_FLAGS
+        .iter()
+        .filter(|(flag, _)| data.flags & flag != 0)
+        .map(|(_, name)| *name)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let children = html! {
+        <div class="vertical">
+            {render_node_header("rpIdHash", "bytes", hex::encode(&data.rp_id_hash))}
+            {render_node_header("flags", "integer", format!("0x{:02x} ({})", data.flags, flags))}
+            {render_node_header("signCount", "integer", data.sign_count.to_string())}
+            {if let Some(credential_data) = &data.attested_credential_data {
+                html! {
+                    <div class="vertical">
+                        {render_node_header("aaguid", "bytes", hex::encode(&credential_data.aaguid))}
+                        {render_node_header("credentialId", "bytes", hex::encode(&credential_data.credential_id))}
+                        {render_value(&credential_data.credential_public_key, "credentialPublicKey", Context::CoseKey)}
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            {if let Some(extensions) = &data.extensions {
+                render_value(extensions, "extensions", Context::Generic)
+            } else {
+                html! {}
+            }}
+        </div>
+    };
+
+    render_container(label, "bytes", "(WebAuthn authenticator data)".to_owned(), children)
+}
+
+fn render_map_entry(map: &Value, key: &Value, value: &Value, context: Context) -> Html {
+    let label = match map_key_label(key, context, map) {
+        Some(name) => format!("{} ({})", name, format_key(key)),
+        None => format_key(key),
+    };
+
+    if let (Value::Text(key), Value::Bytes(data)) = (key, value) {
+        if key == "authData" {
+            if let Ok(authenticator_data) = parse_authenticator_data(data) {
+                return render_authenticator_data(&label, &authenticator_data);
+            }
+        }
+    }
+
+    match map_value_description(key, value, context) {
+        Some(description) => render_node_header(
+            &label,
+            value.type_name(),
+            format!("{} ({})", format_value(value), description),
+        ),
+        None => render_value(value, &label, Context::Generic),
+    }
+}
+
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::Integer(value) => value.to_string(),
+        Value::Text(value) => format!("{:?}", value),
+        Value::Bool(value) => value.to_string(),
+        Value::Float(value) => value.to_string(),
+        Value::Simple(value) => value.to_string(),
+        _ => String::new(),
+    }
+}
+
+fn render_value(value: &Value, label: &str, context: Context) -> Html {
+    match value {
+        Value::Bytes(bytes) => render_bytes(label, bytes, context),
+        Value::Array(items) => {
+            let children = items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| match context {
+                    Context::CoseMessage(tag) => {
+                        let (name, item_context) = cose_message_item(tag, index);
+                        render_value(item, name, item_context)
+                    }
+                    _ => render_value(item, &format!("#{}", index), Context::Generic),
+                })
+                .collect::<Html>();
+
+            render_container(label, "array", format!("({} items)", items.len()), children)
+        }
+        Value::Map(entries) => {
+            let children = entries
+                .iter()
+                .map(|(key, entry_value)| render_map_entry(value, key, entry_value, context))
+                .collect::<Html>();
+
+            render_container(label, "map", format!("({} entries)", entries.len()), children)
+        }
+        Value::Tag(tag, inner) => render_container(
+            label,
+            "tag",
+            format!("{} ({})", tag, tag_name(*tag)),
+            render_value(inner, "", tag_context(*tag)),
+        ),
+        Value::Extension(ext_type, data) => {
+            let description = if *ext_type == MSGPACK_TIMESTAMP_EXT {
+                format!("type {} (timestamp): {}", ext_type, hex::encode(data))
+            } else {
+                format!("type {}: {}", ext_type, hex::encode(data))
+            };
+
+            render_node_header(label, "ext", description)
+        }
+        Value::Null | Value::Undefined => render_node_header(label, value.type_name(), String::new()),
+        value => render_node_header(label, value.type_name(), format_value(value)),
+    }
+}
+
+#[function_component(CborPage)]
+pub fn cbor_page() -> Html {
+    let raw_data = use_state(Vec::<u8>::new);
+    let is_msgpack = use_state(|| false);
+
+    let raw_data_setter = raw_data.setter();
+    let set_data = Callback::from(move |data: Vec<u8>| raw_data_setter.set(data));
+
+    let is_msgpack_setter = is_msgpack.setter();
+    let set_is_msgpack = Callback::from(move |state: bool| is_msgpack_setter.set(state));
+
+    let values = if raw_data.is_empty() {
+        None
+    } else if *is_msgpack {
+        Some(decode_msgpack(&raw_data))
+    } else {
+        Some(decode_cbor(&raw_data))
+    };
+
+    html! {
+        <article class="vertical">
+            <div class="horizontal">
+                <span class="total">{"CBOR"}</span>
+                <Switch id={"cbor-format".to_owned()} state={*is_msgpack} setter={set_is_msgpack} />
+                <span class="total">{"MessagePack"}</span>
+            </div>
+            <ByteInput
+                bytes={(*raw_data).clone()}
+                setter={set_data}
+                format={BytesFormat::Base64}
+                placeholder={"CBOR or MessagePack data".to_owned()}
+                rows={4}
+            />
+            {match values {
+                Some(Ok(values)) => html! {
+                    <div class="parser-tree">
+                        {values.iter().map(|value| render_value(value, "", Context::Generic)).collect::<Html>()}
+                    </div>
+                },
+                Some(Err(err)) => html! { <span class="total">{format!("Invalid data: {}", err)}</span> },
+                None => html! {},
+            }}
+        </article>
+    }
+}
@@ -0,0 +1,273 @@
+use super::value::Value;
+use crate::utils::BytesReader;
+
+// https://www.rfc-editor.org/rfc/rfc8949
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+const MAJOR_SIMPLE: u8 = 7;
+
+const INDEFINITE_LENGTH: u8 = 31;
+const BREAK: u8 = 0xff;
+
+const MAX_DEPTH: usize = 128;
+
+/// Converts IEEE 754 half-precision float to `f64` (RFC 8949, Appendix D).
+fn half_to_f64(half: u16) -> f64 {
+    let exponent = (half >> 10) & 0x1f;
+    let mantissa = f64::from(half & 0x3ff);
+
+    let value = match exponent {
+        0 => mantissa * 2_f64.powi(-24),
+        31 if mantissa == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        exponent => (mantissa + 1024.0) * 2_f64.powi(i32::from(exponent) - 25),
+    };
+
+    if half & 0x8000 != 0 {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Reads the data item argument. Returns `None` for the indefinite length.
+fn read_argument(reader: &mut BytesReader<'_>, additional_info: u8) -> Result<Option<u64>, String> {
+    Ok(Some(match additional_info {
+        0..=23 => u64::from(additional_info),
+        24 => u64::from(reader.read_u8()?),
+        25 => u64::from(reader.read_u16_be()?),
+        26 => u64::from(reader.read_u32_be()?),
+        27 => u64::from_be_bytes(reader.read_array()?),
+        INDEFINITE_LENGTH => return Ok(None),
+        _ => return Err(format!("Reserved additional info value: {}", additional_info)),
+    }))
+}
+
+fn read_len(argument: u64) -> Result<usize, String> {
+    usize::try_from(argument).map_err(|_| format!("Length is too big: {}", argument))
+}
+
+fn is_break(reader: &BytesReader<'_>, data: &[u8]) -> bool {
+    data.get(data.len() - reader.remaining()) == Some(&BREAK)
+}
+
+/// Reads chunks of the indefinite-length byte or text string.
+fn read_chunks(reader: &mut BytesReader<'_>, data: &[u8], major_type: u8) -> Result<Vec<u8>, String> {
+    let mut result = Vec::new();
+
+    while !is_break(reader, data) {
+        let initial_byte = reader.read_u8()?;
+        if initial_byte >> 5 != major_type {
+            return Err("Invalid chunk type in the indefinite-length string".into());
+        }
+
+        let len = read_argument(reader, initial_byte & 0x1f)?.ok_or("Nested indefinite-length strings")?;
+        result.extend_from_slice(reader.read_bytes(read_len(len)?)?);
+    }
+    reader.read_u8()?;
+
+    Ok(result)
+}
+
+fn decode_item(reader: &mut BytesReader<'_>, data: &[u8], depth: usize) -> Result<Value, String> {
+    if depth > MAX_DEPTH {
+        return Err("Data items nesting is too deep".into());
+    }
+
+    let initial_byte = reader.read_u8()?;
+    let major_type = initial_byte >> 5;
+    let additional_info = initial_byte & 0x1f;
+
+    if major_type == MAJOR_SIMPLE {
+        return Ok(match additional_info {
+            20 => Value::Bool(false),
+            21 => Value::Bool(true),
+            22 => Value::Null,
+            23 => Value::Undefined,
+            24 => Value::Simple(reader.read_u8()?),
+            25 => Value::Float(half_to_f64(reader.read_u16_be()?)),
+            26 => Value::Float(f32::from_bits(reader.read_u32_be()?).into()),
+            27 => Value::Float(f64::from_bits(u64::from_be_bytes(reader.read_array()?))),
+            INDEFINITE_LENGTH => return Err("Unexpected break".into()),
+            28..=30 => return Err(format!("Reserved additional info value: {}", additional_info)),
+            simple => Value::Simple(simple),
+        });
+    }
+
+    let argument = read_argument(reader, additional_info)?;
+
+    Ok(match (major_type, argument) {
+        (MAJOR_UNSIGNED, Some(value)) => Value::Integer(value.into()),
+        (MAJOR_NEGATIVE, Some(value)) => Value::Integer(-1 - i128::from(value)),
+        (MAJOR_BYTES, Some(len)) => Value::Bytes(reader.read_bytes(read_len(len)?)?.to_vec()),
+        (MAJOR_BYTES, None) => Value::Bytes(read_chunks(reader, data, MAJOR_BYTES)?),
+        (MAJOR_TEXT, Some(len)) => {
+            Value::Text(String::from_utf8_lossy(reader.read_bytes(read_len(len)?)?).into_owned())
+        }
+        (MAJOR_TEXT, None) => {
+            Value::Text(String::from_utf8_lossy(&read_chunks(reader, data, MAJOR_TEXT)?).into_owned())
+        }
+        (MAJOR_ARRAY, Some(len)) => {
+            // Items are pushed one by one: the untrusted length must not be used for the preallocation.
+            let mut items = Vec::new();
+            for _ in 0..len {
+                items.push(decode_item(reader, data, depth + 1)?);
+            }
+
+            Value::Array(items)
+        }
+        (MAJOR_ARRAY, None) => {
+            let mut items = Vec::new();
+            while !is_break(reader, data) {
+                items.push(decode_item(reader, data, depth + 1)?);
+            }
+            reader.read_u8()?;
+
+            Value::Array(items)
+        }
+        (MAJOR_MAP, Some(len)) => {
+            let mut entries = Vec::new();
+            for _ in 0..len {
+                entries.push((
+                    decode_item(reader, data, depth + 1)?,
+                    decode_item(reader, data, depth + 1)?,
+                ));
+            }
+
+            Value::Map(entries)
+        }
+        (MAJOR_MAP, None) => {
+            let mut entries = Vec::new();
+            while !is_break(reader, data) {
+                entries.push((
+                    decode_item(reader, data, depth + 1)?,
+                    decode_item(reader, data, depth + 1)?,
+                ));
+            }
+            reader.read_u8()?;
+
+            Value::Map(entries)
+        }
+        (MAJOR_TAG, Some(tag)) => Value::Tag(tag, Box::new(decode_item(reader, data, depth + 1)?)),
+        (major_type, _) => return Err(format!("Invalid indefinite length for the major type {}", major_type)),
+    })
+}
+
+/// Decodes the first data item and returns it with the number of consumed bytes.
+pub fn decode_cbor_item(data: &[u8]) -> Result<(Value, usize), String> {
+    let mut reader = BytesReader::new(data);
+    let value = decode_item(&mut reader, data, 0)?;
+
+    Ok((value, data.len() - reader.remaining()))
+}
+
+/// Decodes all data items (CBOR sequence, RFC 8742).
+pub fn decode_cbor(data: &[u8]) -> Result<Vec<Value>, String> {
+    let mut reader = BytesReader::new(data);
+
+    let mut values = Vec::new();
+    while !reader.is_empty() {
+        values.push(decode_item(&mut reader, data, 0)?);
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        // RFC 8949, Appendix A
+        let cases: [(&[u8], Value); 8] = [
+            (
+                &[0x1b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+                Value::Integer(u64::MAX.into()),
+            ),
+            (
+                &[0x3b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+                Value::Integer(-1 - i128::from(u64::MAX)),
+            ),
+            (
+                &[0xa2, 0x01, 0x02, 0x03, 0x04],
+                Value::Map(vec![
+                    (Value::Integer(1), Value::Integer(2)),
+                    (Value::Integer(3), Value::Integer(4)),
+                ]),
+            ),
+            (
+                &[0x9f, 0x01, 0x82, 0x02, 0x03, 0xff],
+                Value::Array(vec![
+                    Value::Integer(1),
+                    Value::Array(vec![Value::Integer(2), Value::Integer(3)]),
+                ]),
+            ),
+            (
+                &[0x5f, 0x42, 0x01, 0x02, 0x43, 0x03, 0x04, 0x05, 0xff],
+                Value::Bytes(vec![1, 2, 3, 4, 5]),
+            ),
+            (b"\x7f\x65strea\x64ming\xff", Value::Text("streaming".into())),
+            (&[0xf9, 0x3c, 0x00], Value::Float(1.0)),
+            (
+                &[0xc1, 0x1a, 0x51, 0x4b, 0x67, 0xb0],
+                Value::Tag(1, Box::new(Value::Integer(1363896240))),
+            ),
+        ];
+
+        for (data, value) in cases {
+            assert_eq!(decode_cbor(data).unwrap(), [value]);
+        }
+
+        assert_eq!(decode_cbor_item(&[0x01, 0x02]).unwrap(), (Value::Integer(1), 1));
+        assert_eq!(half_to_f64(0x7c00), f64::INFINITY);
+    }
+
+    #[test]
+    fn truncated() {
+        for data in [
+            &[0xa2, 0x01, 0x02, 0x03][..],
+            &[0x9f, 0x01, 0x02],
+            &[0x5f, 0x42, 0x01, 0x02],
+            &[0x1b, 0xff, 0xff],
+            &[0x63, b'a'],
+        ] {
+            assert!(decode_cbor(data).is_err());
+        }
+    }
+
+    #[test]
+    fn overflowing_length() {
+        for major_type in [MAJOR_BYTES, MAJOR_TEXT, MAJOR_ARRAY, MAJOR_MAP] {
+            let data = [
+                major_type << 5 | 27,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                0x00,
+            ];
+            assert!(decode_cbor(&data).is_err());
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(decode_cbor(&[0xff]).unwrap_err(), "Unexpected break");
+        assert!(decode_cbor(&[0x1c]).is_err());
+        assert!(decode_cbor(&[0x5f, 0x61, b'a', 0xff]).is_err());
+        assert_eq!(
+            decode_cbor(&[0x81; MAX_DEPTH + 2]).unwrap_err(),
+            "Data items nesting is too deep"
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! COSE (RFC 9052), CWT (RFC 8392), and WebAuthn structures labeling.
+
+use super::cbor_decoder::decode_cbor_item;
+use super::value::Value;
+use crate::utils::BytesReader;
+
+/// Describes how the map keys or array items should be labeled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Context {
+    Generic,
+    /// COSE message array. Contains the COSE tag.
+    CoseMessage(u64),
+    CoseHeaders,
+    CoseKey,
+    CwtClaims,
+    /// Byte string with the encoded CBOR data item inside (tag 24).
+    EncodedCbor,
+}
+
+const ENCODED_CBOR: u64 = 24;
+// https://www.iana.org/assignments/cose/cose.xhtml
+const COSE_ENCRYPT0: u64 = 16;
+const COSE_MAC0: u64 = 17;
+const COSE_SIGN1: u64 = 18;
+const CWT: u64 = 61;
+const COSE_ENCRYPT: u64 = 96;
+const COSE_MAC: u64 = 97;
+const COSE_SIGN: u64 = 98;
+
+pub fn tag_name(tag: u64) -> &'static str {
+    match tag {
+        0 => "date/time string",
+        1 => "epoch-based date/time",
+        2 => "unsigned bignum",
+        3 => "negative bignum",
+        ENCODED_CBOR => "encoded CBOR data item",
+        32 => "URI",
+        COSE_ENCRYPT0 => "COSE_Encrypt0",
+        COSE_MAC0 => "COSE_Mac0",
+        COSE_SIGN1 => "COSE_Sign1",
+        CWT => "CWT",
+        COSE_ENCRYPT => "COSE_Encrypt",
+        COSE_MAC => "COSE_Mac",
+        COSE_SIGN => "COSE_Sign",
+        55799 => "self-described CBOR",
+        _ => "unknown",
+    }
+}
+
+/// Returns the context for the tagged value.
+pub fn tag_context(tag: u64) -> Context {
+    match tag {
+        COSE_ENCRYPT0 | COSE_MAC0 | COSE_SIGN1 | COSE_ENCRYPT | COSE_MAC | COSE_SIGN => Context::CoseMessage(tag),
+        ENCODED_CBOR => Context::EncodedCbor,
+        _ => Context::Generic,
+    }
+}
+
+/// Returns the name and the context of the COSE message array item.
+pub fn cose_message_item(tag: u64, index: usize) -> (&'static str, Context) {
+    let names: &[&str] = match tag {
+        COSE_ENCRYPT0 => &["protected", "unprotected", "ciphertext"],
+        COSE_MAC0 => &["protected", "unprotected", "payload", "tag"],
+        COSE_SIGN1 => &["protected", "unprotected", "payload", "signature"],
+        COSE_ENCRYPT => &["protected", "unprotected", "ciphertext", "recipients"],
+        COSE_MAC => &["protected", "unprotected", "payload", "tag", "recipients"],
+        COSE_SIGN => &["protected", "unprotected", "payload", "signatures"],
+        _ => &[],
+    };
+
+    let context = match index {
+        0 | 1 => Context::CoseHeaders,
+        // The payload of the signed or MACed CWT contains claims.
+        2 if tag != COSE_ENCRYPT0 && tag != COSE_ENCRYPT => Context::CwtClaims,
+        _ => Context::Generic,
+    };
+
+    (names.get(index).copied().unwrap_or("unknown"), context)
+}
+
+pub fn algorithm_name(algorithm: i128) -> &'static str {
+    match algorithm {
+        -65535 => "RS1",
+        -259 => "RS512",
+        -258 => "RS384",
+        -257 => "RS256",
+        -39 => "PS512",
+        -38 => "PS384",
+        -37 => "PS256",
+        -36 => "ES512",
+        -35 => "ES384",
+        -8 => "EdDSA",
+        -7 => "ES256",
+        1 => "A128GCM",
+        2 => "A192GCM",
+        3 => "A256GCM",
+        4 => "HMAC 256/64",
+        5 => "HMAC 256/256",
+        6 => "HMAC 384/384",
+        7 => "HMAC 512/512",
+        24 => "ChaCha20/Poly1305",
+        _ => "unknown",
+    }
+}
+
+fn key_type_name(key_type: i128) -> &'static str {
+    match key_type {
+        1 => "OKP",
+        2 => "EC2",
+        3 => "RSA",
+        4 => "Symmetric",
+        _ => "unknown",
+    }
+}
+
+fn curve_name(curve: i128) -> &'static str {
+    match curve {
+        1 => "P-256",
+        2 => "P-384",
+        3 => "P-521",
+        4 => "X25519",
+        5 => "X448",
+        6 => "Ed25519",
+        7 => "Ed448",
+        _ => "unknown",
+    }
+}
+
+fn header_label(label: i128) -> Option<&'static str> {
+    Some(match label {
+        1 => "alg",
+        2 => "crit",
+        3 => "content type",
+        4 => "kid",
+        5 => "IV",
+        6 => "Partial IV",
+        7 => "counter signature",
+        33 => "x5chain",
+        _ => return None,
+    })
+}
+
+/// COSE_Key labels. Negative labels depend on the key type.
+fn key_label(label: i128, key_type: Option<i128>) -> Option<&'static str> {
+    Some(match (label, key_type) {
+        (1, _) => "kty",
+        (2, _) => "kid",
+        (3, _) => "alg",
+        (4, _) => "key_ops",
+        (5, _) => "Base IV",
+        (-1, Some(3)) => "n",
+        (-2, Some(3)) => "e",
+        (-1, Some(4)) => "k",
+        (-1, _) => "crv",
+        (-2, _) => "x",
+        (-3, _) => "y",
+        (-4, _) => "d",
+        _ => return None,
+    })
+}
+
+fn cwt_claim_label(label: i128) -> Option<&'static str> {
+    Some(match label {
+        1 => "iss",
+        2 => "sub",
+        3 => "aud",
+        4 => "exp",
+        5 => "nbf",
+        6 => "iat",
+        7 => "cti",
+        8 => "cnf",
+        _ => return None,
+    })
+}
+
+/// Returns the label of the map key in the given context.
+pub fn map_key_label(key: &Value, context: Context, map: &Value) -> Option<&'static str> {
+    let label = key.as_integer()?;
+
+    match context {
+        Context::CoseHeaders => header_label(label),
+        Context::CoseKey => key_label(label, map.get(&Value::Integer(1)).and_then(Value::as_integer)),
+        Context::CwtClaims => cwt_claim_label(label),
+        _ => None,
+    }
+}
+
+/// Returns the human-readable description of the map value in the given context.
+pub fn map_value_description(key: &Value, value: &Value, context: Context) -> Option<&'static str> {
+    let (label, value) = (key.as_integer()?, value.as_integer()?);
+
+    Some(match (context, label) {
+        (Context::CoseHeaders, 1) => algorithm_name(value),
+        (Context::CoseKey, 1) => key_type_name(value),
+        (Context::CoseKey, 3) => algorithm_name(value),
+        (Context::CoseKey, -1) => curve_name(value),
+        _ => return None,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttestedCredentialData {
+    pub aaguid: Vec<u8>,
+    pub credential_id: Vec<u8>,
+    pub credential_public_key: Value,
+}
+
+/// WebAuthn authenticator data.
+// https://www.w3.org/TR/webauthn-2/#sctn-authenticator-data
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthenticatorData {
+    pub rp_id_hash: Vec<u8>,
+    pub flags: u8,
+    pub sign_count: u32,
+    pub attested_credential_data: Option<AttestedCredentialData>,
+    pub extensions: Option<Value>,
+}
+
+const FLAG_ATTESTED_CREDENTIAL_DATA: u8 = 0x40;
+const FLAG_EXTENSIONS: u8 = 0x80;
+
+pub const AUTHENTICATOR_DATA_FLAGS: [(u8, &str); 6] = [
+    (0x01, "UP"),
+    (0x04, "UV"),
+    (0x08, "BE"),
+    (0x10, "BS"),
+    (FLAG_ATTESTED_CREDENTIAL_DATA, "AT"),
+    (FLAG_EXTENSIONS, "ED"),
+];
+
+pub fn parse_authenticator_data(data: &[u8]) -> Result<AuthenticatorData, String> {
+    let mut reader = BytesReader::new(data);
+
+    let rp_id_hash = reader.read_bytes(32)?.to_vec();
+    let flags = reader.read_u8()?;
+    let sign_count = reader.read_u32_be()?;
+
+    let attested_credential_data = if flags & FLAG_ATTESTED_CREDENTIAL_DATA != 0 {
+        let aaguid = reader.read_bytes(16)?.to_vec();
+        let credential_id_len = usize::from(reader.read_u16_be()?);
+        let credential_id = reader.read_bytes(credential_id_len)?.to_vec();
+
+        let remaining = reader.read_remaining();
+        let (credential_public_key, len) = decode_cbor_item(remaining)?;
+        reader = BytesReader::new(&remaining[len..]);
+
+        Some(AttestedCredentialData {
+            aaguid,
+            credential_id,
+            credential_public_key,
+        })
+    } else {
+        None
+    };
+
+    let extensions = if flags & FLAG_EXTENSIONS != 0 {
+        Some(decode_cbor_item(reader.read_remaining())?.0)
+    } else {
+        None
+    };
+
+    Ok(AuthenticatorData {
+        rp_id_hash,
+        flags,
+        sign_count,
+        attested_credential_data,
+        extensions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authenticator_data() -> Vec<u8> {
+        [
+            &[0x11; 32][..],
+            &[0x01 | FLAG_ATTESTED_CREDENTIAL_DATA],
+            &7_u32.to_be_bytes(),
+            &[0x22; 16],
+            &[0x00, 0x02, 0xaa, 0xbb],
+            // COSE_Key { 1: 2 }
+            &[0xa1, 0x01, 0x02],
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn authenticator_data_with_credential() {
+        assert_eq!(
+            parse_authenticator_data(&authenticator_data()).unwrap(),
+            AuthenticatorData {
+                rp_id_hash: vec![0x11; 32],
+                flags: 0x41,
+                sign_count: 7,
+                attested_credential_data: Some(AttestedCredentialData {
+                    aaguid: vec![0x22; 16],
+                    credential_id: vec![0xaa, 0xbb],
+                    credential_public_key: Value::Map(vec![(Value::Integer(1), Value::Integer(2))]),
+                }),
+                extensions: None,
+            }
+        );
+    }
+
+    #[test]
+    fn truncated_authenticator_data() {
+        let data = authenticator_data();
+
+        for len in [0, 32, 36, 50, 55, data.len() - 1] {
+            assert!(parse_authenticator_data(&data[..len]).is_err());
+        }
+    }
+
+    #[test]
+    fn overflowing_credential_id_length() {
+        let mut data = authenticator_data();
+        data[53..55].copy_from_slice(&u16::MAX.to_be_bytes());
+
+        assert!(parse_authenticator_data(&data).is_err());
+    }
+}
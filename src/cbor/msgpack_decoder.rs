@@ -0,0 +1,174 @@
+use super::value::Value;
+use crate::utils::BytesReader;
+
+// https://github.com/msgpack/msgpack/blob/master/spec.md
+const MAX_DEPTH: usize = 128;
+
+fn read_len(reader: &mut BytesReader<'_>, len_size: usize) -> Result<usize, String> {
+    Ok(match len_size {
+        1 => usize::from(reader.read_u8()?),
+        2 => usize::from(reader.read_u16_be()?),
+        _ => reader.read_u32_be()? as usize,
+    })
+}
+
+fn read_str(reader: &mut BytesReader<'_>, len: usize) -> Result<Value, String> {
+    Ok(Value::Text(
+        String::from_utf8_lossy(reader.read_bytes(len)?).into_owned(),
+    ))
+}
+
+fn read_array(reader: &mut BytesReader<'_>, len: usize, depth: usize) -> Result<Value, String> {
+    // Items are pushed one by one: the untrusted length must not be used for the preallocation.
+    let mut items = Vec::new();
+    for _ in 0..len {
+        items.push(decode_item(reader, depth + 1)?);
+    }
+
+    Ok(Value::Array(items))
+}
+
+fn read_map(reader: &mut BytesReader<'_>, len: usize, depth: usize) -> Result<Value, String> {
+    let mut entries = Vec::new();
+    for _ in 0..len {
+        entries.push((decode_item(reader, depth + 1)?, decode_item(reader, depth + 1)?));
+    }
+
+    Ok(Value::Map(entries))
+}
+
+fn read_ext(reader: &mut BytesReader<'_>, len: usize) -> Result<Value, String> {
+    let ext_type = reader.read_u8()? as i8;
+
+    Ok(Value::Extension(ext_type, reader.read_bytes(len)?.to_vec()))
+}
+
+fn decode_item(reader: &mut BytesReader<'_>, depth: usize) -> Result<Value, String> {
+    if depth > MAX_DEPTH {
+        return Err("Data items nesting is too deep".into());
+    }
+
+    let format = reader.read_u8()?;
+
+    Ok(match format {
+        0x00..=0x7f => Value::Integer(format.into()),
+        0x80..=0x8f => read_map(reader, usize::from(format & 0x0f), depth)?,
+        0x90..=0x9f => read_array(reader, usize::from(format & 0x0f), depth)?,
+        0xa0..=0xbf => read_str(reader, usize::from(format & 0x1f))?,
+        0xc0 => Value::Null,
+        0xc2 => Value::Bool(false),
+        0xc3 => Value::Bool(true),
+        0xc4..=0xc6 => {
+            let len = read_len(reader, 1 << (format - 0xc4))?;
+            Value::Bytes(reader.read_bytes(len)?.to_vec())
+        }
+        0xc7..=0xc9 => {
+            let len = read_len(reader, 1 << (format - 0xc7))?;
+            read_ext(reader, len)?
+        }
+        0xca => Value::Float(f32::from_bits(reader.read_u32_be()?).into()),
+        0xcb => Value::Float(f64::from_bits(u64::from_be_bytes(reader.read_array()?))),
+        0xcc => Value::Integer(reader.read_u8()?.into()),
+        0xcd => Value::Integer(reader.read_u16_be()?.into()),
+        0xce => Value::Integer(reader.read_u32_be()?.into()),
+        0xcf => Value::Integer(u64::from_be_bytes(reader.read_array()?).into()),
+        0xd0 => Value::Integer((reader.read_u8()? as i8).into()),
+        0xd1 => Value::Integer((reader.read_u16_be()? as i16).into()),
+        0xd2 => Value::Integer((reader.read_u32_be()? as i32).into()),
+        0xd3 => Value::Integer(i64::from_be_bytes(reader.read_array()?).into()),
+        0xd4..=0xd8 => read_ext(reader, 1 << (format - 0xd4))?,
+        0xd9..=0xdb => {
+            let len = read_len(reader, 1 << (format - 0xd9))?;
+            read_str(reader, len)?
+        }
+        0xdc | 0xdd => {
+            let len = read_len(reader, if format == 0xdc { 2 } else { 4 })?;
+            read_array(reader, len, depth)?
+        }
+        0xde | 0xdf => {
+            let len = read_len(reader, if format == 0xde { 2 } else { 4 })?;
+            read_map(reader, len, depth)?
+        }
+        0xe0..=0xff => Value::Integer((format as i8).into()),
+        0xc1 => return Err("Format 0xc1 is never used".into()),
+    })
+}
+
+/// Decodes all MessagePack objects in the buffer.
+pub fn decode_msgpack(data: &[u8]) -> Result<Vec<Value>, String> {
+    let mut reader = BytesReader::new(data);
+
+    let mut values = Vec::new();
+    while !reader.is_empty() {
+        values.push(decode_item(&mut reader, 0)?);
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let cases: [(&[u8], Value); 6] = [
+            (
+                b"\x93\x01\xa3abc\xc3",
+                Value::Array(vec![Value::Integer(1), Value::Text("abc".into()), Value::Bool(true)]),
+            ),
+            (
+                b"\x81\xa1a\xcd\x01\x00",
+                Value::Map(vec![(Value::Text("a".into()), Value::Integer(256))]),
+            ),
+            (
+                &[0xd3, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+                Value::Integer(-1),
+            ),
+            (&[0xe0], Value::Integer(-32)),
+            (
+                &[0xd6, 0xff, 0x00, 0x00, 0x00, 0x01],
+                Value::Extension(-1, vec![0, 0, 0, 1]),
+            ),
+            (&[0xcb, 0x3f, 0xf0, 0, 0, 0, 0, 0, 0], Value::Float(1.0)),
+        ];
+
+        for (data, value) in cases {
+            assert_eq!(decode_msgpack(data).unwrap(), [value]);
+        }
+
+        assert_eq!(
+            decode_msgpack(&[0xc0, 0xc2]).unwrap(),
+            [Value::Null, Value::Bool(false)]
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        for data in [
+            &[0x93, 0x01][..],
+            &[0xa3, b'a'],
+            &[0xcd, 0x01],
+            &[0xc4],
+            &[0xd6, 0xff, 0x00],
+        ] {
+            assert!(decode_msgpack(data).is_err());
+        }
+    }
+
+    #[test]
+    fn overflowing_length() {
+        for format in [0xc6, 0xc9, 0xdb, 0xdd, 0xdf] {
+            assert!(decode_msgpack(&[format, 0xff, 0xff, 0xff, 0xff, 0x00]).is_err());
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(decode_msgpack(&[0xc1]).unwrap_err(), "Format 0xc1 is never used");
+        assert_eq!(
+            decode_msgpack(&[0x91; MAX_DEPTH + 2]).unwrap_err(),
+            "Data items nesting is too deep"
+        );
+    }
+}
@@ -0,0 +1,53 @@
+/// Decoded CBOR or MessagePack data item.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// CBOR negative integers can be as small as `-2^64`, so `i128` is used.
+    Integer(i128),
+    Bytes(Vec<u8>),
+    Text(String),
+    Array(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+    /// CBOR tagged data item.
+    Tag(u64, Box<Value>),
+    /// MessagePack extension type.
+    Extension(i8, Vec<u8>),
+    Bool(bool),
+    Float(f64),
+    Null,
+    Undefined,
+    Simple(u8),
+}
+
+impl Value {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Integer(_) => "integer",
+            Value::Bytes(_) => "bytes",
+            Value::Text(_) => "text",
+            Value::Array(_) => "array",
+            Value::Map(_) => "map",
+            Value::Tag(_, _) => "tag",
+            Value::Extension(_, _) => "ext",
+            Value::Bool(_) => "bool",
+            Value::Float(_) => "float",
+            Value::Null => "null",
+            Value::Undefined => "undefined",
+            Value::Simple(_) => "simple",
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Value::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the map value by the key.
+    pub fn get(&self, key: &Value) -> Option<&Value> {
+        match self {
+            Value::Map(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
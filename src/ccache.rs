@@ -0,0 +1,134 @@
+mod kirbi;
+mod parser;
+
+use yew::{function_component, html, use_state, Callback, Html};
+use yew_notifications::{use_notification, Notification, NotificationType};
+
+use self::kirbi::encode_kirbi;
+use self::parser::{parse_ccache, ticket_flag_names, Ccache, Credential};
+use crate::common::{ByteInput, BytesFormat, FileInput};
+use crate::keytab::etype_name;
+use crate::timestamp::format_unix_time;
+use crate::url_query_params::generate_asn1_link;
+use crate::utils::download_bytes;
+
+fn format_optional_time(timestamp: u32) -> String {
+    if timestamp == 0 {
+        "-".to_owned()
+    } else {
+        format_unix_time(timestamp)
+    }
+}
+
+fn kirbi_file_name(credential: &Credential) -> String {
+    let is_allowed = |c: char| c.is_ascii_alphanumeric() || c == '.' || c == '@';
+    let name = credential.server.name().replace(|c: char| !is_allowed(c), "_");
+
+    format!("{}.kirbi", name)
+}
+
+#[function_component(CcachePage)]
+pub fn ccache_page() -> Html {
+    let raw_ccache = use_state(Vec::<u8>::new);
+    let ccache = use_state(|| None::<Ccache>);
+
+    let notifications = use_notification::<Notification>();
+
+    let raw_ccache_setter = raw_ccache.setter();
+    let ccache_setter = ccache.setter();
+    let notification_manager = notifications.clone();
+    let set_ccache = Callback::from(move |data: Vec<u8>| {
+        match parse_ccache(&data) {
+            Ok(parsed) => ccache_setter.set(Some(parsed)),
+            Err(err) => {
+                ccache_setter.set(None);
+                notification_manager.spawn(Notification::new(
+                    NotificationType::Error,
+                    "Invalid ccache",
+                    err,
+                    Notification::NOTIFICATION_LIFETIME,
+                ));
+            }
+        }
+        raw_ccache_setter.set(data);
+    });
+
+    html! {
+        <article class="vertical">
+            <div class="horizontal">
+                <span>{"Upload ccache file:"}</span>
+                <FileInput setter={set_ccache.clone()} />
+            </div>
+            <ByteInput
+                bytes={(*raw_ccache).clone()}
+                setter={set_ccache}
+                format={BytesFormat::Base64}
+                placeholder={"ccache".to_owned()}
+                rows={4}
+            />
+            {if let Some(ccache) = &*ccache {
+                html! {
+                    <div class="vertical">
+                        <span>{format!("Version: 0x05{:02x}", ccache.version)}</span>
+                        <span>{format!("Default principal: {}", ccache.default_principal.name())}</span>
+                        <table class="parser-table">
+                            <tr>
+                                <th>{"Client"}</th>
+                                <th>{"Server"}</th>
+                                <th>{"Etype"}</th>
+                                <th>{"Flags"}</th>
+                                <th>{"Auth time"}</th>
+                                <th>{"Start time"}</th>
+                                <th>{"End time"}</th>
+                                <th>{"Renew till"}</th>
+                                <th />
+                            </tr>
+                            {ccache.credentials.iter().filter(|credential| !credential.is_config()).map(|credential| {
+                                let credential_to_export = credential.clone();
+                                let notification_manager = notifications.clone();
+                                let on_export = Callback::from(move |_| {
+                                    let result = encode_kirbi(&credential_to_export).and_then(|kirbi| {
+                                        download_bytes(&kirbi_file_name(&credential_to_export), "application/octet-stream", &kirbi)
+                                    });
+                                    if let Err(err) = result {
+                                        notification_manager.spawn(Notification::new(
+                                            NotificationType::Error,
+                                            "Can not export ticket",
+                                            err,
+                                            Notification::NOTIFICATION_LIFETIME,
+                                        ));
+                                    }
+                                });
+
+                                html! {
+                                    <tr>
+                                        <td>{credential.client.name()}</td>
+                                        <td>{credential.server.name()}</td>
+                                        <td>{format!("{} ({})", etype_name(credential.key_type), credential.key_type)}</td>
+                                        <td>{format!("0x{:08x} {}", credential.ticket_flags, ticket_flag_names(credential.ticket_flags).join(", "))}</td>
+                                        <td>{format_unix_time(credential.auth_time)}</td>
+                                        <td>{format_optional_time(credential.start_time)}</td>
+                                        <td>{format_unix_time(credential.end_time)}</td>
+                                        <td>{format_optional_time(credential.renew_till)}</td>
+                                        <td>
+                                            <div class="horizontal">
+                                                <a class="a-link" href={generate_asn1_link(credential.ticket.clone())} target="_blank">{"ASN.1"}</a>
+                                                <button class="action-button" onclick={on_export}>{"kirbi"}</button>
+                                            </div>
+                                        </td>
+                                    </tr>
+                                }
+                            }).collect::<Html>()}
+                        </table>
+                        <span class="total">{format!(
+                            "tickets: {}",
+                            ccache.credentials.iter().filter(|credential| !credential.is_config()).count()
+                        )}</span>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+        </article>
+    }
+}
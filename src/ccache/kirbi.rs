@@ -0,0 +1,153 @@
+use time::OffsetDateTime;
+
+use super::parser::{Credential, Principal};
+use crate::timestamp::TimestampFormat;
+
+const INTEGER: u8 = 0x02;
+const BIT_STRING: u8 = 0x03;
+const OCTET_STRING: u8 = 0x04;
+const GENERALIZED_TIME: u8 = 0x18;
+const GENERAL_STRING: u8 = 0x1b;
+const SEQUENCE: u8 = 0x30;
+
+const KRB_CRED_APPLICATION: u8 = 0x76;
+const ENC_KRB_CRED_PART_APPLICATION: u8 = 0x7d;
+
+const KERBEROS_VERSION: i64 = 5;
+const KRB_CRED_MESSAGE_TYPE: i64 = 22;
+
+fn encode_length(len: usize, buf: &mut Vec<u8>) {
+    if len < 0x80 {
+        buf.push(len as u8);
+    } else {
+        let len_bytes = len.to_be_bytes();
+        let first_non_zero = len_bytes
+            .iter()
+            .position(|byte| *byte != 0)
+            .unwrap_or(len_bytes.len() - 1);
+        let len_bytes = &len_bytes[first_non_zero..];
+
+        buf.push(0x80 | len_bytes.len() as u8);
+        buf.extend_from_slice(len_bytes);
+    }
+}
+
+fn tlv(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut buf = vec![tag];
+    encode_length(value.len(), &mut buf);
+    buf.extend_from_slice(value);
+
+    buf
+}
+
+fn explicit(context_number: u8, value: &[u8]) -> Vec<u8> {
+    tlv(0xa0 | context_number, value)
+}
+
+fn sequence(fields: &[Vec<u8>]) -> Vec<u8> {
+    tlv(SEQUENCE, &fields.concat())
+}
+
+fn integer(value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let mut start = 0;
+    // Remove redundant leading bytes while keeping the sign bit.
+    while start < bytes.len() - 1
+        && ((bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0))
+    {
+        start += 1;
+    }
+
+    tlv(INTEGER, &bytes[start..])
+}
+
+fn general_string(value: &str) -> Vec<u8> {
+    tlv(GENERAL_STRING, value.as_bytes())
+}
+
+fn kerberos_time(timestamp: u32) -> Result<Vec<u8>, String> {
+    let date_time =
+        OffsetDateTime::from_unix_timestamp(timestamp.into()).map_err(|err| format!("Invalid timestamp: {:?}", err))?;
+
+    Ok(tlv(
+        GENERALIZED_TIME,
+        TimestampFormat::KerberosTime.format(&date_time).as_bytes(),
+    ))
+}
+
+fn principal_name(principal: &Principal) -> Vec<u8> {
+    sequence(&[
+        explicit(0, &integer(principal.name_type.into())),
+        explicit(
+            1,
+            &sequence(
+                &principal
+                    .components
+                    .iter()
+                    .map(|component| general_string(component))
+                    .collect::<Vec<_>>(),
+            ),
+        ),
+    ])
+}
+
+fn ticket_flags(flags: u32) -> Vec<u8> {
+    let mut value = vec![0];
+    value.extend_from_slice(&flags.to_be_bytes());
+
+    tlv(BIT_STRING, &value)
+}
+
+fn krb_cred_info(credential: &Credential) -> Result<Vec<u8>, String> {
+    let mut fields = vec![
+        explicit(
+            0,
+            &sequence(&[
+                explicit(0, &integer(credential.key_type.into())),
+                explicit(1, &tlv(OCTET_STRING, &credential.key)),
+            ]),
+        ),
+        explicit(1, &general_string(&credential.client.realm)),
+        explicit(2, &principal_name(&credential.client)),
+        explicit(3, &ticket_flags(credential.ticket_flags)),
+        explicit(4, &kerberos_time(credential.auth_time)?),
+    ];
+    // Zero times mean absent values.
+    if credential.start_time != 0 {
+        fields.push(explicit(5, &kerberos_time(credential.start_time)?));
+    }
+    fields.push(explicit(6, &kerberos_time(credential.end_time)?));
+    if credential.renew_till != 0 {
+        fields.push(explicit(7, &kerberos_time(credential.renew_till)?));
+    }
+    fields.push(explicit(8, &general_string(&credential.server.realm)));
+    fields.push(explicit(9, &principal_name(&credential.server)));
+
+    Ok(sequence(&fields))
+}
+
+/// Encodes the credential as the KRB-CRED message (RFC 4120, Section 5.8) with the unencrypted (etype 0) enc-part.
+/// This format is also known as `.kirbi` and used by Mimikatz and Rubeus.
+pub fn encode_kirbi(credential: &Credential) -> Result<Vec<u8>, String> {
+    let enc_krb_cred_part = tlv(
+        ENC_KRB_CRED_PART_APPLICATION,
+        &sequence(&[explicit(0, &sequence(&[krb_cred_info(credential)?]))]),
+    );
+
+    Ok(tlv(
+        KRB_CRED_APPLICATION,
+        &sequence(&[
+            explicit(0, &integer(KERBEROS_VERSION)),
+            explicit(1, &integer(KRB_CRED_MESSAGE_TYPE)),
+            explicit(2, &sequence(&[credential.ticket.clone()])),
+            explicit(
+                3,
+                &sequence(&[
+                    explicit(0, &integer(0)),
+                    explicit(2, &tlv(OCTET_STRING, &enc_krb_cred_part)),
+                ]),
+            ),
+        ]),
+    ))
+}
@@ -0,0 +1,288 @@
+use crate::utils::BytesReader;
+
+// https://web.mit.edu/kerberos/krb5-devel/doc/formats/ccache_file_format.html
+const CCACHE_FIRST_BYTE: u8 = 0x05;
+const CCACHE_VERSION_3: u8 = 0x03;
+const CCACHE_VERSION_4: u8 = 0x04;
+
+/// Realm of the principal that MIT and Heimdal use to store the cache configuration entries.
+const CONFIG_REALM: &str = "X-CACHECONF:";
+
+/// Ticket flags in the order they are defined in the KerberosFlags bit string (RFC 4120, Section 5.3).
+pub const TICKET_FLAGS: [(u32, &str); 16] = [
+    (0x8000_0000, "reserved"),
+    (0x4000_0000, "forwardable"),
+    (0x2000_0000, "forwarded"),
+    (0x1000_0000, "proxiable"),
+    (0x0800_0000, "proxy"),
+    (0x0400_0000, "may-postdate"),
+    (0x0200_0000, "postdated"),
+    (0x0100_0000, "invalid"),
+    (0x0080_0000, "renewable"),
+    (0x0040_0000, "initial"),
+    (0x0020_0000, "pre-authent"),
+    (0x0010_0000, "hw-authent"),
+    (0x0008_0000, "transited-policy-checked"),
+    (0x0004_0000, "ok-as-delegate"),
+    (0x0001_0000, "enc-pa-rep"),
+    (0x0000_8000, "anonymous"),
+];
+
+pub fn ticket_flag_names(flags: u32) -> Vec<&'static str> {
+    TICKET_FLAGS
+        .iter()
+        .filter(|(flag, _)| flags & flag != 0)
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    pub name_type: u32,
+    pub realm: String,
+    pub components: Vec<String>,
+}
+
+impl Principal {
+    pub fn name(&self) -> String {
+        format!("{}@{}", self.components.join("/"), self.realm)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Credential {
+    pub client: Principal,
+    pub server: Principal,
+    pub key_type: u16,
+    pub key: Vec<u8>,
+    pub auth_time: u32,
+    pub start_time: u32,
+    pub end_time: u32,
+    pub renew_till: u32,
+    pub ticket_flags: u32,
+    pub ticket: Vec<u8>,
+}
+
+impl Credential {
+    /// Returns `true` if it's the cache configuration entry and not a real ticket.
+    pub fn is_config(&self) -> bool {
+        self.server.realm == CONFIG_REALM
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ccache {
+    pub version: u8,
+    pub default_principal: Principal,
+    pub credentials: Vec<Credential>,
+}
+
+fn read_counted_octet_string<'data>(reader: &mut BytesReader<'data>) -> Result<&'data [u8], String> {
+    let len = reader.read_u32_be()? as usize;
+
+    reader.read_bytes(len)
+}
+
+fn read_principal(reader: &mut BytesReader<'_>) -> Result<Principal, String> {
+    let name_type = reader.read_u32_be()?;
+    let components_count = reader.read_u32_be()?;
+    let realm = String::from_utf8_lossy(read_counted_octet_string(reader)?).into_owned();
+    let components = (0..components_count)
+        .map(|_| read_counted_octet_string(reader).map(|component| String::from_utf8_lossy(component).into_owned()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Principal {
+        name_type,
+        realm,
+        components,
+    })
+}
+
+/// Skips the list of addresses or authorization data entries. Both have the same structure.
+fn skip_typed_data_list(reader: &mut BytesReader<'_>) -> Result<(), String> {
+    let count = reader.read_u32_be()?;
+    for _ in 0..count {
+        let _data_type = reader.read_u16_be()?;
+        read_counted_octet_string(reader)?;
+    }
+
+    Ok(())
+}
+
+fn read_credential(reader: &mut BytesReader<'_>, version: u8) -> Result<Credential, String> {
+    let client = read_principal(reader)?;
+    let server = read_principal(reader)?;
+
+    let key_type = reader.read_u16_be()?;
+    // The version 3 has the enctype repeated twice.
+    if version == CCACHE_VERSION_3 {
+        reader.read_u16_be()?;
+    }
+    let key = read_counted_octet_string(reader)?.to_vec();
+
+    let auth_time = reader.read_u32_be()?;
+    let start_time = reader.read_u32_be()?;
+    let end_time = reader.read_u32_be()?;
+    let renew_till = reader.read_u32_be()?;
+    let _is_skey = reader.read_u8()?;
+    let ticket_flags = reader.read_u32_be()?;
+
+    // addresses
+    skip_typed_data_list(reader)?;
+    // authdata
+    skip_typed_data_list(reader)?;
+
+    let ticket = read_counted_octet_string(reader)?.to_vec();
+    let _second_ticket = read_counted_octet_string(reader)?;
+
+    Ok(Credential {
+        client,
+        server,
+        key_type,
+        key,
+        auth_time,
+        start_time,
+        end_time,
+        renew_till,
+        ticket_flags,
+        ticket,
+    })
+}
+
+pub fn parse_ccache(data: &[u8]) -> Result<Ccache, String> {
+    let mut reader = BytesReader::new(data);
+
+    let first_byte = reader.read_u8()?;
+    let version = reader.read_u8()?;
+    if first_byte != CCACHE_FIRST_BYTE || (version != CCACHE_VERSION_3 && version != CCACHE_VERSION_4) {
+        return Err(format!(
+            "Unsupported ccache version: 0x{:02x}{:02x}. Only 0x0503 and 0x0504 are supported.",
+            first_byte, version
+        ));
+    }
+
+    if version == CCACHE_VERSION_4 {
+        // The header contains only the KDC time offset, so we skip it.
+        let header_len = usize::from(reader.read_u16_be()?);
+        reader.read_bytes(header_len)?;
+    }
+
+    let default_principal = read_principal(&mut reader).map_err(|err| format!("Invalid default principal: {}", err))?;
+
+    let mut credentials = Vec::new();
+    while !reader.is_empty() {
+        credentials.push(read_credential(&mut reader, version).map_err(|err| format!("Invalid credential: {}", err))?);
+    }
+
+    Ok(Ccache {
+        version,
+        default_principal,
+        credentials,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counted(data: &[u8]) -> Vec<u8> {
+        [&(data.len() as u32).to_be_bytes()[..], data].concat()
+    }
+
+    fn principal(realm: &str, components: &[&str]) -> Vec<u8> {
+        let mut principal = [1_u32.to_be_bytes(), (components.len() as u32).to_be_bytes()].concat();
+        principal.extend_from_slice(&counted(realm.as_bytes()));
+        for component in components {
+            principal.extend_from_slice(&counted(component.as_bytes()));
+        }
+
+        principal
+    }
+
+    fn ccache() -> Vec<u8> {
+        [
+            &[CCACHE_FIRST_BYTE, CCACHE_VERSION_4][..],
+            // header: KDC time offset
+            &[0, 12, 0, 1, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0],
+            &principal("EXAMPLE.COM", &["alice"]),
+            // credential
+            &principal("EXAMPLE.COM", &["alice"]),
+            &principal("EXAMPLE.COM", &["krbtgt", "EXAMPLE.COM"]),
+            &18_u16.to_be_bytes(),
+            &counted(&[0xaa; 32]),
+            &1_000_u32.to_be_bytes(),
+            &1_000_u32.to_be_bytes(),
+            &2_000_u32.to_be_bytes(),
+            &3_000_u32.to_be_bytes(),
+            &[0],
+            &0x40c1_0000_u32.to_be_bytes(),
+            // addresses
+            &1_u32.to_be_bytes(),
+            &2_u16.to_be_bytes(),
+            &counted(&[127, 0, 0, 1]),
+            // authdata
+            &0_u32.to_be_bytes(),
+            &counted(&[0x61, 0x00]),
+            &counted(&[]),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn parse() {
+        let ccache = parse_ccache(&ccache()).unwrap();
+
+        assert_eq!(ccache.version, CCACHE_VERSION_4);
+        assert_eq!(ccache.default_principal.name(), "alice@EXAMPLE.COM");
+
+        let [credential] = ccache.credentials.as_slice() else {
+            panic!("expected one credential");
+        };
+        assert_eq!(credential.server.name(), "krbtgt/EXAMPLE.COM@EXAMPLE.COM");
+        assert_eq!(credential.key_type, 18);
+        assert_eq!(credential.key, [0xaa; 32]);
+        assert_eq!((credential.start_time, credential.end_time), (1_000, 2_000));
+        assert_eq!(credential.ticket, [0x61, 0x00]);
+        assert!(!credential.is_config());
+        assert_eq!(
+            ticket_flag_names(credential.ticket_flags),
+            ["forwardable", "renewable", "initial", "enc-pa-rep"]
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        let data = ccache();
+
+        assert!(parse_ccache(&data[..1]).is_err());
+        assert!(parse_ccache(&data[..10]).is_err());
+        assert!(parse_ccache(&data[..data.len() - 1])
+            .unwrap_err()
+            .starts_with("Invalid credential"));
+    }
+
+    #[test]
+    fn overflowing_length() {
+        // realm length of the default principal
+        let mut data = ccache();
+        data[24..28].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(parse_ccache(&data)
+            .unwrap_err()
+            .starts_with("Invalid default principal"));
+
+        // components count of the default principal
+        let mut data = ccache();
+        data[20..24].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(parse_ccache(&data).is_err());
+
+        // header length
+        let mut data = ccache();
+        data[2..4].copy_from_slice(&u16::MAX.to_be_bytes());
+        assert!(parse_ccache(&data).is_err());
+    }
+
+    #[test]
+    fn unsupported_version() {
+        assert!(parse_ccache(&[0x05, 0x02]).unwrap_err().contains("0x0502"));
+    }
+}
@@ -0,0 +1,44 @@
+use js_sys::Uint8Array;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{File, HtmlInputElement};
+use yew::platform::spawn_local;
+use yew::{function_component, html, Callback, Html, Properties, TargetCast};
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct FileInputProps {
+    /// Called with the file content after the file is read.
+    pub setter: Callback<Vec<u8>>,
+    #[prop_or_default]
+    pub accept: String,
+}
+
+/// Reads the whole file content.
+pub async fn read_file(file: File) -> Result<Vec<u8>, String> {
+    let buffer = JsFuture::from(file.array_buffer())
+        .await
+        .map_err(|err| format!("Can not read the {} file: {:?}", file.name(), err))?;
+
+    Ok(Uint8Array::new(&buffer).to_vec())
+}
+
+#[function_component(FileInput)]
+pub fn file_input(props: &FileInputProps) -> Html {
+    let setter = props.setter.clone();
+    let onchange = Callback::from(move |event: html::onchange::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+
+        if let Some(file) = input.files().and_then(|files| files.get(0)) {
+            let setter = setter.clone();
+            spawn_local(async move {
+                match read_file(file).await {
+                    Ok(data) => setter.emit(data),
+                    Err(err) => error!("{}", err),
+                }
+            });
+        }
+    });
+
+    html! {
+        <input type="file" class="file-input" accept={props.accept.clone()} {onchange} />
+    }
+}
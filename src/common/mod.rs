@@ -1,16 +1,22 @@
 mod byte_input;
 mod bytes_viewer;
 mod checkbox;
+mod file_input;
 mod loader;
+mod qr_code;
 mod rc_slice;
 mod simple_output;
 mod switch;
 mod table;
 
+use asn1_parser::Pem;
 use base64::Engine;
 pub use byte_input::{build_byte_input, ByteInput};
+pub use bytes_viewer::BytesViewer;
 pub use checkbox::Checkbox;
+pub use file_input::{read_file, FileInput};
 pub use loader::Loader;
+pub use qr_code::QrCode;
 pub use rc_slice::RcSlice;
 pub use simple_output::build_simple_output;
 pub use switch::Switch;
@@ -98,6 +104,9 @@ fn parse_bytes(raw: &str, format: BytesFormat) -> Result<Vec<u8>, String> {
                 .collect::<String>();
             hex::decode(raw).map_err(|err| format!("invalid hex input: {:?}", err))
         }
+        BytesFormat::Base64 if Pem::is_pem(raw) => Pem::decode(raw)
+            .map(Pem::into_data)
+            .map_err(|err| format!("invalid pem input: {:?}", err)),
         BytesFormat::Base64 => {
             let raw = raw
                 .chars()
@@ -0,0 +1,96 @@
+use qrcode::{Color, QrCode as QrCodeData};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CanvasRenderingContext2d, HtmlAnchorElement, HtmlCanvasElement};
+use yew::{function_component, html, use_effect_with, use_memo, use_node_ref, Callback, Html, NodeRef, Properties};
+
+/// Size of one QR code module in pixels.
+const MODULE_SIZE: usize = 6;
+/// Amount of light modules around the QR code. The QR code specification requires at least 4 modules.
+const QUIET_ZONE: usize = 4;
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct QrCodeProps {
+    pub data: Vec<u8>,
+}
+
+fn draw_qr_code(canvas: &HtmlCanvasElement, qr_code: &QrCodeData) -> Result<(), JsValue> {
+    let width = qr_code.width();
+    let size = (width + QUIET_ZONE * 2) * MODULE_SIZE;
+
+    canvas.set_width(size as u32);
+    canvas.set_height(size as u32);
+
+    let context = canvas
+        .get_context("2d")?
+        .ok_or_else(|| JsValue::from_str("2d context is not supported"))?
+        .dyn_into::<CanvasRenderingContext2d>()?;
+
+    context.set_fill_style(&JsValue::from_str("#ffffff"));
+    context.fill_rect(0.0, 0.0, size as f64, size as f64);
+
+    context.set_fill_style(&JsValue::from_str("#000000"));
+    for (index, color) in qr_code.to_colors().into_iter().enumerate() {
+        if color == Color::Dark {
+            let x = (index % width + QUIET_ZONE) * MODULE_SIZE;
+            let y = (index / width + QUIET_ZONE) * MODULE_SIZE;
+
+            context.fill_rect(x as f64, y as f64, MODULE_SIZE as f64, MODULE_SIZE as f64);
+        }
+    }
+
+    Ok(())
+}
+
+fn download_png(canvas_ref: &NodeRef) -> Result<(), JsValue> {
+    let canvas = canvas_ref
+        .cast::<HtmlCanvasElement>()
+        .ok_or_else(|| JsValue::from_str("QR code canvas is not rendered"))?;
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("document is not available"))?;
+
+    let link = document.create_element("a")?.dyn_into::<HtmlAnchorElement>()?;
+    link.set_href(&canvas.to_data_url_with_type("image/png")?);
+    link.set_download("qr-code.png");
+    link.click();
+
+    Ok(())
+}
+
+#[function_component(QrCode)]
+pub fn qr_code(props: &QrCodeProps) -> Html {
+    let canvas_ref = use_node_ref();
+
+    // The QR code encoding is expensive, so it is not repeated on every render.
+    let qr_code = use_memo(props.data.clone(), |data| QrCodeData::new(data));
+    let is_valid = qr_code.is_ok();
+
+    let canvas = canvas_ref.clone();
+    use_effect_with(props.data.clone(), move |_| {
+        if let (Ok(qr_code), Some(canvas)) = (qr_code.as_ref(), canvas.cast::<HtmlCanvasElement>()) {
+            if let Err(err) = draw_qr_code(&canvas, qr_code) {
+                error!("Can not draw the QR code: {:?}", err);
+            }
+        }
+    });
+
+    let canvas = canvas_ref.clone();
+    let onclick = Callback::from(move |_| {
+        if let Err(err) = download_png(&canvas) {
+            error!("Can not download the QR code: {:?}", err);
+        }
+    });
+
+    if !is_valid {
+        return html! {
+            <span class="total">{"The data is too long to be encoded as the QR code"}</span>
+        };
+    }
+
+    html! {
+        <div class="vertical qr-code">
+            <canvas ref={canvas_ref} />
+            <button class="action-button" {onclick}>{"Download PNG"}</button>
+        </div>
+    }
+}
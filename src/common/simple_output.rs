@@ -3,7 +3,7 @@ use yew_hooks::use_clipboard;
 use yew_notifications::{Notification, NotificationType};
 
 use super::BytesFormat;
-use crate::common::{encode_bytes, get_format_button_class, get_set_format_callback, BYTES_FORMATS};
+use crate::common::{encode_bytes, get_format_button_class, get_set_format_callback, QrCode, BYTES_FORMATS};
 
 #[derive(PartialEq, Properties, Clone)]
 pub struct SimpleOutputProps {
@@ -21,6 +21,7 @@ pub fn simple_output(props: &SimpleOutputProps) -> Html {
     } = props.clone();
 
     let bytes_format = use_state(|| format);
+    let show_qr_code = use_state(|| false);
 
     let format_setter = bytes_format.setter();
     use_effect_with(bytes_format.clone(), move |format| {
@@ -40,6 +41,11 @@ pub fn simple_output(props: &SimpleOutputProps) -> Html {
         ));
     });
 
+    let qr_code_data = encoded_bytes.as_bytes().to_vec();
+    let show_qr_code_setter = show_qr_code.setter();
+    let show_qr_code_value = *show_qr_code;
+    let on_qr_code_click = Callback::from(move |_| show_qr_code_setter.set(!show_qr_code_value));
+
     html! {
         <div class="output">
             <div class="formats-container">{
@@ -55,7 +61,17 @@ pub fn simple_output(props: &SimpleOutputProps) -> Html {
                 }).collect::<Html>()
             }</div>
             <span class="simple-digest" onclick={onclick}>{encoded_bytes}</span>
-            <span class="total">{format!("total: {}", output.len())}</span>
+            <div class="horizontal">
+                <span class="total">{format!("total: {}", output.len())}</span>
+                <button class="format-button" onclick={on_qr_code_click}>{
+                    if *show_qr_code { "hide QR" } else { "show as QR" }
+                }</button>
+            </div>
+            {if *show_qr_code {
+                html! { <QrCode data={qr_code_data} /> }
+            } else {
+                html! {}
+            }}
         </div>
     }
 }
@@ -16,7 +16,10 @@ use yew::{function_component, html, use_effect_with, use_state, Callback, Html};
 use yew_hooks::{use_clipboard, use_local_storage, use_location};
 use yew_notifications::{use_notification, Notification, NotificationType};
 
-use self::computations::{process_argon2, process_krb_cipher, process_krb_hmac, process_rsa, process_zlib};
+use self::computations::{
+    process_argon2, process_html_entities, process_krb_cipher, process_krb_hmac, process_quoted_printable, process_rsa,
+    process_url_encoding, process_zlib,
+};
 use crate::crypto_helper::computations::process_bcrypt;
 use crate::url_query_params::generate_crypto_helper_link;
 
@@ -41,6 +44,9 @@ fn convert(algrithm: &Algorithm) -> Result<Vec<u8>, String> {
         Algorithm::Bcrypt(input) => process_bcrypt(input),
         Algorithm::Zlib(input) => process_zlib(input),
         Algorithm::Argon2(input) => process_argon2(input),
+        Algorithm::UrlEncoding(input) => process_url_encoding(input),
+        Algorithm::HtmlEntities(input) => process_html_entities(input),
+        Algorithm::QuotedPrintable(input) => process_quoted_printable(input),
     }
 }
 
@@ -19,8 +19,11 @@ pub const SHA384: &str = "SHA384";
 pub const BCRYPT: &str = "BCRYPT";
 pub const ZLIB: &str = "ZLIB";
 pub const ARGON2: &str = "ARGON2";
+pub const URL_ENCODING: &str = "URL-ENCODING";
+pub const HTML_ENTITIES: &str = "HTML-ENTITIES";
+pub const QUOTED_PRINTABLE: &str = "QUOTED-PRINTABLE";
 
-pub const SUPPORTED_ALGORITHMS: [&str; 13] = [
+pub const SUPPORTED_ALGORITHMS: [&str; 16] = [
     MD5,
     SHA1,
     SHA256,
@@ -34,6 +37,9 @@ pub const SUPPORTED_ALGORITHMS: [&str; 13] = [
     BCRYPT,
     ZLIB,
     ARGON2,
+    URL_ENCODING,
+    HTML_ENTITIES,
+    QUOTED_PRINTABLE,
 ];
 
 pub const HASHING_ALGOS: [&str; 7] = [MD5, SHA1, SHA256, SHA384, SHA512, BCRYPT, ARGON2];
@@ -44,6 +50,8 @@ pub const HMAC_ALGOS: [&str; 2] = [HMAC_SHA1_96_AES128, HMAC_SHA1_96_AES256];
 
 pub const COMPRESSION_ALGOS: [&str; 1] = [ZLIB];
 
+pub const ENCODING_ALGOS: [&str; 3] = [URL_ENCODING, HTML_ENTITIES, QUOTED_PRINTABLE];
+
 const RSA_ACTIONS: [&str; 4] = ["Sign", "Verify", "Encrypt", "Decrypt"];
 
 pub const RSA_HASH_MD5: &str = "MD5";
@@ -363,6 +371,77 @@ pub struct ZlibInput {
     pub data: Vec<u8>,
 }
 
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize, Default)]
+pub enum EncodingMode {
+    #[default]
+    Encode,
+    Decode,
+}
+
+impl From<EncodingMode> for bool {
+    fn from(mode: EncodingMode) -> Self {
+        match mode {
+            EncodingMode::Encode => false,
+            EncodingMode::Decode => true,
+        }
+    }
+}
+
+impl From<bool> for EncodingMode {
+    fn from(mode: bool) -> Self {
+        match mode {
+            true => EncodingMode::Decode,
+            false => EncodingMode::Encode,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Default)]
+pub struct EncodingInput {
+    pub mode: EncodingMode,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub data: Vec<u8>,
+}
+
+/// Defines which characters are left unescaped during the percent-encoding.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize, Default)]
+pub enum UrlEncodingKind {
+    /// Works like the JS `encodeURIComponent`: only unreserved characters are left as is.
+    #[default]
+    Component,
+    /// Works like the JS `encodeURI`: reserved URL characters (`/`, `?`, `&`, etc) are left as is.
+    Full,
+}
+
+impl From<UrlEncodingKind> for bool {
+    fn from(kind: UrlEncodingKind) -> Self {
+        match kind {
+            UrlEncodingKind::Component => false,
+            UrlEncodingKind::Full => true,
+        }
+    }
+}
+
+impl From<bool> for UrlEncodingKind {
+    fn from(kind: bool) -> Self {
+        match kind {
+            true => UrlEncodingKind::Full,
+            false => UrlEncodingKind::Component,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Default)]
+pub struct UrlEncodingInput {
+    pub kind: UrlEncodingKind,
+    /// Uses `+` for spaces like `application/x-www-form-urlencoded`. Otherwise, `+` is a regular character.
+    #[serde(default)]
+    pub form: bool,
+    pub mode: EncodingMode,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub data: Vec<u8>,
+}
+
 #[derive(Eq, Clone, Copy, PartialEq, Debug, Serialize, Deserialize, Default)]
 pub enum Argon2Variant {
     Argon2i,
@@ -552,6 +631,9 @@ pub enum Algorithm {
     Bcrypt(BcryptInput),
     Zlib(ZlibInput),
     Argon2(Argon2Input),
+    UrlEncoding(UrlEncodingInput),
+    HtmlEntities(EncodingInput),
+    QuotedPrintable(EncodingInput),
 }
 
 impl TryFrom<&str> for Algorithm {
@@ -584,6 +666,12 @@ impl TryFrom<&str> for Algorithm {
             return Ok(Algorithm::Zlib(Default::default()));
         } else if value == ARGON2 {
             return Ok(Algorithm::Argon2(Default::default()));
+        } else if value == URL_ENCODING {
+            return Ok(Algorithm::UrlEncoding(Default::default()));
+        } else if value == HTML_ENTITIES {
+            return Ok(Algorithm::HtmlEntities(Default::default()));
+        } else if value == QUOTED_PRINTABLE {
+            return Ok(Algorithm::QuotedPrintable(Default::default()));
         }
 
         Err(format!(
@@ -609,6 +697,9 @@ impl From<&Algorithm> for &str {
             Algorithm::Bcrypt(_) => BCRYPT,
             Algorithm::Zlib(_) => ZLIB,
             Algorithm::Argon2(_) => ARGON2,
+            Algorithm::UrlEncoding(_) => URL_ENCODING,
+            Algorithm::HtmlEntities(_) => HTML_ENTITIES,
+            Algorithm::QuotedPrintable(_) => QUOTED_PRINTABLE,
         }
     }
 }
@@ -12,8 +12,8 @@ use rsa::rand_core::OsRng;
 use rsa::Pkcs1v15Encrypt;
 
 use super::algorithm::{
-    Argon2Action, Argon2Input, BcryptAction, BcryptInput, KrbInput, KrbInputData, KrbMode, RsaAction, RsaInput,
-    ZlibInput, ZlibMode,
+    Argon2Action, Argon2Input, BcryptAction, BcryptInput, EncodingInput, EncodingMode, KrbInput, KrbInputData, KrbMode,
+    RsaAction, RsaInput, UrlEncodingInput, UrlEncodingKind, ZlibInput, ZlibMode,
 };
 
 pub fn process_rsa(input: &RsaInput) -> Result<Vec<u8>, String> {
@@ -132,3 +132,234 @@ pub fn process_argon2(input: &Argon2Input) -> Result<Vec<u8>, String> {
         }
     }
 }
+
+const URL_UNRESERVED_CHARS: &[u8] = b"-_.!~*'()";
+const URL_RESERVED_CHARS: &[u8] = b";,/?:@&=+$#";
+
+fn decode_hex_digit(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn decode_hex_pair(data: &[u8], index: usize) -> Option<u8> {
+    let high = decode_hex_digit(*data.get(index)?)?;
+    let low = decode_hex_digit(*data.get(index + 1)?)?;
+
+    Some(high << 4 | low)
+}
+
+pub fn process_url_encoding(input: &UrlEncodingInput) -> Result<Vec<u8>, String> {
+    match input.mode {
+        EncodingMode::Encode => {
+            let mut encoded = Vec::with_capacity(input.data.len());
+
+            for byte in input.data.iter().copied() {
+                if byte == b' ' && input.form {
+                    encoded.push(b'+');
+                } else if byte.is_ascii_alphanumeric()
+                    || URL_UNRESERVED_CHARS.contains(&byte)
+                    || (input.kind == UrlEncodingKind::Full
+                        && URL_RESERVED_CHARS.contains(&byte)
+                        // '+' means a space in the form data
+                        && !(input.form && byte == b'+'))
+                {
+                    encoded.push(byte);
+                } else {
+                    encoded.extend_from_slice(format!("%{:02X}", byte).as_bytes());
+                }
+            }
+
+            Ok(encoded)
+        }
+        EncodingMode::Decode => {
+            let data = &input.data;
+            let mut decoded = Vec::with_capacity(data.len());
+
+            let mut index = 0;
+            while index < data.len() {
+                match data[index] {
+                    b'%' => {
+                        decoded.push(
+                            decode_hex_pair(data, index + 1)
+                                .ok_or_else(|| format!("Invalid percent-encoded sequence at position {}", index))?,
+                        );
+                        index += 3;
+                    }
+                    b'+' if input.form => {
+                        decoded.push(b' ');
+                        index += 1;
+                    }
+                    byte => {
+                        decoded.push(byte);
+                        index += 1;
+                    }
+                }
+            }
+
+            Ok(decoded)
+        }
+    }
+}
+
+const HTML_NAMED_ENTITIES: [(&str, char); 12] = [
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+    ("nbsp", '\u{a0}'),
+    ("copy", '\u{a9}'),
+    ("reg", '\u{ae}'),
+    ("trade", '\u{2122}'),
+    ("hellip", '\u{2026}'),
+    ("ndash", '\u{2013}'),
+    ("mdash", '\u{2014}'),
+];
+
+fn decode_html_entity(entity: &str) -> Option<char> {
+    if let Some(number) = entity.strip_prefix('#') {
+        let code = if let Some(hex) = number.strip_prefix('x').or_else(|| number.strip_prefix('X')) {
+            u32::from_str_radix(hex, 16).ok()?
+        } else {
+            number.parse::<u32>().ok()?
+        };
+
+        return char::from_u32(code);
+    }
+
+    HTML_NAMED_ENTITIES
+        .iter()
+        .find(|(name, _)| *name == entity)
+        .map(|(_, c)| *c)
+}
+
+pub fn process_html_entities(input: &EncodingInput) -> Result<Vec<u8>, String> {
+    let data = std::str::from_utf8(&input.data).map_err(|err| format!("Input is not valid UTF-8 text: {:?}", err))?;
+
+    match input.mode {
+        EncodingMode::Encode => {
+            let mut encoded = String::with_capacity(data.len());
+
+            for c in data.chars() {
+                match c {
+                    '&' => encoded.push_str("&amp;"),
+                    '<' => encoded.push_str("&lt;"),
+                    '>' => encoded.push_str("&gt;"),
+                    '"' => encoded.push_str("&quot;"),
+                    '\'' => encoded.push_str("&#39;"),
+                    c if c.is_ascii() => encoded.push(c),
+                    c => encoded.push_str(&format!("&#x{:x};", c as u32)),
+                }
+            }
+
+            Ok(encoded.into_bytes())
+        }
+        EncodingMode::Decode => {
+            let mut decoded = String::with_capacity(data.len());
+            let mut rest = data;
+
+            while let Some(start) = rest.find('&') {
+                decoded.push_str(&rest[..start]);
+                rest = &rest[start..];
+
+                // unknown or malformed entities are left as is
+                match rest
+                    .find(';')
+                    .and_then(|end| Some((decode_html_entity(&rest[1..end])?, end)))
+                {
+                    Some((c, end)) => {
+                        decoded.push(c);
+                        rest = &rest[end + 1..];
+                    }
+                    None => {
+                        decoded.push('&');
+                        rest = &rest[1..];
+                    }
+                }
+            }
+            decoded.push_str(rest);
+
+            Ok(decoded.into_bytes())
+        }
+    }
+}
+
+const QUOTED_PRINTABLE_MAX_LINE_LEN: usize = 76;
+
+pub fn process_quoted_printable(input: &EncodingInput) -> Result<Vec<u8>, String> {
+    let data = &input.data;
+
+    match input.mode {
+        EncodingMode::Encode => {
+            let mut encoded = Vec::with_capacity(data.len());
+            let mut line_len = 0;
+
+            for (index, byte) in data.iter().copied().enumerate() {
+                if byte == b'\n' {
+                    encoded.push(byte);
+                    line_len = 0;
+                    continue;
+                }
+
+                let is_line_end = matches!(data.get(index + 1), None | Some(b'\n'))
+                    || (data.get(index + 1) == Some(&b'\r') && data.get(index + 2) == Some(&b'\n'));
+                let literal = match byte {
+                    b'=' => false,
+                    // trailing whitespaces must be encoded (RFC 2045, Section 6.7, rule 3)
+                    b' ' | b'\t' => !is_line_end,
+                    // CR is kept only as a part of the CRLF line break
+                    b'\r' => data.get(index + 1) == Some(&b'\n'),
+                    33..=126 => true,
+                    _ => false,
+                };
+                let symbol_len = if literal { 1 } else { 3 };
+
+                // a soft line break "=" takes one more character
+                if byte != b'\r' && line_len + symbol_len > QUOTED_PRINTABLE_MAX_LINE_LEN - 1 {
+                    encoded.extend_from_slice(b"=\r\n");
+                    line_len = 0;
+                }
+
+                if literal {
+                    encoded.push(byte);
+                } else {
+                    encoded.extend_from_slice(format!("={:02X}", byte).as_bytes());
+                }
+                line_len += symbol_len;
+            }
+
+            Ok(encoded)
+        }
+        EncodingMode::Decode => {
+            let mut decoded = Vec::with_capacity(data.len());
+
+            let mut index = 0;
+            while index < data.len() {
+                if data[index] != b'=' {
+                    decoded.push(data[index]);
+                    index += 1;
+                    continue;
+                }
+
+                // soft line breaks
+                if data[index + 1..].starts_with(b"\r\n") {
+                    index += 3;
+                } else if data[index + 1..].starts_with(b"\n") {
+                    index += 2;
+                } else {
+                    decoded.push(
+                        decode_hex_pair(data, index + 1)
+                            .ok_or_else(|| format!("Invalid quoted-printable sequence at position {}", index))?,
+                    );
+                    index += 3;
+                }
+            }
+
+            Ok(decoded)
+        }
+    }
+}
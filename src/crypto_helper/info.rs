@@ -5,7 +5,7 @@ use yew::html::onchange::Event;
 use yew::{function_component, html, use_state, Callback, Html, Properties, TargetCast, UseStateSetter};
 
 use super::algorithm::Algorithm;
-use crate::crypto_helper::algorithm::{COMPRESSION_ALGOS, ENCRYPTION_ALGOS, HASHING_ALGOS, HMAC_ALGOS};
+use crate::crypto_helper::algorithm::{COMPRESSION_ALGOS, ENCODING_ALGOS, ENCRYPTION_ALGOS, HASHING_ALGOS, HMAC_ALGOS};
 use crate::crypto_helper::info::algo_search::AlgoSearch;
 use crate::generate_algo_list_for_yew;
 
@@ -78,6 +78,21 @@ fn get_algorithm_info(algorithm: &Algorithm) -> Html {
             <a href="https://www.rfc-editor.org/rfc/inline-errata/rfc9106.html">{"RFC"}</a>
             </span>
         },
+        Algorithm::UrlEncoding(_) => html! {
+            <span>{"Percent-encode/decode the URL or its component."}
+            <a href="https://www.rfc-editor.org/rfc/rfc3986#section-2.1">{"RFC"}</a>
+            </span>
+        },
+        Algorithm::HtmlEntities(_) => html! {
+            <span>{"Escape/unescape HTML special characters using character references."}
+            <a href="https://html.spec.whatwg.org/multipage/syntax.html#character-references">{"Spec"}</a>
+            </span>
+        },
+        Algorithm::QuotedPrintable(_) => html! {
+            <span>{"Encode/decode data using the Quoted-Printable content transfer encoding."}
+            <a href="https://www.rfc-editor.org/rfc/rfc2045#section-6.7">{"RFC"}</a>
+            </span>
+        },
     }
 }
 
@@ -117,6 +132,7 @@ pub fn info(props: &InfoProps) -> Html {
     let encryption_algos = generate_algo_list_for_yew!(algo_list: ENCRYPTION_ALGOS, props: props);
     let hmac_algos = generate_algo_list_for_yew!(algo_list: HMAC_ALGOS, props: props);
     let compression_algos = generate_algo_list_for_yew!(algo_list: COMPRESSION_ALGOS, props: props);
+    let encoding_algos = generate_algo_list_for_yew!(algo_list: ENCODING_ALGOS, props: props);
 
     html! {
         <div class="horizontal">
@@ -135,6 +151,9 @@ pub fn info(props: &InfoProps) -> Html {
                         <optgroup label="COMPRESSION"> {
                             compression_algos
                         }</optgroup>
+                        <optgroup label="ENCODING"> {
+                            encoding_algos
+                        }</optgroup>
                     </select>
                     <input type="checkbox" id={"algo-search"} class="search-input" onchange={on_algo_search_change} />
                     <label for={"algo-search"} class="search-button">
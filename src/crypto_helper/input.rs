@@ -1,5 +1,6 @@
 mod argon2;
 mod bcrypt;
+mod encoding;
 mod krb;
 mod rsa;
 mod zlib;
@@ -9,6 +10,7 @@ use yew::{function_component, html, Callback, Html, Properties, UseStateSetter};
 
 use self::argon2::build_argon2_input;
 use self::bcrypt::build_bcrypt_input;
+use self::encoding::{build_encoding_input, build_url_encoding_input};
 use self::krb::build_krb_input;
 use self::rsa::build_rsa_input;
 use self::zlib::build_zlib_input;
@@ -99,6 +101,20 @@ fn get_input_components(algorithm: &Algorithm, setter: &UseStateSetter<Algorithm
             input.clone(),
             Callback::from(move |input| setter.set(Algorithm::Argon2(input))),
         ),
+        Algorithm::UrlEncoding(input) => build_url_encoding_input(
+            input.clone(),
+            Callback::from(move |input| setter.set(Algorithm::UrlEncoding(input))),
+        ),
+        Algorithm::HtmlEntities(input) => build_encoding_input(
+            "html",
+            input.clone(),
+            Callback::from(move |input| setter.set(Algorithm::HtmlEntities(input))),
+        ),
+        Algorithm::QuotedPrintable(input) => build_encoding_input(
+            "quoted-printable",
+            input.clone(),
+            Callback::from(move |input| setter.set(Algorithm::QuotedPrintable(input))),
+        ),
     }
 }
 
@@ -0,0 +1,131 @@
+use yew::{function_component, html, Callback, Html, Properties};
+
+use crate::common::{build_byte_input, BytesFormat, Switch};
+use crate::crypto_helper::algorithm::{EncodingInput as EncodingInputData, UrlEncodingInput as UrlEncodingInputData};
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct EncodingInputProps {
+    pub name: &'static str,
+    pub input: EncodingInputData,
+    pub input_setter: Callback<EncodingInputData>,
+}
+
+#[function_component(EncodingInput)]
+pub fn encoding_input(props: &EncodingInputProps) -> Html {
+    let EncodingInputProps {
+        name,
+        input,
+        input_setter,
+    } = props.clone();
+    let EncodingInputData { mode, data } = input;
+
+    let set_input = input_setter.clone();
+    let data_setter = Callback::from(move |data: Vec<u8>| {
+        set_input.emit(EncodingInputData { mode, data });
+    });
+
+    let encoding_data = data.clone();
+    let set_mode = Callback::from(move |mode: bool| {
+        input_setter.emit(EncodingInputData {
+            mode: mode.into(),
+            data: encoding_data.clone(),
+        });
+    });
+
+    html! {
+        <div class="vertical">
+            <div class="horizontal">
+                <span class="total">{"encode"}</span>
+                <Switch id={format!("{}-mode", name)} setter={set_mode} state={bool::from(mode)}/>
+                <span class="total">{"decode"}</span>
+            </div>
+            {build_byte_input(data, data_setter, Some(BytesFormat::Ascii), Some(name.into()))}
+        </div>
+    }
+}
+
+pub fn build_encoding_input(
+    name: &'static str,
+    input: EncodingInputData,
+    input_setter: Callback<EncodingInputData>,
+) -> Html {
+    html! {
+        <EncodingInput {name} {input} {input_setter} />
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct UrlEncodingInputProps {
+    pub input: UrlEncodingInputData,
+    pub input_setter: Callback<UrlEncodingInputData>,
+}
+
+#[function_component(UrlEncodingInput)]
+pub fn url_encoding_input(props: &UrlEncodingInputProps) -> Html {
+    let UrlEncodingInputProps { input, input_setter } = props.clone();
+    let UrlEncodingInputData { kind, form, mode, data } = input;
+
+    let set_input = input_setter.clone();
+    let data_setter = Callback::from(move |data: Vec<u8>| {
+        set_input.emit(UrlEncodingInputData { kind, form, mode, data });
+    });
+
+    let set_input = input_setter.clone();
+    let url_data = data.clone();
+    let set_mode = Callback::from(move |mode: bool| {
+        set_input.emit(UrlEncodingInputData {
+            kind,
+            form,
+            mode: mode.into(),
+            data: url_data.clone(),
+        });
+    });
+
+    let set_input = input_setter.clone();
+    let url_data = data.clone();
+    let set_kind = Callback::from(move |kind: bool| {
+        set_input.emit(UrlEncodingInputData {
+            kind: kind.into(),
+            form,
+            mode,
+            data: url_data.clone(),
+        });
+    });
+
+    let url_data = data.clone();
+    let set_form = Callback::from(move |form: bool| {
+        input_setter.emit(UrlEncodingInputData {
+            kind,
+            form,
+            mode,
+            data: url_data.clone(),
+        });
+    });
+
+    html! {
+        <div class="vertical">
+            <div class="horizontal">
+                <span class="total">{"encode"}</span>
+                <Switch id={"url-encoding-mode".to_string()} setter={set_mode} state={bool::from(mode)}/>
+                <span class="total">{"decode"}</span>
+            </div>
+            <div class="horizontal">
+                <span class="total">{"component"}</span>
+                <Switch id={"url-encoding-kind".to_string()} setter={set_kind} state={bool::from(kind)}/>
+                <span class="total">{"full url"}</span>
+            </div>
+            <div class="horizontal">
+                <span class="total">{"space as %20"}</span>
+                <Switch id={"url-encoding-form".to_string()} setter={set_form} state={form}/>
+                <span class="total">{"space as + (form data)"}</span>
+            </div>
+            {build_byte_input(data, data_setter, Some(BytesFormat::Ascii), Some("url".into()))}
+        </div>
+    }
+}
+
+pub fn build_url_encoding_input(input: UrlEncodingInputData, input_setter: Callback<UrlEncodingInputData>) -> Html {
+    html! {
+        <UrlEncodingInput {input} {input_setter} />
+    }
+}
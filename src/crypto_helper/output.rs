@@ -29,6 +29,9 @@ fn get_output_components(algorithm: &Algorithm, output: &[u8], add_notification:
         ),
         Algorithm::Zlib(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
         Algorithm::Argon2(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
+        Algorithm::UrlEncoding(_) => build_simple_output(output.into(), BytesFormat::Ascii, add_notification),
+        Algorithm::HtmlEntities(_) => build_simple_output(output.into(), BytesFormat::Ascii, add_notification),
+        Algorithm::QuotedPrintable(_) => build_simple_output(output.into(), BytesFormat::Ascii, add_notification),
     }
 }
 
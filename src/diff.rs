@@ -1,3 +1,4 @@
+mod bytes_diff;
 mod diff_algo;
 mod diff_viewer;
 mod task;
@@ -12,10 +13,11 @@ use yew::{function_component, html, use_effect_with, use_state_eq, Callback, Htm
 use yew_agent::oneshot::use_oneshot_runner;
 use yew_hooks::use_local_storage;
 
+use self::bytes_diff::BytesDiff;
 use self::diff_algo::DiffAlgo;
 use self::diff_viewer::DiffViewer;
 pub use self::task::{DiffTask, DiffTaskParams, JsonCodec};
-use crate::common::Loader;
+use crate::common::{Loader, Switch};
 
 const DEFAULT_ORIGINAL: &str = "TheBestTvarynka
 TheBestTvarynka
@@ -73,6 +75,7 @@ fn render_algorithm_options(current_algorithm: DiffAlgo) -> Vec<VNode> {
 
 #[function_component(DiffPage)]
 pub fn diff_page() -> Html {
+    let bytes_mode = use_state_eq(|| false);
     let original = use_state_eq(|| DEFAULT_ORIGINAL.to_owned());
     let changed = use_state_eq(|| DEFAULT_CHANGED.to_owned());
     let algorithm = use_state_eq(|| DEFAULT_ALGORITHM);
@@ -191,8 +194,28 @@ pub fn diff_page() -> Html {
         }
     });
 
+    let bytes_mode_setter = bytes_mode.setter();
+    let set_bytes_mode = Callback::from(move |mode| bytes_mode_setter.set(mode));
+    let mode_switch = html! {
+        <div class="horizontal">
+            <span class="total">{"text"}</span>
+            <Switch id={"diff-mode".to_owned()} state={*bytes_mode} setter={set_bytes_mode} />
+            <span class="total">{"bytes"}</span>
+        </div>
+    };
+
+    if *bytes_mode {
+        return html! {
+            <div class={"vertical asn1-page"}>
+                {mode_switch}
+                <BytesDiff />
+            </div>
+        };
+    }
+
     html! {
         <div class={"vertical asn1-page"} {onkeydown}>
+            {mode_switch}
             <div class="horizontal">
                 <span>{"Diff algorithm:"}</span>
                 <div>
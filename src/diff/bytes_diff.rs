@@ -0,0 +1,138 @@
+use yew::virtual_dom::VNode;
+use yew::{function_component, html, use_effect_with, use_state_eq, Callback, Html};
+use yew_hooks::use_local_storage;
+
+use crate::common::{hex_format_byte, ByteInput, BytesFormat};
+
+const LOCAL_STORAGE_ORIGINAL_BYTES: &str = "ORIGINAL_BYTES";
+const LOCAL_STORAGE_CHANGED_BYTES: &str = "CHANGED_BYTES";
+
+const BYTES_PER_ROW: usize = 16;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BytesDiffStats {
+    /// Offset of the first byte that differs in both inputs.
+    /// If one input is a prefix of another, then it's equal to the length of the shorter input.
+    pub first_difference: Option<usize>,
+    /// Percentage of the equal bytes on the same positions.
+    pub similarity: f64,
+}
+
+/// Compares two byte sequences position by position.
+pub fn compare_bytes(original: &[u8], changed: &[u8]) -> BytesDiffStats {
+    let max_len = original.len().max(changed.len());
+
+    if max_len == 0 {
+        return BytesDiffStats {
+            first_difference: None,
+            similarity: 100.0,
+        };
+    }
+
+    let first_difference = (0..max_len).find(|index| original.get(*index) != changed.get(*index));
+    let equal_bytes = original.iter().zip(changed.iter()).filter(|(a, b)| a == b).count();
+
+    BytesDiffStats {
+        first_difference,
+        similarity: equal_bytes as f64 * 100.0 / max_len as f64,
+    }
+}
+
+fn byte_span(byte: Option<u8>, other: Option<u8>, diff_class: &'static str) -> VNode {
+    match byte {
+        Some(byte) => html! {
+            <span class={if Some(byte) == other { "bytes-diff-byte" } else { diff_class }}>{hex_format_byte(byte)}</span>
+        },
+        None => html! {
+            <span class="bytes-diff-byte bytes-diff-missing">{"--"}</span>
+        },
+    }
+}
+
+fn render_rows(bytes: &[u8], other: &[u8], diff_class: &'static str) -> Vec<VNode> {
+    let max_len = bytes.len().max(other.len());
+
+    (0..max_len)
+        .step_by(BYTES_PER_ROW)
+        .map(|row_start| {
+            let row_end = (row_start + BYTES_PER_ROW).min(max_len);
+            html! {
+                <div class="bytes-diff-row">
+                    <span class="diff-line-number">{format!("{:08x}", row_start)}</span>
+                    {(row_start..row_end).map(|index| {
+                        byte_span(bytes.get(index).copied(), other.get(index).copied(), diff_class)
+                    }).collect::<Vec<_>>()}
+                </div>
+            }
+        })
+        .collect()
+}
+
+#[function_component(BytesDiff)]
+pub fn bytes_diff() -> Html {
+    let original = use_state_eq(Vec::<u8>::new);
+    let changed = use_state_eq(Vec::<u8>::new);
+
+    let original_local_storage = use_local_storage::<String>(LOCAL_STORAGE_ORIGINAL_BYTES.to_owned());
+    let original_setter = original.setter();
+    let changed_local_storage = use_local_storage::<String>(LOCAL_STORAGE_CHANGED_BYTES.to_owned());
+    let changed_setter = changed.setter();
+    use_effect_with([], move |_: &[(); 0]| {
+        if let Some(bytes) = (*original_local_storage).as_ref().and_then(|raw| hex::decode(raw).ok()) {
+            original_setter.set(bytes);
+        }
+        if let Some(bytes) = (*changed_local_storage).as_ref().and_then(|raw| hex::decode(raw).ok()) {
+            changed_setter.set(bytes);
+        }
+    });
+
+    let local_storage = use_local_storage::<String>(LOCAL_STORAGE_ORIGINAL_BYTES.to_owned());
+    use_effect_with(original.clone(), move |original| {
+        local_storage.set(hex::encode(original.as_slice()));
+    });
+
+    let local_storage = use_local_storage::<String>(LOCAL_STORAGE_CHANGED_BYTES.to_owned());
+    use_effect_with(changed.clone(), move |changed| {
+        local_storage.set(hex::encode(changed.as_slice()));
+    });
+
+    let original_setter = original.setter();
+    let changed_setter = changed.setter();
+
+    let BytesDiffStats {
+        first_difference,
+        similarity,
+    } = compare_bytes(&original, &changed);
+
+    html! {
+        <div class="vertical">
+            <div class="horizontal">
+                <ByteInput
+                    bytes={(*original).clone()}
+                    setter={Callback::from(move |bytes| original_setter.set(bytes))}
+                    format={BytesFormat::Hex}
+                    placeholder={"original".to_owned()}
+                    rows={8}
+                />
+                <ByteInput
+                    bytes={(*changed).clone()}
+                    setter={Callback::from(move |bytes| changed_setter.set(bytes))}
+                    format={BytesFormat::Hex}
+                    placeholder={"changed".to_owned()}
+                    rows={8}
+                />
+            </div>
+            <div class="horizontal">
+                <span>{match first_difference {
+                    Some(offset) => format!("First difference at offset: {} (0x{:x})", offset, offset),
+                    None => "Inputs are equal".to_owned(),
+                }}</span>
+                <span class="total">{format!("similarity: {:.2}%", similarity)}</span>
+            </div>
+            <div class="bytes-diff-container">
+                <div class="bytes-diff-view">{render_rows(&original, &changed, "bytes-diff-byte diff-remove")}</div>
+                <div class="bytes-diff-view">{render_rows(&changed, &original, "bytes-diff-byte diff-insert")}</div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,81 @@
+mod parser;
+
+use yew::{function_component, html, use_state, Callback, Html};
+
+use self::parser::{algorithm_name, flag_names, format_guid, parse_dpapi_blob, DpapiBlob};
+use crate::common::{ByteInput, BytesFormat, BytesViewer, FileInput};
+
+fn render_bytes(name: &'static str, bytes: &[u8]) -> Html {
+    if bytes.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <div class="vertical">
+            <span>{format!("{} ({} bytes)", name, bytes.len())}</span>
+            <BytesViewer bytes={bytes.to_vec()} />
+        </div>
+    }
+}
+
+fn render_algorithm(name: &'static str, alg_id: u32, len: u32) -> Html {
+    html! {
+        <span>{format!("{}: {} (0x{:04x}), {} bits", name, algorithm_name(alg_id), alg_id, len)}</span>
+    }
+}
+
+fn render_blob(blob: &DpapiBlob) -> Html {
+    html! {
+        <div class="vertical">
+            <span>{format!("Version: {}", blob.version)}</span>
+            <span>{format!("Provider: {}", format_guid(&blob.provider))}</span>
+            <span>{format!("Master key: {} (version {})", format_guid(&blob.master_key), blob.master_key_version)}</span>
+            <div class="vertical">
+                <span>{format!("Flags: 0x{:08x}", blob.flags)}</span>
+                <div class="parser-flags">
+                    {flag_names(blob.flags).into_iter().map(|name| html! { <span class="parser-flag">{name}</span> }).collect::<Html>()}
+                </div>
+            </div>
+            <span>{format!("Description: {}", blob.description)}</span>
+            {render_algorithm("Encryption algorithm", blob.crypt_algorithm, blob.crypt_algorithm_len)}
+            {render_algorithm("Hash algorithm", blob.hash_algorithm, blob.hash_algorithm_len)}
+            {render_bytes("Salt", &blob.salt)}
+            {render_bytes("HMAC key", &blob.hmac_key)}
+            {render_bytes("HMAC2 key", &blob.hmac2_key)}
+            {render_bytes("Encrypted data", &blob.data)}
+            {render_bytes("Signature", &blob.signature)}
+        </div>
+    }
+}
+
+#[function_component(DpapiPage)]
+pub fn dpapi_page() -> Html {
+    let raw_blob = use_state(Vec::<u8>::new);
+
+    let raw_blob_setter = raw_blob.setter();
+    let set_blob = Callback::from(move |data: Vec<u8>| raw_blob_setter.set(data));
+
+    html! {
+        <article class="vertical">
+            <div class="horizontal">
+                <span>{"Upload DPAPI blob:"}</span>
+                <FileInput setter={set_blob.clone()} />
+            </div>
+            <ByteInput
+                bytes={(*raw_blob).clone()}
+                setter={set_blob}
+                format={BytesFormat::Base64}
+                placeholder={"DPAPI blob (CryptProtectData output)".to_owned()}
+                rows={4}
+            />
+            {if raw_blob.is_empty() {
+                html! {}
+            } else {
+                match parse_dpapi_blob(&raw_blob) {
+                    Ok(blob) => render_blob(&blob),
+                    Err(err) => html! { <span class="total">{format!("Invalid DPAPI blob: {}", err)}</span> },
+                }
+            }}
+        </article>
+    }
+}
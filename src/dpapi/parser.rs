@@ -0,0 +1,214 @@
+use crate::utils::BytesReader;
+
+// https://github.com/gentilkiwi/mimikatz/blob/master/modules/kull_m_dpapi.h
+const DPAPI_PROVIDER_GUID: &str = "df9d8cd0-1501-11d1-8c7a-00c04fc297eb";
+
+/// Prefix of the DPAPI-protected keys stored by Chromium-based browsers (`Local State` file).
+const CHROMIUM_KEY_PREFIX: &[u8] = b"DPAPI";
+
+const PROTECTION_FLAGS: [(u32, &str); 7] = [
+    (0x0000_0001, "CRYPTPROTECT_UI_FORBIDDEN"),
+    (0x0000_0004, "CRYPTPROTECT_LOCAL_MACHINE"),
+    (0x0000_0008, "CRYPTPROTECT_CRED_SYNC"),
+    (0x0000_0010, "CRYPTPROTECT_AUDIT"),
+    (0x0000_0040, "CRYPTPROTECT_VERIFY_PROTECTION"),
+    (0x0000_0080, "CRYPTPROTECT_CRED_REGENERATE"),
+    (0x2000_0000, "CRYPTPROTECT_SYSTEM"),
+];
+
+pub fn flag_names(flags: u32) -> Vec<&'static str> {
+    PROTECTION_FLAGS
+        .iter()
+        .filter(|(flag, _)| flags & flag != 0)
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+/// Returns the name of the CryptoAPI algorithm identifier.
+// https://learn.microsoft.com/en-us/windows/win32/seccrypto/alg-id
+pub fn algorithm_name(alg_id: u32) -> &'static str {
+    match alg_id {
+        0x6601 => "DES",
+        0x6603 => "3DES",
+        0x6609 => "3DES (112)",
+        0x660e => "AES-128",
+        0x660f => "AES-192",
+        0x6610 => "AES-256",
+        0x6801 => "RC4",
+        0x8003 => "MD5",
+        0x8004 => "SHA1",
+        0x8009 => "HMAC",
+        0x800c => "SHA-256",
+        0x800d => "SHA-384",
+        0x800e => "SHA-512",
+        _ => "unknown",
+    }
+}
+
+/// Formats the little-endian encoded GUID (`xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`).
+pub fn format_guid(guid: &[u8; 16]) -> String {
+    format!(
+        "{:08x}-{:04x}-{:04x}-{}-{}",
+        u32::from_le_bytes([guid[0], guid[1], guid[2], guid[3]]),
+        u16::from_le_bytes([guid[4], guid[5]]),
+        u16::from_le_bytes([guid[6], guid[7]]),
+        hex::encode(&guid[8..10]),
+        hex::encode(&guid[10..]),
+    )
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DpapiBlob {
+    pub version: u32,
+    pub provider: [u8; 16],
+    pub master_key_version: u32,
+    pub master_key: [u8; 16],
+    pub flags: u32,
+    pub description: String,
+    pub crypt_algorithm: u32,
+    pub crypt_algorithm_len: u32,
+    pub salt: Vec<u8>,
+    pub hmac_key: Vec<u8>,
+    pub hash_algorithm: u32,
+    pub hash_algorithm_len: u32,
+    pub hmac2_key: Vec<u8>,
+    pub data: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+fn read_counted_bytes(reader: &mut BytesReader<'_>) -> Result<Vec<u8>, String> {
+    let len = reader.read_u32_le()? as usize;
+
+    Ok(reader.read_bytes(len)?.to_vec())
+}
+
+fn read_description(reader: &mut BytesReader<'_>) -> Result<String, String> {
+    let description = read_counted_bytes(reader)?
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect::<Vec<_>>();
+
+    Ok(String::from_utf16_lossy(&description).trim_end_matches('\0').to_owned())
+}
+
+pub fn parse_dpapi_blob(data: &[u8]) -> Result<DpapiBlob, String> {
+    let data = data.strip_prefix(CHROMIUM_KEY_PREFIX).unwrap_or(data);
+    let mut reader = BytesReader::new(data);
+
+    let version = reader.read_u32_le()?;
+    let provider = reader.read_array()?;
+    if format_guid(&provider) != DPAPI_PROVIDER_GUID {
+        return Err(format!(
+            "Invalid provider GUID: expected {} but got {}",
+            DPAPI_PROVIDER_GUID,
+            format_guid(&provider)
+        ));
+    }
+
+    Ok(DpapiBlob {
+        version,
+        provider,
+        master_key_version: reader.read_u32_le()?,
+        master_key: reader.read_array()?,
+        flags: reader.read_u32_le()?,
+        description: read_description(&mut reader)?,
+        crypt_algorithm: reader.read_u32_le()?,
+        crypt_algorithm_len: reader.read_u32_le()?,
+        salt: read_counted_bytes(&mut reader)?,
+        hmac_key: read_counted_bytes(&mut reader)?,
+        hash_algorithm: reader.read_u32_le()?,
+        hash_algorithm_len: reader.read_u32_le()?,
+        hmac2_key: read_counted_bytes(&mut reader)?,
+        data: read_counted_bytes(&mut reader)?,
+        signature: read_counted_bytes(&mut reader)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // df9d8cd0-1501-11d1-8c7a-00c04fc297eb in the little-endian layout
+    const PROVIDER: [u8; 16] = [
+        0xd0, 0x8c, 0x9d, 0xdf, 0x01, 0x15, 0xd1, 0x11, 0x8c, 0x7a, 0x00, 0xc0, 0x4f, 0xc2, 0x97, 0xeb,
+    ];
+
+    fn counted(data: &[u8]) -> Vec<u8> {
+        [&(data.len() as u32).to_le_bytes()[..], data].concat()
+    }
+
+    fn blob() -> Vec<u8> {
+        [
+            &1_u32.to_le_bytes()[..],
+            &PROVIDER,
+            &1_u32.to_le_bytes(),
+            &[0x11; 16],
+            &0x2000_0004_u32.to_le_bytes(),
+            &counted(&[b'k', 0, b'e', 0, b'y', 0, 0, 0]),
+            &0x6610_u32.to_le_bytes(),
+            &256_u32.to_le_bytes(),
+            &counted(&[0x22; 4]),
+            &counted(&[]),
+            &0x800e_u32.to_le_bytes(),
+            &512_u32.to_le_bytes(),
+            &counted(&[0x33; 4]),
+            &counted(&[0x44; 8]),
+            &counted(&[0x55; 4]),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn parse() {
+        let parsed = parse_dpapi_blob(&blob()).unwrap();
+
+        assert_eq!(format_guid(&parsed.provider), DPAPI_PROVIDER_GUID);
+        assert_eq!(format_guid(&parsed.master_key), "11111111-1111-1111-1111-111111111111");
+        assert_eq!(parsed.description, "key");
+        assert_eq!(
+            flag_names(parsed.flags),
+            ["CRYPTPROTECT_LOCAL_MACHINE", "CRYPTPROTECT_SYSTEM"]
+        );
+        assert_eq!(algorithm_name(parsed.crypt_algorithm), "AES-256");
+        assert_eq!(algorithm_name(parsed.hash_algorithm), "SHA-512");
+        assert_eq!(parsed.salt, [0x22; 4]);
+        assert!(parsed.hmac_key.is_empty());
+        assert_eq!(parsed.hmac2_key, [0x33; 4]);
+        assert_eq!(parsed.data, [0x44; 8]);
+        assert_eq!(parsed.signature, [0x55; 4]);
+
+        let chromium = [CHROMIUM_KEY_PREFIX, &blob()].concat();
+        assert_eq!(parse_dpapi_blob(&chromium).unwrap(), parsed);
+    }
+
+    #[test]
+    fn invalid_provider() {
+        let mut data = blob();
+        data[4] ^= 0xff;
+
+        assert!(parse_dpapi_blob(&data).unwrap_err().contains("Invalid provider GUID"));
+    }
+
+    #[test]
+    fn truncated() {
+        let data = blob();
+
+        assert!(parse_dpapi_blob(&data[..3]).is_err());
+        assert!(parse_dpapi_blob(&data[..20]).is_err());
+        assert!(parse_dpapi_blob(&data[..data.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn overflowing_length() {
+        // the description length
+        let mut data = blob();
+        data[44..48].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(parse_dpapi_blob(&data).is_err());
+
+        // the signature length
+        let mut data = blob();
+        let offset = data.len() - 8;
+        data[offset..offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(parse_dpapi_blob(&data).is_err());
+    }
+}
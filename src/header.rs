@@ -11,6 +11,22 @@ pub fn header() -> Html {
             <Link<Route> to={Route::Jwt}>{"JWT debugger"}</Link<Route>>
             <Link<Route> to={Route::Asn1Parser}>{"Asn1 debugger (beta)"}</Link<Route>>
             <Link<Route> to={Route::Diff}>{"Diff"}</Link<Route>>
+            <Link<Route> to={Route::Timestamp}>{"Timestamp"}</Link<Route>>
+            <Link<Route> to={Route::HttpSignature}>{"HTTP signatures"}</Link<Route>>
+            <div class="header-dropdown">
+                <span>{"Parsers"}</span>
+                <div class="header-dropdown-content">
+                    <Link<Route> to={Route::Keytab}>{"Keytab"}</Link<Route>>
+                    <Link<Route> to={Route::Ccache}>{"Ccache"}</Link<Route>>
+                    <Link<Route> to={Route::Spnego}>{"SPNEGO"}</Link<Route>>
+                    <Link<Route> to={Route::Ntlm}>{"NTLM"}</Link<Route>>
+                    <Link<Route> to={Route::Tls}>{"TLS"}</Link<Route>>
+                    <Link<Route> to={Route::Protobuf}>{"Protobuf"}</Link<Route>>
+                    <Link<Route> to={Route::Cbor}>{"CBOR / MessagePack"}</Link<Route>>
+                    <Link<Route> to={Route::SshSig}>{"SSH signature"}</Link<Route>>
+                    <Link<Route> to={Route::Dpapi}>{"DPAPI"}</Link<Route>>
+                </div>
+            </div>
             <Link<Route> to={Route::About}>{"About"}</Link<Route>>
         </header>
     }
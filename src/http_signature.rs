@@ -0,0 +1,289 @@
+mod algorithms;
+mod message;
+mod structured_field;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use time::OffsetDateTime;
+use web_sys::{Event, HtmlInputElement};
+use yew::{function_component, html, use_state, Callback, Html, TargetCast};
+
+use self::algorithms::{HttpSignatureAlgorithm, HTTP_SIGNATURE_ALGORITHMS};
+use self::message::HttpRequest;
+use self::structured_field::{find_parameter, parse_dictionary, parse_inner_list, BareItem, InnerList, Item, Member};
+use crate::common::build_byte_input;
+
+const DEFAULT_COMPONENTS: &str = r#""@method" "@authority" "@path" "content-type""#;
+const DEFAULT_LABEL: &str = "sig1";
+
+struct SignResult {
+    signature_base: String,
+    signature_input: String,
+    signature: String,
+}
+
+fn sign(
+    request: &str,
+    label: &str,
+    components: &str,
+    key_id: &str,
+    algorithm: HttpSignatureAlgorithm,
+    key: &[u8],
+) -> Result<SignResult, String> {
+    let request = HttpRequest::parse(request)?;
+
+    let mut signature_params = parse_inner_list(&format!("({})", components))?;
+    signature_params.parameters.push((
+        "created".to_owned(),
+        BareItem::Integer(OffsetDateTime::now_utc().unix_timestamp()),
+    ));
+    if !key_id.is_empty() {
+        signature_params
+            .parameters
+            .push(("keyid".to_owned(), BareItem::String(key_id.to_owned())));
+    }
+    signature_params
+        .parameters
+        .push(("alg".to_owned(), BareItem::String(algorithm.as_ref().to_owned())));
+
+    let signature_base = request.signature_base(&signature_params)?;
+    let signature = algorithm.sign(key, signature_base.as_bytes())?;
+
+    Ok(SignResult {
+        signature_base,
+        signature_input: format!("Signature-Input: {}={}", label, signature_params.serialize()),
+        signature: format!("Signature: {}=:{}:", label, STANDARD.encode(signature)),
+    })
+}
+
+/// Signature from the `Signature-Input` and `Signature` request headers.
+struct ReceivedSignature {
+    label: String,
+    signature_params: InnerList,
+    signature: Option<Vec<u8>>,
+}
+
+fn received_signatures(request: &HttpRequest) -> Result<Vec<ReceivedSignature>, String> {
+    let signature_inputs = match request.header("signature-input") {
+        Some(signature_input) => parse_dictionary(&signature_input)?,
+        None => return Ok(Vec::new()),
+    };
+    let signatures = match request.header("signature") {
+        Some(signature) => parse_dictionary(&signature)?,
+        None => Vec::new(),
+    };
+
+    signature_inputs
+        .into_iter()
+        .map(|(label, member)| {
+            let signature_params = match member {
+                Member::InnerList(signature_params) => signature_params,
+                Member::Item(_) => return Err(format!("Signature-Input {} must be an inner list", label)),
+            };
+            let signature = signatures.iter().find_map(|(signature_label, member)| match member {
+                Member::Item(Item {
+                    bare_item: BareItem::ByteSequence(signature),
+                    ..
+                }) if *signature_label == label => Some(signature.clone()),
+                _ => None,
+            });
+
+            Ok(ReceivedSignature {
+                label,
+                signature_params,
+                signature,
+            })
+        })
+        .collect()
+}
+
+fn render_verification(
+    request: &HttpRequest,
+    received: &ReceivedSignature,
+    selected_algorithm: HttpSignatureAlgorithm,
+    key: &[u8],
+) -> Html {
+    let signature_base = match request.signature_base(&received.signature_params) {
+        Ok(signature_base) => signature_base,
+        Err(err) => return html! { <span class="parser-invalid">{err}</span> },
+    };
+
+    // The algorithm from the signature parameters takes precedence over the selected one.
+    let algorithm = match find_parameter(&received.signature_params.parameters, "alg")
+        .and_then(BareItem::as_str)
+        .map(HttpSignatureAlgorithm::try_from)
+    {
+        Some(Ok(algorithm)) => algorithm,
+        Some(Err(err)) => return html! { <span class="parser-invalid">{err}</span> },
+        None => selected_algorithm,
+    };
+
+    html! {
+        <div class="vertical">
+            <span class="total">{format!("Signature {} ({})", received.label, algorithm.as_ref())}</span>
+            <pre class="http-signature-base">{&signature_base}</pre>
+            {match (&received.signature, key.is_empty()) {
+                (None, _) => html! { <span class="parser-invalid">{"Signature header does not contain this label"}</span> },
+                (Some(_), true) => html! {},
+                (Some(signature), false) => match algorithm.verify(key, signature_base.as_bytes(), signature) {
+                    Ok(true) => html! { <span class="parser-valid">{"Signature is valid"}</span> },
+                    Ok(false) => html! { <span class="parser-invalid">{"Signature is invalid"}</span> },
+                    Err(err) => html! { <span class="parser-invalid">{err}</span> },
+                },
+            }}
+        </div>
+    }
+}
+
+fn render_received_signatures(raw_request: &str, algorithm: HttpSignatureAlgorithm, key: &[u8]) -> Html {
+    if raw_request.trim().is_empty() {
+        return html! {};
+    }
+
+    let request = match HttpRequest::parse(raw_request) {
+        Ok(request) => request,
+        Err(err) => return html! { <span class="total">{format!("Invalid request: {}", err)}</span> },
+    };
+
+    match received_signatures(&request) {
+        Ok(signatures) => signatures
+            .iter()
+            .map(|received| render_verification(&request, received, algorithm, key))
+            .collect::<Html>(),
+        Err(err) => html! { <span class="total">{format!("Invalid signature headers: {}", err)}</span> },
+    }
+}
+
+#[function_component(HttpSignaturePage)]
+pub fn http_signature_page() -> Html {
+    let raw_request = use_state(String::new);
+    let algorithm = use_state(HttpSignatureAlgorithm::default);
+    let hmac_key = use_state(Vec::<u8>::new);
+    let pem_key = use_state(String::new);
+    let label = use_state(|| DEFAULT_LABEL.to_owned());
+    let components = use_state(|| DEFAULT_COMPONENTS.to_owned());
+    let key_id = use_state(String::new);
+    let sign_result = use_state(|| None::<Result<SignResult, String>>);
+
+    let raw_request_setter = raw_request.setter();
+    let on_request_input = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        raw_request_setter.set(input.value());
+    });
+
+    let algorithm_setter = algorithm.setter();
+    let on_algorithm_change = Callback::from(move |event: Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        if let Ok(algorithm) = HttpSignatureAlgorithm::try_from(input.value().as_str()) {
+            algorithm_setter.set(algorithm);
+        }
+    });
+
+    let hmac_key_setter = hmac_key.setter();
+    let set_hmac_key = Callback::from(move |key: Vec<u8>| hmac_key_setter.set(key));
+
+    let pem_key_setter = pem_key.setter();
+    let on_pem_key_input = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        pem_key_setter.set(input.value());
+    });
+
+    let label_setter = label.setter();
+    let on_label_input = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        label_setter.set(input.value());
+    });
+
+    let components_setter = components.setter();
+    let on_components_input = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        components_setter.set(input.value());
+    });
+
+    let key_id_setter = key_id.setter();
+    let on_key_id_input = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        key_id_setter.set(input.value());
+    });
+
+    let key = if algorithm.is_symmetric() {
+        (*hmac_key).clone()
+    } else {
+        pem_key.as_bytes().to_vec()
+    };
+
+    let sign_result_setter = sign_result.setter();
+    let request_to_sign = (*raw_request).clone();
+    let sign_params = ((*label).clone(), (*components).clone(), (*key_id).clone(), *algorithm);
+    let key_to_sign = key.clone();
+    let on_sign = Callback::from(move |_| {
+        let (label, components, key_id, algorithm) = &sign_params;
+        sign_result_setter.set(Some(sign(
+            &request_to_sign,
+            label,
+            components,
+            key_id,
+            *algorithm,
+            &key_to_sign,
+        )));
+    });
+
+    html! {
+        <article class="vertical">
+            <textarea
+                rows="10"
+                placeholder={"HTTP request: request line and headers, e.g.\nPOST /foo?param=Value HTTP/1.1\nHost: example.com"}
+                class="base-input"
+                value={(*raw_request).clone()}
+                oninput={on_request_input}
+            />
+            <div class="horizontal">
+                <span>{"Algorithm:"}</span>
+                <select class="base-input" onchange={on_algorithm_change}>
+                    {HTTP_SIGNATURE_ALGORITHMS.iter().map(|algo| html! {
+                        <option selected={*algorithm == *algo} value={algo.as_ref().to_owned()}>{algo.as_ref()}</option>
+                    }).collect::<Html>()}
+                </select>
+            </div>
+            {if algorithm.is_symmetric() {
+                build_byte_input((*hmac_key).clone(), set_hmac_key, None, Some("HMAC SHA256 key".into()))
+            } else {
+                html! {
+                    <textarea
+                        rows="4"
+                        placeholder={"private key (to sign) or public key (to verify) in PEM"}
+                        class="base-input"
+                        value={(*pem_key).clone()}
+                        oninput={on_pem_key_input}
+                    />
+                }
+            }}
+            <span class="total">{"Sign"}</span>
+            <div class="horizontal">
+                <input class="base-input" placeholder="label" value={(*label).clone()} oninput={on_label_input} />
+                <input class="base-input" placeholder="keyid" value={(*key_id).clone()} oninput={on_key_id_input} />
+            </div>
+            <input
+                class="base-input"
+                placeholder={"covered components"}
+                value={(*components).clone()}
+                oninput={on_components_input}
+            />
+            <button class="action-button" onclick={on_sign}>{"sign"}</button>
+            {match &*sign_result {
+                Some(Ok(result)) => html! {
+                    <div class="vertical">
+                        <pre class="http-signature-base">{&result.signature_base}</pre>
+                        <span class="http-signature-header">{&result.signature_input}</span>
+                        <span class="http-signature-header">{&result.signature}</span>
+                    </div>
+                },
+                Some(Err(err)) => html! { <span class="parser-invalid">{err}</span> },
+                None => html! {},
+            }}
+            <span class="total">{"Verify"}</span>
+            <span>{"Signatures from the Signature-Input and Signature headers of the request:"}</span>
+            {render_received_signatures(&raw_request, *algorithm, &key)}
+        </article>
+    }
+}
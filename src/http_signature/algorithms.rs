@@ -0,0 +1,230 @@
+use picky::hash::HashAlgorithm;
+use picky::key::{PrivateKey, PublicKey};
+use picky::signature::SignatureAlgorithm;
+use rsa::pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey};
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::rand_core::OsRng;
+use rsa::{Pss, RsaPrivateKey, RsaPublicKey};
+use sha2::{Digest, Sha512};
+
+const INTEGER: u8 = 0x02;
+const SEQUENCE: u8 = 0x30;
+
+/// HTTP signature algorithms from the IANA registry.
+// https://www.rfc-editor.org/rfc/rfc9421#name-initial-contents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HttpSignatureAlgorithm {
+    RsaPssSha512,
+    RsaV15Sha256,
+    #[default]
+    HmacSha256,
+    EcdsaP256Sha256,
+    EcdsaP384Sha384,
+    Ed25519,
+}
+
+pub const HTTP_SIGNATURE_ALGORITHMS: [HttpSignatureAlgorithm; 6] = [
+    HttpSignatureAlgorithm::RsaPssSha512,
+    HttpSignatureAlgorithm::RsaV15Sha256,
+    HttpSignatureAlgorithm::HmacSha256,
+    HttpSignatureAlgorithm::EcdsaP256Sha256,
+    HttpSignatureAlgorithm::EcdsaP384Sha384,
+    HttpSignatureAlgorithm::Ed25519,
+];
+
+impl AsRef<str> for HttpSignatureAlgorithm {
+    fn as_ref(&self) -> &str {
+        match self {
+            HttpSignatureAlgorithm::RsaPssSha512 => "rsa-pss-sha512",
+            HttpSignatureAlgorithm::RsaV15Sha256 => "rsa-v1_5-sha256",
+            HttpSignatureAlgorithm::HmacSha256 => "hmac-sha256",
+            HttpSignatureAlgorithm::EcdsaP256Sha256 => "ecdsa-p256-sha256",
+            HttpSignatureAlgorithm::EcdsaP384Sha384 => "ecdsa-p384-sha384",
+            HttpSignatureAlgorithm::Ed25519 => "ed25519",
+        }
+    }
+}
+
+impl TryFrom<&str> for HttpSignatureAlgorithm {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        HTTP_SIGNATURE_ALGORITHMS
+            .iter()
+            .find(|algorithm| algorithm.as_ref() == value)
+            .copied()
+            .ok_or_else(|| format!("Unsupported signature algorithm: {}", value))
+    }
+}
+
+impl HttpSignatureAlgorithm {
+    pub fn is_symmetric(self) -> bool {
+        self == HttpSignatureAlgorithm::HmacSha256
+    }
+
+    /// Size of the `r` and `s` values of the ECDSA signature.
+    fn ecdsa_scalar_len(self) -> Option<usize> {
+        match self {
+            HttpSignatureAlgorithm::EcdsaP256Sha256 => Some(32),
+            HttpSignatureAlgorithm::EcdsaP384Sha384 => Some(48),
+            _ => None,
+        }
+    }
+
+    fn picky_algorithm(self) -> SignatureAlgorithm {
+        match self {
+            HttpSignatureAlgorithm::RsaV15Sha256 => SignatureAlgorithm::RsaPkcs1v15(HashAlgorithm::SHA2_256),
+            HttpSignatureAlgorithm::EcdsaP256Sha256 => SignatureAlgorithm::Ecdsa(HashAlgorithm::SHA2_256),
+            HttpSignatureAlgorithm::EcdsaP384Sha384 => SignatureAlgorithm::Ecdsa(HashAlgorithm::SHA2_384),
+            _ => SignatureAlgorithm::Ed25519,
+        }
+    }
+
+    pub fn sign(self, key: &[u8], data: &[u8]) -> Result<Vec<u8>, String> {
+        match self {
+            HttpSignatureAlgorithm::HmacSha256 => Ok(hmac_sha256::HMAC::mac(data, key).to_vec()),
+            HttpSignatureAlgorithm::RsaPssSha512 => {
+                let private_key = parse_rsa_private_key(key)?;
+
+                private_key
+                    .sign_with_rng(&mut OsRng, Pss::new::<Sha512>(), &Sha512::digest(data))
+                    .map_err(|err| format!("Can not create signature: {:?}", err))
+            }
+            algorithm => {
+                let private_key =
+                    PrivateKey::from_pem_str(pem_str(key)?).map_err(|err| format!("Invalid private key: {:?}", err))?;
+
+                let signature = algorithm
+                    .picky_algorithm()
+                    .sign(data, &private_key)
+                    .map_err(|err| format!("Can not create signature: {:?}", err))?;
+
+                match algorithm.ecdsa_scalar_len() {
+                    Some(len) => der_to_raw_ecdsa_signature(&signature, len),
+                    None => Ok(signature),
+                }
+            }
+        }
+    }
+
+    /// Returns `Ok(false)` if the signature does not match.
+    pub fn verify(self, key: &[u8], data: &[u8], signature: &[u8]) -> Result<bool, String> {
+        match self {
+            HttpSignatureAlgorithm::HmacSha256 => Ok(hmac_sha256::HMAC::mac(data, key)[..] == *signature),
+            HttpSignatureAlgorithm::RsaPssSha512 => {
+                let public_key = parse_rsa_public_key(key)?;
+
+                Ok(public_key
+                    .verify(Pss::new::<Sha512>(), &Sha512::digest(data), signature)
+                    .is_ok())
+            }
+            algorithm => {
+                let public_key =
+                    PublicKey::from_pem_str(pem_str(key)?).map_err(|err| format!("Invalid public key: {:?}", err))?;
+
+                let signature = match algorithm.ecdsa_scalar_len() {
+                    Some(len) => raw_to_der_ecdsa_signature(signature, len)?,
+                    None => signature.to_vec(),
+                };
+
+                Ok(algorithm
+                    .picky_algorithm()
+                    .verify(&public_key, data, &signature)
+                    .is_ok())
+            }
+        }
+    }
+}
+
+fn pem_str(key: &[u8]) -> Result<&str, String> {
+    std::str::from_utf8(key).map_err(|err| format!("PEM key must be a valid UTF-8 string: {:?}", err))
+}
+
+fn parse_rsa_private_key(key: &[u8]) -> Result<RsaPrivateKey, String> {
+    let pem = pem_str(key)?;
+
+    RsaPrivateKey::from_pkcs8_pem(pem)
+        .or_else(|_| RsaPrivateKey::from_pkcs1_pem(pem))
+        .map_err(|err| format!("Invalid RSA private key: {:?}", err))
+}
+
+fn parse_rsa_public_key(key: &[u8]) -> Result<RsaPublicKey, String> {
+    let pem = pem_str(key)?;
+
+    RsaPublicKey::from_public_key_pem(pem)
+        .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem))
+        .map_err(|err| format!("Invalid RSA public key: {:?}", err))
+}
+
+fn read_der_integer(data: &[u8]) -> Result<(&[u8], &[u8]), String> {
+    match data {
+        [INTEGER, len, rest @ ..] if usize::from(*len) <= rest.len() && *len < 0x80 => {
+            Ok(rest.split_at(usize::from(*len)))
+        }
+        _ => Err("Invalid DER-encoded ECDSA signature".into()),
+    }
+}
+
+/// Converts the DER-encoded Ecdsa-Sig-Value into the `r || s` form used by the HTTP Message Signatures.
+fn der_to_raw_ecdsa_signature(der: &[u8], scalar_len: usize) -> Result<Vec<u8>, String> {
+    let body = match der {
+        [SEQUENCE, 0x81, _, body @ ..] | [SEQUENCE, _, body @ ..] => body,
+        _ => return Err("Invalid DER-encoded ECDSA signature".into()),
+    };
+
+    let (r, rest) = read_der_integer(body)?;
+    let (s, _) = read_der_integer(rest)?;
+
+    let mut raw = vec![0; scalar_len * 2];
+    for (i, value) in [r, s].into_iter().enumerate() {
+        let value = &value[value.iter().position(|byte| *byte != 0).unwrap_or(value.len())..];
+        if value.len() > scalar_len {
+            return Err("ECDSA signature value is too long".into());
+        }
+
+        let end = scalar_len * (i + 1);
+        raw[end - value.len()..end].copy_from_slice(value);
+    }
+
+    Ok(raw)
+}
+
+fn der_unsigned_integer(magnitude: &[u8]) -> Vec<u8> {
+    let magnitude = &magnitude[magnitude.iter().position(|byte| *byte != 0).unwrap_or(magnitude.len())..];
+
+    let mut value = Vec::new();
+    if magnitude.first().map_or(true, |byte| byte & 0x80 != 0) {
+        value.push(0);
+    }
+    value.extend_from_slice(magnitude);
+
+    let mut integer = vec![INTEGER, value.len() as u8];
+    integer.extend_from_slice(&value);
+
+    integer
+}
+
+fn raw_to_der_ecdsa_signature(raw: &[u8], scalar_len: usize) -> Result<Vec<u8>, String> {
+    if raw.len() != scalar_len * 2 {
+        return Err(format!(
+            "Invalid ECDSA signature length: expected {} bytes but got {}",
+            scalar_len * 2,
+            raw.len()
+        ));
+    }
+
+    let body = [
+        der_unsigned_integer(&raw[..scalar_len]),
+        der_unsigned_integer(&raw[scalar_len..]),
+    ]
+    .concat();
+
+    let mut der = vec![SEQUENCE];
+    if body.len() >= 0x80 {
+        der.push(0x81);
+    }
+    der.push(body.len() as u8);
+    der.extend_from_slice(&body);
+
+    Ok(der)
+}
@@ -0,0 +1,234 @@
+use super::structured_field::{BareItem, InnerList, Item};
+
+const DEFAULT_SCHEME: &str = "https";
+const SIGNATURE_PARAMS: &str = "@signature-params";
+
+/// Parsed HTTP request in the HTTP/1.1 text form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: String,
+    pub target: String,
+    /// Header names are lowercased.
+    pub headers: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let mut lines = raw.trim_start().lines();
+
+        let request_line = lines.next().ok_or("Empty request")?;
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().ok_or("Missing request method")?.to_owned();
+        let target = parts.next().ok_or("Missing request target")?.to_owned();
+
+        let mut headers: Vec<(String, String)> = Vec::new();
+        for line in lines.take_while(|line| !line.trim().is_empty()) {
+            // Obsolete line folding.
+            if line.starts_with([' ', '\t']) {
+                let (_, value) = headers.last_mut().ok_or("Header continuation without header")?;
+                value.push(' ');
+                value.push_str(line.trim());
+                continue;
+            }
+
+            let (name, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("Invalid header line: {}", line))?;
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_owned()));
+        }
+
+        Ok(Self {
+            method,
+            target,
+            headers,
+        })
+    }
+
+    /// Returns all header values combined with ", " or `None` if the header is absent.
+    pub fn header(&self, name: &str) -> Option<String> {
+        let values = self
+            .headers
+            .iter()
+            .filter(|(header_name, _)| header_name == name)
+            .map(|(_, value)| value.as_str())
+            .collect::<Vec<_>>();
+
+        if values.is_empty() {
+            None
+        } else {
+            Some(values.join(", "))
+        }
+    }
+
+    fn split_target(&self) -> (String, String, &str) {
+        match self.target.split_once("://") {
+            // absolute-form
+            Some((scheme, rest)) => {
+                let path_start = rest.find(['/', '?']).unwrap_or(rest.len());
+                (
+                    scheme.to_ascii_lowercase(),
+                    rest[..path_start].to_ascii_lowercase(),
+                    &rest[path_start..],
+                )
+            }
+            None => (
+                DEFAULT_SCHEME.to_owned(),
+                self.header("host").unwrap_or_default().to_ascii_lowercase(),
+                &self.target,
+            ),
+        }
+    }
+
+    // https://www.rfc-editor.org/rfc/rfc9421#name-derived-components
+    fn derived_component(&self, name: &str) -> Result<String, String> {
+        let (scheme, authority, path_and_query) = self.split_target();
+        let (path, query) = match path_and_query.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (path_and_query, None),
+        };
+        let path = if path.is_empty() { "/" } else { path };
+
+        Ok(match name {
+            "@method" => self.method.clone(),
+            "@target-uri" => format!("{}://{}{}", scheme, authority, path_and_query),
+            "@authority" => authority,
+            "@scheme" => scheme,
+            "@request-target" => self.target.clone(),
+            "@path" => path.to_owned(),
+            "@query" => format!("?{}", query.unwrap_or_default()),
+            name => return Err(format!("Unsupported derived component: {}", name)),
+        })
+    }
+
+    /// Returns the component value for the signature base.
+    pub fn component_value(&self, component: &Item) -> Result<String, String> {
+        let name = match &component.bare_item {
+            BareItem::String(name) => name,
+            _ => {
+                return Err(format!(
+                    "Component identifier must be a string: {}",
+                    component.serialize()
+                ))
+            }
+        };
+
+        if let Some((key, _)) = component.parameters.first() {
+            return Err(format!("Unsupported component parameter: {}", key));
+        }
+
+        if name.starts_with('@') {
+            self.derived_component(name)
+        } else {
+            self.header(name)
+                .ok_or_else(|| format!("Header is missing in the request: {}", name))
+        }
+    }
+
+    /// Builds the signature base for the covered components and signature parameters.
+    // https://www.rfc-editor.org/rfc/rfc9421#name-creating-the-signature-base
+    pub fn signature_base(&self, signature_params: &InnerList) -> Result<String, String> {
+        let mut base = String::new();
+
+        for component in &signature_params.items {
+            if component.bare_item == BareItem::String(SIGNATURE_PARAMS.to_owned()) {
+                return Err("@signature-params can not be a covered component".into());
+            }
+
+            base.push_str(&format!(
+                "{}: {}\n",
+                component.serialize(),
+                self.component_value(component)?
+            ));
+        }
+
+        base.push_str(&format!("\"{}\": {}", SIGNATURE_PARAMS, signature_params.serialize()));
+
+        Ok(base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::structured_field::parse_inner_list;
+    use super::*;
+
+    const REQUEST: &str = "POST /foo?param=Value&Pet=dog HTTP/1.1\r\n\
+                           Host: Example.com\r\n\
+                           Date: Tue, 20 Apr 2021 02:07:55 GMT\r\n\
+                           X-Empty:\r\n\
+                           X-List: a\r\n\
+                           X-Folded: first\r\n \
+                           second\r\n\
+                           x-list: b\r\n\
+                           \r\n\
+                           {\"hello\": \"world\"}";
+
+    #[test]
+    fn parse() {
+        let request = HttpRequest::parse(REQUEST).unwrap();
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.target, "/foo?param=Value&Pet=dog");
+        assert_eq!(request.headers.len(), 6);
+        assert_eq!(request.header("host").as_deref(), Some("Example.com"));
+        assert_eq!(request.header("x-empty").as_deref(), Some(""));
+        assert_eq!(request.header("x-list").as_deref(), Some("a, b"));
+        assert_eq!(request.header("x-folded").as_deref(), Some("first second"));
+        assert_eq!(request.header("content-length"), None);
+    }
+
+    #[test]
+    fn signature_base() {
+        let request = HttpRequest::parse(REQUEST).unwrap();
+        let signature_params =
+            parse_inner_list(r#"("@method" "@target-uri" "@path" "@query" "date");created=1618884473"#).unwrap();
+
+        assert_eq!(
+            request.signature_base(&signature_params).unwrap(),
+            "\"@method\": POST\n\
+             \"@target-uri\": https://example.com/foo?param=Value&Pet=dog\n\
+             \"@path\": /foo\n\
+             \"@query\": ?param=Value&Pet=dog\n\
+             \"date\": Tue, 20 Apr 2021 02:07:55 GMT\n\
+             \"@signature-params\": (\"@method\" \"@target-uri\" \"@path\" \"@query\" \"date\");created=1618884473"
+        );
+    }
+
+    #[test]
+    fn absolute_form_target() {
+        let request = HttpRequest::parse("GET HTTP://Example.COM:8443?a=b HTTP/1.1\n").unwrap();
+        let signature_params = parse_inner_list(r#"("@scheme" "@authority" "@path" "@request-target")"#).unwrap();
+
+        assert_eq!(
+            request.signature_base(&signature_params).unwrap(),
+            "\"@scheme\": http\n\
+             \"@authority\": example.com:8443\n\
+             \"@path\": /\n\
+             \"@request-target\": HTTP://Example.COM:8443?a=b\n\
+             \"@signature-params\": (\"@scheme\" \"@authority\" \"@path\" \"@request-target\")"
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        assert!(HttpRequest::parse("").unwrap_err().contains("Empty request"));
+        assert!(HttpRequest::parse("GET").unwrap_err().contains("request target"));
+        assert!(HttpRequest::parse("GET / HTTP/1.1\r\nHost").is_err());
+        assert!(HttpRequest::parse("GET / HTTP/1.1\r\n folded").is_err());
+    }
+
+    #[test]
+    fn invalid_components() {
+        let request = HttpRequest::parse(REQUEST).unwrap();
+
+        for components in [
+            r#"("content-type")"#,
+            r#"("@status")"#,
+            r#"(method)"#,
+            r#"("x-list";sf)"#,
+            r#"("@signature-params")"#,
+        ] {
+            assert!(request.signature_base(&parse_inner_list(components).unwrap()).is_err());
+        }
+    }
+}
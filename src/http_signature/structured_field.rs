@@ -0,0 +1,433 @@
+//! Minimal Structured Field Values (RFC 8941) parser and serializer.
+//! It supports only the parts that are needed for the HTTP Message Signatures: dictionaries, inner lists, and parameters.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BareItem {
+    Integer(i64),
+    String(String),
+    Token(String),
+    ByteSequence(Vec<u8>),
+    Boolean(bool),
+}
+
+impl BareItem {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            BareItem::String(value) | BareItem::Token(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn serialize(&self) -> String {
+        match self {
+            BareItem::Integer(value) => value.to_string(),
+            BareItem::String(value) => format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"")),
+            BareItem::Token(value) => value.clone(),
+            BareItem::ByteSequence(value) => format!(":{}:", STANDARD.encode(value)),
+            BareItem::Boolean(value) => format!("?{}", u8::from(*value)),
+        }
+    }
+}
+
+pub type Parameters = Vec<(String, BareItem)>;
+
+pub fn serialize_parameters(parameters: &Parameters) -> String {
+    parameters
+        .iter()
+        .map(|(key, value)| match value {
+            BareItem::Boolean(true) => format!(";{}", key),
+            value => format!(";{}={}", key, value.serialize()),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub bare_item: BareItem,
+    pub parameters: Parameters,
+}
+
+impl Item {
+    pub fn serialize(&self) -> String {
+        format!(
+            "{}{}",
+            self.bare_item.serialize(),
+            serialize_parameters(&self.parameters)
+        )
+    }
+}
+
+pub fn find_parameter<'a>(parameters: &'a Parameters, key: &str) -> Option<&'a BareItem> {
+    parameters.iter().find(|(k, _)| k == key).map(|(_, value)| value)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InnerList {
+    pub items: Vec<Item>,
+    pub parameters: Parameters,
+}
+
+impl InnerList {
+    pub fn serialize(&self) -> String {
+        format!(
+            "({}){}",
+            self.items.iter().map(Item::serialize).collect::<Vec<_>>().join(" "),
+            serialize_parameters(&self.parameters)
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Member {
+    Item(Item),
+    InnerList(InnerList),
+}
+
+/// Duplicate keys overwrite the previous value but keep its position.
+fn insert_or_overwrite<T>(entries: &mut Vec<(String, T)>, key: String, value: T) {
+    match entries.iter_mut().find(|(k, _)| *k == key) {
+        Some(entry) => entry.1 = value,
+        None => entries.push((key, value)),
+    }
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let c = self.peek()?;
+        self.position += 1;
+
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), String> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            c => Err(format!(
+                "Expected '{}' at position {} but got {:?}",
+                expected as char,
+                self.position,
+                c.map(char::from)
+            )),
+        }
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek() == Some(b' ') {
+            self.position += 1;
+        }
+    }
+
+    fn skip_ows(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t')) {
+            self.position += 1;
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.position;
+        while self.peek().map_or(false, &predicate) {
+            self.position += 1;
+        }
+
+        // Only ASCII characters are accepted by the predicates below.
+        std::str::from_utf8(&self.input[start..self.position]).unwrap_or_default()
+    }
+
+    fn parse_key(&mut self) -> Result<String, String> {
+        match self.peek() {
+            Some(c) if c.is_ascii_lowercase() || c == b'*' => {}
+            c => return Err(format!("Invalid key start: {:?}", c.map(char::from))),
+        }
+
+        Ok(self
+            .take_while(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || b"_-.*".contains(&c))
+            .to_owned())
+    }
+
+    fn parse_bare_item(&mut self) -> Result<BareItem, String> {
+        match self.peek() {
+            Some(b'"') => self.parse_string(),
+            Some(b':') => self.parse_byte_sequence(),
+            Some(b'?') => {
+                self.next();
+                match self.next() {
+                    Some(b'1') => Ok(BareItem::Boolean(true)),
+                    Some(b'0') => Ok(BareItem::Boolean(false)),
+                    _ => Err("Invalid boolean value".into()),
+                }
+            }
+            Some(c) if c == b'-' || c.is_ascii_digit() => {
+                let number = self.take_while(|c| c == b'-' || c.is_ascii_digit());
+                number
+                    .parse()
+                    .map(BareItem::Integer)
+                    .map_err(|err| format!("Invalid integer {}: {:?}", number, err))
+            }
+            Some(c) if c.is_ascii_alphabetic() || c == b'*' => Ok(BareItem::Token(
+                self.take_while(|c| c.is_ascii_graphic() && !b"\"(),;=[]{}<>?@\\".contains(&c))
+                    .to_owned(),
+            )),
+            c => Err(format!("Unexpected character: {:?}", c.map(char::from))),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<BareItem, String> {
+        self.expect(b'"')?;
+
+        let mut value = String::new();
+        loop {
+            match self.next().ok_or("Unterminated string")? {
+                b'"' => return Ok(BareItem::String(value)),
+                b'\\' => match self.next() {
+                    Some(c @ (b'"' | b'\\')) => value.push(char::from(c)),
+                    _ => return Err("Invalid escape sequence in the string".into()),
+                },
+                c if (0x20..0x7f).contains(&c) => value.push(char::from(c)),
+                c => return Err(format!("Invalid character in the string: 0x{:02x}", c)),
+            }
+        }
+    }
+
+    fn parse_byte_sequence(&mut self) -> Result<BareItem, String> {
+        self.expect(b':')?;
+        let encoded = self.take_while(|c| c != b':');
+        self.expect(b':')?;
+
+        STANDARD
+            .decode(encoded)
+            .map(BareItem::ByteSequence)
+            .map_err(|err| format!("Invalid byte sequence: {:?}", err))
+    }
+
+    fn parse_parameters(&mut self) -> Result<Parameters, String> {
+        let mut parameters = Parameters::new();
+        while self.peek() == Some(b';') {
+            self.next();
+            self.skip_spaces();
+
+            let key = self.parse_key()?;
+            let value = if self.peek() == Some(b'=') {
+                self.next();
+                self.parse_bare_item()?
+            } else {
+                BareItem::Boolean(true)
+            };
+
+            insert_or_overwrite(&mut parameters, key, value);
+        }
+
+        Ok(parameters)
+    }
+
+    fn parse_item(&mut self) -> Result<Item, String> {
+        Ok(Item {
+            bare_item: self.parse_bare_item()?,
+            parameters: self.parse_parameters()?,
+        })
+    }
+
+    fn parse_inner_list(&mut self) -> Result<InnerList, String> {
+        self.expect(b'(')?;
+
+        let mut items = Vec::new();
+        loop {
+            self.skip_spaces();
+            if self.peek() == Some(b')') {
+                self.next();
+                break;
+            }
+
+            items.push(self.parse_item()?);
+            match self.peek() {
+                Some(b' ' | b')') => {}
+                c => {
+                    return Err(format!(
+                        "Unexpected character in the inner list: {:?}",
+                        c.map(char::from)
+                    ))
+                }
+            }
+        }
+
+        Ok(InnerList {
+            items,
+            parameters: self.parse_parameters()?,
+        })
+    }
+
+    fn parse_member(&mut self) -> Result<Member, String> {
+        if self.peek() == Some(b'(') {
+            Ok(Member::InnerList(self.parse_inner_list()?))
+        } else {
+            Ok(Member::Item(self.parse_item()?))
+        }
+    }
+}
+
+pub fn parse_dictionary(input: &str) -> Result<Vec<(String, Member)>, String> {
+    let mut parser = Parser {
+        input: input.trim().as_bytes(),
+        position: 0,
+    };
+
+    let mut dictionary: Vec<(String, Member)> = Vec::new();
+    while parser.peek().is_some() {
+        let key = parser.parse_key()?;
+        let member = if parser.peek() == Some(b'=') {
+            parser.next();
+            parser.parse_member()?
+        } else {
+            Member::Item(Item {
+                bare_item: BareItem::Boolean(true),
+                parameters: parser.parse_parameters()?,
+            })
+        };
+
+        insert_or_overwrite(&mut dictionary, key, member);
+
+        parser.skip_ows();
+        if parser.peek().is_some() {
+            parser.expect(b',')?;
+            parser.skip_ows();
+            if parser.peek().is_none() {
+                return Err("Trailing comma in the dictionary".into());
+            }
+        }
+    }
+
+    Ok(dictionary)
+}
+
+/// Parses the inner list, e.g. the covered components list: `("@method" "@authority");created=1618884473`.
+pub fn parse_inner_list(input: &str) -> Result<InnerList, String> {
+    let mut parser = Parser {
+        input: input.trim().as_bytes(),
+        position: 0,
+    };
+
+    let inner_list = parser.parse_inner_list()?;
+    if parser.peek().is_some() {
+        return Err(format!(
+            "Unexpected data after the inner list at position {}",
+            parser.position
+        ));
+    }
+
+    Ok(inner_list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string_item(value: &str) -> Item {
+        Item {
+            bare_item: BareItem::String(value.into()),
+            parameters: Parameters::new(),
+        }
+    }
+
+    #[test]
+    fn dictionary() {
+        let input = r#"sig1=("@method" "@authority");created=1618884473;keyid="test-key", sig2=:AQID:, flag;a=?0"#;
+        let dictionary = parse_dictionary(input).unwrap();
+
+        assert_eq!(dictionary.len(), 3);
+        assert_eq!(
+            dictionary[0],
+            (
+                "sig1".into(),
+                Member::InnerList(InnerList {
+                    items: vec![string_item("@method"), string_item("@authority")],
+                    parameters: vec![
+                        ("created".into(), BareItem::Integer(1618884473)),
+                        ("keyid".into(), BareItem::String("test-key".into())),
+                    ],
+                })
+            )
+        );
+        assert_eq!(
+            dictionary[1].1,
+            Member::Item(Item {
+                bare_item: BareItem::ByteSequence(vec![1, 2, 3]),
+                parameters: Parameters::new(),
+            })
+        );
+        assert_eq!(
+            dictionary[2].1,
+            Member::Item(Item {
+                bare_item: BareItem::Boolean(true),
+                parameters: vec![("a".into(), BareItem::Boolean(false))],
+            })
+        );
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        let dictionary = parse_dictionary("a=1, b=2, a=3").unwrap();
+
+        let values = dictionary
+            .iter()
+            .map(|(key, member)| match member {
+                Member::Item(item) => (key.as_str(), item.bare_item.clone()),
+                Member::InnerList(_) => panic!("unexpected inner list"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values, [("a", BareItem::Integer(3)), ("b", BareItem::Integer(2))]);
+    }
+
+    #[test]
+    fn inner_list_round_trip() {
+        let input = r#"("@method" "content-digest" "x\"y\\z");created=1618884473;alg=ed25519;tag=:AQID:;x"#;
+        let inner_list = parse_inner_list(input).unwrap();
+
+        assert_eq!(inner_list.items[2], string_item("x\"y\\z"));
+        assert_eq!(
+            find_parameter(&inner_list.parameters, "alg").and_then(BareItem::as_str),
+            Some("ed25519")
+        );
+        assert_eq!(
+            find_parameter(&inner_list.parameters, "x"),
+            Some(&BareItem::Boolean(true))
+        );
+        assert_eq!(inner_list.serialize(), input);
+    }
+
+    #[test]
+    fn truncated() {
+        assert!(parse_inner_list(r#"("@method""#).is_err());
+        assert!(parse_inner_list(r#"("@method"#).unwrap_err().contains("Unterminated"));
+        assert!(parse_inner_list(r#"("@method");created="#).is_err());
+        assert!(parse_dictionary("sig1=:AQID").is_err());
+        assert!(parse_dictionary("a=1,").unwrap_err().contains("Trailing comma"));
+        assert!(parse_dictionary("a=?").is_err());
+    }
+
+    #[test]
+    fn overflowing_integer() {
+        assert!(parse_dictionary("a=9223372036854775807").is_ok());
+        assert!(parse_dictionary("a=9223372036854775808").is_err());
+        assert!(parse_inner_list("();created=99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(parse_inner_list(r#"("@method")x"#).is_err());
+        assert!(parse_inner_list(r#"("a","b")"#).is_err());
+        assert!(parse_dictionary("A=1").is_err());
+        assert!(parse_dictionary("a=\"\x01\"").is_err());
+        assert!(parse_dictionary(r#"a="\n""#).is_err());
+    }
+}
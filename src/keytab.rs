@@ -0,0 +1,121 @@
+mod parser;
+
+use yew::{function_component, html, use_state, Callback, Html};
+use yew_hooks::use_clipboard;
+use yew_notifications::{use_notification, Notification, NotificationType};
+
+pub use self::parser::etype_name;
+use self::parser::{encode_keytab, parse_keytab, KeytabEntry};
+use crate::common::{ByteInput, BytesFormat, FileInput};
+use crate::timestamp::format_unix_time;
+use crate::utils::{copy_to_clipboard_with_notification, download_bytes};
+
+#[function_component(KeytabPage)]
+pub fn keytab_page() -> Html {
+    let raw_keytab = use_state(Vec::<u8>::new);
+    let entries = use_state(Vec::<KeytabEntry>::new);
+
+    let notifications = use_notification::<Notification>();
+    let clipboard = use_clipboard();
+
+    let raw_keytab_setter = raw_keytab.setter();
+    let entries_setter = entries.setter();
+    let notification_manager = notifications.clone();
+    let set_keytab = Callback::from(move |data: Vec<u8>| {
+        match parse_keytab(&data) {
+            Ok(keytab_entries) => entries_setter.set(keytab_entries),
+            Err(err) => {
+                // Entries of the previous keytab do not describe the new data.
+                entries_setter.set(Vec::new());
+                notification_manager.spawn(Notification::new(
+                    NotificationType::Error,
+                    "Invalid keytab",
+                    err,
+                    Notification::NOTIFICATION_LIFETIME,
+                ));
+            }
+        }
+        raw_keytab_setter.set(data);
+    });
+
+    let keytab_entries = (*entries).clone();
+    let notification_manager = notifications.clone();
+    let on_download = Callback::from(move |_| {
+        let result = encode_keytab(&keytab_entries)
+            .and_then(|keytab| download_bytes("krb5.keytab", "application/octet-stream", &keytab));
+        if let Err(err) = result {
+            notification_manager.spawn(Notification::new(
+                NotificationType::Error,
+                "Can not export keytab",
+                err,
+                Notification::NOTIFICATION_LIFETIME,
+            ));
+        }
+    });
+
+    html! {
+        <article class="vertical">
+            <div class="horizontal">
+                <span>{"Upload keytab file:"}</span>
+                <FileInput setter={set_keytab.clone()} />
+            </div>
+            <ByteInput
+                bytes={(*raw_keytab).clone()}
+                setter={set_keytab}
+                format={BytesFormat::Base64}
+                placeholder={"keytab".to_owned()}
+                rows={4}
+            />
+            {if entries.is_empty() {
+                html! {}
+            } else {
+                html! {
+                    <div class="vertical">
+                        <table class="parser-table">
+                            <tr>
+                                <th>{"Principal"}</th>
+                                <th>{"Name type"}</th>
+                                <th>{"KVNO"}</th>
+                                <th>{"Etype"}</th>
+                                <th>{"Timestamp"}</th>
+                                <th>{"Key"}</th>
+                                <th />
+                            </tr>
+                            {entries.iter().enumerate().map(|(index, entry)| {
+                                let entries_setter = entries.setter();
+                                let keytab_entries = (*entries).clone();
+                                let on_remove = Callback::from(move |_| {
+                                    let mut keytab_entries = keytab_entries.clone();
+                                    keytab_entries.remove(index);
+                                    entries_setter.set(keytab_entries);
+                                });
+                                let key = hex::encode(&entry.key);
+
+                                html! {
+                                    <tr>
+                                        <td>{entry.principal()}</td>
+                                        <td>{entry.name_type}</td>
+                                        <td>{entry.kvno}</td>
+                                        <td>{format!("{} ({})", etype_name(entry.key_type), entry.key_type)}</td>
+                                        <td>{format_unix_time(entry.timestamp)}</td>
+                                        <td
+                                            class="parser-table-copy"
+                                            onclick={copy_to_clipboard_with_notification(key.clone(), clipboard.clone(), "Key", notifications.clone())}
+                                        >
+                                            {key}
+                                        </td>
+                                        <td><button class="action-button" onclick={on_remove}>{"remove"}</button></td>
+                                    </tr>
+                                }
+                            }).collect::<Html>()}
+                        </table>
+                        <div class="horizontal">
+                            <button class="action-button" onclick={on_download}>{"Download keytab"}</button>
+                            <span class="total">{format!("entries: {}", entries.len())}</span>
+                        </div>
+                    </div>
+                }
+            }}
+        </article>
+    }
+}
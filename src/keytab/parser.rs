@@ -0,0 +1,237 @@
+use crate::utils::BytesReader;
+
+// https://web.mit.edu/kerberos/krb5-devel/doc/formats/keytab_file_format.html
+const KEYTAB_FIRST_BYTE: u8 = 0x05;
+const KEYTAB_VERSION: u8 = 0x02;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeytabEntry {
+    pub realm: String,
+    pub components: Vec<String>,
+    pub name_type: u32,
+    /// Time when the key was written to the keytab (Unix seconds).
+    pub timestamp: u32,
+    pub kvno: u32,
+    pub key_type: u16,
+    pub key: Vec<u8>,
+}
+
+impl KeytabEntry {
+    pub fn principal(&self) -> String {
+        format!("{}@{}", self.components.join("/"), self.realm)
+    }
+}
+
+fn read_counted_string(reader: &mut BytesReader<'_>) -> Result<String, String> {
+    let len = usize::from(reader.read_u16_be()?);
+
+    Ok(String::from_utf8_lossy(reader.read_bytes(len)?).into_owned())
+}
+
+fn read_entry(data: &[u8]) -> Result<KeytabEntry, String> {
+    let mut reader = BytesReader::new(data);
+
+    let components_count = reader.read_u16_be()?;
+    let realm = read_counted_string(&mut reader)?;
+    let components = (0..components_count)
+        .map(|_| read_counted_string(&mut reader))
+        .collect::<Result<Vec<_>, _>>()?;
+    let name_type = reader.read_u32_be()?;
+    let timestamp = reader.read_u32_be()?;
+    let kvno8 = reader.read_u8()?;
+    let key_type = reader.read_u16_be()?;
+    let key_len = usize::from(reader.read_u16_be()?);
+    let key = reader.read_bytes(key_len)?.to_vec();
+
+    // The 32-bit key version overrides the 8-bit one if it's present and non-zero.
+    let kvno = if reader.remaining() >= 4 {
+        match reader.read_u32_be()? {
+            0 => u32::from(kvno8),
+            kvno => kvno,
+        }
+    } else {
+        u32::from(kvno8)
+    };
+
+    Ok(KeytabEntry {
+        realm,
+        components,
+        name_type,
+        timestamp,
+        kvno,
+        key_type,
+        key,
+    })
+}
+
+pub fn parse_keytab(data: &[u8]) -> Result<Vec<KeytabEntry>, String> {
+    let mut reader = BytesReader::new(data);
+
+    let first_byte = reader.read_u8()?;
+    let version = reader.read_u8()?;
+    if first_byte != KEYTAB_FIRST_BYTE || version != KEYTAB_VERSION {
+        return Err(format!(
+            "Unsupported keytab version: 0x{:02x}{:02x}. Only 0x0502 is supported.",
+            first_byte, version
+        ));
+    }
+
+    let mut entries = Vec::new();
+    while !reader.is_empty() {
+        let size = reader.read_u32_be()? as i32;
+        let entry_data = reader.read_bytes(size.unsigned_abs() as usize)?;
+
+        // Negative size means the deleted entry (a hole in the file).
+        if size > 0 {
+            entries.push(read_entry(entry_data).map_err(|err| format!("Invalid keytab entry: {}", err))?);
+        }
+    }
+
+    Ok(entries)
+}
+
+fn write_counted_string(data: &str, buf: &mut Vec<u8>) -> Result<(), String> {
+    let len = u16::try_from(data.len()).map_err(|_| format!("String is too long: {}", data))?;
+
+    buf.extend_from_slice(&len.to_be_bytes());
+    buf.extend_from_slice(data.as_bytes());
+
+    Ok(())
+}
+
+fn encode_entry(entry: &KeytabEntry) -> Result<Vec<u8>, String> {
+    let mut buf = Vec::new();
+
+    let components_count = u16::try_from(entry.components.len()).map_err(|_| "Too many principal components")?;
+    buf.extend_from_slice(&components_count.to_be_bytes());
+    write_counted_string(&entry.realm, &mut buf)?;
+    for component in &entry.components {
+        write_counted_string(component, &mut buf)?;
+    }
+    buf.extend_from_slice(&entry.name_type.to_be_bytes());
+    buf.extend_from_slice(&entry.timestamp.to_be_bytes());
+    buf.push((entry.kvno & 0xff) as u8);
+    buf.extend_from_slice(&entry.key_type.to_be_bytes());
+    let key_len = u16::try_from(entry.key.len()).map_err(|_| "Key is too long")?;
+    buf.extend_from_slice(&key_len.to_be_bytes());
+    buf.extend_from_slice(&entry.key);
+    buf.extend_from_slice(&entry.kvno.to_be_bytes());
+
+    Ok(buf)
+}
+
+pub fn encode_keytab(entries: &[KeytabEntry]) -> Result<Vec<u8>, String> {
+    let mut keytab = vec![KEYTAB_FIRST_BYTE, KEYTAB_VERSION];
+
+    for entry in entries {
+        let entry = encode_entry(entry)?;
+        let size = i32::try_from(entry.len()).map_err(|_| "Keytab entry is too big")?;
+
+        keytab.extend_from_slice(&size.to_be_bytes());
+        keytab.extend_from_slice(&entry);
+    }
+
+    Ok(keytab)
+}
+
+/// Returns the Kerberos encryption type name.
+// https://www.iana.org/assignments/kerberos-parameters/kerberos-parameters.xhtml#kerberos-parameters-1
+pub fn etype_name(etype: u16) -> &'static str {
+    match etype {
+        1 => "des-cbc-crc",
+        2 => "des-cbc-md4",
+        3 => "des-cbc-md5",
+        16 => "des3-cbc-sha1-kd",
+        17 => "aes128-cts-hmac-sha1-96",
+        18 => "aes256-cts-hmac-sha1-96",
+        19 => "aes128-cts-hmac-sha256-128",
+        20 => "aes256-cts-hmac-sha384-192",
+        23 => "rc4-hmac",
+        24 => "rc4-hmac-exp",
+        25 => "camellia128-cts-cmac",
+        26 => "camellia256-cts-cmac",
+        _ => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEYTAB: &[u8] = &[
+        0x05, 0x02, 0, 0, 0, 25, // entry size
+        0, 1, // components count
+        0, 3, b'A', b'.', b'B', // realm
+        0, 1, b'u', // component
+        0, 0, 0, 1, // name type
+        0x5f, 0x5e, 0x10, 0x00, // timestamp
+        2,    // kvno
+        0, 17, // key type
+        0, 2, 0xaa, 0xbb, // key
+    ];
+
+    fn entry() -> KeytabEntry {
+        KeytabEntry {
+            realm: "A.B".into(),
+            components: vec!["u".into()],
+            name_type: 1,
+            timestamp: 0x5f5e_1000,
+            kvno: 2,
+            key_type: 17,
+            key: vec![0xaa, 0xbb],
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let entries = parse_keytab(KEYTAB).unwrap();
+
+        assert_eq!(entries, [entry()]);
+        assert_eq!(entries[0].principal(), "u@A.B");
+        assert_eq!(etype_name(entries[0].key_type), "aes128-cts-hmac-sha1-96");
+    }
+
+    #[test]
+    fn deleted_entry() {
+        let mut keytab = KEYTAB.to_vec();
+        keytab[2..6].copy_from_slice(&(-25_i32).to_be_bytes());
+
+        assert!(parse_keytab(&keytab).unwrap().is_empty());
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        let mut entry = entry();
+        entry.kvno = 0x1_0002;
+
+        let keytab = encode_keytab(&[entry.clone()]).unwrap();
+        assert_eq!(parse_keytab(&keytab).unwrap(), [entry]);
+    }
+
+    #[test]
+    fn truncated() {
+        assert!(parse_keytab(&KEYTAB[..1]).is_err());
+        assert!(parse_keytab(&KEYTAB[..4]).is_err());
+        assert!(parse_keytab(&KEYTAB[..KEYTAB.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn overflowing_size() {
+        for size in [i32::MAX, i32::MIN] {
+            let mut keytab = KEYTAB.to_vec();
+            keytab[2..6].copy_from_slice(&size.to_be_bytes());
+
+            assert!(parse_keytab(&keytab).is_err());
+        }
+
+        // the key length is bigger than the entry
+        let mut keytab = KEYTAB.to_vec();
+        keytab[27..29].copy_from_slice(&u16::MAX.to_be_bytes());
+        assert!(parse_keytab(&keytab).is_err());
+    }
+
+    #[test]
+    fn unsupported_version() {
+        assert!(parse_keytab(&[0x05, 0x01]).unwrap_err().contains("0x0501"));
+    }
+}
@@ -3,25 +3,47 @@ extern crate log;
 
 mod about;
 mod asn1;
+mod cbor;
+mod ccache;
 mod common;
 mod crypto_helper;
 pub mod diff;
+mod dpapi;
 mod footer;
 mod header;
+mod http_signature;
 mod jwt;
+mod keytab;
 mod not_found;
+mod ntlm;
+mod protobuf;
 pub mod serde;
+mod spnego;
+mod sshsig;
+mod timestamp;
+mod tls;
 mod url_query_params;
 mod utils;
 
 use about::About;
 use asn1::Asn1ParserPage;
+use cbor::CborPage;
+use ccache::CcachePage;
 use crypto_helper::CryptoHelper;
 use diff::DiffPage;
+use dpapi::DpapiPage;
 use footer::footer;
 use header::Header;
+use http_signature::HttpSignaturePage;
 use jwt::Jwt;
+use keytab::KeytabPage;
 use not_found::not_found;
+use ntlm::NtlmPage;
+use protobuf::ProtobufPage;
+use spnego::SpnegoPage;
+use sshsig::SshSigPage;
+use timestamp::TimestampConverter;
+use tls::TlsPage;
 use yew::{function_component, html, Html};
 use yew_agent::oneshot::OneshotProvider;
 use yew_notifications::{Notification, NotificationFactory, NotificationsProvider};
@@ -41,6 +63,28 @@ enum Route {
     Jwt,
     #[at("/diff")]
     Diff,
+    #[at("/timestamp")]
+    Timestamp,
+    #[at("/keytab")]
+    Keytab,
+    #[at("/ccache")]
+    Ccache,
+    #[at("/spnego")]
+    Spnego,
+    #[at("/ntlm")]
+    Ntlm,
+    #[at("/tls")]
+    Tls,
+    #[at("/protobuf")]
+    Protobuf,
+    #[at("/cbor")]
+    Cbor,
+    #[at("/sshsig")]
+    SshSig,
+    #[at("/dpapi")]
+    Dpapi,
+    #[at("/http-signature")]
+    HttpSignature,
     #[at("/about")]
     About,
     #[not_found]
@@ -60,6 +104,17 @@ fn switch(routes: Route) -> Html {
                 <DiffPage />
             </OneshotProvider<DiffTask, JsonCodec>>
         },
+        Route::Timestamp => html! { <TimestampConverter /> },
+        Route::Keytab => html! { <KeytabPage /> },
+        Route::Ccache => html! { <CcachePage /> },
+        Route::Spnego => html! { <SpnegoPage /> },
+        Route::Ntlm => html! { <NtlmPage /> },
+        Route::Tls => html! { <TlsPage /> },
+        Route::Protobuf => html! { <ProtobufPage /> },
+        Route::Cbor => html! { <CborPage /> },
+        Route::SshSig => html! { <SshSigPage /> },
+        Route::Dpapi => html! { <DpapiPage /> },
+        Route::HttpSignature => html! { <HttpSignaturePage /> },
         Route::About => html! { <About /> },
         Route::NotFound => not_found(),
     }
@@ -0,0 +1,313 @@
+mod parser;
+mod verification;
+
+use time::OffsetDateTime;
+use web_sys::HtmlInputElement;
+use yew::{function_component, html, use_state, Callback, Html, Properties, TargetCast};
+
+use self::parser::{
+    flag_names, parse_ntlm_message, AuthenticateMessage, AvPair, ChallengeMessage, NegotiateMessage, NtlmMessage,
+    NtlmV2Response, Version, NTLM_SIGNATURE,
+};
+use self::verification::{nt_proof_str, ntowf_v2};
+use crate::common::BytesViewer;
+use crate::spnego::{parse_spnego, MechToken, SpnegoToken};
+use crate::timestamp::format_date_time;
+use crate::utils::decode_base64;
+
+const HEADER_PREFIXES: [&str; 2] = ["NTLM ", "Negotiate "];
+
+/// Difference between the FILETIME epoch (1601-01-01) and the Unix epoch in 100-nanosecond intervals.
+const FILETIME_UNIX_EPOCH_DIFF: i128 = 116_444_736_000_000_000;
+
+fn extract_ntlm_from_spnego(data: &[u8]) -> Result<Vec<u8>, String> {
+    let mech_token = match parse_spnego(data)? {
+        SpnegoToken::Init(token) => token.mech_token,
+        SpnegoToken::Resp(token) => token.response_token,
+        SpnegoToken::Raw(mech_token) => Some(mech_token),
+    };
+
+    match mech_token {
+        Some(MechToken::Ntlm(message)) => Ok(message),
+        _ => Err("SPNEGO token does not contain NTLM message".into()),
+    }
+}
+
+fn parse_message(raw: &str) -> Result<NtlmMessage, String> {
+    let raw = raw.trim();
+    let raw = raw
+        .strip_prefix("Authorization:")
+        .or_else(|| raw.strip_prefix("WWW-Authenticate:"))
+        .unwrap_or(raw)
+        .trim();
+    let raw = HEADER_PREFIXES
+        .iter()
+        .find_map(|prefix| raw.strip_prefix(prefix))
+        .unwrap_or(raw)
+        .trim();
+
+    let data = decode_base64(raw.trim_end_matches('='))?;
+
+    if data.starts_with(NTLM_SIGNATURE) {
+        parse_ntlm_message(&data)
+    } else {
+        parse_ntlm_message(&extract_ntlm_from_spnego(&data)?)
+    }
+}
+
+fn format_filetime(filetime: u64) -> String {
+    OffsetDateTime::from_unix_timestamp_nanos((i128::from(filetime) - FILETIME_UNIX_EPOCH_DIFF) * 100)
+        .map(|date_time| format_date_time(&date_time))
+        .unwrap_or_else(|_| filetime.to_string())
+}
+
+fn render_flags(flags: u32) -> Html {
+    html! {
+        <div class="vertical">
+            <span>{format!("Negotiate flags: 0x{:08x}", flags)}</span>
+            <div class="parser-flags">
+                {flag_names(flags).into_iter().map(|name| html! { <span class="parser-flag">{name}</span> }).collect::<Html>()}
+            </div>
+        </div>
+    }
+}
+
+fn render_version(version: &Option<Version>) -> Html {
+    match version {
+        Some(version) => html! {
+            <span>{format!(
+                "Version: {}.{} (build {}), NTLM revision {}",
+                version.major, version.minor, version.build, version.ntlm_revision
+            )}</span>
+        },
+        None => html! {},
+    }
+}
+
+fn render_bytes(name: &'static str, bytes: &[u8]) -> Html {
+    if bytes.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <div class="vertical">
+            <span>{name}</span>
+            <BytesViewer bytes={bytes.to_vec()} />
+        </div>
+    }
+}
+
+fn render_av_pairs(av_pairs: &[AvPair]) -> Html {
+    if av_pairs.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <table class="parser-table">
+            <tr>
+                <th>{"AV pair"}</th>
+                <th>{"Id"}</th>
+                <th>{"Value"}</th>
+            </tr>
+            {av_pairs.iter().map(|av_pair| html! {
+                <tr>
+                    <td>{av_pair.name()}</td>
+                    <td>{av_pair.id}</td>
+                    <td>{av_pair.format_value()}</td>
+                </tr>
+            }).collect::<Html>()}
+        </table>
+    }
+}
+
+fn render_negotiate(message: &NegotiateMessage) -> Html {
+    html! {
+        <div class="vertical">
+            <span class="total">{"NEGOTIATE_MESSAGE (Type 1)"}</span>
+            {render_flags(message.flags)}
+            <span>{format!("Domain: {}", message.domain)}</span>
+            <span>{format!("Workstation: {}", message.workstation)}</span>
+            {render_version(&message.version)}
+        </div>
+    }
+}
+
+fn render_challenge(message: &ChallengeMessage) -> Html {
+    html! {
+        <div class="vertical">
+            <span class="total">{"CHALLENGE_MESSAGE (Type 2)"}</span>
+            {render_flags(message.flags)}
+            <span>{format!("Target name: {}", message.target_name)}</span>
+            <span>{format!("Server challenge: {}", hex::encode(&message.server_challenge))}</span>
+            {render_av_pairs(&message.target_info)}
+            {render_version(&message.version)}
+        </div>
+    }
+}
+
+fn render_ntlm_v2_response(response: &NtlmV2Response) -> Html {
+    html! {
+        <div class="vertical">
+            <span>{format!("NTProofStr: {}", hex::encode(&response.nt_proof_str))}</span>
+            <span>{format!("Timestamp: {}", format_filetime(response.timestamp))}</span>
+            <span>{format!("Client challenge: {}", hex::encode(&response.client_challenge))}</span>
+            {render_av_pairs(&response.av_pairs)}
+        </div>
+    }
+}
+
+#[derive(PartialEq)]
+enum VerificationResult {
+    Valid,
+    Invalid,
+    Error(String),
+}
+
+fn verify(
+    message: &AuthenticateMessage,
+    response: &NtlmV2Response,
+    password: &str,
+    server_challenge: &str,
+) -> VerificationResult {
+    let server_challenge = match hex::decode(server_challenge.trim()) {
+        Ok(server_challenge) if server_challenge.len() == 8 => server_challenge,
+        Ok(_) => return VerificationResult::Error("Server challenge must be 8 bytes long".into()),
+        Err(err) => return VerificationResult::Error(format!("Invalid server challenge: {:?}", err)),
+    };
+
+    let response_key_nt = ntowf_v2(password, &message.user, &message.domain);
+    if nt_proof_str(&response_key_nt, &server_challenge, &response.blob)[..] == response.nt_proof_str[..] {
+        VerificationResult::Valid
+    } else {
+        VerificationResult::Invalid
+    }
+}
+
+#[derive(PartialEq, Properties)]
+struct AuthenticateProps {
+    message: AuthenticateMessage,
+    server_challenge: Option<Vec<u8>>,
+}
+
+#[function_component(Authenticate)]
+fn authenticate(props: &AuthenticateProps) -> Html {
+    let message = &props.message;
+    let ntlm_v2_response = NtlmV2Response::parse(&message.nt_response);
+
+    let password = use_state(String::new);
+    let server_challenge = use_state(|| props.server_challenge.as_ref().map(hex::encode).unwrap_or_default());
+
+    let password_setter = password.setter();
+    let on_password_input = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        password_setter.set(input.value());
+    });
+
+    let server_challenge_setter = server_challenge.setter();
+    let on_server_challenge_input = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        server_challenge_setter.set(input.value());
+    });
+
+    html! {
+        <div class="vertical">
+            <span class="total">{"AUTHENTICATE_MESSAGE (Type 3)"}</span>
+            {render_flags(message.flags)}
+            <span>{format!("Domain: {}", message.domain)}</span>
+            <span>{format!("User: {}", message.user)}</span>
+            <span>{format!("Workstation: {}", message.workstation)}</span>
+            {render_version(&message.version)}
+            {if let Some(mic) = &message.mic {
+                html! { <span>{format!("MIC: {}", hex::encode(mic))}</span> }
+            } else {
+                html! {}
+            }}
+            {render_bytes("LM response", &message.lm_response)}
+            {render_bytes("NT response", &message.nt_response)}
+            {render_bytes("Encrypted random session key", &message.encrypted_session_key)}
+            {if let Some(response) = &ntlm_v2_response {
+                html! {
+                    <div class="vertical">
+                        <span class="total">{"NTLMv2 response"}</span>
+                        {render_ntlm_v2_response(response)}
+                        <span class="total">{"NTLMv2 response verification"}</span>
+                        <div class="horizontal">
+                            <input
+                                class="base-input"
+                                type="password"
+                                placeholder="password"
+                                value={(*password).clone()}
+                                oninput={on_password_input}
+                            />
+                            <input
+                                class="base-input"
+                                placeholder="server challenge (hex)"
+                                value={(*server_challenge).clone()}
+                                oninput={on_server_challenge_input}
+                            />
+                        </div>
+                        {if password.is_empty() || server_challenge.is_empty() {
+                            html! {}
+                        } else {
+                            match verify(message, response, &password, &server_challenge) {
+                                VerificationResult::Valid => html! { <span class="parser-valid">{"NTProofStr is valid"}</span> },
+                                VerificationResult::Invalid => html! { <span class="parser-invalid">{"NTProofStr is invalid"}</span> },
+                                VerificationResult::Error(err) => html! { <span class="parser-invalid">{err}</span> },
+                            }
+                        }}
+                    </div>
+                }
+            } else {
+                html! { <span>{"NTLMv1 response"}</span> }
+            }}
+        </div>
+    }
+}
+
+#[function_component(NtlmPage)]
+pub fn ntlm_page() -> Html {
+    let raw_message = use_state(String::new);
+    let message = use_state(|| None::<Result<NtlmMessage, String>>);
+    // The server challenge from the last parsed CHALLENGE_MESSAGE. It's used for the NTLMv2 response verification.
+    let server_challenge = use_state(|| None::<Vec<u8>>);
+
+    let raw_message_setter = raw_message.setter();
+    let message_setter = message.setter();
+    let server_challenge_setter = server_challenge.setter();
+    let oninput = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        let value = input.value();
+
+        if value.trim().is_empty() {
+            message_setter.set(None);
+        } else {
+            let parsed = parse_message(&value);
+            if let Ok(NtlmMessage::Challenge(challenge)) = &parsed {
+                server_challenge_setter.set(Some(challenge.server_challenge.clone()));
+            }
+            message_setter.set(Some(parsed));
+        }
+        raw_message_setter.set(value);
+    });
+
+    html! {
+        <article class="vertical">
+            <textarea
+                rows="5"
+                placeholder={"base64 encoded NTLM message or the whole 'NTLM ...' header value"}
+                class="base-input"
+                value={(*raw_message).clone()}
+                {oninput}
+            />
+            {match &*message {
+                Some(Ok(NtlmMessage::Negotiate(message))) => render_negotiate(message),
+                Some(Ok(NtlmMessage::Challenge(message))) => render_challenge(message),
+                Some(Ok(NtlmMessage::Authenticate(message))) => html! {
+                    <Authenticate message={message.clone()} server_challenge={(*server_challenge).clone()} />
+                },
+                Some(Err(err)) => html! { <span class="total">{format!("Invalid message: {}", err)}</span> },
+                None => html! {},
+            }}
+        </article>
+    }
+}
@@ -0,0 +1,512 @@
+use crate::utils::BytesReader;
+
+// https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-nlmp/b38c36ed-2804-4868-a9ff-8dd3182128e4
+pub const NTLM_SIGNATURE: &[u8] = b"NTLMSSP\0";
+
+const NEGOTIATE_MESSAGE: u32 = 1;
+const CHALLENGE_MESSAGE: u32 = 2;
+const AUTHENTICATE_MESSAGE: u32 = 3;
+
+pub const NTLMSSP_NEGOTIATE_UNICODE: u32 = 0x0000_0001;
+
+const VERSION_LEN: usize = 8;
+const MIC_LEN: usize = 16;
+const CHALLENGE_HEADER_LEN: usize = 48;
+const AUTHENTICATE_HEADER_LEN: usize = 64;
+
+pub const NEGOTIATE_FLAGS: [(u32, &str); 22] = [
+    (0x0000_0001, "NEGOTIATE_UNICODE"),
+    (0x0000_0002, "NEGOTIATE_OEM"),
+    (0x0000_0004, "REQUEST_TARGET"),
+    (0x0000_0010, "NEGOTIATE_SIGN"),
+    (0x0000_0020, "NEGOTIATE_SEAL"),
+    (0x0000_0040, "NEGOTIATE_DATAGRAM"),
+    (0x0000_0080, "NEGOTIATE_LM_KEY"),
+    (0x0000_0200, "NEGOTIATE_NTLM"),
+    (0x0000_0800, "ANONYMOUS"),
+    (0x0000_1000, "NEGOTIATE_OEM_DOMAIN_SUPPLIED"),
+    (0x0000_2000, "NEGOTIATE_OEM_WORKSTATION_SUPPLIED"),
+    (0x0000_8000, "NEGOTIATE_ALWAYS_SIGN"),
+    (0x0001_0000, "TARGET_TYPE_DOMAIN"),
+    (0x0002_0000, "TARGET_TYPE_SERVER"),
+    (0x0008_0000, "NEGOTIATE_EXTENDED_SESSIONSECURITY"),
+    (0x0010_0000, "NEGOTIATE_IDENTIFY"),
+    (0x0040_0000, "REQUEST_NON_NT_SESSION_KEY"),
+    (0x0080_0000, "NEGOTIATE_TARGET_INFO"),
+    (0x0200_0000, "NEGOTIATE_VERSION"),
+    (0x2000_0000, "NEGOTIATE_128"),
+    (0x4000_0000, "NEGOTIATE_KEY_EXCH"),
+    (0x8000_0000, "NEGOTIATE_56"),
+];
+
+pub fn flag_names(flags: u32) -> Vec<&'static str> {
+    NEGOTIATE_FLAGS
+        .iter()
+        .filter(|(flag, _)| flags & flag != 0)
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    pub major: u8,
+    pub minor: u8,
+    pub build: u16,
+    pub ntlm_revision: u8,
+}
+
+impl Version {
+    fn parse(data: &[u8]) -> Result<Self, String> {
+        let mut reader = BytesReader::new(data);
+
+        let major = reader.read_u8()?;
+        let minor = reader.read_u8()?;
+        let build = reader.read_u16_le()?;
+        reader.read_bytes(3)?;
+        let ntlm_revision = reader.read_u8()?;
+
+        Ok(Self {
+            major,
+            minor,
+            build,
+            ntlm_revision,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvPair {
+    pub id: u16,
+    pub value: Vec<u8>,
+}
+
+impl AvPair {
+    pub fn name(&self) -> &'static str {
+        match self.id {
+            0 => "MsvAvEOL",
+            1 => "MsvAvNbComputerName",
+            2 => "MsvAvNbDomainName",
+            3 => "MsvAvDnsComputerName",
+            4 => "MsvAvDnsDomainName",
+            5 => "MsvAvDnsTreeName",
+            6 => "MsvAvFlags",
+            7 => "MsvAvTimestamp",
+            8 => "MsvAvSingleHost",
+            9 => "MsvAvTargetName",
+            10 => "MsvAvChannelBindings",
+            _ => "unknown",
+        }
+    }
+
+    /// Returns the human-readable AV pair value.
+    pub fn format_value(&self) -> String {
+        match self.id {
+            1..=5 | 9 => decode_utf16(&self.value),
+            6 if self.value.len() == 4 => {
+                format!("0x{:08x}", u32::from_le_bytes(self.value[..].try_into().unwrap()))
+            }
+            7 if self.value.len() == 8 => {
+                format!("{} (FILETIME)", u64::from_le_bytes(self.value[..].try_into().unwrap()))
+            }
+            _ => hex::encode(&self.value),
+        }
+    }
+}
+
+fn parse_av_pairs(data: &[u8]) -> Result<Vec<AvPair>, String> {
+    let mut reader = BytesReader::new(data);
+    let mut av_pairs = Vec::new();
+
+    while !reader.is_empty() {
+        let id = reader.read_u16_le()?;
+        let len = usize::from(reader.read_u16_le()?);
+        let value = reader.read_bytes(len)?.to_vec();
+
+        let is_eol = id == 0;
+        av_pairs.push(AvPair { id, value });
+
+        if is_eol {
+            break;
+        }
+    }
+
+    Ok(av_pairs)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NtlmV2Response {
+    pub nt_proof_str: Vec<u8>,
+    /// The NTLMv2_CLIENT_CHALLENGE structure. It's used for the NTProofStr calculation.
+    pub blob: Vec<u8>,
+    pub timestamp: u64,
+    pub client_challenge: Vec<u8>,
+    pub av_pairs: Vec<AvPair>,
+}
+
+impl NtlmV2Response {
+    /// Parses the NTLMv2 response. Returns `None` if it's NTLMv1 response.
+    pub fn parse(nt_response: &[u8]) -> Option<Self> {
+        // NTLMv1 response is always 24 bytes long
+        if nt_response.len() <= 24 {
+            return None;
+        }
+
+        let parse = || -> Result<Self, String> {
+            let mut reader = BytesReader::new(nt_response);
+
+            let nt_proof_str = reader.read_bytes(16)?.to_vec();
+            let blob = reader.read_remaining();
+
+            let mut reader = BytesReader::new(blob);
+            // RespType, HiRespType, Reserved1, Reserved2
+            reader.read_bytes(8)?;
+            let timestamp = reader.read_u64_le()?;
+            let client_challenge = reader.read_bytes(8)?.to_vec();
+            // Reserved3
+            reader.read_bytes(4)?;
+            let av_pairs = parse_av_pairs(reader.read_remaining())?;
+
+            Ok(Self {
+                nt_proof_str,
+                blob: blob.to_vec(),
+                timestamp,
+                client_challenge,
+                av_pairs,
+            })
+        };
+
+        parse().ok()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegotiateMessage {
+    pub flags: u32,
+    pub domain: String,
+    pub workstation: String,
+    pub version: Option<Version>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChallengeMessage {
+    pub target_name: String,
+    pub flags: u32,
+    pub server_challenge: Vec<u8>,
+    pub target_info: Vec<AvPair>,
+    pub version: Option<Version>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticateMessage {
+    pub lm_response: Vec<u8>,
+    pub nt_response: Vec<u8>,
+    pub domain: String,
+    pub user: String,
+    pub workstation: String,
+    pub encrypted_session_key: Vec<u8>,
+    pub flags: u32,
+    pub version: Option<Version>,
+    pub mic: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NtlmMessage {
+    Negotiate(NegotiateMessage),
+    Challenge(ChallengeMessage),
+    Authenticate(AuthenticateMessage),
+}
+
+pub fn decode_utf16(data: &[u8]) -> String {
+    let chars = data
+        .chunks(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair.get(1).copied().unwrap_or_default()]))
+        .collect::<Vec<_>>();
+
+    String::from_utf16_lossy(&chars)
+}
+
+fn decode_string(data: &[u8], flags: u32) -> String {
+    if flags & NTLMSSP_NEGOTIATE_UNICODE != 0 {
+        decode_utf16(data)
+    } else {
+        data.iter().map(|byte| char::from(*byte)).collect()
+    }
+}
+
+/// Describes the payload field location: length, max length, and offset from the message beginning.
+struct Field {
+    len: usize,
+    offset: usize,
+}
+
+impl Field {
+    fn read(reader: &mut BytesReader<'_>) -> Result<Self, String> {
+        let len = usize::from(reader.read_u16_le()?);
+        let _max_len = reader.read_u16_le()?;
+        let offset = reader.read_u32_le()? as usize;
+
+        Ok(Self { len, offset })
+    }
+
+    fn data<'data>(&self, message: &'data [u8]) -> Result<&'data [u8], String> {
+        self.offset
+            .checked_add(self.len)
+            .and_then(|end| message.get(self.offset..end))
+            .ok_or_else(|| {
+                format!(
+                    "Field (offset: {}, len: {}) is outside the message",
+                    self.offset, self.len
+                )
+            })
+    }
+}
+
+/// Returns the offset of the message payload.
+fn payload_offset(fields: &[&Field], message: &[u8]) -> usize {
+    fields
+        .iter()
+        .filter(|field| field.len > 0)
+        .map(|field| field.offset)
+        .min()
+        .unwrap_or(message.len())
+}
+
+fn parse_negotiate(message: &[u8], reader: &mut BytesReader<'_>) -> Result<NegotiateMessage, String> {
+    let flags = reader.read_u32_le()?;
+    let domain = Field::read(reader)?;
+    let workstation = Field::read(reader)?;
+    let version = if reader.remaining() >= VERSION_LEN {
+        Some(Version::parse(reader.read_bytes(VERSION_LEN)?)?)
+    } else {
+        None
+    };
+
+    Ok(NegotiateMessage {
+        flags,
+        // domain and workstation are always OEM encoded in the NEGOTIATE_MESSAGE
+        domain: decode_string(domain.data(message)?, 0),
+        workstation: decode_string(workstation.data(message)?, 0),
+        version,
+    })
+}
+
+fn parse_challenge(message: &[u8], reader: &mut BytesReader<'_>) -> Result<ChallengeMessage, String> {
+    let target_name = Field::read(reader)?;
+    let flags = reader.read_u32_le()?;
+    let server_challenge = reader.read_bytes(8)?.to_vec();
+    // Reserved
+    reader.read_bytes(8)?;
+    let target_info = Field::read(reader)?;
+    let version = if payload_offset(&[&target_name, &target_info], message) >= CHALLENGE_HEADER_LEN + VERSION_LEN {
+        Some(Version::parse(reader.read_bytes(VERSION_LEN)?)?)
+    } else {
+        None
+    };
+
+    Ok(ChallengeMessage {
+        target_name: decode_string(target_name.data(message)?, flags),
+        flags,
+        server_challenge,
+        target_info: parse_av_pairs(target_info.data(message)?)?,
+        version,
+    })
+}
+
+fn parse_authenticate(message: &[u8], reader: &mut BytesReader<'_>) -> Result<AuthenticateMessage, String> {
+    let lm_response = Field::read(reader)?;
+    let nt_response = Field::read(reader)?;
+    let domain = Field::read(reader)?;
+    let user = Field::read(reader)?;
+    let workstation = Field::read(reader)?;
+    let encrypted_session_key = Field::read(reader)?;
+    let flags = reader.read_u32_le()?;
+
+    // The Version and MIC fields are present only if the payload starts after them.
+    let payload_offset = payload_offset(
+        &[
+            &lm_response,
+            &nt_response,
+            &domain,
+            &user,
+            &workstation,
+            &encrypted_session_key,
+        ],
+        message,
+    );
+
+    let version = if payload_offset >= AUTHENTICATE_HEADER_LEN + VERSION_LEN {
+        Some(Version::parse(reader.read_bytes(VERSION_LEN)?)?)
+    } else {
+        None
+    };
+    let mic = if payload_offset >= AUTHENTICATE_HEADER_LEN + VERSION_LEN + MIC_LEN {
+        Some(reader.read_bytes(MIC_LEN)?.to_vec())
+    } else {
+        None
+    };
+
+    Ok(AuthenticateMessage {
+        lm_response: lm_response.data(message)?.to_vec(),
+        nt_response: nt_response.data(message)?.to_vec(),
+        domain: decode_string(domain.data(message)?, flags),
+        user: decode_string(user.data(message)?, flags),
+        workstation: decode_string(workstation.data(message)?, flags),
+        encrypted_session_key: encrypted_session_key.data(message)?.to_vec(),
+        flags,
+        version,
+        mic,
+    })
+}
+
+pub fn parse_ntlm_message(message: &[u8]) -> Result<NtlmMessage, String> {
+    let mut reader = BytesReader::new(message);
+
+    if reader.read_bytes(NTLM_SIGNATURE.len())? != NTLM_SIGNATURE {
+        return Err("Invalid NTLMSSP signature".into());
+    }
+
+    match reader.read_u32_le()? {
+        NEGOTIATE_MESSAGE => Ok(NtlmMessage::Negotiate(parse_negotiate(message, &mut reader)?)),
+        CHALLENGE_MESSAGE => Ok(NtlmMessage::Challenge(parse_challenge(message, &mut reader)?)),
+        AUTHENTICATE_MESSAGE => Ok(NtlmMessage::Authenticate(parse_authenticate(message, &mut reader)?)),
+        message_type => Err(format!("Unsupported NTLM message type: {}", message_type)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERSION: [u8; 8] = [10, 0, 0x61, 0x4a, 0, 0, 0, 15];
+
+    fn challenge_message(target_info_offset: u32) -> Vec<u8> {
+        [
+            NTLM_SIGNATURE,
+            &CHALLENGE_MESSAGE.to_le_bytes(),
+            // TargetNameFields
+            &[8, 0, 8, 0, 56, 0, 0, 0],
+            &0x0080_0001_u32.to_le_bytes(),
+            &[1, 2, 3, 4, 5, 6, 7, 8],
+            &[0; 8],
+            // TargetInfoFields
+            &[16, 0, 16, 0],
+            &target_info_offset.to_le_bytes(),
+            &VERSION,
+            b"T\0E\0S\0T\0",
+            // MsvAvNbDomainName
+            &[2, 0, 8, 0],
+            b"T\0E\0S\0T\0",
+            // MsvAvEOL
+            &[0, 0, 0, 0],
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn negotiate() {
+        let message = [
+            NTLM_SIGNATURE,
+            &NEGOTIATE_MESSAGE.to_le_bytes(),
+            &0x0200_0201_u32.to_le_bytes(),
+            &[0; 8],
+            &[0; 8],
+            &VERSION,
+        ]
+        .concat();
+
+        assert_eq!(
+            parse_ntlm_message(&message).unwrap(),
+            NtlmMessage::Negotiate(NegotiateMessage {
+                flags: 0x0200_0201,
+                domain: String::new(),
+                workstation: String::new(),
+                version: Some(Version {
+                    major: 10,
+                    minor: 0,
+                    build: 19041,
+                    ntlm_revision: 15,
+                }),
+            })
+        );
+        assert_eq!(
+            flag_names(0x0200_0201),
+            ["NEGOTIATE_UNICODE", "NEGOTIATE_NTLM", "NEGOTIATE_VERSION"]
+        );
+    }
+
+    #[test]
+    fn challenge() {
+        let NtlmMessage::Challenge(challenge) = parse_ntlm_message(&challenge_message(64)).unwrap() else {
+            panic!("expected the CHALLENGE_MESSAGE");
+        };
+
+        assert_eq!(challenge.target_name, "TEST");
+        assert_eq!(challenge.server_challenge, [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(challenge.version.unwrap().build, 19041);
+        assert_eq!(
+            challenge.target_info,
+            [
+                AvPair {
+                    id: 2,
+                    value: b"T\0E\0S\0T\0".to_vec(),
+                },
+                AvPair {
+                    id: 0,
+                    value: Vec::new()
+                },
+            ]
+        );
+        assert_eq!(challenge.target_info[0].format_value(), "TEST");
+    }
+
+    #[test]
+    fn truncated() {
+        let message = challenge_message(64);
+
+        assert!(parse_ntlm_message(&message[..30]).is_err());
+        assert!(parse_ntlm_message(&message[..70]).is_err());
+        assert!(parse_ntlm_message(b"NTLMSS").is_err());
+    }
+
+    #[test]
+    fn field_outside_message() {
+        assert!(parse_ntlm_message(&challenge_message(u32::MAX))
+            .unwrap_err()
+            .contains("outside the message"));
+        assert!(parse_ntlm_message(&challenge_message(80)).is_err());
+    }
+
+    #[test]
+    fn ntlm_v2_response() {
+        let nt_response = [
+            &[0xaa; 16][..],
+            &[1, 1, 0, 0, 0, 0, 0, 0],
+            &0x01d9_0000_0000_0000_u64.to_le_bytes(),
+            &[0xbb; 8],
+            &[0; 4],
+            &[0, 0, 0, 0],
+        ]
+        .concat();
+
+        let response = NtlmV2Response::parse(&nt_response).unwrap();
+        assert_eq!(response.nt_proof_str, [0xaa; 16]);
+        assert_eq!(response.timestamp, 0x01d9_0000_0000_0000);
+        assert_eq!(response.client_challenge, [0xbb; 8]);
+        assert_eq!(
+            response.av_pairs,
+            [AvPair {
+                id: 0,
+                value: Vec::new()
+            }]
+        );
+
+        // NTLMv1 response
+        assert!(NtlmV2Response::parse(&[0; 24]).is_none());
+        // truncated client challenge
+        assert!(NtlmV2Response::parse(&nt_response[..36]).is_none());
+        // AV pair length is bigger than the remaining data
+        let mut overflowing = nt_response.clone();
+        overflowing.splice(44.., [1, 0, 0xff, 0xff]);
+        assert!(NtlmV2Response::parse(&overflowing).is_none());
+    }
+}
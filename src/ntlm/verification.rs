@@ -0,0 +1,42 @@
+use md4::{Digest, Md4};
+
+const HMAC_BLOCK_SIZE: usize = 64;
+
+fn hmac_md5(key: &[u8], data: &[u8]) -> [u8; 16] {
+    let mut block_key = [0; HMAC_BLOCK_SIZE];
+    if key.len() > HMAC_BLOCK_SIZE {
+        block_key[..16].copy_from_slice(&md5::compute(key).0);
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = md5::Context::new();
+    inner.consume(block_key.map(|byte| byte ^ 0x36));
+    inner.consume(data);
+
+    let mut outer = md5::Context::new();
+    outer.consume(block_key.map(|byte| byte ^ 0x5c));
+    outer.consume(inner.compute().0);
+
+    outer.compute().0
+}
+
+fn utf16_le(data: &str) -> Vec<u8> {
+    data.encode_utf16().flat_map(|c| c.to_le_bytes()).collect()
+}
+
+/// Computes the NTOWFv2 key.
+// https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-nlmp/5e550938-91d4-459f-b67d-75d70009e3f3
+pub fn ntowf_v2(password: &str, user: &str, domain: &str) -> [u8; 16] {
+    let nt_hash = Md4::digest(utf16_le(password));
+
+    hmac_md5(&nt_hash, &utf16_le(&format!("{}{}", user.to_uppercase(), domain)))
+}
+
+/// Computes the NTProofStr using the server challenge and the NTLMv2_CLIENT_CHALLENGE structure (blob).
+pub fn nt_proof_str(response_key_nt: &[u8], server_challenge: &[u8], blob: &[u8]) -> [u8; 16] {
+    let mut data = server_challenge.to_vec();
+    data.extend_from_slice(blob);
+
+    hmac_md5(response_key_nt, &data)
+}
@@ -0,0 +1,95 @@
+mod decoder;
+
+use yew::{function_component, html, use_state, Callback, Html};
+
+use self::decoder::{decode_protobuf, zigzag_decode, Field, FieldValue};
+use crate::common::{ByteInput, BytesFormat};
+
+fn render_field_header(field: &Field, description: String) -> Html {
+    html! {
+        <div class="asn1-constructor-header">
+            <span class="protobuf-field-number">{field.number}</span>
+            <span class="asn1-node-info-label">{format!("{} @{}", field.value.wire_type_name(), field.offset)}</span>
+            <span>{description}</span>
+        </div>
+    }
+}
+
+fn render_fields(fields: &[Field]) -> Html {
+    fields.iter().map(render_field).collect::<Html>()
+}
+
+fn render_field(field: &Field) -> Html {
+    match &field.value {
+        FieldValue::Varint(value) => render_field_header(
+            field,
+            format!("{} (signed: {}, sint: {})", value, *value as i64, zigzag_decode(*value)),
+        ),
+        FieldValue::I64(value) => render_field_header(
+            field,
+            format!(
+                "{} (signed: {}, double: {})",
+                value,
+                *value as i64,
+                f64::from_bits(*value)
+            ),
+        ),
+        FieldValue::I32(value) => render_field_header(
+            field,
+            format!(
+                "{} (signed: {}, float: {})",
+                value,
+                *value as i32,
+                f32::from_bits(*value)
+            ),
+        ),
+        FieldValue::String(value) => render_field_header(field, format!("{:?}", value)),
+        FieldValue::Bytes(value) => render_field_header(field, format!("0x{}", hex::encode(value))),
+        FieldValue::Message(fields) | FieldValue::Group(fields) => html! {
+            <div style="width: 100%">
+                {render_field_header(field, format!("({} fields)", fields.len()))}
+                <div class="asn1-constructor-body">
+                    {render_fields(fields)}
+                </div>
+            </div>
+        },
+    }
+}
+
+#[function_component(ProtobufPage)]
+pub fn protobuf_page() -> Html {
+    let raw_data = use_state(Vec::<u8>::new);
+    let fields = use_state(|| None::<Result<Vec<Field>, String>>);
+
+    let raw_data_setter = raw_data.setter();
+    let fields_setter = fields.setter();
+    let set_data = Callback::from(move |data: Vec<u8>| {
+        fields_setter.set(if data.is_empty() {
+            None
+        } else {
+            Some(decode_protobuf(&data))
+        });
+        raw_data_setter.set(data);
+    });
+
+    html! {
+        <article class="vertical">
+            <ByteInput
+                bytes={(*raw_data).clone()}
+                setter={set_data}
+                format={BytesFormat::Base64}
+                placeholder={"protobuf message".to_owned()}
+                rows={4}
+            />
+            {match &*fields {
+                Some(Ok(fields)) => html! {
+                    <div class="parser-tree">
+                        {render_fields(fields)}
+                    </div>
+                },
+                Some(Err(err)) => html! { <span class="total">{format!("Invalid protobuf message: {}", err)}</span> },
+                None => html! {},
+            }}
+        </article>
+    }
+}
@@ -0,0 +1,242 @@
+use crate::utils::BytesReader;
+
+// https://protobuf.dev/programming-guides/encoding/
+const WIRE_TYPE_VARINT: u8 = 0;
+const WIRE_TYPE_I64: u8 = 1;
+const WIRE_TYPE_LEN: u8 = 2;
+const WIRE_TYPE_START_GROUP: u8 = 3;
+const WIRE_TYPE_END_GROUP: u8 = 4;
+const WIRE_TYPE_I32: u8 = 5;
+
+const MAX_VARINT_LEN: usize = 10;
+/// Max message nesting level. Deeper length-delimited values are not interpreted as messages.
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Varint(u64),
+    I64(u64),
+    I32(u32),
+    /// Length-delimited value that looks like a nested message.
+    Message(Vec<Field>),
+    /// Length-delimited value that is a valid printable UTF-8 string.
+    String(String),
+    Bytes(Vec<u8>),
+    Group(Vec<Field>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub number: u64,
+    /// Field position in the decoded buffer.
+    pub offset: usize,
+    pub value: FieldValue,
+}
+
+impl FieldValue {
+    pub fn wire_type_name(&self) -> &'static str {
+        match self {
+            FieldValue::Varint(_) => "varint",
+            FieldValue::I64(_) => "i64",
+            FieldValue::I32(_) => "i32",
+            FieldValue::Message(_) | FieldValue::String(_) | FieldValue::Bytes(_) => "len",
+            FieldValue::Group(_) => "group",
+        }
+    }
+}
+
+/// Decodes the ZigZag-encoded signed integer (`sint32` and `sint64` types).
+pub fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn read_varint(reader: &mut BytesReader<'_>) -> Result<u64, String> {
+    let mut value = 0_u64;
+
+    for index in 0..MAX_VARINT_LEN {
+        let byte = reader.read_u8()?;
+        value |= u64::from(byte & 0x7f) << (7 * index);
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    Err("Varint is too long".into())
+}
+
+fn is_printable(data: &str) -> bool {
+    data.chars()
+        .all(|c| !c.is_control() || c == '\n' || c == '\r' || c == '\t')
+}
+
+fn decode_length_delimited(data: &[u8], offset: usize, depth: usize) -> FieldValue {
+    if !data.is_empty() {
+        if let Ok(string) = std::str::from_utf8(data) {
+            if is_printable(string) {
+                return FieldValue::String(string.to_owned());
+            }
+        }
+
+        if depth < MAX_DEPTH {
+            if let Ok((fields, _)) = decode_fields(data, offset, depth + 1, None) {
+                return FieldValue::Message(fields);
+            }
+        }
+    }
+
+    FieldValue::Bytes(data.to_vec())
+}
+
+/// Decodes fields until the end of data or until the end group tag with the `group_number`.
+/// Returns decoded fields and the number of consumed bytes.
+fn decode_fields(
+    data: &[u8],
+    base_offset: usize,
+    depth: usize,
+    group_number: Option<u64>,
+) -> Result<(Vec<Field>, usize), String> {
+    let mut reader = BytesReader::new(data);
+    let mut fields = Vec::new();
+
+    while !reader.is_empty() {
+        let position = data.len() - reader.remaining();
+        let offset = base_offset + position;
+        let tag = read_varint(&mut reader)?;
+        let number = tag >> 3;
+        let wire_type = (tag & 0x07) as u8;
+
+        if number == 0 {
+            return Err(format!("Invalid field number 0 at offset {}", offset));
+        }
+
+        let value = match wire_type {
+            WIRE_TYPE_VARINT => FieldValue::Varint(read_varint(&mut reader)?),
+            WIRE_TYPE_I64 => FieldValue::I64(u64::from_le_bytes(reader.read_array()?)),
+            WIRE_TYPE_I32 => FieldValue::I32(u32::from_le_bytes(reader.read_array()?)),
+            WIRE_TYPE_LEN => {
+                let len = usize::try_from(read_varint(&mut reader)?).map_err(|_| "Length is too big")?;
+                let value_offset = base_offset + data.len() - reader.remaining();
+
+                decode_length_delimited(reader.read_bytes(len)?, value_offset, depth)
+            }
+            WIRE_TYPE_START_GROUP => {
+                if depth >= MAX_DEPTH {
+                    return Err("Groups nesting is too deep".into());
+                }
+
+                let group_position = data.len() - reader.remaining();
+                let (group_fields, group_len) = decode_fields(
+                    &data[group_position..],
+                    base_offset + group_position,
+                    depth + 1,
+                    Some(number),
+                )?;
+                reader.read_bytes(group_len)?;
+
+                FieldValue::Group(group_fields)
+            }
+            WIRE_TYPE_END_GROUP => {
+                return match group_number {
+                    Some(group_number) if group_number == number => Ok((fields, data.len() - reader.remaining())),
+                    _ => Err(format!("Unexpected end group tag at offset {}", offset)),
+                };
+            }
+            wire_type => return Err(format!("Invalid wire type {} at offset {}", wire_type, offset)),
+        };
+
+        fields.push(Field { number, offset, value });
+    }
+
+    match group_number {
+        Some(number) => Err(format!("Group {} is not closed", number)),
+        None => Ok((fields, data.len())),
+    }
+}
+
+pub fn decode_protobuf(data: &[u8]) -> Result<Vec<Field>, String> {
+    decode_fields(data, 0, 0, None).map(|(fields, _)| fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1: 150, 2: "testing", 3: { 1: 150 }, 4: fixed32 1, 5: group { 1: 1 }
+    const MESSAGE: &[u8] = &[
+        0x08, 0x96, 0x01, 0x12, 0x07, b't', b'e', b's', b't', b'i', b'n', b'g', 0x1a, 0x03, 0x08, 0x96, 0x01, 0x25,
+        0x01, 0x00, 0x00, 0x00, 0x2b, 0x08, 0x01, 0x2c,
+    ];
+
+    #[test]
+    fn decode() {
+        assert_eq!(
+            decode_protobuf(MESSAGE).unwrap(),
+            [
+                Field {
+                    number: 1,
+                    offset: 0,
+                    value: FieldValue::Varint(150),
+                },
+                Field {
+                    number: 2,
+                    offset: 3,
+                    value: FieldValue::String("testing".into()),
+                },
+                Field {
+                    number: 3,
+                    offset: 12,
+                    value: FieldValue::Message(vec![Field {
+                        number: 1,
+                        offset: 14,
+                        value: FieldValue::Varint(150),
+                    }]),
+                },
+                Field {
+                    number: 4,
+                    offset: 17,
+                    value: FieldValue::I32(1),
+                },
+                Field {
+                    number: 5,
+                    offset: 22,
+                    value: FieldValue::Group(vec![Field {
+                        number: 1,
+                        offset: 23,
+                        value: FieldValue::Varint(1),
+                    }]),
+                },
+            ]
+        );
+        assert_eq!(zigzag_decode(3), -2);
+        assert_eq!(zigzag_decode(u64::MAX), i64::MIN);
+    }
+
+    #[test]
+    fn truncated() {
+        assert!(decode_protobuf(&MESSAGE[..2]).is_err());
+        assert!(decode_protobuf(&MESSAGE[..8]).is_err());
+        assert!(decode_protobuf(&MESSAGE[..20])
+            .unwrap_err()
+            .starts_with("Unexpected end of data"));
+        assert_eq!(
+            decode_protobuf(&MESSAGE[..MESSAGE.len() - 1]).unwrap_err(),
+            "Group 5 is not closed"
+        );
+    }
+
+    #[test]
+    fn overflowing_length() {
+        let too_long_len = [0x12, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, 0x00];
+        assert!(decode_protobuf(&too_long_len).is_err());
+
+        let too_long_varint = [0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+        assert_eq!(decode_protobuf(&too_long_varint).unwrap_err(), "Varint is too long");
+    }
+
+    #[test]
+    fn deep_groups() {
+        let data = [0x0b; MAX_DEPTH + 1];
+        assert_eq!(decode_protobuf(&data).unwrap_err(), "Groups nesting is too deep");
+    }
+}
@@ -0,0 +1,159 @@
+mod parser;
+
+use web_sys::HtmlInputElement;
+use yew::{function_component, html, use_state, Callback, Html, TargetCast};
+
+use self::parser::{kerberos_token_name, mech_name, neg_state_name, NegTokenInit, NegTokenResp};
+pub use self::parser::{parse_spnego, MechToken, SpnegoToken};
+use crate::common::BytesViewer;
+use crate::url_query_params::generate_asn1_link;
+use crate::utils::decode_base64;
+
+const NEGOTIATE_PREFIX: &str = "Negotiate ";
+
+fn parse_token(raw: &str) -> Result<SpnegoToken, String> {
+    let raw = raw.trim();
+    let raw = raw
+        .strip_prefix("Authorization:")
+        .or_else(|| raw.strip_prefix("WWW-Authenticate:"))
+        .unwrap_or(raw)
+        .trim();
+    let raw = raw.strip_prefix(NEGOTIATE_PREFIX).unwrap_or(raw).trim();
+
+    parse_spnego(&decode_base64(raw.trim_end_matches('='))?)
+}
+
+fn mech_with_name(oid: &str) -> String {
+    format!("{} ({})", oid, mech_name(oid))
+}
+
+fn render_bytes_field(name: &'static str, bytes: &Option<Vec<u8>>) -> Html {
+    match bytes {
+        Some(bytes) => html! {
+            <div class="vertical">
+                <span>{name}</span>
+                <BytesViewer bytes={bytes.clone()} />
+            </div>
+        },
+        None => html! {},
+    }
+}
+
+fn render_mech_token(name: &'static str, mech_token: &Option<MechToken>) -> Html {
+    let mech_token = match mech_token {
+        Some(mech_token) => mech_token,
+        None => return html! {},
+    };
+
+    let (description, data, asn1_link) = match mech_token {
+        MechToken::Kerberos { token_id, message } => (
+            format!(
+                "Kerberos {} (token id: 0x{:04x})",
+                kerberos_token_name(*token_id),
+                token_id
+            ),
+            message,
+            true,
+        ),
+        MechToken::Ntlm(data) => ("NTLM message".to_owned(), data, false),
+        MechToken::Unknown(data) => ("Unknown token".to_owned(), data, true),
+    };
+
+    html! {
+        <div class="vertical">
+            <div class="horizontal">
+                <span>{format!("{}: {}", name, description)}</span>
+                {if asn1_link {
+                    html! {
+                        <a class="a-link" href={generate_asn1_link(data.clone())} target="_blank">{"Open in ASN.1 viewer"}</a>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+            <BytesViewer bytes={data.clone()} />
+        </div>
+    }
+}
+
+fn render_neg_token_init(token: &NegTokenInit) -> Html {
+    html! {
+        <div class="vertical">
+            <span class="total">{"NegTokenInit"}</span>
+            <table class="parser-table">
+                <tr>
+                    <th>{"#"}</th>
+                    <th>{"Mech type"}</th>
+                </tr>
+                {token.mech_types.iter().enumerate().map(|(index, mech)| html! {
+                    <tr>
+                        <td>{index}</td>
+                        <td>{mech_with_name(mech)}</td>
+                    </tr>
+                }).collect::<Html>()}
+            </table>
+            {render_bytes_field("reqFlags", &token.req_flags)}
+            {render_mech_token("mechToken", &token.mech_token)}
+            {render_bytes_field("mechListMIC", &token.mech_list_mic)}
+        </div>
+    }
+}
+
+fn render_neg_token_resp(token: &NegTokenResp) -> Html {
+    html! {
+        <div class="vertical">
+            <span class="total">{"NegTokenResp"}</span>
+            {if let Some(neg_state) = token.neg_state {
+                html! { <span>{format!("negState: {} ({})", neg_state_name(neg_state), neg_state)}</span> }
+            } else {
+                html! {}
+            }}
+            {if let Some(supported_mech) = &token.supported_mech {
+                html! { <span>{format!("supportedMech: {}", mech_with_name(supported_mech))}</span> }
+            } else {
+                html! {}
+            }}
+            {render_mech_token("responseToken", &token.response_token)}
+            {render_bytes_field("mechListMIC", &token.mech_list_mic)}
+        </div>
+    }
+}
+
+#[function_component(SpnegoPage)]
+pub fn spnego_page() -> Html {
+    let raw_token = use_state(String::new);
+    let token = use_state(|| None::<Result<SpnegoToken, String>>);
+
+    let raw_token_setter = raw_token.setter();
+    let token_setter = token.setter();
+    let oninput = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        let value = input.value();
+
+        token_setter.set(if value.trim().is_empty() {
+            None
+        } else {
+            Some(parse_token(&value))
+        });
+        raw_token_setter.set(value);
+    });
+
+    html! {
+        <article class="vertical">
+            <textarea
+                rows="5"
+                placeholder={"base64 encoded SPNEGO token or the whole 'Negotiate ...' header value"}
+                class="base-input"
+                value={(*raw_token).clone()}
+                {oninput}
+            />
+            {match &*token {
+                Some(Ok(SpnegoToken::Init(token))) => render_neg_token_init(token),
+                Some(Ok(SpnegoToken::Resp(token))) => render_neg_token_resp(token),
+                Some(Ok(SpnegoToken::Raw(mech_token))) => render_mech_token("Raw GSS-API token", &Some(mech_token.clone())),
+                Some(Err(err)) => html! { <span class="total">{format!("Invalid token: {}", err)}</span> },
+                None => html! {},
+            }}
+        </article>
+    }
+}
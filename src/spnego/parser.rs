@@ -0,0 +1,351 @@
+use crate::utils::BytesReader;
+
+// https://www.rfc-editor.org/rfc/rfc4178#section-4.2
+pub const SPNEGO_OID: &str = "1.3.6.1.5.5.2";
+pub const KERBEROS_OID: &str = "1.2.840.113554.1.2.2";
+pub const MS_KERBEROS_OID: &str = "1.2.840.48018.1.2.2";
+pub const NTLM_OID: &str = "1.3.6.1.4.1.311.2.2.10";
+
+const NTLM_SIGNATURE: &[u8] = b"NTLMSSP\0";
+
+const APPLICATION_0: u8 = 0x60;
+const SEQUENCE: u8 = 0x30;
+const OID: u8 = 0x06;
+const OCTET_STRING: u8 = 0x04;
+const BIT_STRING: u8 = 0x03;
+const ENUMERATED: u8 = 0x0a;
+const CONTEXT_0: u8 = 0xa0;
+const CONTEXT_1: u8 = 0xa1;
+const CONTEXT_2: u8 = 0xa2;
+const CONTEXT_3: u8 = 0xa3;
+const CONTEXT_4: u8 = 0xa4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MechToken {
+    /// Kerberos message with the GSS-API framing (RFC 4121, Section 4.1).
+    Kerberos {
+        token_id: u16,
+        message: Vec<u8>,
+    },
+    Ntlm(Vec<u8>),
+    Unknown(Vec<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegTokenInit {
+    pub mech_types: Vec<String>,
+    pub req_flags: Option<Vec<u8>>,
+    pub mech_token: Option<MechToken>,
+    pub mech_list_mic: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegTokenResp {
+    pub neg_state: Option<u8>,
+    pub supported_mech: Option<String>,
+    pub response_token: Option<MechToken>,
+    pub mech_list_mic: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpnegoToken {
+    Init(NegTokenInit),
+    Resp(NegTokenResp),
+    /// Some clients send the raw Kerberos token instead of the SPNEGO one.
+    Raw(MechToken),
+}
+
+pub fn mech_name(oid: &str) -> &'static str {
+    match oid {
+        SPNEGO_OID => "SPNEGO",
+        KERBEROS_OID => "Kerberos 5",
+        MS_KERBEROS_OID => "MS Kerberos 5",
+        NTLM_OID => "NTLMSSP",
+        "1.2.840.113554.1.2.2.3" => "Kerberos 5 user-to-user",
+        "1.3.6.1.5.2.5" => "IAKERB",
+        "1.3.6.1.4.1.311.2.2.30" => "NEGOEX",
+        _ => "unknown",
+    }
+}
+
+pub fn neg_state_name(neg_state: u8) -> &'static str {
+    match neg_state {
+        0 => "accept-completed",
+        1 => "accept-incomplete",
+        2 => "reject",
+        3 => "request-mic",
+        _ => "unknown",
+    }
+}
+
+pub fn kerberos_token_name(token_id: u16) -> &'static str {
+    match token_id {
+        0x0100 => "AP-REQ",
+        0x0200 => "AP-REP",
+        0x0300 => "KRB-ERROR",
+        0x0400 => "TGT-REQ",
+        _ => "unknown",
+    }
+}
+
+fn read_len(reader: &mut BytesReader<'_>) -> Result<usize, String> {
+    let first = reader.read_u8()?;
+    if first & 0x80 == 0 {
+        return Ok(usize::from(first));
+    }
+
+    let len_bytes = usize::from(first & 0x7f);
+    if len_bytes == 0 || len_bytes > 4 {
+        return Err(format!("Unsupported length encoding: 0x{:02x}", first));
+    }
+
+    Ok(reader
+        .read_bytes(len_bytes)?
+        .iter()
+        .fold(0, |len, byte| len << 8 | usize::from(*byte)))
+}
+
+fn read_tlv<'data>(reader: &mut BytesReader<'data>) -> Result<(u8, &'data [u8]), String> {
+    let tag = reader.read_u8()?;
+    let len = read_len(reader)?;
+
+    Ok((tag, reader.read_bytes(len)?))
+}
+
+fn expect_tlv<'data>(reader: &mut BytesReader<'data>, expected_tag: u8) -> Result<&'data [u8], String> {
+    let (tag, value) = read_tlv(reader)?;
+    if tag != expected_tag {
+        return Err(format!("Expected tag 0x{:02x} but got 0x{:02x}", expected_tag, tag));
+    }
+
+    Ok(value)
+}
+
+/// Reads the value of the explicitly tagged field.
+fn read_explicit(data: &[u8], inner_tag: u8) -> Result<&[u8], String> {
+    expect_tlv(&mut BytesReader::new(data), inner_tag)
+}
+
+fn parse_oid(data: &[u8]) -> Result<String, String> {
+    let oid = oid::ObjectIdentifier::try_from(data).map_err(|err| format!("Invalid OID: {:?}", err))?;
+
+    Ok((&oid).into())
+}
+
+pub fn parse_mech_token(data: &[u8]) -> MechToken {
+    if data.starts_with(NTLM_SIGNATURE) {
+        return MechToken::Ntlm(data.to_vec());
+    }
+
+    let parse_kerberos = || -> Result<MechToken, String> {
+        let inner = expect_tlv(&mut BytesReader::new(data), APPLICATION_0)?;
+        let mut reader = BytesReader::new(inner);
+
+        let oid = parse_oid(expect_tlv(&mut reader, OID)?)?;
+        if oid != KERBEROS_OID && oid != MS_KERBEROS_OID {
+            return Err(format!("Unexpected mech OID: {}", oid));
+        }
+
+        let token_id = reader.read_u16_be()?;
+        let message = reader.read_remaining().to_vec();
+
+        Ok(MechToken::Kerberos { token_id, message })
+    };
+
+    parse_kerberos().unwrap_or_else(|_| MechToken::Unknown(data.to_vec()))
+}
+
+fn parse_neg_token_init(data: &[u8]) -> Result<NegTokenInit, String> {
+    let mut reader = BytesReader::new(expect_tlv(&mut BytesReader::new(data), SEQUENCE)?);
+
+    let mut token = NegTokenInit {
+        mech_types: Vec::new(),
+        req_flags: None,
+        mech_token: None,
+        mech_list_mic: None,
+    };
+
+    while !reader.is_empty() {
+        let (tag, value) = read_tlv(&mut reader)?;
+        match tag {
+            CONTEXT_0 => {
+                let mut mech_types = BytesReader::new(read_explicit(value, SEQUENCE)?);
+                while !mech_types.is_empty() {
+                    token.mech_types.push(parse_oid(expect_tlv(&mut mech_types, OID)?)?);
+                }
+            }
+            CONTEXT_1 => token.req_flags = Some(read_explicit(value, BIT_STRING)?.to_vec()),
+            CONTEXT_2 => token.mech_token = Some(parse_mech_token(read_explicit(value, OCTET_STRING)?)),
+            // [3] is the mechListMIC in NegTokenInit and negHints in the Microsoft's NegTokenInit2
+            CONTEXT_3 => {
+                if let Ok(mic) = read_explicit(value, OCTET_STRING) {
+                    token.mech_list_mic = Some(mic.to_vec());
+                }
+            }
+            CONTEXT_4 => token.mech_list_mic = Some(read_explicit(value, OCTET_STRING)?.to_vec()),
+            _ => return Err(format!("Unexpected NegTokenInit field: 0x{:02x}", tag)),
+        }
+    }
+
+    Ok(token)
+}
+
+fn parse_neg_token_resp(data: &[u8]) -> Result<NegTokenResp, String> {
+    let mut reader = BytesReader::new(expect_tlv(&mut BytesReader::new(data), SEQUENCE)?);
+
+    let mut token = NegTokenResp {
+        neg_state: None,
+        supported_mech: None,
+        response_token: None,
+        mech_list_mic: None,
+    };
+
+    while !reader.is_empty() {
+        let (tag, value) = read_tlv(&mut reader)?;
+        match tag {
+            CONTEXT_0 => token.neg_state = read_explicit(value, ENUMERATED)?.last().copied(),
+            CONTEXT_1 => token.supported_mech = Some(parse_oid(read_explicit(value, OID)?)?),
+            CONTEXT_2 => token.response_token = Some(parse_mech_token(read_explicit(value, OCTET_STRING)?)),
+            CONTEXT_3 => token.mech_list_mic = Some(read_explicit(value, OCTET_STRING)?.to_vec()),
+            _ => return Err(format!("Unexpected NegTokenResp field: 0x{:02x}", tag)),
+        }
+    }
+
+    Ok(token)
+}
+
+fn parse_negotiation_token(data: &[u8]) -> Result<SpnegoToken, String> {
+    match read_tlv(&mut BytesReader::new(data))? {
+        (CONTEXT_0, value) => Ok(SpnegoToken::Init(parse_neg_token_init(value)?)),
+        (CONTEXT_1, value) => Ok(SpnegoToken::Resp(parse_neg_token_resp(value)?)),
+        (tag, _) => Err(format!("Unexpected NegotiationToken tag: 0x{:02x}", tag)),
+    }
+}
+
+/// Parses the SPNEGO token: GSS-API InitialContextToken with the NegTokenInit or the bare NegTokenResp.
+pub fn parse_spnego(data: &[u8]) -> Result<SpnegoToken, String> {
+    match data.first() {
+        Some(&APPLICATION_0) => {
+            let inner = expect_tlv(&mut BytesReader::new(data), APPLICATION_0)?;
+            let mut reader = BytesReader::new(inner);
+
+            let oid = parse_oid(expect_tlv(&mut reader, OID)?)?;
+            if oid == KERBEROS_OID || oid == MS_KERBEROS_OID {
+                return Ok(SpnegoToken::Raw(parse_mech_token(data)));
+            }
+            if oid != SPNEGO_OID {
+                return Err(format!("Unsupported mech: {} ({})", oid, mech_name(&oid)));
+            }
+
+            parse_negotiation_token(reader.read_remaining())
+        }
+        Some(_) => parse_negotiation_token(data),
+        None => Err("Token is empty".into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPNEGO_OID_TLV: &[u8] = &[OID, 6, 0x2b, 0x06, 0x01, 0x05, 0x05, 0x02];
+    const KERBEROS_OID_TLV: &[u8] = &[OID, 9, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x12, 0x01, 0x02, 0x02];
+    const NTLM_OID_TLV: &[u8] = &[OID, 10, 0x2b, 0x06, 0x01, 0x04, 0x01, 0x82, 0x37, 0x02, 0x02, 0x0a];
+    const NTLM_NEGOTIATE: &[u8] = b"NTLMSSP\0\x01\0\0\0";
+
+    /// Encodes the TLV with the short length form.
+    fn tlv(tag: u8, value: &[u8]) -> Vec<u8> {
+        [&[tag, u8::try_from(value.len()).unwrap()][..], value].concat()
+    }
+
+    #[test]
+    fn neg_token_init() {
+        let mech_types = tlv(SEQUENCE, &[KERBEROS_OID_TLV, NTLM_OID_TLV].concat());
+        let neg_token_init = tlv(
+            SEQUENCE,
+            &[
+                tlv(CONTEXT_0, &mech_types),
+                tlv(CONTEXT_2, &tlv(OCTET_STRING, NTLM_NEGOTIATE)),
+            ]
+            .concat(),
+        );
+        let token = tlv(
+            APPLICATION_0,
+            &[SPNEGO_OID_TLV, &tlv(CONTEXT_0, &neg_token_init)].concat(),
+        );
+
+        assert_eq!(
+            parse_spnego(&token).unwrap(),
+            SpnegoToken::Init(NegTokenInit {
+                mech_types: vec![KERBEROS_OID.into(), NTLM_OID.into()],
+                req_flags: None,
+                mech_token: Some(MechToken::Ntlm(NTLM_NEGOTIATE.to_vec())),
+                mech_list_mic: None,
+            })
+        );
+    }
+
+    #[test]
+    fn neg_token_resp() {
+        let neg_token_resp = tlv(
+            SEQUENCE,
+            &[
+                tlv(CONTEXT_0, &tlv(ENUMERATED, &[1])),
+                tlv(CONTEXT_1, NTLM_OID_TLV),
+                tlv(CONTEXT_3, &tlv(OCTET_STRING, &[0xaa; 16])),
+            ]
+            .concat(),
+        );
+        let token = tlv(CONTEXT_1, &neg_token_resp);
+
+        assert_eq!(
+            parse_spnego(&token).unwrap(),
+            SpnegoToken::Resp(NegTokenResp {
+                neg_state: Some(1),
+                supported_mech: Some(NTLM_OID.into()),
+                response_token: None,
+                mech_list_mic: Some(vec![0xaa; 16]),
+            })
+        );
+        assert_eq!(neg_state_name(1), "accept-incomplete");
+    }
+
+    #[test]
+    fn raw_kerberos_token() {
+        let token = tlv(
+            APPLICATION_0,
+            &[KERBEROS_OID_TLV, &[0x01, 0x00], &[0x6e, 0x00]].concat(),
+        );
+
+        assert_eq!(
+            parse_spnego(&token).unwrap(),
+            SpnegoToken::Raw(MechToken::Kerberos {
+                token_id: 0x0100,
+                message: vec![0x6e, 0x00],
+            })
+        );
+        assert_eq!(parse_mech_token(&[0x01, 0x02]), MechToken::Unknown(vec![0x01, 0x02]));
+    }
+
+    #[test]
+    fn truncated() {
+        let token = tlv(CONTEXT_1, &tlv(SEQUENCE, &tlv(CONTEXT_1, NTLM_OID_TLV)));
+
+        assert!(parse_spnego(&[]).is_err());
+        for len in 1..token.len() {
+            assert!(parse_spnego(&token[..len]).is_err());
+        }
+    }
+
+    #[test]
+    fn overflowing_length() {
+        assert!(parse_spnego(&[CONTEXT_1, 0x84, 0xff, 0xff, 0xff, 0xff]).is_err());
+        assert!(
+            parse_spnego(&[CONTEXT_1, 0x88, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff])
+                .unwrap_err()
+                .contains("Unsupported length encoding")
+        );
+        // the inner length is bigger than the outer one
+        assert!(parse_spnego(&[CONTEXT_1, 0x04, SEQUENCE, 0x82, 0xff, 0xff]).is_err());
+    }
+}
@@ -0,0 +1,163 @@
+mod parser;
+mod verification;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use web_sys::HtmlInputElement;
+use yew::{function_component, html, use_state, Callback, Html, TargetCast};
+
+use self::parser::{decode_armored, parse_host_keys, parse_signature, HostKey, SshPublicKey, SshSignature};
+use self::verification::verify_signature;
+use crate::common::BytesViewer;
+
+fn render_public_key(public_key: &SshPublicKey) -> Html {
+    html! {
+        <div class="vertical">
+            <span>{format!(
+                "Public key: {}{}",
+                public_key.key_type,
+                public_key.bits().map(|bits| format!(" ({} bits)", bits)).unwrap_or_default()
+            )}</span>
+            <span>{public_key.sha256_fingerprint()}</span>
+            <span>{public_key.md5_fingerprint()}</span>
+            <span>{format!("{} {}", public_key.key_type, STANDARD.encode(&public_key.blob))}</span>
+        </div>
+    }
+}
+
+fn render_verification(signature: &SshSignature, message: &str) -> Html {
+    match verify_signature(signature, message.as_bytes()) {
+        Ok(true) => html! { <span class="parser-valid">{"Signature is valid"}</span> },
+        Ok(false) => html! { <span class="parser-invalid">{"Signature is invalid"}</span> },
+        Err(err) => html! { <span class="parser-invalid">{err}</span> },
+    }
+}
+
+fn render_signature(signature: &SshSignature, message: &str) -> Html {
+    html! {
+        <div class="vertical">
+            <span class="total">{format!("SSH signature (version {})", signature.version)}</span>
+            <span>{format!("Namespace: {}", signature.namespace)}</span>
+            <span>{format!("Hash algorithm: {}", signature.hash_algorithm)}</span>
+            {render_public_key(&signature.public_key)}
+            <span>{format!("Signature algorithm: {}", signature.signature_type)}</span>
+            <BytesViewer bytes={signature.signature.clone()} />
+            {if message.is_empty() {
+                html! {}
+            } else {
+                render_verification(signature, message)
+            }}
+        </div>
+    }
+}
+
+fn render_host_keys(host_keys: &[Result<HostKey, String>]) -> Html {
+    if host_keys.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <table class="parser-table">
+            <tr>
+                <th>{"Hosts / options"}</th>
+                <th>{"Key type"}</th>
+                <th>{"Bits"}</th>
+                <th>{"Fingerprint"}</th>
+                <th>{"Comment"}</th>
+            </tr>
+            {host_keys.iter().map(|host_key| match host_key {
+                Ok(host_key) => html! {
+                    <tr>
+                        <td>
+                            {host_key.marker.clone().map(|marker| format!("{} ", marker)).unwrap_or_default()}
+                            {if host_key.is_hashed_host() {
+                                "(hashed)".to_owned()
+                            } else {
+                                host_key.hosts.clone().unwrap_or_default()
+                            }}
+                        </td>
+                        <td>{&host_key.public_key.key_type}</td>
+                        <td>{host_key.public_key.bits().map(|bits| bits.to_string()).unwrap_or_default()}</td>
+                        <td>
+                            <div class="vertical">
+                                <span>{host_key.public_key.sha256_fingerprint()}</span>
+                                <span>{host_key.public_key.md5_fingerprint()}</span>
+                            </div>
+                        </td>
+                        <td>{&host_key.comment}</td>
+                    </tr>
+                },
+                Err(err) => html! {
+                    <tr>
+                        <td colspan="5" class="parser-invalid">{err}</td>
+                    </tr>
+                },
+            }).collect::<Html>()}
+        </table>
+    }
+}
+
+#[function_component(SshSigPage)]
+pub fn sshsig_page() -> Html {
+    let raw_signature = use_state(String::new);
+    let message = use_state(String::new);
+    let raw_host_keys = use_state(String::new);
+
+    let raw_signature_setter = raw_signature.setter();
+    let on_signature_input = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        raw_signature_setter.set(input.value());
+    });
+
+    let message_setter = message.setter();
+    let on_message_input = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        message_setter.set(input.value());
+    });
+
+    let raw_host_keys_setter = raw_host_keys.setter();
+    let on_host_keys_input = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        raw_host_keys_setter.set(input.value());
+    });
+
+    let signature = if raw_signature.trim().is_empty() {
+        None
+    } else {
+        Some(decode_armored(&raw_signature).and_then(|data| parse_signature(&data)))
+    };
+
+    html! {
+        <article class="vertical">
+            <span class="total">{"SSH signature"}</span>
+            <textarea
+                rows="6"
+                placeholder={"-----BEGIN SSH SIGNATURE-----"}
+                class="base-input"
+                value={(*raw_signature).clone()}
+                oninput={on_signature_input}
+            />
+            <textarea
+                rows="3"
+                placeholder={"signed message"}
+                class="base-input"
+                value={(*message).clone()}
+                oninput={on_message_input}
+            />
+            {match &signature {
+                Some(Ok(signature)) => render_signature(signature, &message),
+                Some(Err(err)) => html! { <span class="total">{format!("Invalid signature: {}", err)}</span> },
+                None => html! {},
+            }}
+            <span class="total">{"Host keys fingerprints"}</span>
+            <textarea
+                rows="4"
+                placeholder={"known_hosts, authorized_keys, or .pub file lines"}
+                class="base-input"
+                value={(*raw_host_keys).clone()}
+                oninput={on_host_keys_input}
+            />
+            {render_host_keys(&parse_host_keys(&raw_host_keys))}
+        </article>
+    }
+}
@@ -0,0 +1,382 @@
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
+use base64::Engine;
+
+use crate::utils::BytesReader;
+
+// https://github.com/openssh/openssh-portable/blob/master/PROTOCOL.sshsig
+const SSHSIG_MAGIC: &[u8] = b"SSHSIG";
+const SSHSIG_VERSION: u32 = 1;
+
+const ARMOR_BEGIN: &str = "-----BEGIN SSH SIGNATURE-----";
+const ARMOR_END: &str = "-----END SSH SIGNATURE-----";
+
+const HASHED_HOST_PREFIX: &str = "|1|";
+const KNOWN_HOSTS_MARKERS: [&str; 2] = ["@cert-authority", "@revoked"];
+const KEY_TYPE_PREFIXES: [&str; 3] = ["ssh-", "ecdsa-", "sk-"];
+
+fn read_string<'data>(reader: &mut BytesReader<'data>) -> Result<&'data [u8], String> {
+    let len = reader.read_u32_be()? as usize;
+
+    reader.read_bytes(len)
+}
+
+fn read_utf8_string(reader: &mut BytesReader<'_>) -> Result<String, String> {
+    String::from_utf8(read_string(reader)?.to_vec()).map_err(|err| format!("Invalid UTF-8 string: {:?}", err))
+}
+
+/// Reads the `mpint` and returns its magnitude without the leading zero byte.
+fn read_mpint<'data>(reader: &mut BytesReader<'data>) -> Result<&'data [u8], String> {
+    let value = read_string(reader)?;
+    if value.first().map_or(false, |byte| byte & 0x80 != 0) {
+        return Err("Negative mpint values are not allowed".into());
+    }
+
+    Ok(value.strip_prefix(&[0]).unwrap_or(value))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublicKeyData {
+    Ed25519(Vec<u8>),
+    Rsa { exponent: Vec<u8>, modulus: Vec<u8> },
+    Ecdsa { curve: String, point: Vec<u8> },
+    Unsupported,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SshPublicKey {
+    pub key_type: String,
+    pub data: PublicKeyData,
+    /// Wire-encoded public key. Fingerprints are calculated over it.
+    pub blob: Vec<u8>,
+}
+
+impl SshPublicKey {
+    pub fn parse(blob: &[u8]) -> Result<Self, String> {
+        let mut reader = BytesReader::new(blob);
+        let key_type = read_utf8_string(&mut reader)?;
+
+        let data = match key_type.as_str() {
+            "ssh-ed25519" => PublicKeyData::Ed25519(read_string(&mut reader)?.to_vec()),
+            "ssh-rsa" => PublicKeyData::Rsa {
+                exponent: read_mpint(&mut reader)?.to_vec(),
+                modulus: read_mpint(&mut reader)?.to_vec(),
+            },
+            "ecdsa-sha2-nistp256" | "ecdsa-sha2-nistp384" | "ecdsa-sha2-nistp521" => PublicKeyData::Ecdsa {
+                curve: read_utf8_string(&mut reader)?,
+                point: read_string(&mut reader)?.to_vec(),
+            },
+            _ => PublicKeyData::Unsupported,
+        };
+
+        Ok(Self {
+            key_type,
+            data,
+            blob: blob.to_vec(),
+        })
+    }
+
+    /// Returns the key size in bits.
+    pub fn bits(&self) -> Option<usize> {
+        match &self.data {
+            PublicKeyData::Ed25519(_) => Some(256),
+            PublicKeyData::Rsa { modulus, .. } => {
+                let leading_zeros = modulus.first().map_or(0, |byte| byte.leading_zeros() as usize);
+                Some(modulus.len() * 8 - leading_zeros)
+            }
+            PublicKeyData::Ecdsa { curve, .. } => match curve.as_str() {
+                "nistp256" => Some(256),
+                "nistp384" => Some(384),
+                "nistp521" => Some(521),
+                _ => None,
+            },
+            PublicKeyData::Unsupported => None,
+        }
+    }
+
+    /// OpenSSH SHA256 fingerprint, e.g. `SHA256:nThbg6kXUpJWGl7E1IGOCspRomTxdCARLviKw6E5SY8`.
+    pub fn sha256_fingerprint(&self) -> String {
+        format!("SHA256:{}", STANDARD_NO_PAD.encode(hmac_sha256::Hash::hash(&self.blob)))
+    }
+
+    /// Legacy OpenSSH MD5 fingerprint, e.g. `MD5:16:27:ac:a5:76:28:2d:36:63:1b:56:4d:eb:df:a6:48`.
+    pub fn md5_fingerprint(&self) -> String {
+        let digest = md5::compute(&self.blob).0;
+
+        format!(
+            "MD5:{}",
+            digest
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<Vec<_>>()
+                .join(":")
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SshSignature {
+    pub version: u32,
+    pub public_key: SshPublicKey,
+    pub namespace: String,
+    pub reserved: Vec<u8>,
+    pub hash_algorithm: String,
+    pub signature_type: String,
+    /// Signature bytes without the algorithm name.
+    pub signature: Vec<u8>,
+}
+
+impl SshSignature {
+    /// Builds the data that is actually signed: the SSHSIG header with the message hash.
+    pub fn signed_data(&self, message: &[u8]) -> Result<Vec<u8>, String> {
+        let message_hash = match self.hash_algorithm.as_str() {
+            "sha256" => hmac_sha256::Hash::hash(message).to_vec(),
+            "sha512" => hmac_sha512::Hash::hash(message).to_vec(),
+            hash_algorithm => return Err(format!("Unsupported hash algorithm: {}", hash_algorithm)),
+        };
+
+        let mut data = SSHSIG_MAGIC.to_vec();
+        for field in [
+            self.namespace.as_bytes(),
+            &self.reserved,
+            self.hash_algorithm.as_bytes(),
+            &message_hash,
+        ] {
+            data.extend_from_slice(&(field.len() as u32).to_be_bytes());
+            data.extend_from_slice(field);
+        }
+
+        Ok(data)
+    }
+
+    /// Splits the ECDSA signature into `r` and `s` values.
+    pub fn ecdsa_components(&self) -> Result<(Vec<u8>, Vec<u8>), String> {
+        let mut reader = BytesReader::new(&self.signature);
+
+        Ok((read_mpint(&mut reader)?.to_vec(), read_mpint(&mut reader)?.to_vec()))
+    }
+}
+
+/// Strips the `-----BEGIN SSH SIGNATURE-----` armor and decodes the base64 content.
+pub fn decode_armored(input: &str) -> Result<Vec<u8>, String> {
+    let input = input.trim();
+    let input = input.strip_prefix(ARMOR_BEGIN).unwrap_or(input);
+    let input = input.strip_suffix(ARMOR_END).unwrap_or(input);
+
+    let encoded = input.split_whitespace().collect::<String>();
+
+    STANDARD
+        .decode(encoded)
+        .map_err(|err| format!("Invalid base64 data: {:?}", err))
+}
+
+pub fn parse_signature(data: &[u8]) -> Result<SshSignature, String> {
+    let mut reader = BytesReader::new(data);
+
+    if reader.read_bytes(SSHSIG_MAGIC.len())? != SSHSIG_MAGIC {
+        return Err("Invalid magic preamble: expected SSHSIG".into());
+    }
+
+    let version = reader.read_u32_be()?;
+    if version != SSHSIG_VERSION {
+        return Err(format!("Unsupported signature version: {}", version));
+    }
+
+    let public_key = SshPublicKey::parse(read_string(&mut reader)?)?;
+    let namespace = read_utf8_string(&mut reader)?;
+    let reserved = read_string(&mut reader)?.to_vec();
+    let hash_algorithm = read_utf8_string(&mut reader)?;
+
+    let mut signature_reader = BytesReader::new(read_string(&mut reader)?);
+    let signature_type = read_utf8_string(&mut signature_reader)?;
+    let signature = read_string(&mut signature_reader)?.to_vec();
+
+    Ok(SshSignature {
+        version,
+        public_key,
+        namespace,
+        reserved,
+        hash_algorithm,
+        signature_type,
+        signature,
+    })
+}
+
+/// Host key from the `known_hosts`, `authorized_keys`, or `.pub` file line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostKey {
+    pub marker: Option<String>,
+    pub hosts: Option<String>,
+    pub public_key: SshPublicKey,
+    pub comment: String,
+}
+
+impl HostKey {
+    pub fn is_hashed_host(&self) -> bool {
+        self.hosts
+            .as_ref()
+            .map_or(false, |hosts| hosts.starts_with(HASHED_HOST_PREFIX))
+    }
+}
+
+fn parse_host_key_line(line: &str) -> Result<HostKey, String> {
+    let mut fields = line.split_whitespace().peekable();
+
+    let marker = fields
+        .next_if(|field| KNOWN_HOSTS_MARKERS.contains(field))
+        .map(str::to_owned);
+
+    // Anything before the key type is the host patterns list.
+    let first = fields.next().ok_or("Empty line")?;
+    let (hosts, key_type) = if KEY_TYPE_PREFIXES.iter().any(|prefix| first.starts_with(prefix)) {
+        (None, first)
+    } else {
+        (Some(first.to_owned()), fields.next().ok_or("Missing key type")?)
+    };
+
+    let blob = STANDARD
+        .decode(fields.next().ok_or("Missing public key")?)
+        .map_err(|err| format!("Invalid base64 public key: {:?}", err))?;
+    let public_key = SshPublicKey::parse(&blob)?;
+
+    if public_key.key_type != key_type {
+        return Err(format!(
+            "Key type mismatch: {} in the line but {} in the key blob",
+            key_type, public_key.key_type
+        ));
+    }
+
+    Ok(HostKey {
+        marker,
+        hosts,
+        public_key,
+        comment: fields.collect::<Vec<_>>().join(" "),
+    })
+}
+
+/// Parses every non-empty and non-comment line.
+pub fn parse_host_keys(input: &str) -> Vec<Result<HostKey, String>> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_host_key_line)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ED25519_KEY: &str = "AAAAC3NzaC1lZDI1NTE5AAAAIBERERERERERERERERERERERERERERERERERERERERER";
+
+    fn string(data: &[u8]) -> Vec<u8> {
+        [&(data.len() as u32).to_be_bytes()[..], data].concat()
+    }
+
+    fn encoded_public_key() -> Vec<u8> {
+        [string(b"ssh-ed25519"), string(&[0x11; 32])].concat()
+    }
+
+    fn encoded_signature() -> Vec<u8> {
+        [
+            SSHSIG_MAGIC,
+            &SSHSIG_VERSION.to_be_bytes(),
+            &string(&encoded_public_key()),
+            &string(b"file"),
+            &string(b""),
+            &string(b"sha512"),
+            &string(&[string(b"ssh-ed25519"), string(&[0x22; 64])].concat()),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn parse() {
+        let signature = parse_signature(&encoded_signature()).unwrap();
+
+        assert_eq!(signature.version, 1);
+        assert_eq!(signature.public_key.data, PublicKeyData::Ed25519(vec![0x11; 32]));
+        assert_eq!(signature.public_key.bits(), Some(256));
+        assert_eq!(signature.namespace, "file");
+        assert_eq!(signature.hash_algorithm, "sha512");
+        assert_eq!(signature.signature_type, "ssh-ed25519");
+        assert_eq!(signature.signature, [0x22; 64]);
+        assert!(signature
+            .signed_data(b"message")
+            .unwrap()
+            .starts_with(b"SSHSIG\x00\x00\x00\x04file"));
+
+        let armored = format!(
+            "{}\n{}\n{}",
+            ARMOR_BEGIN,
+            STANDARD.encode(encoded_signature()),
+            ARMOR_END
+        );
+        assert_eq!(decode_armored(&armored).unwrap(), encoded_signature());
+    }
+
+    #[test]
+    fn host_keys() {
+        let input = format!(
+            "# comment\n\nssh-ed25519 {0} user@host\n@revoked example.com,10.0.0.1 ssh-ed25519 {0}\n",
+            ED25519_KEY
+        );
+        let host_keys = parse_host_keys(&input)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(host_keys.len(), 2);
+        assert_eq!(host_keys[0].public_key.blob, encoded_public_key());
+        assert_eq!(host_keys[0].comment, "user@host");
+        assert_eq!(host_keys[1].marker.as_deref(), Some("@revoked"));
+        assert_eq!(host_keys[1].hosts.as_deref(), Some("example.com,10.0.0.1"));
+        assert_eq!(
+            host_keys[0].public_key.sha256_fingerprint(),
+            "SHA256:SQfC+vTbLURn9cTkVxIS8fGQ3FKNAJWeB0o139+gV4M"
+        );
+        assert_eq!(
+            host_keys[0].public_key.md5_fingerprint(),
+            "MD5:94:76:ac:2c:a5:3f:05:76:18:bf:9c:b0:12:de:ae:7d"
+        );
+
+        let mismatch = format!("ssh-rsa {}", ED25519_KEY);
+        assert!(parse_host_keys(&mismatch)[0]
+            .as_ref()
+            .unwrap_err()
+            .starts_with("Key type mismatch"));
+    }
+
+    #[test]
+    fn truncated() {
+        let signature = encoded_signature();
+
+        for len in 0..signature.len() {
+            assert!(parse_signature(&signature[..len]).is_err());
+        }
+        assert!(SshPublicKey::parse(&encoded_public_key()[..20]).is_err());
+    }
+
+    #[test]
+    fn overflowing_length() {
+        let mut signature = encoded_signature();
+        signature[10..14].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(parse_signature(&signature).is_err());
+
+        let mut public_key = encoded_public_key();
+        public_key[15..19].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(SshPublicKey::parse(&public_key).is_err());
+    }
+
+    #[test]
+    fn negative_mpint() {
+        let rsa_key = [string(b"ssh-rsa"), string(&[0x80]), string(&[0x00, 0xc5])].concat();
+        assert_eq!(
+            SshPublicKey::parse(&rsa_key).unwrap_err(),
+            "Negative mpint values are not allowed"
+        );
+
+        let rsa_key = [string(b"ssh-rsa"), string(&[0x01, 0x00, 0x01]), string(&[0x00, 0xc5])].concat();
+        assert_eq!(SshPublicKey::parse(&rsa_key).unwrap().bits(), Some(8));
+    }
+}
@@ -0,0 +1,112 @@
+use picky::hash::HashAlgorithm;
+use picky::key::PublicKey;
+use picky::signature::SignatureAlgorithm;
+
+use super::parser::{PublicKeyData, SshPublicKey, SshSignature};
+
+const INTEGER: u8 = 0x02;
+const BIT_STRING: u8 = 0x03;
+const SEQUENCE: u8 = 0x30;
+
+// DER-encoded algorithm identifiers.
+const RSA_ENCRYPTION: &[u8] = &[
+    0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01, 0x05, 0x00,
+];
+const EC_PUBLIC_KEY_OID: &[u8] = &[0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+const PRIME256V1_OID: &[u8] = &[0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+const SECP384R1_OID: &[u8] = &[0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x22];
+const ED25519: &[u8] = &[0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70];
+
+fn tlv(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut buf = vec![tag];
+    if value.len() < 0x80 {
+        buf.push(value.len() as u8);
+    } else if value.len() <= 0xff {
+        buf.extend_from_slice(&[0x81, value.len() as u8]);
+    } else {
+        buf.push(0x82);
+        buf.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    }
+    buf.extend_from_slice(value);
+
+    buf
+}
+
+/// Encodes the unsigned big-endian magnitude as DER INTEGER.
+fn unsigned_integer(magnitude: &[u8]) -> Vec<u8> {
+    let first_non_zero = magnitude.iter().position(|byte| *byte != 0).unwrap_or(magnitude.len());
+    let magnitude = &magnitude[first_non_zero..];
+
+    let mut value = Vec::with_capacity(magnitude.len() + 1);
+    if magnitude.first().map_or(true, |byte| byte & 0x80 != 0) {
+        value.push(0);
+    }
+    value.extend_from_slice(magnitude);
+
+    tlv(INTEGER, &value)
+}
+
+fn bit_string(data: &[u8]) -> Vec<u8> {
+    let mut value = vec![0];
+    value.extend_from_slice(data);
+
+    tlv(BIT_STRING, &value)
+}
+
+/// Converts the SSH public key into the DER-encoded SubjectPublicKeyInfo.
+fn subject_public_key_info(public_key: &SshPublicKey) -> Result<Vec<u8>, String> {
+    let (algorithm, subject_public_key) = match &public_key.data {
+        PublicKeyData::Ed25519(key) => (ED25519.to_vec(), key.clone()),
+        PublicKeyData::Rsa { exponent, modulus } => (
+            RSA_ENCRYPTION.to_vec(),
+            tlv(
+                SEQUENCE,
+                &[unsigned_integer(modulus), unsigned_integer(exponent)].concat(),
+            ),
+        ),
+        PublicKeyData::Ecdsa { curve, point } => {
+            let curve_oid = match curve.as_str() {
+                "nistp256" => PRIME256V1_OID,
+                "nistp384" => SECP384R1_OID,
+                curve => return Err(format!("Unsupported curve: {}", curve)),
+            };
+
+            (tlv(SEQUENCE, &[EC_PUBLIC_KEY_OID, curve_oid].concat()), point.clone())
+        }
+        PublicKeyData::Unsupported => return Err(format!("Unsupported key type: {}", public_key.key_type)),
+    };
+
+    Ok(tlv(SEQUENCE, &[algorithm, bit_string(&subject_public_key)].concat()))
+}
+
+fn signature_algorithm(signature_type: &str) -> Result<SignatureAlgorithm, String> {
+    Ok(match signature_type {
+        "ssh-ed25519" => SignatureAlgorithm::Ed25519,
+        "ssh-rsa" => SignatureAlgorithm::RsaPkcs1v15(HashAlgorithm::SHA1),
+        "rsa-sha2-256" => SignatureAlgorithm::RsaPkcs1v15(HashAlgorithm::SHA2_256),
+        "rsa-sha2-512" => SignatureAlgorithm::RsaPkcs1v15(HashAlgorithm::SHA2_512),
+        "ecdsa-sha2-nistp256" => SignatureAlgorithm::Ecdsa(HashAlgorithm::SHA2_256),
+        "ecdsa-sha2-nistp384" => SignatureAlgorithm::Ecdsa(HashAlgorithm::SHA2_384),
+        signature_type => return Err(format!("Unsupported signature type: {}", signature_type)),
+    })
+}
+
+/// Verifies the SSH signature over the message. Returns `Ok(false)` if the signature does not match.
+pub fn verify_signature(signature: &SshSignature, message: &[u8]) -> Result<bool, String> {
+    let algorithm = signature_algorithm(&signature.signature_type)?;
+
+    let public_key = PublicKey::from_der(&subject_public_key_info(&signature.public_key)?)
+        .map_err(|err| format!("Invalid public key: {:?}", err))?;
+
+    // SSH encodes ECDSA signatures as two mpints, but picky expects the DER-encoded Ecdsa-Sig-Value.
+    let signature_bytes = if signature.signature_type.starts_with("ecdsa-") {
+        let (r, s) = signature.ecdsa_components()?;
+        tlv(SEQUENCE, &[unsigned_integer(&r), unsigned_integer(&s)].concat())
+    } else {
+        signature.signature.clone()
+    };
+
+    Ok(algorithm
+        .verify(&public_key, &signature.signed_data(message)?, &signature_bytes)
+        .is_ok())
+}
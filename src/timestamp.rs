@@ -0,0 +1,60 @@
+mod format;
+mod timestamp_input;
+
+use time::{OffsetDateTime, UtcOffset};
+use yew::{function_component, html, use_effect_with, use_state_eq, Callback, Html};
+use yew_hooks::use_local_storage;
+
+use self::format::TIMESTAMP_FORMATS;
+pub use self::format::{format_date_time, format_unix_time, TimestampFormat};
+use self::timestamp_input::TimestampInput;
+
+const TIMESTAMP_LOCAL_STORAGE_KEY: &str = "TIMESTAMP_DATA";
+
+#[function_component(TimestampConverter)]
+pub fn timestamp_converter() -> Html {
+    let date_time = use_state_eq(OffsetDateTime::now_utc);
+
+    let date_time_setter = date_time.setter();
+    let local_storage = use_local_storage::<String>(TIMESTAMP_LOCAL_STORAGE_KEY.to_owned());
+    use_effect_with([], move |_: &[(); 0]| {
+        if let Some(raw_nanos) = (*local_storage).as_ref() {
+            match raw_nanos
+                .parse::<i128>()
+                .map_err(|err| err.to_string())
+                .and_then(|nanos| OffsetDateTime::from_unix_timestamp_nanos(nanos).map_err(|err| err.to_string()))
+            {
+                Ok(date_time) => date_time_setter.set(date_time),
+                Err(err) => error!("Can not load timestamp from local storage: {:?}", err),
+            }
+        }
+    });
+
+    let local_storage = use_local_storage::<String>(TIMESTAMP_LOCAL_STORAGE_KEY.to_owned());
+    use_effect_with(date_time.clone(), move |date_time| {
+        local_storage.set(date_time.unix_timestamp_nanos().to_string());
+    });
+
+    let local_offset = UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC);
+
+    html! {
+        <article class="vertical">
+            <div class="timestamp-inputs">{
+                TIMESTAMP_FORMATS.iter().map(|format| {
+                    let date_time_setter = date_time.setter();
+                    html! {
+                        <TimestampInput
+                            format={*format}
+                            date_time={*date_time}
+                            setter={Callback::from(move |date_time| date_time_setter.set(date_time))}
+                        />
+                    }
+                }).collect::<Html>()
+            }</div>
+            <div class="vertical">
+                <span>{format!("UTC: {}", format_date_time(&date_time.to_offset(UtcOffset::UTC)))}</span>
+                <span>{format!("Local: {}", format_date_time(&date_time.to_offset(local_offset)))}</span>
+            </div>
+        </article>
+    }
+}
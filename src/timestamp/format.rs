@@ -0,0 +1,276 @@
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+/// Difference between the Windows epoch (1601-01-01) and the Unix epoch (1970-01-01) in 100-nanosecond intervals.
+const FILETIME_UNIX_EPOCH_DIFF: i128 = 116_444_736_000_000_000;
+const NANOS_IN_FILETIME_INTERVAL: i128 = 100;
+const NANOS_IN_MILLISECOND: i128 = 1_000_000;
+const NANOS_IN_SECOND: i128 = 1_000_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampFormat {
+    UnixSeconds,
+    UnixMilliseconds,
+    /// Windows FILETIME as a hex-encoded 64-bit integer.
+    FileTime,
+    /// LDAP/AD timestamps (`pwdLastSet`, `accountExpires`, etc): decimal FILETIME value.
+    Ldap,
+    GeneralizedTime,
+    /// GeneralizedTime without fractional seconds (RFC 4120, Section 5.2.3).
+    KerberosTime,
+}
+
+pub const TIMESTAMP_FORMATS: [TimestampFormat; 6] = [
+    TimestampFormat::UnixSeconds,
+    TimestampFormat::UnixMilliseconds,
+    TimestampFormat::FileTime,
+    TimestampFormat::Ldap,
+    TimestampFormat::GeneralizedTime,
+    TimestampFormat::KerberosTime,
+];
+
+impl TimestampFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimestampFormat::UnixSeconds => "Unix (s)",
+            TimestampFormat::UnixMilliseconds => "Unix (ms)",
+            TimestampFormat::FileTime => "FILETIME (hex)",
+            TimestampFormat::Ldap => "LDAP/AD",
+            TimestampFormat::GeneralizedTime => "GeneralizedTime",
+            TimestampFormat::KerberosTime => "KerberosTime",
+        }
+    }
+
+    pub fn format(&self, date_time: &OffsetDateTime) -> String {
+        let nanos = date_time.unix_timestamp_nanos();
+
+        match self {
+            TimestampFormat::UnixSeconds => nanos.div_euclid(NANOS_IN_SECOND).to_string(),
+            TimestampFormat::UnixMilliseconds => nanos.div_euclid(NANOS_IN_MILLISECOND).to_string(),
+            TimestampFormat::FileTime => match u64::try_from(to_file_time(nanos)) {
+                Ok(file_time) => format!("{:016x}", file_time),
+                Err(_) => "out of range".to_owned(),
+            },
+            TimestampFormat::Ldap => to_file_time(nanos).to_string(),
+            TimestampFormat::GeneralizedTime => format_generalized_time(date_time, true),
+            TimestampFormat::KerberosTime => format_generalized_time(date_time, false),
+        }
+    }
+
+    pub fn parse(&self, raw: &str) -> Result<OffsetDateTime, String> {
+        let raw = raw.trim();
+
+        let nanos = match self {
+            TimestampFormat::UnixSeconds => parse_int(raw)?
+                .checked_mul(NANOS_IN_SECOND)
+                .ok_or("Timestamp is out of range")?,
+            TimestampFormat::UnixMilliseconds => parse_int(raw)?
+                .checked_mul(NANOS_IN_MILLISECOND)
+                .ok_or("Timestamp is out of range")?,
+            TimestampFormat::FileTime => {
+                let raw = raw.strip_prefix("0x").unwrap_or(raw);
+                from_file_time(
+                    u64::from_str_radix(raw, 16)
+                        .map_err(|err| format!("Invalid FILETIME: {:?}", err))?
+                        .into(),
+                )?
+            }
+            TimestampFormat::Ldap => from_file_time(parse_int(raw)?)?,
+            TimestampFormat::GeneralizedTime => return parse_generalized_time(raw, true),
+            TimestampFormat::KerberosTime => return parse_generalized_time(raw, false),
+        };
+
+        OffsetDateTime::from_unix_timestamp_nanos(nanos).map_err(|err| format!("Timestamp is out of range: {:?}", err))
+    }
+}
+
+fn parse_int(raw: &str) -> Result<i128, String> {
+    raw.parse::<i128>().map_err(|err| format!("Invalid integer: {:?}", err))
+}
+
+fn to_file_time(unix_nanos: i128) -> i128 {
+    unix_nanos.div_euclid(NANOS_IN_FILETIME_INTERVAL) + FILETIME_UNIX_EPOCH_DIFF
+}
+
+fn from_file_time(file_time: i128) -> Result<i128, String> {
+    file_time
+        .checked_sub(FILETIME_UNIX_EPOCH_DIFF)
+        .and_then(|intervals| intervals.checked_mul(NANOS_IN_FILETIME_INTERVAL))
+        .ok_or_else(|| "Timestamp is out of range".to_owned())
+}
+
+fn format_generalized_time(date_time: &OffsetDateTime, with_fraction: bool) -> String {
+    let date_time = date_time.to_offset(UtcOffset::UTC);
+
+    let mut formatted = format!(
+        "{:04}{:02}{:02}{:02}{:02}{:02}",
+        date_time.year(),
+        u8::from(date_time.month()),
+        date_time.day(),
+        date_time.hour(),
+        date_time.minute(),
+        date_time.second(),
+    );
+
+    let nanosecond = date_time.nanosecond();
+    if with_fraction && nanosecond != 0 {
+        formatted.push('.');
+        formatted.push_str(format!("{:09}", nanosecond).trim_end_matches('0'));
+    }
+
+    formatted.push('Z');
+
+    formatted
+}
+
+fn parse_number<T: std::str::FromStr>(raw: &str, range: std::ops::Range<usize>) -> Result<T, String> {
+    raw.get(range)
+        .filter(|digits| digits.bytes().all(|c| c.is_ascii_digit()))
+        .and_then(|digits| digits.parse::<T>().ok())
+        .ok_or_else(|| format!("Invalid GeneralizedTime: {}", raw))
+}
+
+/// Parses the UTC GeneralizedTime: `YYYYMMDDHHMMSS[.fff]Z`. The fraction is rejected when `with_fraction` is false.
+fn parse_generalized_time(raw: &str, with_fraction: bool) -> Result<OffsetDateTime, String> {
+    let raw = raw
+        .strip_suffix('Z')
+        .ok_or("GeneralizedTime must be in UTC and end with 'Z'")?;
+
+    let year: i32 = parse_number(raw, 0..4)?;
+    let month: u8 = parse_number(raw, 4..6)?;
+    let day: u8 = parse_number(raw, 6..8)?;
+    let hour: u8 = parse_number(raw, 8..10)?;
+    let minute: u8 = parse_number(raw, 10..12)?;
+    let second: u8 = parse_number(raw, 12..14)?;
+
+    let nanosecond = match raw.get(14..) {
+        None | Some("") => 0,
+        Some(_) if !with_fraction => return Err("KerberosTime must not contain fractional seconds".into()),
+        Some(fraction) => {
+            let digits = fraction
+                .strip_prefix('.')
+                .or_else(|| fraction.strip_prefix(','))
+                .filter(|digits| !digits.is_empty() && digits.len() <= 9)
+                .ok_or_else(|| format!("Invalid GeneralizedTime fraction: {}", fraction))?;
+
+            parse_number::<u32>(digits, 0..digits.len())? * 10_u32.pow(9 - digits.len() as u32)
+        }
+    };
+
+    let month = Month::try_from(month).map_err(|err| format!("Invalid month: {:?}", err))?;
+    let date = Date::from_calendar_date(year, month, day).map_err(|err| format!("Invalid date: {:?}", err))?;
+    let time =
+        Time::from_hms_nano(hour, minute, second, nanosecond).map_err(|err| format!("Invalid time: {:?}", err))?;
+
+    Ok(PrimitiveDateTime::new(date, time).assume_utc())
+}
+
+/// Formats the date and time in a human-readable form: `YYYY-MM-DD HH:MM:SS.fff +HH:MM`.
+pub fn format_date_time(date_time: &OffsetDateTime) -> String {
+    let offset = date_time.offset();
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:03} {}{:02}:{:02}",
+        date_time.year(),
+        u8::from(date_time.month()),
+        date_time.day(),
+        date_time.hour(),
+        date_time.minute(),
+        date_time.second(),
+        date_time.millisecond(),
+        if offset.is_negative() { '-' } else { '+' },
+        offset.whole_hours().abs(),
+        offset.minutes_past_hour().abs(),
+    )
+}
+
+/// Formats the Unix timestamp (in seconds) using [format_date_time].
+pub fn format_unix_time(timestamp: u32) -> String {
+    OffsetDateTime::from_unix_timestamp(timestamp.into())
+        .map(|date_time| format_date_time(&date_time))
+        .unwrap_or_else(|_| timestamp.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(format: TimestampFormat, raw: &str) -> Result<i128, String> {
+        format.parse(raw).map(|date_time| date_time.unix_timestamp_nanos())
+    }
+
+    #[test]
+    fn round_trip() {
+        let date_time = TimestampFormat::UnixMilliseconds.parse("1618884473123").unwrap();
+
+        for (format, formatted) in [
+            (TimestampFormat::UnixSeconds, "1618884473"),
+            (TimestampFormat::UnixMilliseconds, "1618884473123"),
+            (TimestampFormat::FileTime, "01d73589f8c5e730"),
+            (TimestampFormat::Ldap, "132633580731230000"),
+            (TimestampFormat::GeneralizedTime, "20210420020753.123Z"),
+            (TimestampFormat::KerberosTime, "20210420020753Z"),
+        ] {
+            assert_eq!(format.format(&date_time), formatted, "{}", format.name());
+        }
+
+        for format in [
+            TimestampFormat::UnixMilliseconds,
+            TimestampFormat::FileTime,
+            TimestampFormat::Ldap,
+            TimestampFormat::GeneralizedTime,
+        ] {
+            assert_eq!(format.parse(&format.format(&date_time)).unwrap(), date_time);
+        }
+    }
+
+    #[test]
+    fn overflowing_file_time() {
+        assert_eq!(from_file_time(FILETIME_UNIX_EPOCH_DIFF), Ok(0));
+        assert!(from_file_time(i128::MAX).is_err());
+        assert!(from_file_time(i128::MIN).is_err());
+        assert!(parse(TimestampFormat::Ldap, &i128::MAX.to_string()).is_err());
+        assert!(parse(TimestampFormat::Ldap, &i128::MIN.to_string()).is_err());
+        assert!(parse(TimestampFormat::FileTime, "ffffffffffffffff").is_err());
+        assert!(parse(TimestampFormat::UnixSeconds, &i128::MAX.to_string()).is_err());
+        assert!(parse(TimestampFormat::UnixMilliseconds, &i128::MIN.to_string()).is_err());
+    }
+
+    #[test]
+    fn generalized_time_fraction() {
+        assert_eq!(
+            parse(TimestampFormat::GeneralizedTime, "20210420020753.5Z"),
+            Ok(1_618_884_473_500_000_000)
+        );
+        assert_eq!(
+            parse(TimestampFormat::GeneralizedTime, "20210420020753,123456789Z"),
+            Ok(1_618_884_473_123_456_789)
+        );
+        assert!(parse(TimestampFormat::GeneralizedTime, "20210420020753.Z").is_err());
+        assert!(parse(TimestampFormat::GeneralizedTime, "20210420020753.1234567890Z").is_err());
+    }
+
+    #[test]
+    fn kerberos_time_rejects_fraction() {
+        assert_eq!(
+            parse(TimestampFormat::KerberosTime, "20210420020753Z"),
+            Ok(1_618_884_473_000_000_000)
+        );
+        assert!(parse(TimestampFormat::KerberosTime, "20210420020753.5Z")
+            .unwrap_err()
+            .contains("fractional seconds"));
+        assert!(parse(TimestampFormat::KerberosTime, "20210420020753,0Z").is_err());
+    }
+
+    #[test]
+    fn invalid_generalized_time() {
+        for raw in [
+            "20210420020753",
+            "2021042002075Z",
+            "20211320020753Z",
+            "20210420250753Z",
+            "2021042002075aZ",
+        ] {
+            assert!(parse(TimestampFormat::GeneralizedTime, raw).is_err(), "{}", raw);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use time::OffsetDateTime;
+use web_sys::HtmlInputElement;
+use yew::{classes, function_component, html, use_effect_with, use_state, Callback, Html, Properties, TargetCast};
+
+use super::format::TimestampFormat;
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct TimestampInputProps {
+    pub format: TimestampFormat,
+    pub date_time: OffsetDateTime,
+    pub setter: Callback<OffsetDateTime>,
+}
+
+#[function_component(TimestampInput)]
+pub fn timestamp_input(props: &TimestampInputProps) -> Html {
+    let TimestampInputProps {
+        format,
+        date_time,
+        setter,
+    } = props.clone();
+
+    let raw_value = use_state(|| format.format(&date_time));
+    let is_valid = use_state(|| true);
+
+    let raw_value_setter = raw_value.setter();
+    let is_valid_setter = is_valid.setter();
+    let current_raw_value = (*raw_value).clone();
+    use_effect_with(date_time, move |date_time| {
+        // We do not want to reformat the value the user is typing right now.
+        if format.parse(&current_raw_value).ok().as_ref() != Some(date_time) {
+            raw_value_setter.set(format.format(date_time));
+            is_valid_setter.set(true);
+        }
+    });
+
+    let raw_value_setter = raw_value.setter();
+    let is_valid_setter = is_valid.setter();
+    let date_time_setter = setter.clone();
+    let oninput = Callback::from(move |event: html::oninput::Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        let value = input.value();
+
+        match format.parse(&value) {
+            Ok(date_time) => {
+                date_time_setter.emit(date_time);
+                is_valid_setter.set(true);
+            }
+            Err(err) => {
+                debug!("Invalid {} value: {}", format.name(), err);
+                is_valid_setter.set(false);
+            }
+        }
+
+        raw_value_setter.set(value);
+    });
+
+    let onclick = Callback::from(move |_| setter.emit(OffsetDateTime::now_utc()));
+
+    html! {
+        <div class="timestamp-input">
+            <span>{format.name()}</span>
+            <input
+                class={classes!("base-input", if !(*is_valid) { "input-error" } else { "" })}
+                value={(*raw_value).clone()}
+                {oninput}
+            />
+            <button class="action-button" {onclick}>{"now"}</button>
+        </div>
+    }
+}
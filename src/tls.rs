@@ -0,0 +1,174 @@
+mod names;
+mod parser;
+
+use yew::{function_component, html, use_state, Callback, Html};
+
+use self::names::{cipher_suite_name, content_type_name, extension_name, handshake_type_name, version_name};
+use self::parser::{
+    extension_details, parse_tls, ClientHello, Extension, HandshakeMessage, ServerHello, TlsMessages, TlsRecord,
+    CLIENT_HELLO, SERVER_HELLO,
+};
+use crate::common::{ByteInput, BytesViewer};
+use crate::url_query_params::generate_asn1_link;
+
+fn format_version(version: u16) -> String {
+    format!("{} (0x{:04x})", version_name(version), version)
+}
+
+fn format_cipher_suite(cipher_suite: u16) -> String {
+    format!("{} (0x{:04x})", cipher_suite_name(cipher_suite), cipher_suite)
+}
+
+fn render_records(records: &[TlsRecord]) -> Html {
+    if records.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <table class="parser-table">
+            <tr>
+                <th>{"Record"}</th>
+                <th>{"Version"}</th>
+                <th>{"Length"}</th>
+            </tr>
+            {records.iter().map(|record| html! {
+                <tr>
+                    <td>{format!("{} ({})", content_type_name(record.content_type), record.content_type)}</td>
+                    <td>{format_version(record.version)}</td>
+                    <td>{record.length}</td>
+                </tr>
+            }).collect::<Html>()}
+        </table>
+    }
+}
+
+fn render_extensions(extensions: &[Extension], handshake_type: u8) -> Html {
+    html! {
+        <table class="parser-table">
+            <tr>
+                <th>{"Extension"}</th>
+                <th>{"Value"}</th>
+            </tr>
+            {extensions.iter().map(|extension| {
+                let value = match extension_details(extension, handshake_type) {
+                    Ok(details) if !details.is_empty() => html! {
+                        <div class="vertical">
+                            {details.into_iter().map(|detail| html! { <span>{detail}</span> }).collect::<Html>()}
+                        </div>
+                    },
+                    Ok(_) => html! { <span>{hex::encode(&extension.data)}</span> },
+                    Err(err) => html! { <span>{format!("{} (invalid: {})", hex::encode(&extension.data), err)}</span> },
+                };
+
+                html! {
+                    <tr>
+                        <td>{format!("{} ({})", extension_name(extension.extension_type), extension.extension_type)}</td>
+                        <td>{value}</td>
+                    </tr>
+                }
+            }).collect::<Html>()}
+        </table>
+    }
+}
+
+fn render_client_hello(client_hello: &ClientHello) -> Html {
+    html! {
+        <div class="vertical">
+            <span class="total">{"ClientHello"}</span>
+            <span>{format!("Version: {}", format_version(client_hello.version))}</span>
+            <span>{format!("Random: {}", hex::encode(&client_hello.random))}</span>
+            <span>{format!("Session id: {}", hex::encode(&client_hello.session_id))}</span>
+            <span>{format!("Compression methods: {:?}", client_hello.compression_methods)}</span>
+            <table class="parser-table">
+                <tr>
+                    <th>{"Cipher suite"}</th>
+                </tr>
+                {client_hello.cipher_suites.iter().map(|cipher_suite| html! {
+                    <tr>
+                        <td>{format_cipher_suite(*cipher_suite)}</td>
+                    </tr>
+                }).collect::<Html>()}
+            </table>
+            {render_extensions(&client_hello.extensions, CLIENT_HELLO)}
+        </div>
+    }
+}
+
+fn render_server_hello(server_hello: &ServerHello) -> Html {
+    html! {
+        <div class="vertical">
+            <span class="total">{"ServerHello"}</span>
+            <span>{format!("Version: {}", format_version(server_hello.version))}</span>
+            <span>{format!("Random: {}", hex::encode(&server_hello.random))}</span>
+            <span>{format!("Session id: {}", hex::encode(&server_hello.session_id))}</span>
+            <span>{format!("Cipher suite: {}", format_cipher_suite(server_hello.cipher_suite))}</span>
+            <span>{format!("Compression method: {}", server_hello.compression_method)}</span>
+            {render_extensions(&server_hello.extensions, SERVER_HELLO)}
+        </div>
+    }
+}
+
+fn render_certificates(certificates: &[Vec<u8>]) -> Html {
+    html! {
+        <div class="vertical">
+            <span class="total">{"Certificate"}</span>
+            {certificates.iter().enumerate().map(|(index, certificate)| html! {
+                <div class="vertical">
+                    <div class="horizontal">
+                        <span>{format!("Certificate #{} ({} bytes)", index, certificate.len())}</span>
+                        <a class="a-link" href={generate_asn1_link(certificate.clone())} target="_blank">{"Open in ASN.1 viewer"}</a>
+                    </div>
+                    <BytesViewer bytes={certificate.clone()} />
+                </div>
+            }).collect::<Html>()}
+        </div>
+    }
+}
+
+fn render_handshake(handshake: &HandshakeMessage) -> Html {
+    match handshake {
+        HandshakeMessage::ClientHello(client_hello) => render_client_hello(client_hello),
+        HandshakeMessage::ServerHello(server_hello) => render_server_hello(server_hello),
+        HandshakeMessage::Certificate(certificates) => render_certificates(certificates),
+        HandshakeMessage::Other { handshake_type, data } => html! {
+            <div class="vertical">
+                <span class="total">{format!("{} ({})", handshake_type_name(*handshake_type), handshake_type)}</span>
+                <BytesViewer bytes={data.clone()} />
+            </div>
+        },
+    }
+}
+
+#[function_component(TlsPage)]
+pub fn tls_page() -> Html {
+    let raw_data = use_state(Vec::<u8>::new);
+    let messages = use_state(|| None::<Result<TlsMessages, String>>);
+
+    let raw_data_setter = raw_data.setter();
+    let messages_setter = messages.setter();
+    let set_data = Callback::from(move |data: Vec<u8>| {
+        messages_setter.set(if data.is_empty() { None } else { Some(parse_tls(&data)) });
+        raw_data_setter.set(data);
+    });
+
+    html! {
+        <article class="vertical">
+            <ByteInput
+                bytes={(*raw_data).clone()}
+                setter={set_data}
+                placeholder={"TLS records or handshake messages".to_owned()}
+                rows={6}
+            />
+            {match &*messages {
+                Some(Ok(messages)) => html! {
+                    <div class="vertical">
+                        {render_records(&messages.records)}
+                        {messages.handshakes.iter().map(render_handshake).collect::<Html>()}
+                    </div>
+                },
+                Some(Err(err)) => html! { <span class="total">{format!("Invalid TLS data: {}", err)}</span> },
+                None => html! {},
+            }}
+        </article>
+    }
+}
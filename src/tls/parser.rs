@@ -0,0 +1,485 @@
+use super::names::{group_name, handshake_type_name, signature_scheme_name, version_name};
+use crate::utils::BytesReader;
+
+// https://www.rfc-editor.org/rfc/rfc8446
+const CONTENT_TYPE_CHANGE_CIPHER_SPEC: u8 = 20;
+const CONTENT_TYPE_HANDSHAKE: u8 = 22;
+const RECORD_HEADER_LEN: usize = 5;
+
+pub const CLIENT_HELLO: u8 = 1;
+pub const SERVER_HELLO: u8 = 2;
+const CERTIFICATE: u8 = 11;
+
+const SERVER_NAME: u16 = 0;
+const SUPPORTED_GROUPS: u16 = 10;
+const SIGNATURE_ALGORITHMS: u16 = 13;
+const ALPN: u16 = 16;
+const SUPPORTED_VERSIONS: u16 = 43;
+const KEY_SHARE: u16 = 51;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsRecord {
+    pub content_type: u8,
+    pub version: u16,
+    pub length: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extension {
+    pub extension_type: u16,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientHello {
+    pub version: u16,
+    pub random: Vec<u8>,
+    pub session_id: Vec<u8>,
+    pub cipher_suites: Vec<u16>,
+    pub compression_methods: Vec<u8>,
+    pub extensions: Vec<Extension>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerHello {
+    pub version: u16,
+    pub random: Vec<u8>,
+    pub session_id: Vec<u8>,
+    pub cipher_suite: u16,
+    pub compression_method: u8,
+    pub extensions: Vec<Extension>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandshakeMessage {
+    ClientHello(ClientHello),
+    ServerHello(ServerHello),
+    /// DER-encoded certificates. The sender's certificate comes first.
+    Certificate(Vec<Vec<u8>>),
+    Other {
+        handshake_type: u8,
+        data: Vec<u8>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsMessages {
+    /// Empty if the input contains raw handshake messages without the record layer.
+    pub records: Vec<TlsRecord>,
+    pub handshakes: Vec<HandshakeMessage>,
+}
+
+fn read_vector_u8<'data>(reader: &mut BytesReader<'data>) -> Result<&'data [u8], String> {
+    let len = usize::from(reader.read_u8()?);
+
+    reader.read_bytes(len)
+}
+
+fn read_vector_u16<'data>(reader: &mut BytesReader<'data>) -> Result<&'data [u8], String> {
+    let len = usize::from(reader.read_u16_be()?);
+
+    reader.read_bytes(len)
+}
+
+fn read_vector_u24<'data>(reader: &mut BytesReader<'data>) -> Result<&'data [u8], String> {
+    let len = reader.read_u24_be()? as usize;
+
+    reader.read_bytes(len)
+}
+
+fn read_u16_list(data: &[u8]) -> Result<Vec<u16>, String> {
+    let mut reader = BytesReader::new(data);
+
+    let mut values = Vec::new();
+    while !reader.is_empty() {
+        values.push(reader.read_u16_be()?);
+    }
+
+    Ok(values)
+}
+
+fn read_extensions(reader: &mut BytesReader<'_>) -> Result<Vec<Extension>, String> {
+    // Extensions are optional in TLS 1.2 and earlier.
+    if reader.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut reader = BytesReader::new(read_vector_u16(reader)?);
+
+    let mut extensions = Vec::new();
+    while !reader.is_empty() {
+        let extension_type = reader.read_u16_be()?;
+        let data = read_vector_u16(&mut reader)?.to_vec();
+
+        extensions.push(Extension { extension_type, data });
+    }
+
+    Ok(extensions)
+}
+
+fn parse_client_hello(data: &[u8]) -> Result<ClientHello, String> {
+    let mut reader = BytesReader::new(data);
+
+    let version = reader.read_u16_be()?;
+    let random = reader.read_bytes(32)?.to_vec();
+    let session_id = read_vector_u8(&mut reader)?.to_vec();
+    let cipher_suites = read_u16_list(read_vector_u16(&mut reader)?)?;
+    let compression_methods = read_vector_u8(&mut reader)?.to_vec();
+    let extensions = read_extensions(&mut reader)?;
+
+    Ok(ClientHello {
+        version,
+        random,
+        session_id,
+        cipher_suites,
+        compression_methods,
+        extensions,
+    })
+}
+
+fn parse_server_hello(data: &[u8]) -> Result<ServerHello, String> {
+    let mut reader = BytesReader::new(data);
+
+    let version = reader.read_u16_be()?;
+    let random = reader.read_bytes(32)?.to_vec();
+    let session_id = read_vector_u8(&mut reader)?.to_vec();
+    let cipher_suite = reader.read_u16_be()?;
+    let compression_method = reader.read_u8()?;
+    let extensions = read_extensions(&mut reader)?;
+
+    Ok(ServerHello {
+        version,
+        random,
+        session_id,
+        cipher_suite,
+        compression_method,
+        extensions,
+    })
+}
+
+fn parse_certificate_list(data: &[u8], has_extensions: bool) -> Result<Vec<Vec<u8>>, String> {
+    let mut reader = BytesReader::new(data);
+
+    let mut certificates = Vec::new();
+    while !reader.is_empty() {
+        certificates.push(read_vector_u24(&mut reader)?.to_vec());
+        if has_extensions {
+            read_vector_u16(&mut reader)?;
+        }
+    }
+
+    Ok(certificates)
+}
+
+fn parse_certificate(data: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+    let mut reader = BytesReader::new(data);
+
+    // TLS 1.2 certificate message contains only the certificate list.
+    let certificate_list = read_vector_u24(&mut reader)?;
+    if reader.is_empty() {
+        if let Ok(certificates) = parse_certificate_list(certificate_list, false) {
+            return Ok(certificates);
+        }
+    }
+
+    // TLS 1.3 certificate message has the request context and extensions for every certificate.
+    let mut reader = BytesReader::new(data);
+    read_vector_u8(&mut reader)?;
+    let certificate_list = read_vector_u24(&mut reader)?;
+
+    parse_certificate_list(certificate_list, true)
+}
+
+fn parse_handshake(handshake_type: u8, data: &[u8]) -> Result<HandshakeMessage, String> {
+    Ok(match handshake_type {
+        CLIENT_HELLO => HandshakeMessage::ClientHello(parse_client_hello(data)?),
+        SERVER_HELLO => HandshakeMessage::ServerHello(parse_server_hello(data)?),
+        CERTIFICATE => HandshakeMessage::Certificate(parse_certificate(data)?),
+        handshake_type => HandshakeMessage::Other {
+            handshake_type,
+            data: data.to_vec(),
+        },
+    })
+}
+
+fn parse_handshakes(data: &[u8]) -> Result<Vec<HandshakeMessage>, String> {
+    let mut reader = BytesReader::new(data);
+
+    let mut handshakes = Vec::new();
+    while !reader.is_empty() {
+        let handshake_type = reader.read_u8()?;
+        let handshake_data = read_vector_u24(&mut reader)?;
+
+        handshakes.push(parse_handshake(handshake_type, handshake_data).map_err(|err| {
+            format!(
+                "Invalid {} handshake message: {}",
+                handshake_type_name(handshake_type),
+                err
+            )
+        })?);
+    }
+
+    Ok(handshakes)
+}
+
+fn looks_like_record(data: &[u8]) -> bool {
+    data.len() >= RECORD_HEADER_LEN && (20..=24).contains(&data[0]) && data[1] == 0x03
+}
+
+/// Parses TLS records or raw handshake messages (without the record layer).
+pub fn parse_tls(data: &[u8]) -> Result<TlsMessages, String> {
+    if !looks_like_record(data) {
+        return Ok(TlsMessages {
+            records: Vec::new(),
+            handshakes: parse_handshakes(data)?,
+        });
+    }
+
+    let mut reader = BytesReader::new(data);
+    let mut records = Vec::new();
+    // Handshake messages can be fragmented across several records.
+    let mut handshake_data = Vec::new();
+    // Handshake records after the ChangeCipherSpec are encrypted.
+    let mut is_encrypted = false;
+
+    while !reader.is_empty() {
+        let content_type = reader.read_u8()?;
+        let version = reader.read_u16_be()?;
+        let fragment = read_vector_u16(&mut reader)?;
+
+        match content_type {
+            CONTENT_TYPE_HANDSHAKE if !is_encrypted => handshake_data.extend_from_slice(fragment),
+            CONTENT_TYPE_CHANGE_CIPHER_SPEC => is_encrypted = true,
+            _ => {}
+        }
+
+        records.push(TlsRecord {
+            content_type,
+            version,
+            length: fragment.len(),
+        });
+    }
+
+    Ok(TlsMessages {
+        records,
+        handshakes: parse_handshakes(&handshake_data)?,
+    })
+}
+
+fn format_u16_list(values: &[u16], name: fn(u16) -> &'static str) -> Vec<String> {
+    values
+        .iter()
+        .map(|value| format!("{} (0x{:04x})", name(*value), value))
+        .collect()
+}
+
+/// Decodes the known extension values into human-readable lines.
+pub fn extension_details(extension: &Extension, handshake_type: u8) -> Result<Vec<String>, String> {
+    let mut reader = BytesReader::new(&extension.data);
+
+    match extension.extension_type {
+        SERVER_NAME if !extension.data.is_empty() => {
+            let mut reader = BytesReader::new(read_vector_u16(&mut reader)?);
+
+            let mut names = Vec::new();
+            while !reader.is_empty() {
+                let _name_type = reader.read_u8()?;
+                names.push(String::from_utf8_lossy(read_vector_u16(&mut reader)?).into_owned());
+            }
+
+            Ok(names)
+        }
+        SUPPORTED_GROUPS => Ok(format_u16_list(
+            &read_u16_list(read_vector_u16(&mut reader)?)?,
+            group_name,
+        )),
+        SIGNATURE_ALGORITHMS => Ok(format_u16_list(
+            &read_u16_list(read_vector_u16(&mut reader)?)?,
+            signature_scheme_name,
+        )),
+        ALPN => {
+            let mut reader = BytesReader::new(read_vector_u16(&mut reader)?);
+
+            let mut protocols = Vec::new();
+            while !reader.is_empty() {
+                protocols.push(String::from_utf8_lossy(read_vector_u8(&mut reader)?).into_owned());
+            }
+
+            Ok(protocols)
+        }
+        SUPPORTED_VERSIONS if handshake_type == CLIENT_HELLO => Ok(format_u16_list(
+            &read_u16_list(read_vector_u8(&mut reader)?)?,
+            version_name,
+        )),
+        SUPPORTED_VERSIONS => Ok(format_u16_list(&[reader.read_u16_be()?], version_name)),
+        KEY_SHARE => {
+            let entries = if handshake_type == CLIENT_HELLO {
+                read_vector_u16(&mut reader)?
+            } else {
+                &extension.data
+            };
+            let mut reader = BytesReader::new(entries);
+
+            let mut key_shares = Vec::new();
+            while !reader.is_empty() {
+                let group = reader.read_u16_be()?;
+                // HelloRetryRequest contains only the selected group.
+                let key_exchange = if reader.is_empty() {
+                    &[]
+                } else {
+                    read_vector_u16(&mut reader)?
+                };
+
+                key_shares.push(format!(
+                    "{} (0x{:04x}): {}",
+                    group_name(group),
+                    group,
+                    hex::encode(key_exchange)
+                ));
+            }
+
+            Ok(key_shares)
+        }
+        _ => Ok(Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vector_u8(data: &[u8]) -> Vec<u8> {
+        [&[data.len() as u8][..], data].concat()
+    }
+
+    fn vector_u16(data: &[u8]) -> Vec<u8> {
+        [&(data.len() as u16).to_be_bytes()[..], data].concat()
+    }
+
+    fn vector_u24(data: &[u8]) -> Vec<u8> {
+        [&(data.len() as u32).to_be_bytes()[1..], data].concat()
+    }
+
+    fn server_name_extension() -> Vec<u8> {
+        [
+            &SERVER_NAME.to_be_bytes()[..],
+            &vector_u16(&vector_u16(&[&[0][..], &vector_u16(b"example.com")].concat())),
+        ]
+        .concat()
+    }
+
+    fn client_hello() -> Vec<u8> {
+        let supported_versions = [
+            &SUPPORTED_VERSIONS.to_be_bytes()[..],
+            &vector_u16(&vector_u8(&[3, 4, 3, 3])),
+        ]
+        .concat();
+        let body = [
+            &[3, 3][..],
+            &[0xaa; 32],
+            &vector_u8(&[0xbb; 4]),
+            &vector_u16(&[0x13, 0x01, 0x13, 0x02]),
+            &vector_u8(&[0]),
+            &vector_u16(&[server_name_extension(), supported_versions].concat()),
+        ]
+        .concat();
+
+        [&[CLIENT_HELLO][..], &vector_u24(&body)].concat()
+    }
+
+    #[test]
+    fn client_hello_record() {
+        let record = [&[CONTENT_TYPE_HANDSHAKE, 3, 1][..], &vector_u16(&client_hello())].concat();
+        let messages = parse_tls(&record).unwrap();
+
+        assert_eq!(
+            messages.records,
+            [TlsRecord {
+                content_type: CONTENT_TYPE_HANDSHAKE,
+                version: 0x0301,
+                length: client_hello().len(),
+            }]
+        );
+
+        let [HandshakeMessage::ClientHello(client_hello)] = messages.handshakes.as_slice() else {
+            panic!("expected one ClientHello");
+        };
+        assert_eq!(client_hello.version, 0x0303);
+        assert_eq!(client_hello.session_id, [0xbb; 4]);
+        assert_eq!(client_hello.cipher_suites, [0x1301, 0x1302]);
+        assert_eq!(client_hello.compression_methods, [0]);
+        assert_eq!(
+            extension_details(&client_hello.extensions[0], CLIENT_HELLO).unwrap(),
+            ["example.com"]
+        );
+        assert_eq!(
+            extension_details(&client_hello.extensions[1], CLIENT_HELLO).unwrap(),
+            ["TLS 1.3 (0x0304)", "TLS 1.2 (0x0303)"]
+        );
+    }
+
+    #[test]
+    fn fragmented_handshake() {
+        let client_hello = client_hello();
+        let (first, second) = client_hello.split_at(10);
+        let records = [
+            &[CONTENT_TYPE_HANDSHAKE, 3, 3][..],
+            &vector_u16(first),
+            &[CONTENT_TYPE_HANDSHAKE, 3, 3],
+            &vector_u16(second),
+        ]
+        .concat();
+
+        let messages = parse_tls(&records).unwrap();
+        assert_eq!(messages.records.len(), 2);
+        assert!(matches!(messages.handshakes[..], [HandshakeMessage::ClientHello(_)]));
+    }
+
+    #[test]
+    fn certificate() {
+        // TLS 1.3: the request context and extensions for every certificate
+        let tls13 = [
+            &vector_u8(&[])[..],
+            &vector_u24(&[vector_u24(&[0x30, 0x00]), vector_u16(&[])].concat()),
+        ]
+        .concat();
+        // TLS 1.2: only the certificate list
+        let tls12 = vector_u24(&vector_u24(&[0x30, 0x00]));
+
+        for body in [tls13, tls12] {
+            let handshake = [&[CERTIFICATE][..], &vector_u24(&body)].concat();
+            assert_eq!(
+                parse_tls(&handshake).unwrap().handshakes,
+                [HandshakeMessage::Certificate(vec![vec![0x30, 0x00]])]
+            );
+        }
+    }
+
+    #[test]
+    fn truncated() {
+        let client_hello = client_hello();
+
+        for len in 1..client_hello.len() {
+            assert!(parse_tls(&client_hello[..len]).is_err());
+        }
+
+        let record = [&[CONTENT_TYPE_HANDSHAKE, 3, 3][..], &vector_u16(&client_hello)].concat();
+        assert!(parse_tls(&record[..record.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn overflowing_length() {
+        // the record length is bigger than the data
+        assert!(parse_tls(&[CONTENT_TYPE_HANDSHAKE, 3, 3, 0xff, 0xff, CLIENT_HELLO]).is_err());
+        // the handshake length is bigger than the data
+        assert!(parse_tls(&[CLIENT_HELLO, 0xff, 0xff, 0xff, 3, 3]).is_err());
+
+        // the extensions length is bigger than the ClientHello
+        let mut client_hello = client_hello();
+        let extensions_offset = 4 + 2 + 32 + 5 + 6 + 2;
+        client_hello[extensions_offset..extensions_offset + 2].copy_from_slice(&[0xff, 0xff]);
+        assert!(parse_tls(&client_hello)
+            .unwrap_err()
+            .starts_with("Invalid client_hello handshake message"));
+    }
+}
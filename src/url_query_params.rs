@@ -1,9 +1,18 @@
+use std::io::{Read, Write};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
 use serde::{Deserialize, Serialize};
 
 use crate::crypto_helper::Algorithm;
 use crate::serde::{deserialize_bytes, serialize_bytes};
 
 const APP_HOST: &str = env!("APP_HOST");
+/// Max size of the decompressed link data. Links are short, so the bigger output is a decompression bomb.
+const MAX_DECOMPRESSED_LEN: u64 = 16 * 1024 * 1024;
 
 pub fn generate_crypto_helper_link(algorithm: &Algorithm) -> String {
     let mut link = APP_HOST.to_string();
@@ -28,17 +37,89 @@ pub fn generate_jwt_link(jwt: String) -> String {
     link
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default)]
 pub struct Asn1 {
-    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    /// Hex-encoded data. It is used by the old links.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_bytes",
+        deserialize_with = "deserialize_bytes"
+    )]
     pub asn1: Vec<u8>,
+    /// Zlib-compressed base64url-encoded data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    /// Id of the selected node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<u64>,
+    /// Name of the decode profile: `DER`, `CER`, or `BER`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// JSON-encoded user notes keyed by the node id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+impl Asn1 {
+    pub fn new(asn1: &[u8], node: Option<u64>, profile: Option<&str>, notes: Option<String>) -> Self {
+        let mut compressor = ZlibEncoder::new(Vec::new(), Compression::best());
+        let compressed = compressor
+            .write_all(asn1)
+            .and_then(|_| compressor.finish())
+            .expect("Compression into the Vec should not fail");
+
+        Self {
+            asn1: Vec::new(),
+            data: Some(URL_SAFE_NO_PAD.encode(compressed)),
+            node,
+            profile: profile.map(str::to_owned),
+            notes,
+        }
+    }
+
+    /// Returns the decompressed data.
+    pub fn bytes(&self) -> Result<Vec<u8>, String> {
+        let Some(data) = self.data.as_ref() else {
+            return Ok(self.asn1.clone());
+        };
+
+        let compressed = URL_SAFE_NO_PAD
+            .decode(data)
+            .map_err(|err| format!("Invalid base64url data: {}", err))?;
+
+        let mut decompressed = Vec::new();
+        ZlibDecoder::new(compressed.as_slice())
+            .take(MAX_DECOMPRESSED_LEN + 1)
+            .read_to_end(&mut decompressed)
+            .map_err(|err| format!("Can not decompress the data: {}", err))?;
+
+        if decompressed.len() as u64 > MAX_DECOMPRESSED_LEN {
+            return Err(format!(
+                "Decompressed data is larger than {} bytes",
+                MAX_DECOMPRESSED_LEN
+            ));
+        }
+
+        Ok(decompressed)
+    }
 }
 
 pub fn generate_asn1_link(asn1: Vec<u8>) -> String {
+    generate_asn1_link_with_node(&asn1, None, None, None)
+}
+
+/// Generates the link that opens the viewer with the provided data, selected node, decode profile, and notes.
+pub fn generate_asn1_link_with_node(
+    asn1: &[u8],
+    node: Option<u64>,
+    profile: Option<&str>,
+    notes: Option<String>,
+) -> String {
     let mut link = APP_HOST.to_string();
 
     link.push_str("/asn1/?");
-    link.push_str(&serde_qs::to_string(&Asn1 { asn1 }).unwrap());
+    link.push_str(&serde_qs::to_string(&Asn1::new(asn1, node, profile, notes)).unwrap());
 
     link
 }
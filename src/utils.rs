@@ -71,3 +71,105 @@ pub fn decode_binary(input: &str) -> Result<Vec<u8>, String> {
         })
         .collect::<Result<Vec<u8>, String>>()
 }
+
+/// Starts the file downloading in the browser.
+pub fn download_bytes(file_name: &str, mime_type: &str, data: &[u8]) -> Result<(), String> {
+    use wasm_bindgen::JsCast;
+    use web_sys::HtmlAnchorElement;
+
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or("document is not available")?;
+
+    let link = document
+        .create_element("a")
+        .map_err(|err| format!("Can not create the link element: {:?}", err))?
+        .dyn_into::<HtmlAnchorElement>()
+        .map_err(|err| format!("Can not create the link element: {:?}", err))?;
+    link.set_href(&format!(
+        "data:{};base64,{}",
+        mime_type,
+        base64::engine::general_purpose::STANDARD.encode(data)
+    ));
+    link.set_download(file_name);
+    link.click();
+
+    Ok(())
+}
+
+/// Simple cursor over the byte slice. Used by binary format parsers.
+pub struct BytesReader<'data> {
+    data: &'data [u8],
+    position: usize,
+}
+
+impl<'data> BytesReader<'data> {
+    pub fn new(data: &'data [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'data [u8], String> {
+        if len > self.remaining() {
+            return Err(format!(
+                "Unexpected end of data: need {} bytes at offset {} but only {} left",
+                len,
+                self.position,
+                self.remaining()
+            ));
+        }
+
+        let bytes = &self.data[self.position..self.position + len];
+        self.position += len;
+
+        Ok(bytes)
+    }
+
+    pub fn read_remaining(&mut self) -> &'data [u8] {
+        let bytes = &self.data[self.position..];
+        self.position = self.data.len();
+
+        bytes
+    }
+
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        Ok(self.read_bytes(N)?.try_into().expect("slice length is checked"))
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, String> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    pub fn read_u16_be(&mut self) -> Result<u16, String> {
+        Ok(u16::from_be_bytes(self.read_array()?))
+    }
+
+    pub fn read_u16_le(&mut self) -> Result<u16, String> {
+        Ok(u16::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_u24_be(&mut self) -> Result<u32, String> {
+        let [b1, b2, b3] = self.read_array()?;
+
+        Ok(u32::from_be_bytes([0, b1, b2, b3]))
+    }
+
+    pub fn read_u32_be(&mut self) -> Result<u32, String> {
+        Ok(u32::from_be_bytes(self.read_array()?))
+    }
+
+    pub fn read_u32_le(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_u64_le(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.read_array()?))
+    }
+}